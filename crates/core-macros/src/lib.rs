@@ -1,6 +1,10 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{ItemFn, parse_macro_input};
+use quote::{quote, quote_spanned};
+use syn::{
+    Expr, ItemFn, LitStr, Token,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+};
 
 #[proc_macro]
 pub fn rsx(_input: TokenStream) -> TokenStream {
@@ -18,3 +22,100 @@ pub fn component(_attr: TokenStream, item: TokenStream) -> TokenStream {
         // For now, we'll just return an empty token stream
     })
 }
+
+/// A single `"key" => default` entry of `saved_state!`
+struct SavedStateEntry {
+    key: LitStr,
+    default: Expr,
+}
+
+/// Arguments of `saved_state!`: either `"key", default` or a list of
+/// `"key" => default` entries
+enum SavedStateInput {
+    Single(SavedStateEntry),
+    Group(Vec<SavedStateEntry>),
+}
+
+impl Parse for SavedStateInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            let default = input.parse()?;
+            input.parse::<Option<Token![,]>>()?;
+            return Ok(Self::Single(SavedStateEntry { key, default }));
+        }
+
+        input.parse::<Token![=>]>()?;
+        let mut entries = vec![SavedStateEntry {
+            key,
+            default: input.parse()?,
+        }];
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key = input.parse()?;
+            input.parse::<Token![=>]>()?;
+            entries.push(SavedStateEntry {
+                key,
+                default: input.parse()?,
+            });
+        }
+        Ok(Self::Group(entries))
+    }
+}
+
+/// Expand one entry to its `use_local_storage` call
+///
+/// `saved_state!` is mostly called inside function bodies, where items are
+/// scoped to their block, so each entry claims its storage key as a linker
+/// symbol instead. A second entry with the same key in the same module then
+/// fails to build with "symbol is already defined".
+fn saved_state_call(
+    SavedStateEntry { key, default }: &SavedStateEntry,
+) -> proc_macro2::TokenStream {
+    let declaration = quote_spanned! {key.span()=>
+        #[unsafe(export_name = ::std::concat!("pulse.saved_state.", ::std::module_path!(), ".", #key))]
+        static SAVED_STATE_KEY: u8 = 0;
+    };
+    quote! {
+        {
+            #declaration
+            ::pulse::use_local_storage(::std::concat!(::std::module_path!(), ".", #key), #default)
+        }
+    }
+}
+
+/// Persistent state stored under a key derived from the calling module
+///
+/// `saved_state!("theme", Theme::Dark)` expands to a `use_local_storage`
+/// call whose storage key is the module path followed by `"theme"`, e.g.
+/// `my_app::settings.theme`, so equal keys in different modules don't clash.
+/// The key is built at compile time. Declaring the same key twice within one
+/// module fails to build, since both declarations would silently share a
+/// single stored value; `cargo check` doesn't catch it, only a full build.
+///
+/// Several states can be declared in one call with `"key" => default`
+/// entries, which returns a tuple with one `(state, setter)` pair per entry.
+///
+/// ```rust,ignore
+/// use pulse::prelude::*;
+///
+/// let (volume, set_volume) = saved_state!("volume", 50u8);
+/// set_volume.set(volume.get() + 5);
+///
+/// let ((theme, _), (muted, _)) = saved_state!(
+///     "theme" => Theme::Dark,
+///     "muted" => false,
+/// );
+/// ```
+#[proc_macro]
+pub fn saved_state(input: TokenStream) -> TokenStream {
+    let entries = match parse_macro_input!(input as SavedStateInput) {
+        SavedStateInput::Single(entry) => return saved_state_call(&entry).into(),
+        SavedStateInput::Group(entries) => entries,
+    };
+    let calls = entries.iter().map(saved_state_call);
+
+    TokenStream::from(quote! {
+        ( #(#calls,)* )
+    })
+}
@@ -0,0 +1,102 @@
+//! Sequential versus parallel rendering of a multi-panel dashboard
+//!
+//! Run with `cargo bench -p pulse_core --features parallel --bench parallel`.
+//!
+//! Each iteration draws a 3x2 grid of panels into a 240x60 in-memory frame.
+//! The "light" panels render a short paragraph; the "heavy" panels wrap a few
+//! thousand words, standing in for dense charts.
+//!
+//! Baseline (release build, x86_64 Linux, rustc 1.95, one CPU core):
+//!
+//! | benchmark                  | time    |
+//! |----------------------------|---------|
+//! | dashboard/light_sequential | ~0.8 ms |
+//! | dashboard/light_parallel   | ~1.6 ms |
+//! | dashboard/heavy_sequential | ~8.5 ms |
+//! | dashboard/heavy_parallel   | ~12 ms  |
+//!
+//! With a single core these numbers show only the overhead of the separate
+//! panel buffers; rerun on a multi-core machine to see the heavy panels gain.
+//! Light panels stay dominated by buffer setup either way.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use pulse_core::parallel::ParallelPanels;
+use ratatui::{
+    Frame, Terminal,
+    backend::TestBackend,
+    layout::{Constraint, Layout, Rect},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Text drawn by one panel
+fn panel_text(words: usize) -> String {
+    (0..words)
+        .map(|index| format!("w{}", index % 97))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Areas of a 3x2 grid covering `area`
+fn grid(area: Rect) -> Vec<Rect> {
+    Layout::vertical([Constraint::Fill(1); 2])
+        .split(area)
+        .iter()
+        .flat_map(|row| {
+            Layout::horizontal([Constraint::Fill(1); 3])
+                .split(*row)
+                .to_vec()
+        })
+        .collect()
+}
+
+fn draw_panel(text: &str, area: Rect, frame: &mut Frame) {
+    let paragraph = Paragraph::new(text)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(paragraph, area);
+}
+
+fn bench_dashboard(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dashboard");
+    let mut terminal = Terminal::new(TestBackend::new(240, 60)).unwrap();
+
+    for (name, words) in [("light", 20), ("heavy", 4_000)] {
+        let text = panel_text(words);
+
+        group.bench_function(format!("{}_sequential", name), |b| {
+            b.iter(|| {
+                terminal
+                    .draw(|frame| {
+                        for area in grid(frame.area()) {
+                            draw_panel(black_box(&text), area, frame);
+                        }
+                    })
+                    .unwrap();
+            })
+        });
+
+        group.bench_function(format!("{}_parallel", name), |b| {
+            b.iter(|| {
+                terminal
+                    .draw(|frame| {
+                        let mut panels = ParallelPanels::new();
+                        for area in grid(frame.area()) {
+                            let text = &text;
+                            panels = panels.panel(area, move |area, frame| {
+                                draw_panel(black_box(text), area, frame)
+                            });
+                        }
+                        panels.render(frame);
+                    })
+                    .unwrap();
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dashboard);
+criterion_main!(benches);
@@ -0,0 +1,244 @@
+//! Right-to-left and bidirectional text
+//!
+//! Terminals draw cells strictly left to right, so Arabic or Hebrew text
+//! written into a buffer in logical order comes out backwards, and mixed
+//! strings like `"Order #42 נשלחה"` are scrambled. [`bidi_reorder`] applies
+//! the Unicode bidirectional algorithm to turn each line into visual order
+//! before it is rendered, and [`bidi_text`] also right-aligns lines whose
+//! direction is right-to-left.
+//!
+//! The app's base direction, used for lines without any strongly directional
+//! character, is provided as a context value and read with
+//! [`use_text_direction`]. Reordering works on whole lines; wrap text into
+//! lines before reordering it rather than letting a wrapping paragraph split
+//! already reordered lines.
+
+use once_cell::sync::Lazy;
+use ratatui::{
+    layout::Alignment,
+    text::{Line, Text},
+};
+use unicode_bidi::{Direction, Level, ParagraphBidiInfo, get_base_direction};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::hooks::context::{Context, create_context_with_default, use_context_with_default};
+
+static TEXT_DIRECTION: Lazy<Context<TextDirection>> =
+    Lazy::new(|| create_context_with_default(TextDirection::default()));
+
+/// Direction in which text is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextDirection {
+    /// Left to right, e.g. English
+    #[default]
+    Ltr,
+    /// Right to left, e.g. Arabic or Hebrew
+    Rtl,
+}
+
+impl TextDirection {
+    /// Direction of the first strongly directional character in `text`
+    ///
+    /// Returns `None` if `text` only contains neutral characters such as
+    /// digits, spaces and punctuation.
+    pub fn detect(text: &str) -> Option<Self> {
+        match get_base_direction(text) {
+            Direction::Ltr => Some(Self::Ltr),
+            Direction::Rtl => Some(Self::Rtl),
+            Direction::Mixed => None,
+        }
+    }
+
+    /// Alignment of a line in this direction
+    pub fn alignment(self) -> Alignment {
+        match self {
+            Self::Ltr => Alignment::Left,
+            Self::Rtl => Alignment::Right,
+        }
+    }
+
+    fn level(self) -> Level {
+        match self {
+            Self::Ltr => Level::ltr(),
+            Self::Rtl => Level::rtl(),
+        }
+    }
+}
+
+/// Mirrored form of paired punctuation, which flips in right-to-left runs
+fn mirror(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        other => other,
+    }
+}
+
+/// Reorder one line into visual order, returning it with its direction
+fn reorder_line(line: &str, base: TextDirection) -> (String, TextDirection) {
+    let direction = TextDirection::detect(line).unwrap_or(base);
+    let info = ParagraphBidiInfo::new(line, Some(direction.level()));
+    if !info.has_rtl() {
+        return (line.to_string(), direction);
+    }
+
+    // Right-to-left runs are reversed and their brackets mirrored, since
+    // terminals don't mirror glyphs themselves. Runs are reversed by
+    // grapheme, so combining marks stay after their base character.
+    let (levels, runs) = info.visual_runs(0..line.len());
+    let mut visual = String::with_capacity(line.len());
+    for run in runs {
+        let text = &line[run.clone()];
+        if levels[run.start].is_rtl() {
+            for grapheme in text.graphemes(true).rev() {
+                let mut chars = grapheme.chars();
+                if let Some(base) = chars.next() {
+                    visual.push(mirror(base));
+                    visual.push_str(chars.as_str());
+                }
+            }
+        } else {
+            visual.push_str(text);
+        }
+    }
+    (visual, direction)
+}
+
+/// Reorder every line of `text` from logical into visual order
+///
+/// The direction of each line follows its first strongly directional
+/// character; lines without one are treated as left to right.
+///
+/// # Examples
+///
+/// ```rust
+/// use pulse_core::bidi::bidi_reorder;
+///
+/// assert_eq!(bidi_reorder("hello שלום"), "hello םולש");
+/// ```
+pub fn bidi_reorder(text: &str) -> String {
+    bidi_reorder_with(text, TextDirection::Ltr)
+}
+
+/// Reorder every line of `text` into visual order with a base direction
+///
+/// `base` applies to lines without a strongly directional character.
+pub fn bidi_reorder_with(text: &str, base: TextDirection) -> String {
+    text.split('\n')
+        .map(|line| reorder_line(line, base).0)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reorder `text` into visual order, aligning each line by its direction
+///
+/// Right-to-left lines are right-aligned, so a paragraph rendered from the
+/// result reads naturally in both directions. `base` applies to lines without
+/// a strongly directional character.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::bidi::{bidi_text, use_text_direction};
+/// # use ratatui::{Frame, layout::Rect, widgets::Paragraph};
+/// # fn render(area: Rect, frame: &mut Frame, message: &str) {
+/// // In a component context:
+/// let direction = use_text_direction();
+/// frame.render_widget(Paragraph::new(bidi_text(message, direction)), area);
+/// # }
+/// ```
+pub fn bidi_text(text: &str, base: TextDirection) -> Text<'static> {
+    text.split('\n')
+        .map(|line| {
+            let (visual, direction) = reorder_line(line, base);
+            Line::from(visual).alignment(direction.alignment())
+        })
+        .collect()
+}
+
+/// Hook that returns the app's base text direction
+///
+/// Defaults to left to right. Provide another direction for a subtree with
+/// `use_context_provider(|| TextDirection::Rtl)` in a parent component.
+pub fn use_text_direction() -> TextDirection {
+    use_context_with_default(&TEXT_DIRECTION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::context::use_context_provider;
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+    #[test]
+    fn test_rtl_run_in_ltr_line_is_reversed() {
+        assert_eq!(bidi_reorder("hello שלום world"), "hello םולש world");
+        assert_eq!(bidi_reorder("plain ascii"), "plain ascii");
+    }
+
+    #[test]
+    fn test_rtl_line_keeps_ltr_runs_and_numbers_in_order() {
+        // An RTL line starts on the right, so its first word ends up last
+        assert_eq!(bidi_reorder("שלום hello"), "hello םולש");
+        assert_eq!(bidi_reorder("מחיר 42 שקל"), "לקש 42 ריחמ");
+        assert_eq!(bidi_reorder("مرحبا بالعالم"), "ملاعلاب ابحرم");
+    }
+
+    #[test]
+    fn test_combining_marks_stay_with_their_base() {
+        // Shalom with vowel points: each point follows its letter
+        let pointed = "\u{5e9}\u{5c1}\u{5b8}\u{5dc}\u{5d5}\u{5b9}\u{5dd}";
+        let visual = "\u{5dd}\u{5d5}\u{5b9}\u{5dc}\u{5e9}\u{5c1}\u{5b8}";
+        assert_eq!(bidi_reorder(pointed), visual);
+        assert_eq!(
+            bidi_reorder(&format!("say {pointed}!")),
+            format!("say {visual}!")
+        );
+    }
+
+    #[test]
+    fn test_lines_are_reordered_independently() {
+        assert_eq!(bidi_reorder("abc\nאבג\n123"), "abc\nגבא\n123");
+    }
+
+    #[test]
+    fn test_base_direction_applies_to_neutral_lines() {
+        assert_eq!(TextDirection::detect("12 + 3"), None);
+        assert_eq!(TextDirection::detect("42 שקל"), Some(TextDirection::Rtl));
+        assert_eq!(bidi_reorder_with("(1)", TextDirection::Rtl), "(1)");
+        assert_eq!(bidi_reorder("שלום (עולם)"), "(םלוע) םולש");
+
+        let text = bidi_text("hello\nשלום\n- 1 -", TextDirection::Rtl);
+        let alignments: Vec<_> = text.lines.iter().map(|line| line.alignment).collect();
+        assert_eq!(
+            alignments,
+            vec![
+                Some(Alignment::Left),
+                Some(Alignment::Right),
+                Some(Alignment::Right)
+            ]
+        );
+        assert_eq!(text.lines[1].to_string(), "םולש");
+    }
+
+    #[test]
+    fn test_text_direction_from_context() {
+        with_test_isolate(|| {
+            with_component_id("DirectionDefault", |_| {
+                assert_eq!(use_text_direction(), TextDirection::Ltr);
+            });
+            with_component_id("DirectionProvider", |_| {
+                use_context_provider(|| TextDirection::Rtl);
+                assert_eq!(use_text_direction(), TextDirection::Rtl);
+            });
+        });
+    }
+}
@@ -0,0 +1,85 @@
+//! Injectable time source for render-loop timing
+//!
+//! Hooks that measure time between frames read it from [`now`] instead of
+//! `Instant::now`, so tests can control it. A [`ManualClock`] freezes time on
+//! the current thread until it is advanced by hand, which makes timing
+//! behaviour deterministic:
+//!
+//! ```rust
+//! use pulse_core::clock::{ManualClock, now};
+//! use std::time::Duration;
+//!
+//! let clock = ManualClock::start();
+//! let before = now();
+//! clock.advance(Duration::from_millis(250));
+//! assert_eq!(now() - before, Duration::from_millis(250));
+//! ```
+//!
+//! The clock is per thread, matching the render loop, which runs all hooks
+//! on a single thread.
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static MANUAL_TIME: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// The current time on this thread
+///
+/// This is `Instant::now()` unless a [`ManualClock`] is active.
+pub fn now() -> Instant {
+    MANUAL_TIME.with(Cell::get).unwrap_or_else(Instant::now)
+}
+
+/// A clock that only moves when advanced, replacing real time on this thread
+///
+/// Real time comes back when the clock is dropped.
+#[derive(Debug)]
+pub struct ManualClock {
+    previous: Option<Instant>,
+    // Bound to the thread whose time it replaces
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ManualClock {
+    /// Freeze this thread's time at the current instant
+    pub fn start() -> Self {
+        let previous = MANUAL_TIME.with(|time| time.replace(Some(now())));
+        Self {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Move this thread's time forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        MANUAL_TIME.with(|time| time.set(Some(now() + duration)));
+    }
+}
+
+impl Drop for ManualClock {
+    fn drop(&mut self) {
+        MANUAL_TIME.with(|time| time.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock_only_moves_when_advanced() {
+        let clock = ManualClock::start();
+        let start = now();
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(now(), start);
+
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(now() - start, Duration::from_secs(2));
+
+        drop(clock);
+        assert!(now() < start + Duration::from_secs(2));
+    }
+}
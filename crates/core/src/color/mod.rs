@@ -0,0 +1,228 @@
+//! Terminal color capability detection and graceful degradation
+//!
+//! Many terminals cannot render 24-bit `Color::Rgb` values: the Linux console
+//! and older emulators only understand the 16 basic ANSI colors, and others are
+//! limited to the 256-color xterm palette. This module detects what the current
+//! terminal supports and maps colors down to the nearest supported palette
+//! entry, so components and themes can use RGB freely and still look right.
+
+use ratatui::style::{Color, Style};
+
+mod mode;
+mod theme;
+
+pub use mode::{
+    ColorMode, apply_color_mode, color_mode, colors_enabled, set_color_mode, strip_colors,
+};
+
+pub use theme::{HIGH_CONTRAST_MIN_RATIO, Theme, color_to_rgb, contrast_ratio, relative_luminance};
+
+#[cfg(test)]
+mod tests;
+
+/// Level of color support offered by the terminal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorSupport {
+    /// No color support (e.g. `TERM=dumb`)
+    Monochrome,
+    /// The 16 basic ANSI colors
+    Ansi16,
+    /// The 256-color xterm palette
+    Ansi256,
+    /// 24-bit RGB colors
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Detect the color support of the current terminal from the environment
+    pub fn detect() -> Self {
+        let var = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        detect_color_support(
+            var("TERM").as_deref(),
+            var("TERM_PROGRAM").as_deref(),
+            var("COLORTERM").as_deref(),
+        )
+    }
+}
+
+/// Determine the color support level from terminal environment variables
+pub fn detect_color_support(
+    term: Option<&str>,
+    term_program: Option<&str>,
+    colorterm: Option<&str>,
+) -> ColorSupport {
+    if let Some(colorterm) = colorterm {
+        let colorterm = colorterm.to_ascii_lowercase();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorSupport::TrueColor;
+        }
+    }
+
+    match term_program {
+        Some("iTerm.app" | "WezTerm" | "vscode" | "Hyper" | "ghostty") => {
+            return ColorSupport::TrueColor;
+        }
+        Some("Apple_Terminal") => return ColorSupport::Ansi256,
+        _ => {}
+    }
+
+    match term {
+        Some("dumb") => ColorSupport::Monochrome,
+        Some(term) if term.contains("truecolor") || term.contains("direct") => {
+            ColorSupport::TrueColor
+        }
+        Some(term) if term.contains("256") => ColorSupport::Ansi256,
+        _ => ColorSupport::Ansi16,
+    }
+}
+
+/// The 16 basic ANSI colors with their default xterm RGB values
+const ANSI16_PALETTE: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Intensity levels of the 6x6x6 color cube in the 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Perceptually weighted squared distance between two RGB colors ("redmean")
+fn color_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let mean_r = (a.0 as i32 + b.0 as i32) / 2;
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+    (((512 + mean_r) * dr * dr) / 256 + 4 * dg * dg + ((767 - mean_r) * db * db) / 256) as u32
+}
+
+/// Index of the cube level closest to a color component
+fn nearest_cube_level(value: u8) -> usize {
+    match value {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => ((value as usize - 35) / 40).min(5),
+    }
+}
+
+/// Convert an RGB color to the nearest entry of the 256-color xterm palette
+///
+/// Only the color cube (16-231) and the grayscale ramp (232-255) are considered,
+/// since the first 16 entries are commonly remapped by terminal themes.
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let (ri, gi, bi) = (
+        nearest_cube_level(r),
+        nearest_cube_level(g),
+        nearest_cube_level(b),
+    );
+    let cube_rgb = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_step = if average < 8 {
+        0
+    } else {
+        ((average - 8) / 10).min(23)
+    };
+    // Round to the closer of the two neighbouring ramp entries
+    let gray_step = if gray_step < 23 && average >= 8 + gray_step * 10 + 5 {
+        gray_step + 1
+    } else {
+        gray_step
+    };
+    let gray_level = (8 + gray_step * 10) as u8;
+    let gray_index = 232 + gray_step as usize;
+
+    let target = (r, g, b);
+    if color_distance(target, (gray_level, gray_level, gray_level))
+        < color_distance(target, cube_rgb)
+    {
+        gray_index as u8
+    } else {
+        cube_index as u8
+    }
+}
+
+/// Convert an RGB color to the nearest of the 16 basic ANSI colors
+pub fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16_PALETTE
+        .iter()
+        .min_by_key(|(_, rgb)| color_distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+/// Get the RGB value of a 256-color palette entry
+pub fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16_PALETTE[index as usize].1,
+        16..=231 => {
+            let offset = index as usize - 16;
+            (
+                CUBE_LEVELS[offset / 36],
+                CUBE_LEVELS[(offset / 6) % 6],
+                CUBE_LEVELS[offset % 6],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+/// Map a color down to the nearest color the terminal can display
+///
+/// Named ANSI colors are kept as-is (except in monochrome mode), RGB colors are
+/// mapped to the 256-color palette or the 16 basic colors, and indexed colors
+/// are mapped to the 16 basic colors when needed.
+///
+/// # Example
+/// ```
+/// use pulse_core::color::{ColorSupport, adapt_color};
+/// use ratatui::style::Color;
+///
+/// let orange = Color::Rgb(255, 135, 0);
+/// assert_eq!(adapt_color(orange, ColorSupport::TrueColor), orange);
+/// assert_eq!(adapt_color(orange, ColorSupport::Ansi256), Color::Indexed(208));
+/// ```
+pub fn adapt_color(color: Color, support: ColorSupport) -> Color {
+    match (support, color) {
+        (_, Color::Reset) => Color::Reset,
+        (ColorSupport::Monochrome, _) => Color::Reset,
+        (ColorSupport::TrueColor, color) => color,
+        (ColorSupport::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        (ColorSupport::Ansi16, Color::Rgb(r, g, b)) => rgb_to_ansi16(r, g, b),
+        (ColorSupport::Ansi16, Color::Indexed(index)) => {
+            let (r, g, b) = ansi256_to_rgb(index);
+            rgb_to_ansi16(r, g, b)
+        }
+        (_, color) => color,
+    }
+}
+
+/// Map all colors of a style down to what the terminal can display
+///
+/// Modifiers such as bold or underline are preserved.
+pub fn adapt_style(style: Style, support: ColorSupport) -> Style {
+    Style {
+        fg: style.fg.map(|color| adapt_color(color, support)),
+        bg: style.bg.map(|color| adapt_color(color, support)),
+        underline_color: style
+            .underline_color
+            .map(|color| adapt_color(color, support)),
+        ..style
+    }
+}
@@ -0,0 +1,93 @@
+//! Global color mode
+//!
+//! Lets applications turn colors off entirely, for accessibility or when the
+//! output is piped somewhere that cannot interpret color escape codes. In
+//! [`ColorMode::Auto`] the [`NO_COLOR`](https://no-color.org) convention is
+//! respected.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use ratatui::{buffer::Buffer, style::Color};
+
+static COLOR_MODE: AtomicU8 = AtomicU8::new(ColorMode::Auto as u8);
+
+/// Controls whether rendered cells keep their colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorMode {
+    /// Use colors unless the `NO_COLOR` environment variable is set
+    #[default]
+    Auto = 0,
+    /// Always render colors, ignoring `NO_COLOR`
+    Always = 1,
+    /// Never render colors; modifiers such as bold are kept
+    Never = 2,
+}
+
+impl ColorMode {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => ColorMode::Always,
+            2 => ColorMode::Never,
+            _ => ColorMode::Auto,
+        }
+    }
+}
+
+/// Set the global color mode
+///
+/// # Example
+/// ```
+/// use pulse_core::color::{ColorMode, color_mode, set_color_mode};
+///
+/// set_color_mode(ColorMode::Never);
+/// assert_eq!(color_mode(), ColorMode::Never);
+/// set_color_mode(ColorMode::Auto);
+/// ```
+pub fn set_color_mode(mode: ColorMode) {
+    COLOR_MODE.store(mode as u8, Ordering::Release);
+}
+
+/// Get the current global color mode
+pub fn color_mode() -> ColorMode {
+    ColorMode::from_u8(COLOR_MODE.load(Ordering::Acquire))
+}
+
+/// Returns true if colors should be rendered under the current color mode
+pub fn colors_enabled() -> bool {
+    mode_enables_colors(color_mode())
+}
+
+/// Returns true if colors should be rendered under `mode`
+fn mode_enables_colors(mode: ColorMode) -> bool {
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()),
+    }
+}
+
+/// Reset the foreground, background and underline colors of every cell
+///
+/// Modifiers such as bold, italic or reversed are preserved, so emphasis
+/// survives in monochrome output.
+pub fn strip_colors(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+        cell.underline_color = Color::Reset;
+    }
+}
+
+/// Apply the global color mode to a rendered buffer
+///
+/// Called by the runtime after each frame is rendered.
+pub fn apply_color_mode(buffer: &mut Buffer) {
+    apply_mode(color_mode(), buffer);
+}
+
+/// Apply `mode` to a rendered buffer
+pub(crate) fn apply_mode(mode: ColorMode, buffer: &mut Buffer) {
+    if !mode_enables_colors(mode) {
+        strip_colors(buffer);
+    }
+}
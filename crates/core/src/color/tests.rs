@@ -0,0 +1,178 @@
+use super::mode::apply_mode;
+use super::*;
+use ratatui::style::Modifier;
+
+#[test]
+fn test_rgb_to_ansi256_color_cube() {
+    // Exact cube entries map to themselves
+    assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+    assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+    assert_eq!(rgb_to_ansi256(0, 255, 0), 46);
+    assert_eq!(rgb_to_ansi256(0, 0, 255), 21);
+    assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+    assert_eq!(rgb_to_ansi256(95, 135, 175), 67);
+
+    // Near-cube colors snap to the closest level
+    assert_eq!(rgb_to_ansi256(250, 5, 5), 196);
+    assert_eq!(rgb_to_ansi256(255, 135, 0), 208);
+}
+
+#[test]
+fn test_rgb_to_ansi256_grayscale_ramp() {
+    assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+    assert_eq!(rgb_to_ansi256(8, 8, 8), 232);
+    assert_eq!(rgb_to_ansi256(238, 238, 238), 255);
+    // Slightly tinted grays still prefer the ramp
+    assert_eq!(rgb_to_ansi256(100, 102, 98), 241);
+}
+
+#[test]
+fn test_rgb_to_ansi256_round_trips_palette() {
+    for index in 16..=255u8 {
+        let (r, g, b) = ansi256_to_rgb(index);
+        let mapped = rgb_to_ansi256(r, g, b);
+        assert_eq!(
+            ansi256_to_rgb(mapped),
+            (r, g, b),
+            "palette entry {} mapped to {}",
+            index,
+            mapped
+        );
+    }
+}
+
+#[test]
+fn test_rgb_to_ansi16() {
+    assert_eq!(rgb_to_ansi16(0, 0, 0), Color::Black);
+    assert_eq!(rgb_to_ansi16(10, 10, 10), Color::Black);
+    assert_eq!(rgb_to_ansi16(200, 0, 0), Color::Red);
+    assert_eq!(rgb_to_ansi16(250, 10, 10), Color::LightRed);
+    assert_eq!(rgb_to_ansi16(0, 190, 0), Color::Green);
+    assert_eq!(rgb_to_ansi16(0, 0, 230), Color::Blue);
+    assert_eq!(rgb_to_ansi16(128, 128, 128), Color::DarkGray);
+    assert_eq!(rgb_to_ansi16(230, 230, 230), Color::Gray);
+    assert_eq!(rgb_to_ansi16(255, 255, 255), Color::White);
+    assert_eq!(rgb_to_ansi16(255, 255, 60), Color::LightYellow);
+    assert_eq!(rgb_to_ansi16(0, 200, 210), Color::Cyan);
+}
+
+#[test]
+fn test_adapt_color_by_support_level() {
+    let teal = Color::Rgb(0, 175, 175);
+
+    assert_eq!(adapt_color(teal, ColorSupport::TrueColor), teal);
+    assert_eq!(adapt_color(teal, ColorSupport::Ansi256), Color::Indexed(37));
+    assert_eq!(adapt_color(teal, ColorSupport::Ansi16), Color::Cyan);
+    assert_eq!(adapt_color(teal, ColorSupport::Monochrome), Color::Reset);
+
+    // Indexed colors degrade to the 16 basic colors
+    assert_eq!(
+        adapt_color(Color::Indexed(196), ColorSupport::Ansi16),
+        Color::LightRed
+    );
+    assert_eq!(
+        adapt_color(Color::Indexed(196), ColorSupport::Ansi256),
+        Color::Indexed(196)
+    );
+
+    // Named colors are already supported everywhere but monochrome terminals
+    assert_eq!(
+        adapt_color(Color::Green, ColorSupport::Ansi16),
+        Color::Green
+    );
+    assert_eq!(
+        adapt_color(Color::Green, ColorSupport::Monochrome),
+        Color::Reset
+    );
+}
+
+#[test]
+fn test_adapt_style_keeps_modifiers() {
+    let style = Style::default()
+        .fg(Color::Rgb(255, 0, 0))
+        .bg(Color::Rgb(0, 0, 0))
+        .add_modifier(Modifier::BOLD);
+
+    let adapted = adapt_style(style, ColorSupport::Ansi16);
+    assert_eq!(adapted.fg, Some(Color::LightRed));
+    assert_eq!(adapted.bg, Some(Color::Black));
+    assert!(adapted.add_modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn test_detect_color_support() {
+    assert_eq!(
+        detect_color_support(Some("xterm-256color"), None, Some("truecolor")),
+        ColorSupport::TrueColor
+    );
+    assert_eq!(
+        detect_color_support(Some("xterm-256color"), None, None),
+        ColorSupport::Ansi256
+    );
+    assert_eq!(
+        detect_color_support(Some("linux"), None, None),
+        ColorSupport::Ansi16
+    );
+    assert_eq!(
+        detect_color_support(Some("dumb"), None, None),
+        ColorSupport::Monochrome
+    );
+    assert_eq!(
+        detect_color_support(None, Some("iTerm.app"), None),
+        ColorSupport::TrueColor
+    );
+    assert!(ColorSupport::TrueColor > ColorSupport::Ansi256);
+    assert!(ColorSupport::Ansi16 > ColorSupport::Monochrome);
+}
+
+#[test]
+fn test_strip_colors_keeps_modifiers() {
+    let area = ratatui::layout::Rect::new(0, 0, 4, 1);
+    let mut buffer = ratatui::buffer::Buffer::empty(area);
+    buffer.set_string(
+        0,
+        0,
+        "bold",
+        Style::default()
+            .fg(Color::Red)
+            .bg(Color::Rgb(10, 20, 30))
+            .underline_color(Color::Yellow)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+    );
+
+    strip_colors(&mut buffer);
+
+    for cell in buffer.content.iter() {
+        assert_eq!(cell.fg, Color::Reset);
+        assert_eq!(cell.bg, Color::Reset);
+        assert_eq!(cell.underline_color, Color::Reset);
+        assert!(
+            cell.modifier
+                .contains(Modifier::BOLD | Modifier::UNDERLINED)
+        );
+    }
+    assert_eq!(buffer[(0, 0)].symbol(), "b");
+}
+
+#[test]
+fn test_color_mode_never_produces_reset_cells() {
+    let area = ratatui::layout::Rect::new(0, 0, 3, 1);
+    let style = Style::default().fg(Color::Green).bg(Color::Blue);
+
+    // Uses the mode directly rather than the global one, which other tests
+    // running in parallel may be rendering with
+    let mut colored = ratatui::buffer::Buffer::empty(area);
+    colored.set_string(0, 0, "abc", style);
+    apply_mode(ColorMode::Always, &mut colored);
+    assert_eq!(colored[(0, 0)].fg, Color::Green);
+    assert_eq!(colored[(0, 0)].bg, Color::Blue);
+
+    let mut monochrome = ratatui::buffer::Buffer::empty(area);
+    monochrome.set_string(0, 0, "abc", style);
+    apply_mode(ColorMode::Never, &mut monochrome);
+
+    for cell in monochrome.content.iter() {
+        assert_eq!(cell.fg, Color::Reset);
+        assert_eq!(cell.bg, Color::Reset);
+    }
+}
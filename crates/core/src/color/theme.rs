@@ -0,0 +1,164 @@
+//! Application themes and high-contrast variants
+
+use ratatui::style::Color;
+
+use super::ansi256_to_rgb;
+
+/// Minimum contrast ratio enforced by [`Theme::high_contrast`]
+///
+/// This is the WCAG AAA threshold for normal text.
+pub const HIGH_CONTRAST_MIN_RATIO: f64 = 7.0;
+
+/// A set of semantic colors used to style an application
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    pub primary: Color,
+    pub secondary: Color,
+    pub accent: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub danger: Color,
+    pub background: Color,
+    pub text: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            primary: Color::Rgb(99, 102, 241),    // Indigo
+            secondary: Color::Rgb(107, 114, 128), // Gray
+            accent: Color::Rgb(168, 85, 247),     // Purple
+            success: Color::Rgb(34, 197, 94),     // Green
+            warning: Color::Rgb(251, 191, 36),    // Amber
+            danger: Color::Rgb(239, 68, 68),      // Red
+            background: Color::Rgb(17, 24, 39),   // Dark
+            text: Color::Rgb(243, 244, 246),      // Light gray
+        }
+    }
+}
+
+impl Theme {
+    /// All foreground colors of the theme, in field order
+    pub fn foregrounds(&self) -> [Color; 7] {
+        [
+            self.primary,
+            self.secondary,
+            self.accent,
+            self.success,
+            self.warning,
+            self.danger,
+            self.text,
+        ]
+    }
+
+    /// Generate a high-contrast variant of this theme
+    ///
+    /// The background is pushed to pure black (for dark themes) or pure white
+    /// (for light themes), and every foreground color is blended towards the
+    /// opposite extreme until it reaches [`HIGH_CONTRAST_MIN_RATIO`] against the
+    /// background. Hues are kept as far as the contrast requirement allows.
+    ///
+    /// # Example
+    /// ```
+    /// use pulse_core::color::{HIGH_CONTRAST_MIN_RATIO, Theme, contrast_ratio};
+    ///
+    /// let theme = Theme::default().high_contrast();
+    /// assert!(contrast_ratio(theme.secondary, theme.background) >= HIGH_CONTRAST_MIN_RATIO);
+    /// ```
+    pub fn high_contrast(&self) -> Self {
+        let dark = relative_luminance(self.background) < 0.5;
+        let (background, target) = if dark {
+            ((0, 0, 0), (255, 255, 255))
+        } else {
+            ((255, 255, 255), (0, 0, 0))
+        };
+        let background_color = Color::Rgb(background.0, background.1, background.2);
+
+        let boost = |color: Color| {
+            let rgb = color_to_rgb(color).unwrap_or(target);
+            let mut step = 0;
+            loop {
+                let mixed = mix(rgb, target, step as f64 / 20.0);
+                let candidate = Color::Rgb(mixed.0, mixed.1, mixed.2);
+                if step == 20
+                    || contrast_ratio(candidate, background_color) >= HIGH_CONTRAST_MIN_RATIO
+                {
+                    return candidate;
+                }
+                step += 1;
+            }
+        };
+
+        Self {
+            primary: boost(self.primary),
+            secondary: boost(self.secondary),
+            accent: boost(self.accent),
+            success: boost(self.success),
+            warning: boost(self.warning),
+            danger: boost(self.danger),
+            background: background_color,
+            text: boost(self.text),
+        }
+    }
+}
+
+/// Get the RGB value of a color, or `None` for [`Color::Reset`]
+///
+/// Named and indexed colors use the default xterm palette.
+pub fn color_to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let index = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => return Some((r, g, b)),
+        Color::Indexed(index) => index,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+    };
+    Some(ansi256_to_rgb(index))
+}
+
+/// Relative luminance of a color as defined by WCAG 2.x, in `0.0..=1.0`
+///
+/// [`Color::Reset`] is treated as black, matching the usual dark terminal default.
+pub fn relative_luminance(color: Color) -> f64 {
+    let (r, g, b) = color_to_rgb(color).unwrap_or((0, 0, 0));
+    let channel = |value: u8| {
+        let value = value as f64 / 255.0;
+        if value <= 0.03928 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+}
+
+/// WCAG contrast ratio between two colors, in `1.0..=21.0`
+pub fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    let (lighter, darker) = if la > lb { (la, lb) } else { (lb, la) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Linearly blend `from` towards `to` by `amount` (`0.0..=1.0`)
+fn mix(from: (u8, u8, u8), to: (u8, u8, u8), amount: f64) -> (u8, u8, u8) {
+    let blend = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * amount).round() as u8;
+    (
+        blend(from.0, to.0),
+        blend(from.1, to.1),
+        blend(from.2, to.2),
+    )
+}
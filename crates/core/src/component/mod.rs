@@ -1,10 +1,16 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::collections::HashMap;
 
+use crate::hooks::effect::CleanupFn;
+
 thread_local! {
     // Track mounted component instances and their mount states
     static MOUNT_STATE: std::cell::RefCell<MountState> = Default::default();
+    // The component currently being rendered by render_with_mount
+    static CURRENT_COMPONENT: Cell<Option<usize>> = const { Cell::new(None) };
 }
 
 // Component wrapper that can be stored and called for unmounting
@@ -32,6 +38,8 @@ struct MountState {
     current_render: std::collections::HashSet<usize>,
     // Store component wrappers for unmount callbacks
     component_refs: HashMap<usize, ComponentWrapper>,
+    // Effect cleanups to run when the component unmounts
+    cleanups: HashMap<usize, Vec<CleanupFn>>,
 }
 
 impl MountState {
@@ -53,7 +61,8 @@ impl MountState {
         is_new
     }
 
-    fn cleanup_unmounted(&mut self) {
+    /// Returns the unmounted components and their effect cleanups
+    fn cleanup_unmounted(&mut self) -> (Vec<usize>, Vec<CleanupFn>) {
         // Find components that were mounted before but not in current render
         let unmounted: Vec<_> = self
             .mounted
@@ -62,15 +71,18 @@ impl MountState {
             .collect();
 
         // Call on_unmount for each unmounted component
+        let mut cleanups = Vec::new();
         for &id_hash in &unmounted {
             if let Some(wrapper) = self.component_refs.remove(&id_hash) {
                 wrapper.call_unmount();
             }
+            cleanups.extend(self.cleanups.remove(&id_hash).unwrap_or_default());
             self.mounted.remove(&id_hash);
         }
 
         // Prepare for next render
         self.current_render.clear();
+        (unmounted, cleanups)
     }
 }
 
@@ -85,21 +97,18 @@ pub trait Component: Clone + 'static {
     fn render(&self, area: Rect, frame: &mut Frame);
 
     /// Gets a unique identifier for this component instance
-    fn component_id(&self) -> String {
-        // Default implementation uses the type name
-        std::any::type_name::<Self>().to_string()
+    ///
+    /// The default borrows the type name, so looking it up on every render
+    /// doesn't allocate. Components that need per-instance ids can return an
+    /// owned string instead.
+    fn component_id(&self) -> Cow<'static, str> {
+        Cow::Borrowed(std::any::type_name::<Self>())
     }
 
     /// Renders the component with mount/unmount lifecycle tracking
     fn render_with_mount(&self, area: Rect, frame: &mut Frame) {
-        let component_id = self.component_id();
-        let id_hash = {
-            use std::collections::hash_map::DefaultHasher;
-            use std::hash::{Hash, Hasher};
-            let mut hasher = DefaultHasher::new();
-            component_id.hash(&mut hasher);
-            hasher.finish() as usize
-        };
+        let id = self.component_id();
+        let id_hash = component_hash(&id);
 
         // Track this component in the current render
         let is_first_render = MOUNT_STATE.with(|state| {
@@ -112,18 +121,92 @@ pub trait Component: Clone + 'static {
             self.on_mount();
         }
 
-        // Call the actual render method
-        self.render(area, frame);
+        // Call the actual render method, attributing effects to this component
+        let parent = CURRENT_COMPONENT.with(|current| current.replace(Some(id_hash)));
+        crate::devtools::enter_component(&id, id_hash);
+        // Context values provided by this component only reach its subtree
+        crate::hooks::context::with_context_scope(|| self.render(area, frame));
+        crate::devtools::exit_component();
+        CURRENT_COMPONENT.with(|current| current.set(parent));
     }
 }
 
+/// Hash identifying a component instance in the mount state
+fn component_hash(component_id: &str) -> usize {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let mut hasher = DefaultHasher::new();
+    component_id.hash(&mut hasher);
+    hasher.finish() as usize
+}
+
+/// Run `render` and return the components it rendered with mount tracking
+///
+/// Used to keep a subtree mounted in frames where it isn't rendered again.
+pub(crate) fn collect_rendered<R>(render: impl FnOnce() -> R) -> (R, Vec<usize>) {
+    let before = MOUNT_STATE.with(|state| state.borrow().current_render.clone());
+    let result = render();
+    let rendered = MOUNT_STATE.with(|state| {
+        state
+            .borrow()
+            .current_render
+            .difference(&before)
+            .copied()
+            .collect()
+    });
+    (result, rendered)
+}
+
+/// Keep the given components mounted in the current render cycle
+pub(crate) fn keep_mounted(rendered: &[usize]) {
+    MOUNT_STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        for id_hash in rendered {
+            if state.mounted.contains(id_hash) {
+                state.current_render.insert(*id_hash);
+            }
+        }
+    });
+}
+
+/// Mount-state key of the component currently being rendered
+///
+/// `None` outside of `render_with_mount`.
+pub(crate) fn current_component() -> Option<usize> {
+    CURRENT_COMPONENT.with(Cell::get)
+}
+
+/// Run `cleanup` when the component currently being rendered unmounts
+///
+/// Effects register their cleanup here so the tasks they spawned don't
+/// outlive the component. Outside of `render_with_mount` this does nothing.
+pub(crate) fn register_unmount_cleanup(cleanup: CleanupFn) {
+    let Some(id_hash) = current_component() else {
+        return;
+    };
+    MOUNT_STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let cleanups = state.cleanups.entry(id_hash).or_default();
+        // Cleanups of effects that re-ran have already been called
+        cleanups.retain(|cleanup| !cleanup.is_done());
+        cleanups.push(cleanup);
+    });
+}
+
 /// Cleans up any components that were unmounted in the last render cycle
 /// This should be called after each render cycle
 pub fn cleanup_unmounted() {
-    MOUNT_STATE.with(|state| {
+    let (unmounted, cleanups) = MOUNT_STATE.with(|state| {
         let mut state = state.borrow_mut();
-        state.cleanup_unmounted();
+        state.cleanup_unmounted()
     });
+    crate::devtools::forget_components(&unmounted);
+
+    // Clean up the effects of unmounted components, which aborts the tasks
+    // they spawned
+    for cleanup in cleanups {
+        cleanup.cleanup();
+    }
 }
 
 impl<T: Component> crate::IntoElement for T {
@@ -29,8 +29,8 @@ impl TestComponent {
 }
 
 impl Component for TestComponent {
-    fn component_id(&self) -> String {
-        format!("test_component_{}", self.id)
+    fn component_id(&self) -> Cow<'static, str> {
+        format!("test_component_{}", self.id).into()
     }
 
     fn on_mount(&self) {
@@ -307,6 +307,26 @@ fn test_component_lifecycle_integration() {
     });
 }
 
+// Default ids borrow the type name instead of allocating per call
+#[test]
+fn test_default_component_id_is_static() {
+    #[derive(Clone)]
+    struct Plain;
+
+    impl Component for Plain {
+        fn render(&self, _area: Rect, _frame: &mut Frame) {}
+    }
+
+    let first = Plain.component_id();
+    let second = Plain.component_id();
+
+    assert!(matches!(first, Cow::Borrowed(_)));
+    assert!(matches!(second, Cow::Borrowed(_)));
+    assert_eq!(first, std::any::type_name::<Plain>());
+    // Both calls hand out the very same static string
+    assert_eq!(first.as_ptr(), second.as_ptr());
+}
+
 // Test for component ID stability and tracking
 #[test]
 fn test_component_id_stability() {
@@ -827,8 +847,8 @@ fn test_component_lifecycle_error_recovery() {
     }
 
     impl Component for PanicComponent {
-        fn component_id(&self) -> String {
-            format!("panic_component_{}", self.id)
+        fn component_id(&self) -> Cow<'static, str> {
+            format!("panic_component_{}", self.id).into()
         }
 
         fn on_mount(&self) {
@@ -888,15 +908,15 @@ fn test_component_id_uniqueness_across_types() {
     }
 
     impl Component for ComponentTypeA {
-        fn component_id(&self) -> String {
-            format!("type_a_{}", self.id)
+        fn component_id(&self) -> Cow<'static, str> {
+            format!("type_a_{}", self.id).into()
         }
         fn render(&self, _area: Rect, _frame: &mut Frame) {}
     }
 
     impl Component for ComponentTypeB {
-        fn component_id(&self) -> String {
-            format!("type_b_{}", self.id)
+        fn component_id(&self) -> Cow<'static, str> {
+            format!("type_b_{}", self.id).into()
         }
         fn render(&self, _area: Rect, _frame: &mut Frame) {}
     }
@@ -934,8 +954,8 @@ fn test_component_lifecycle_with_nested_renders() {
     }
 
     impl Component for NestedRenderComponent {
-        fn component_id(&self) -> String {
-            format!("nested_{}", self.id)
+        fn component_id(&self) -> Cow<'static, str> {
+            format!("nested_{}", self.id).into()
         }
 
         fn on_mount(&self) {
@@ -1084,9 +1104,9 @@ fn test_component_lifecycle_with_dynamic_ids() {
     }
 
     impl Component for DynamicIdComponent {
-        fn component_id(&self) -> String {
+        fn component_id(&self) -> Cow<'static, str> {
             let counter = *self.counter.lock().unwrap();
-            format!("dynamic_{}_{}", self.base_id, counter)
+            format!("dynamic_{}_{}", self.base_id, counter).into()
         }
 
         fn on_mount(&self) {
@@ -1130,8 +1150,8 @@ fn test_zero_sized_component_handling() {
     struct ZeroSizedComponent;
 
     impl Component for ZeroSizedComponent {
-        fn component_id(&self) -> String {
-            "zero_sized_component".to_string()
+        fn component_id(&self) -> Cow<'static, str> {
+            "zero_sized_component".into()
         }
 
         fn render(&self, _area: Rect, _frame: &mut Frame) {}
@@ -1178,8 +1198,8 @@ fn test_component_lifecycle_ordering() {
     }
 
     impl Component for OrderTestComponent {
-        fn component_id(&self) -> String {
-            format!("order_test_{}", self.id)
+        fn component_id(&self) -> Cow<'static, str> {
+            format!("order_test_{}", self.id).into()
         }
 
         fn on_mount(&self) {
@@ -1254,3 +1274,144 @@ fn test_component_lifecycle_ordering() {
     // comp2 should not have unmount events
     assert!(!log.iter().any(|entry| entry.contains("comp2_unmount")));
 }
+
+/// Component whose effect spawns a task that runs until aborted
+#[derive(Clone)]
+struct TickerComponent {
+    ticks: Arc<std::sync::atomic::AtomicUsize>,
+    dropped: Arc<std::sync::atomic::AtomicBool>,
+}
+
+impl Component for TickerComponent {
+    fn render(&self, _area: Rect, _frame: &mut Frame) {
+        use crate::hooks::effect::{spawn_effect_task, use_effect};
+        use std::sync::atomic::Ordering;
+
+        let ticks = self.ticks.clone();
+        let dropped = self.dropped.clone();
+        use_effect(
+            move || {
+                spawn_effect_task(async move {
+                    // Flags when the task's future is dropped by the abort
+                    struct DropFlag(Arc<std::sync::atomic::AtomicBool>);
+                    impl Drop for DropFlag {
+                        fn drop(&mut self) {
+                            self.0.store(true, Ordering::SeqCst);
+                        }
+                    }
+                    let _flag = DropFlag(dropped);
+
+                    loop {
+                        ticks.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                    }
+                });
+                None::<fn()>
+            },
+            (),
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_unmount_aborts_effect_tasks() {
+    use crate::hooks::{HookContext, clear_hook_context, set_hook_context};
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    let component = TickerComponent {
+        ticks: Arc::new(AtomicUsize::new(0)),
+        dropped: Arc::new(AtomicBool::new(false)),
+    };
+    let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+    set_hook_context(Rc::new(HookContext::new()));
+
+    terminal
+        .draw(|frame| component.render_with_mount(frame.area(), frame))
+        .unwrap();
+    cleanup_unmounted();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(component.ticks.load(Ordering::SeqCst) > 0);
+    assert!(!component.dropped.load(Ordering::SeqCst));
+
+    // Next frame without the component: it unmounts and its task is aborted
+    cleanup_unmounted();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert!(component.dropped.load(Ordering::SeqCst));
+
+    let ticks = component.ticks.load(Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(component.ticks.load(Ordering::SeqCst), ticks);
+
+    clear_hook_context();
+}
+
+/// Component whose async effect waits for `release` before resolving
+#[derive(Clone)]
+struct AsyncSubscriber {
+    release: Arc<tokio::sync::Notify>,
+    effects: Arc<std::sync::atomic::AtomicUsize>,
+    cleanups: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl Component for AsyncSubscriber {
+    fn render(&self, _area: Rect, _frame: &mut Frame) {
+        use crate::hooks::effect::use_async_effect_once;
+        use std::sync::atomic::Ordering;
+
+        let this = self.clone();
+        use_async_effect_once(move || async move {
+            this.release.notified().await;
+            this.effects.fetch_add(1, Ordering::SeqCst);
+            move || async move {
+                this.cleanups.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+    }
+}
+
+#[tokio::test]
+async fn test_unmount_stops_async_effects() {
+    use crate::hooks::{HookContext, clear_hook_context, set_hook_context};
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    let component = AsyncSubscriber {
+        release: Arc::new(tokio::sync::Notify::new()),
+        effects: Arc::new(AtomicUsize::new(0)),
+        cleanups: Arc::new(AtomicUsize::new(0)),
+    };
+    let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+    let mut mount = |component: &AsyncSubscriber| {
+        set_hook_context(Rc::new(HookContext::new()));
+        terminal
+            .draw(|frame| component.render_with_mount(frame.area(), frame))
+            .unwrap();
+        cleanup_unmounted();
+    };
+
+    // Unmounted before its effect resolved: the effect is aborted
+    mount(&component);
+    cleanup_unmounted();
+    component.release.notify_one();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(component.effects.load(Ordering::SeqCst), 0);
+
+    // Unmounted after it resolved: the async cleanup runs
+    mount(&component);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    component.release.notify_one();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(component.effects.load(Ordering::SeqCst), 1);
+    assert_eq!(component.cleanups.load(Ordering::SeqCst), 0);
+
+    cleanup_unmounted();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(component.cleanups.load(Ordering::SeqCst), 1);
+
+    clear_hook_context();
+}
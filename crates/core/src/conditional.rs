@@ -0,0 +1,427 @@
+//! Conditional rendering
+//!
+//! [`show_if`] and [`match_render`] replace hand-written
+//! `if show_help { ... }` branches in `render`. The chosen component is
+//! rendered with mount tracking, so a component that gets hidden is
+//! unmounted (its `on_unmount` and effect cleanups run) instead of silently
+//! lingering. Each helper keeps the hooks of its content in a hook context
+//! of its own, so hiding the content doesn't shift the hook order of the
+//! calling component, and content that is shown again starts with fresh
+//! state.
+//!
+//! [`render_or_message`] picks between content and a fallback by the size of
+//! the area it is rendered into, so apps show a "terminal too small" notice
+//! instead of a clipped or broken layout. Unlike the other helpers it keeps
+//! the content mounted while the fallback is shown, so enlarging the terminal
+//! again brings the app back as it was.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use ratatui::{
+    Frame,
+    layout::{Rect, Size},
+};
+
+use crate::component::keep_mounted;
+use crate::hooks::{HookContext, with_hook_context};
+use crate::memo::render_isolated;
+use crate::{Component, Element};
+
+/// Per-call-site state of a conditional helper
+struct ConditionalState {
+    context: Rc<HookContext>,
+    /// Which branch rendered into `context` last
+    branch: Option<usize>,
+}
+
+/// The content chosen by [`show_if`] or [`match_render`]
+///
+/// Render it like any other component; renders nothing if no content was
+/// chosen.
+#[derive(Clone)]
+pub struct Conditional {
+    content: Option<Element>,
+    context: Rc<HookContext>,
+}
+
+impl Conditional {
+    /// Returns true if content was chosen
+    pub fn is_shown(&self) -> bool {
+        self.content.is_some()
+    }
+}
+
+impl Component for Conditional {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if let Some(content) = &self.content {
+            render_isolated(&self.context, || content.render(area, frame));
+        }
+    }
+}
+
+/// Pick the content of `branch` for the calling hook slot
+fn choose(branch: Option<usize>, content: impl FnOnce() -> Option<Element>) -> Conditional {
+    let state = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        ctx.get_or_init_state(index, || ConditionalState {
+            context: Rc::new(HookContext::new()),
+            branch: None,
+        })
+    });
+    let mut state = state.borrow_mut();
+
+    // A different branch starts over with fresh hook state
+    if state.branch != branch {
+        state.context.clear();
+        state.branch = branch;
+    }
+
+    Conditional {
+        content: branch.and(content()),
+        context: state.context.clone(),
+    }
+}
+
+/// Render the component built by `component` only while `condition` holds
+///
+/// This is a hook: call it on every render, whatever the condition.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::{Component, conditional::show_if};
+/// # use ratatui::{Frame, layout::Rect};
+/// # #[derive(Clone)] struct HelpOverlay;
+/// # impl Component for HelpOverlay { fn render(&self, _: Rect, _: &mut Frame) {} }
+/// # fn render(area: Rect, frame: &mut Frame, show_help: bool) {
+/// // In a component context:
+/// show_if(show_help, || HelpOverlay).render(area, frame);
+/// # }
+/// ```
+pub fn show_if<C, F>(condition: bool, component: F) -> Conditional
+where
+    C: Component,
+    F: FnOnce() -> C,
+{
+    choose(condition.then_some(0), || {
+        Some(Element::component(component()))
+    })
+}
+
+/// Render the element of the first arm whose value equals `value`
+///
+/// Renders nothing if no arm matches. Switching arms unmounts the content
+/// of the previous arm. This is a hook: call it on every render.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::{Component, Element, conditional::match_render};
+/// # use ratatui::{Frame, layout::Rect};
+/// # #[derive(Clone)] struct Overview;
+/// # impl Component for Overview { fn render(&self, _: Rect, _: &mut Frame) {} }
+/// # #[derive(Clone)] struct Settings;
+/// # impl Component for Settings { fn render(&self, _: Rect, _: &mut Frame) {} }
+/// #[derive(PartialEq)]
+/// enum Tab {
+///     Overview,
+///     Settings,
+/// }
+///
+/// # fn render(area: Rect, frame: &mut Frame, tab: Tab) {
+/// // In a component context:
+/// match_render(
+///     &tab,
+///     [
+///         (Tab::Overview, Element::component(Overview)),
+///         (Tab::Settings, Element::component(Settings)),
+///     ],
+/// )
+/// .render(area, frame);
+/// # }
+/// ```
+pub fn match_render<V, I>(value: &V, arms: I) -> Conditional
+where
+    V: PartialEq,
+    I: IntoIterator<Item = (V, Element)>,
+{
+    let chosen = arms
+        .into_iter()
+        .enumerate()
+        .find(|(_, (arm, _))| arm == value)
+        .map(|(index, (_, element))| (index, element));
+
+    let branch = chosen.as_ref().map(|(index, _)| *index);
+    choose(branch, || chosen.map(|(_, element)| element))
+}
+
+/// Per-call-site state of [`render_or_message`]
+#[derive(Default)]
+struct SizeGuardState {
+    content: Rc<HookContext>,
+    fallback: Rc<HookContext>,
+    /// Components rendered by the content the last time it fit
+    rendered: Vec<usize>,
+}
+
+/// Content that is replaced by a fallback in areas smaller than it needs
+///
+/// Created by [`render_or_message`]. Render it like any other component; the
+/// choice is made from the area it is rendered into.
+#[derive(Clone)]
+pub struct SizeGuard {
+    min_size: Size,
+    content: Element,
+    fallback: Element,
+    state: Rc<RefCell<SizeGuardState>>,
+}
+
+impl SizeGuard {
+    /// The smallest area the content is rendered into
+    pub fn min_size(&self) -> Size {
+        self.min_size
+    }
+
+    /// Returns true if the content fits into `area`
+    pub fn fits(&self, area: Rect) -> bool {
+        area.width >= self.min_size.width && area.height >= self.min_size.height
+    }
+}
+
+impl Component for SizeGuard {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let mut state = self.state.borrow_mut();
+        if self.fits(area) {
+            state.fallback.clear();
+            state.rendered = render_isolated(&state.content, || self.content.render(area, frame));
+        } else {
+            // The content stays mounted with its state until it fits again
+            keep_mounted(&state.rendered);
+            render_isolated(&state.fallback, || self.fallback.render(area, frame));
+        }
+    }
+}
+
+/// Render `component` in areas of at least `min_width` x `min_height`
+/// cells and `fallback` in smaller ones
+///
+/// While the fallback is shown the component stays mounted and keeps its
+/// state, it just isn't rendered. [`TooSmall`](crate::widgets::TooSmall) is
+/// a ready-made fallback telling the user to enlarge the terminal. This is a
+/// hook: call it on every render.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::{Component, conditional::render_or_message, widgets::TooSmall};
+/// # use ratatui::{Frame, layout::Rect};
+/// # #[derive(Clone)] struct Dashboard;
+/// # impl Component for Dashboard { fn render(&self, _: Rect, _: &mut Frame) {} }
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// // In a component context:
+/// render_or_message(60, 16, || Dashboard, || TooSmall::new(60, 16)).render(area, frame);
+/// # }
+/// ```
+pub fn render_or_message<C, D, F, G>(
+    min_width: u16,
+    min_height: u16,
+    component: F,
+    fallback: G,
+) -> SizeGuard
+where
+    C: Component,
+    D: Component,
+    F: FnOnce() -> C,
+    G: FnOnce() -> D,
+{
+    let state = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        ctx.get_or_init_state(index, SizeGuardState::default)
+    });
+
+    SizeGuard {
+        min_size: Size::new(min_width, min_height),
+        content: Element::component(component()),
+        fallback: Element::component(fallback()),
+        state,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::cleanup_unmounted;
+    use crate::hooks::state::use_state;
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+    use ratatui::{Terminal, backend::TestBackend, buffer::Buffer};
+    use std::borrow::Cow;
+    use std::sync::{Arc, Mutex};
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    #[derive(Clone)]
+    struct Tracked {
+        name: &'static str,
+        log: Log,
+    }
+
+    impl Component for Tracked {
+        fn component_id(&self) -> Cow<'static, str> {
+            format!("ConditionalTracked{}", self.name).into()
+        }
+
+        fn on_mount(&self) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("mount {}", self.name));
+        }
+
+        fn on_unmount(&self) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("unmount {}", self.name));
+        }
+
+        fn render(&self, _area: Rect, _frame: &mut Frame) {
+            let (renders, set_renders) = use_state(|| 0);
+            let previous = renders.get();
+            set_renders.set(previous + 1);
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("render {} {}", self.name, previous));
+        }
+    }
+
+    fn frame(terminal: &mut Terminal<TestBackend>, render: impl FnOnce(Rect, &mut Frame)) {
+        terminal.draw(|frame| render(frame.area(), frame)).unwrap();
+        cleanup_unmounted();
+    }
+
+    fn take(log: &Log) -> Vec<String> {
+        std::mem::take(&mut *log.lock().unwrap())
+    }
+
+    #[test]
+    fn test_toggling_condition_mounts_and_unmounts() {
+        with_test_isolate(|| {
+            let log = Log::default();
+            let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+            let render = |terminal: &mut Terminal<TestBackend>, show: bool| {
+                let help = Tracked {
+                    name: "help",
+                    log: log.clone(),
+                };
+                with_component_id("ShowIfParent", |_| {
+                    frame(terminal, |area, frame| {
+                        show_if(show, || help).render(area, frame);
+                        // Hooks after the helper keep their order
+                        let (after, _) = use_state(|| "after");
+                        assert_eq!(after.get(), "after");
+                    });
+                });
+            };
+
+            render(&mut terminal, true);
+            render(&mut terminal, true);
+            assert_eq!(
+                take(&log),
+                vec!["mount help", "render help 0", "render help 1"]
+            );
+
+            render(&mut terminal, false);
+            assert_eq!(take(&log), vec!["unmount help"]);
+
+            // Shown again, the component mounts with fresh state
+            render(&mut terminal, true);
+            assert_eq!(take(&log), vec!["mount help", "render help 0"]);
+        });
+    }
+
+    #[test]
+    fn test_too_small_area_renders_fallback() {
+        use crate::widgets::TooSmall;
+
+        with_test_isolate(|| {
+            let log = Log::default();
+            let render = |width: u16, height: u16| {
+                let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+                let content = Tracked {
+                    name: "content",
+                    log: log.clone(),
+                };
+                with_component_id("SizeGuardParent", |_| {
+                    frame(&mut terminal, |area, frame| {
+                        let guard = render_or_message(20, 3, || content, || TooSmall::new(20, 3));
+                        assert_eq!(guard.fits(area), width >= 20 && height >= 3);
+                        guard.render(area, frame);
+                    });
+                });
+                terminal.backend().buffer().clone()
+            };
+            let text = |buffer: Buffer| -> String {
+                buffer.content.iter().map(|cell| cell.symbol()).collect()
+            };
+
+            let buffer = render(20, 3);
+            assert_eq!(take(&log), vec!["mount content", "render content 0"]);
+            assert!(!text(buffer).contains("too small"));
+
+            // Too small, the notice is shown and the content stays mounted
+            let notice = text(render(19, 3));
+            assert!(notice.contains("Terminal too small"), "{}", notice);
+            assert!(notice.contains("19x3"), "{}", notice);
+            assert!(text(render(0, 0)).is_empty());
+            assert!(take(&log).is_empty());
+
+            // Large enough again, the content continues with its state
+            render(30, 5);
+            assert_eq!(take(&log), vec!["render content 1"]);
+        });
+    }
+
+    #[test]
+    fn test_match_render_switches_arms() {
+        with_test_isolate(|| {
+            let log = Log::default();
+            let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+            let render = |terminal: &mut Terminal<TestBackend>, tab: u8| {
+                let arms = [
+                    (
+                        1,
+                        Element::component(Tracked {
+                            name: "one",
+                            log: log.clone(),
+                        }),
+                    ),
+                    (
+                        2,
+                        Element::component(Tracked {
+                            name: "two",
+                            log: log.clone(),
+                        }),
+                    ),
+                ];
+                with_component_id("MatchRenderParent", |_| {
+                    frame(terminal, |area, frame| {
+                        let chosen = match_render(&tab, arms);
+                        assert_eq!(chosen.is_shown(), tab != 0);
+                        chosen.render(area, frame);
+                    });
+                });
+            };
+
+            render(&mut terminal, 1);
+            assert_eq!(take(&log), vec!["mount one", "render one 0"]);
+
+            render(&mut terminal, 2);
+            assert_eq!(take(&log), vec!["mount two", "render two 0", "unmount one"]);
+
+            render(&mut terminal, 0);
+            assert_eq!(take(&log), vec!["unmount two"]);
+        });
+    }
+}
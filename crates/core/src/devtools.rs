@@ -0,0 +1,402 @@
+//! Dev overlay with the rendered component tree
+//!
+//! While tree tracking is enabled, every component rendered with
+//! `render_with_mount` is recorded together with its render count and the
+//! number of hooks it called. [`component_tree`] returns the tree of the last
+//! frame and [`DevOverlay`] draws it over the live UI. The runtime enables
+//! tracking and toggles the overlay when a dev overlay key is configured.
+//! Components can add labeled values of their own to their node with
+//! [`use_debug_value`](crate::hooks::debug_value::use_debug_value).
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::Component;
+use crate::hooks::{HookContext, get_hook_context};
+
+thread_local! {
+    static TRACKING: Cell<bool> = const { Cell::new(false) };
+    static TREE: RefCell<TreeState> = RefCell::new(TreeState::default());
+}
+
+/// A component rendered in the last frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentNode {
+    /// The `component_id` of the component
+    pub id: String,
+    /// How often the component was rendered while tracking was enabled
+    pub render_count: u64,
+    /// Number of hooks the component itself called in the frame
+    pub hooks: usize,
+    /// Labeled values registered with `use_debug_value`, in call order
+    pub debug_values: Vec<(String, String)>,
+    /// Components rendered by this component
+    pub children: Vec<ComponentNode>,
+}
+
+/// A component whose render is in progress
+struct OpenNode {
+    node: ComponentNode,
+    /// Identity of the hook context the component started rendering in
+    context: Option<*const HookContext>,
+    start_index: usize,
+    /// Hooks called by children in the same hook context
+    child_hooks: usize,
+}
+
+#[derive(Default)]
+struct TreeState {
+    open: Vec<OpenNode>,
+    /// Top-level components of the frame being rendered
+    roots: Vec<ComponentNode>,
+    /// Tree of the last completed frame
+    last: Vec<ComponentNode>,
+    /// Render counts of the mounted components, keyed by their id hash
+    render_counts: HashMap<usize, u64>,
+}
+
+/// The current hook context and its hook index
+fn hook_position() -> (Option<*const HookContext>, usize) {
+    match get_hook_context() {
+        Some(context) => (Some(Rc::as_ptr(&context)), context.current_hook_index()),
+        None => (None, 0),
+    }
+}
+
+/// Enable or disable recording the component tree
+pub fn set_tree_tracking(enabled: bool) {
+    TRACKING.with(|tracking| tracking.set(enabled));
+}
+
+/// Returns true if the component tree is recorded
+pub fn is_tree_tracking() -> bool {
+    TRACKING.with(Cell::get)
+}
+
+/// Record that the component `id`, hashed to `id_hash`, starts rendering
+pub(crate) fn enter_component(id: &str, id_hash: usize) {
+    if !is_tree_tracking() {
+        return;
+    }
+
+    let (context, start_index) = hook_position();
+    TREE.with(|tree| {
+        let mut tree = tree.borrow_mut();
+        let render_count = tree.render_counts.entry(id_hash).or_default();
+        *render_count += 1;
+        let node = ComponentNode {
+            id: id.to_string(),
+            render_count: *render_count,
+            hooks: 0,
+            debug_values: Vec::new(),
+            children: Vec::new(),
+        };
+        tree.open.push(OpenNode {
+            node,
+            context,
+            start_index,
+            child_hooks: 0,
+        });
+    });
+}
+
+/// Record that the component entered last finished rendering
+pub(crate) fn exit_component() {
+    if !is_tree_tracking() {
+        return;
+    }
+
+    let (context, end_index) = hook_position();
+    TREE.with(|tree| {
+        let mut tree = tree.borrow_mut();
+        let Some(OpenNode {
+            mut node,
+            context: start_context,
+            start_index,
+            child_hooks,
+        }) = tree.open.pop()
+        else {
+            return;
+        };
+
+        // Children rendered in the same context advanced the same index
+        let total = if context == start_context {
+            end_index.saturating_sub(start_index)
+        } else {
+            0
+        };
+        node.hooks = total.saturating_sub(child_hooks);
+
+        match tree.open.last_mut() {
+            Some(parent) => {
+                if parent.context == start_context {
+                    parent.child_hooks += total;
+                }
+                parent.node.children.push(node);
+            }
+            None => tree.roots.push(node),
+        }
+    });
+}
+
+/// Forget the render counts of unmounted components
+///
+/// A component mounted again later starts counting from zero.
+pub(crate) fn forget_components(id_hashes: &[usize]) {
+    TREE.with(|tree| {
+        let mut tree = tree.borrow_mut();
+        for id_hash in id_hashes {
+            tree.render_counts.remove(id_hash);
+        }
+    });
+}
+
+/// Attach a labeled value to the component currently rendering
+///
+/// A second value with the same label replaces the first.
+pub(crate) fn record_debug_value(label: String, value: String) {
+    TREE.with(|tree| {
+        let mut tree = tree.borrow_mut();
+        let Some(open) = tree.open.last_mut() else {
+            return;
+        };
+        let values = &mut open.node.debug_values;
+        match values.iter_mut().find(|(existing, _)| *existing == label) {
+            Some((_, existing)) => *existing = value,
+            None => values.push((label, value)),
+        }
+    });
+}
+
+/// Finish the component tree of the current frame
+///
+/// Called by the runtime after the component tree was rendered.
+pub fn commit_component_tree() {
+    TREE.with(|tree| {
+        let mut tree = tree.borrow_mut();
+        tree.open.clear();
+        tree.last = std::mem::take(&mut tree.roots);
+    });
+}
+
+/// The components rendered in the last frame, as a tree
+///
+/// Empty unless tree tracking is enabled.
+pub fn component_tree() -> Vec<ComponentNode> {
+    TREE.with(|tree| tree.borrow().last.clone())
+}
+
+/// Render `nodes` as indented lines
+fn tree_lines(nodes: &[ComponentNode], depth: usize, lines: &mut Vec<Line<'static>>) {
+    for node in nodes {
+        lines.push(Line::from(format!(
+            "{}{}  renders: {}  hooks: {}",
+            "  ".repeat(depth),
+            node.id,
+            node.render_count,
+            node.hooks
+        )));
+        for (label, value) in &node.debug_values {
+            lines.push(Line::from(format!(
+                "{}  {}: {}",
+                "  ".repeat(depth),
+                label,
+                value
+            )));
+        }
+        tree_lines(&node.children, depth + 1, lines);
+    }
+}
+
+/// Component drawing the last frame's component tree over the UI
+#[derive(Clone, Default)]
+pub struct DevOverlay;
+
+impl Component for DevOverlay {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let [area] = Layout::horizontal([Constraint::Percentage(60)])
+            .flex(Flex::End)
+            .areas(area);
+
+        let mut lines = Vec::new();
+        tree_lines(&component_tree(), 0, &mut lines);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Component tree "),
+            ),
+            area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::state::use_state;
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::borrow::Cow;
+
+    #[derive(Clone)]
+    struct App;
+
+    impl Component for App {
+        fn component_id(&self) -> Cow<'static, str> {
+            "App".into()
+        }
+
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            use_state(|| 0);
+            Sidebar.render_with_mount(area, frame);
+            use_state(|| 1);
+            Content.render_with_mount(area, frame);
+        }
+    }
+
+    #[derive(Clone)]
+    struct Sidebar;
+
+    impl Component for Sidebar {
+        fn component_id(&self) -> Cow<'static, str> {
+            "Sidebar".into()
+        }
+
+        fn render(&self, _area: Rect, _frame: &mut Frame) {
+            use_state(|| 0);
+            use_state(|| 1);
+            use_state(|| 2);
+        }
+    }
+
+    #[derive(Clone)]
+    struct Content;
+
+    impl Component for Content {
+        fn component_id(&self) -> Cow<'static, str> {
+            "Content".into()
+        }
+
+        fn render(&self, _area: Rect, _frame: &mut Frame) {}
+    }
+
+    fn node(
+        id: &str,
+        render_count: u64,
+        hooks: usize,
+        children: Vec<ComponentNode>,
+    ) -> ComponentNode {
+        ComponentNode {
+            id: id.to_string(),
+            render_count,
+            hooks,
+            debug_values: Vec::new(),
+            children,
+        }
+    }
+
+    #[test]
+    fn test_tree_reflects_rendered_components() {
+        with_test_isolate(|| {
+            set_tree_tracking(true);
+            let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+            for _ in 0..2 {
+                with_component_id("DevtoolsRoot", |_| {
+                    terminal
+                        .draw(|frame| App.render_with_mount(frame.area(), frame))
+                        .unwrap();
+                });
+                commit_component_tree();
+            }
+            set_tree_tracking(false);
+
+            assert_eq!(
+                component_tree(),
+                vec![node(
+                    "App",
+                    2,
+                    2,
+                    vec![node("Sidebar", 2, 3, vec![]), node("Content", 2, 0, vec![])]
+                )]
+            );
+        });
+    }
+
+    #[test]
+    fn test_unmount_forgets_render_counts() {
+        with_test_isolate(|| {
+            set_tree_tracking(true);
+            let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+            let mut draw = |show_content: bool| {
+                with_component_id("DevtoolsUnmountRoot", |_| {
+                    terminal
+                        .draw(|frame| {
+                            Sidebar.render_with_mount(frame.area(), frame);
+                            if show_content {
+                                Content.render_with_mount(frame.area(), frame);
+                            }
+                        })
+                        .unwrap();
+                });
+                commit_component_tree();
+                crate::component::cleanup_unmounted();
+            };
+
+            draw(true);
+            draw(true);
+            draw(false);
+            assert_eq!(TREE.with(|tree| tree.borrow().render_counts.len()), 1);
+
+            // Mounted again, the count starts over
+            draw(true);
+            set_tree_tracking(false);
+            assert_eq!(
+                component_tree(),
+                vec![node("Sidebar", 4, 3, vec![]), node("Content", 1, 0, vec![])]
+            );
+        });
+    }
+
+    #[test]
+    fn test_nothing_recorded_without_tracking() {
+        with_test_isolate(|| {
+            let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+            with_component_id("DevtoolsUntracked", |_| {
+                terminal
+                    .draw(|frame| App.render_with_mount(frame.area(), frame))
+                    .unwrap();
+            });
+            commit_component_tree();
+            assert!(component_tree().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_overlay_lists_components() {
+        let mut app = node("App", 1, 2, vec![node("Sidebar", 4, 3, vec![])]);
+        app.debug_values = vec![("route".to_string(), "/home".to_string())];
+        let nodes = vec![app];
+        let mut lines = Vec::new();
+        tree_lines(&nodes, 0, &mut lines);
+        let lines: Vec<String> = lines.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "App  renders: 1  hooks: 2",
+                "  route: /home",
+                "  Sidebar  renders: 4  hooks: 3"
+            ]
+        );
+    }
+}
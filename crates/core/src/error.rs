@@ -0,0 +1,184 @@
+//! Errors with context
+//!
+//! [`ContextError`] wraps an error together with a description of what was
+//! being done when it happened. Context can be added at every level an error
+//! passes through, so a failure reads like
+//! `while loading finance data: while fetching quotes: connection refused`.
+//!
+//! Any `Result` whose error converts into a boxed error gains `context` and
+//! `with_context` through the [`ResultExt`] trait.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+/// Boxed error type accepted as the source of a [`ContextError`]
+pub type BoxError = Box<dyn Error + Send + Sync>;
+
+/// An error annotated with what was being done when it occurred
+///
+/// # Examples
+/// ```
+/// use pulse_core::error::{ContextError, ResultExt};
+///
+/// fn connect() -> Result<(), std::io::Error> {
+///     Err(std::io::Error::other("connection refused"))
+/// }
+///
+/// let error = connect()
+///     .context("while fetching quotes")
+///     .context("while loading finance data")
+///     .unwrap_err();
+///
+/// assert_eq!(
+///     error.to_string(),
+///     "while loading finance data: while fetching quotes: connection refused"
+/// );
+/// assert_eq!(error.root_cause().to_string(), "connection refused");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContextError {
+    context: String,
+    /// Shared so the error can be cloned, e.g. into a `FutureState`
+    source: Option<Arc<dyn Error + Send + Sync>>,
+}
+
+impl ContextError {
+    /// Create an error from a message, without an underlying source
+    pub fn msg(message: impl Into<String>) -> Self {
+        Self {
+            context: message.into(),
+            source: None,
+        }
+    }
+
+    /// Wrap `source` with a description of what was being done
+    pub fn new(context: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        Self {
+            context: context.into(),
+            source: Some(Arc::from(source.into())),
+        }
+    }
+
+    /// Wrap this error in another layer of context
+    pub fn context(self, context: impl Into<String>) -> Self {
+        Self::new(context, self)
+    }
+
+    /// The outermost context message
+    pub fn message(&self) -> &str {
+        &self.context
+    }
+
+    /// All context messages, outermost first, followed by the root cause
+    pub fn chain(&self) -> Vec<String> {
+        let mut chain = Vec::new();
+        let mut current: Option<&(dyn Error + 'static)> = Some(self);
+        while let Some(error) = current {
+            match error.downcast_ref::<ContextError>() {
+                Some(context_error) => chain.push(context_error.context.clone()),
+                None => chain.push(error.to_string()),
+            }
+            current = error.source();
+        }
+        chain
+    }
+
+    /// The innermost error
+    pub fn root_cause(&self) -> &(dyn Error + 'static) {
+        let mut current: &(dyn Error + 'static) = self;
+        while let Some(source) = current.source() {
+            current = source;
+        }
+        current
+    }
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Some(source) => write!(f, "{}: {}", self.context, source),
+            None => write!(f, "{}", self.context),
+        }
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn Error + 'static))
+    }
+}
+
+impl From<String> for ContextError {
+    fn from(message: String) -> Self {
+        Self::msg(message)
+    }
+}
+
+impl From<&str> for ContextError {
+    fn from(message: &str) -> Self {
+        Self::msg(message)
+    }
+}
+
+/// Adds context to the error of a `Result`
+pub trait ResultExt<T> {
+    /// Wrap the error with a context message
+    fn context(self, context: impl Into<String>) -> Result<T, ContextError>;
+
+    /// Wrap the error with a lazily built context message
+    fn with_context<C, F>(self, context: F) -> Result<T, ContextError>
+    where
+        C: Into<String>,
+        F: FnOnce() -> C;
+}
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: Into<BoxError>,
+{
+    fn context(self, context: impl Into<String>) -> Result<T, ContextError> {
+        self.map_err(|error| ContextError::new(context, error))
+    }
+
+    fn with_context<C, F>(self, context: F) -> Result<T, ContextError>
+    where
+        C: Into<String>,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|error| ContextError::new(context(), error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_lists_every_layer() {
+        let error = Err::<(), _>("connection refused".to_string())
+            .context("while fetching quotes")
+            .context("while loading finance data")
+            .unwrap_err();
+
+        assert_eq!(
+            error.chain(),
+            vec![
+                "while loading finance data",
+                "while fetching quotes",
+                "connection refused"
+            ]
+        );
+        assert_eq!(error.message(), "while loading finance data");
+    }
+
+    #[test]
+    fn test_message_without_source() {
+        let error = ContextError::msg("nothing to load");
+        assert_eq!(error.to_string(), "nothing to load");
+        assert!(error.source().is_none());
+        assert_eq!(error.root_cause().to_string(), "nothing to load");
+    }
+}
@@ -0,0 +1,263 @@
+//! Locale-aware number, currency and date formatting
+//!
+//! [`LocaleFormat`] holds the conventions of one locale: decimal and grouping
+//! separators, where a currency symbol goes and the order of date fields.
+//! [`format_number`], [`format_currency`] and [`format_date`] use the
+//! conventions of the active [`i18n`](crate::i18n) locale, so switching the
+//! locale with [`set_locale`](crate::i18n::set_locale) also reformats every
+//! number on the next render.
+//!
+//! ```rust
+//! use pulse_core::format::LocaleFormat;
+//!
+//! assert_eq!(LocaleFormat::for_locale("en").currency(1234.5, "USD"), "$1,234.50");
+//! assert_eq!(LocaleFormat::for_locale("de").currency(1234.5, "EUR"), "1.234,50\u{a0}€");
+//! ```
+
+use chrono::Datelike;
+
+use crate::i18n::locale;
+
+/// Order of the day, month and year in a short date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// Month, day, year, e.g. `03/14/2025`
+    MonthDayYear,
+    /// Day, month, year, e.g. `14.03.2025`
+    DayMonthYear,
+    /// Year, month, day, e.g. `2025/03/14`
+    YearMonthDay,
+}
+
+/// Formatting conventions of a locale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocaleFormat {
+    /// Separator between the integer and fractional digits
+    pub decimal_separator: char,
+    /// Separator between groups of three integer digits
+    pub group_separator: char,
+    /// Whether the currency symbol comes before the amount
+    pub symbol_before: bool,
+    /// Whether a space separates the currency symbol from the amount
+    pub symbol_spaced: bool,
+    /// Order of the fields in a short date
+    pub date_order: DateOrder,
+    /// Separator between the fields of a short date
+    pub date_separator: char,
+}
+
+impl Default for LocaleFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            group_separator: ',',
+            symbol_before: true,
+            symbol_spaced: false,
+            date_order: DateOrder::MonthDayYear,
+            date_separator: '/',
+        }
+    }
+}
+
+impl LocaleFormat {
+    /// Conventions of `locale`, such as `"en-GB"` or `"de"`
+    ///
+    /// A region without specific conventions uses those of its language, and
+    /// an unknown language uses US English conventions.
+    pub fn for_locale(locale: &str) -> Self {
+        let us = Self::default();
+        let day_first = Self {
+            date_order: DateOrder::DayMonthYear,
+            ..us
+        };
+        let continental = Self {
+            decimal_separator: ',',
+            group_separator: '.',
+            symbol_before: false,
+            symbol_spaced: true,
+            ..day_first
+        };
+
+        let locale = locale.replace('_', "-");
+        let language = locale.split('-').next().unwrap_or_default();
+        match (language, locale.as_str()) {
+            (_, "en-GB" | "en-IE" | "en-AU" | "en-NZ" | "en-IN") => day_first,
+            ("de", _) => Self {
+                date_separator: '.',
+                ..continental
+            },
+            ("fr", _) => Self {
+                group_separator: '\u{202f}',
+                ..continental
+            },
+            ("es" | "it" | "pt" | "nl", _) => continental,
+            ("ja" | "zh" | "ko", _) => Self {
+                date_order: DateOrder::YearMonthDay,
+                ..us
+            },
+            _ => us,
+        }
+    }
+
+    /// Conventions of the active [`i18n`](crate::i18n) locale
+    pub fn current() -> Self {
+        Self::for_locale(&locale())
+    }
+
+    /// Format `value` with `decimals` fractional digits and grouped thousands
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pulse_core::format::LocaleFormat;
+    ///
+    /// assert_eq!(LocaleFormat::for_locale("en").number(-1234567.891, 2), "-1,234,567.89");
+    /// assert_eq!(LocaleFormat::for_locale("de").number(1234.6, 0), "1.235");
+    /// ```
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let digits = format!("{:.*}", decimals, value.abs());
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+
+        let mut result = String::with_capacity(digits.len() + integer.len() / 3 + 1);
+        if value < 0.0 && digits.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            result.push('-');
+        }
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                result.push(self.group_separator);
+            }
+            result.push(digit);
+        }
+        if !fraction.is_empty() {
+            result.push(self.decimal_separator);
+            result.push_str(fraction);
+        }
+        result
+    }
+
+    /// Format `amount` of the currency with ISO 4217 `code`, such as `"EUR"`
+    ///
+    /// Common currencies are shown with their symbol and usual number of
+    /// fractional digits; others are shown with their code and two digits.
+    pub fn currency(&self, amount: f64, code: &str) -> String {
+        let (symbol, decimals) = currency_symbol(code);
+        let number = self.number(amount, decimals);
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number.as_str()),
+        };
+        let space = if self.symbol_spaced { "\u{a0}" } else { "" };
+
+        if self.symbol_before {
+            format!("{}{}{}{}", sign, symbol, space, number)
+        } else {
+            format!("{}{}{}{}", sign, number, space, symbol)
+        }
+    }
+
+    /// Format `date` as a short numeric date
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use pulse_core::format::LocaleFormat;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    /// assert_eq!(LocaleFormat::for_locale("en-US").date(&date), "03/14/2025");
+    /// assert_eq!(LocaleFormat::for_locale("de").date(&date), "14.03.2025");
+    /// ```
+    pub fn date(&self, date: &impl Datelike) -> String {
+        let day = format!("{:02}", date.day());
+        let month = format!("{:02}", date.month());
+        let year = format!("{:04}", date.year());
+        let fields = match self.date_order {
+            DateOrder::MonthDayYear => [month, day, year],
+            DateOrder::DayMonthYear => [day, month, year],
+            DateOrder::YearMonthDay => [year, month, day],
+        };
+        fields.join(&self.date_separator.to_string())
+    }
+}
+
+/// Symbol and fractional digits of the currency with ISO 4217 `code`
+fn currency_symbol(code: &str) -> (&str, usize) {
+    match code {
+        "USD" => ("$", 2),
+        "EUR" => ("€", 2),
+        "GBP" => ("£", 2),
+        "JPY" => ("¥", 0),
+        "CNY" => ("¥", 2),
+        "INR" => ("₹", 2),
+        "KRW" => ("₩", 0),
+        "CHF" => ("CHF", 2),
+        other => (other, 2),
+    }
+}
+
+/// Format `value` in the active locale with `decimals` fractional digits
+pub fn format_number(value: f64, decimals: usize) -> String {
+    LocaleFormat::current().number(value, decimals)
+}
+
+/// Format `amount` of the currency `code` in the active locale
+pub fn format_currency(amount: f64, code: &str) -> String {
+    LocaleFormat::current().currency(amount, code)
+}
+
+/// Format `date` as a short date in the active locale
+pub fn format_date(date: &impl Datelike) -> String {
+    LocaleFormat::current().date(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_thousands_separators() {
+        let en = LocaleFormat::for_locale("en");
+        assert_eq!(en.number(1234567.0, 0), "1,234,567");
+        assert_eq!(en.number(999.0, 0), "999");
+        assert_eq!(en.number(0.5, 2), "0.50");
+        assert_eq!(en.number(-0.001, 2), "0.00");
+
+        assert_eq!(
+            LocaleFormat::for_locale("de-AT").number(1234567.25, 2),
+            "1.234.567,25"
+        );
+        assert_eq!(
+            LocaleFormat::for_locale("fr_FR").number(1234567.25, 2),
+            "1\u{202f}234\u{202f}567,25"
+        );
+        assert_eq!(en.number(f64::NAN, 2), "NaN");
+    }
+
+    #[test]
+    fn test_currency_symbol_placement() {
+        let en = LocaleFormat::for_locale("en-US");
+        assert_eq!(en.currency(1234.5, "USD"), "$1,234.50");
+        assert_eq!(en.currency(-42.0, "GBP"), "-£42.00");
+        assert_eq!(en.currency(1500.0, "JPY"), "¥1,500");
+
+        let de = LocaleFormat::for_locale("de");
+        assert_eq!(de.currency(1234.5, "EUR"), "1.234,50\u{a0}€");
+        assert_eq!(de.currency(-3.0, "USD"), "-3,00\u{a0}$");
+        assert_eq!(de.currency(10.0, "SEK"), "10,00\u{a0}SEK");
+    }
+
+    #[test]
+    fn test_date_order() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 4).unwrap();
+        assert_eq!(LocaleFormat::for_locale("en").date(&date), "03/04/2025");
+        assert_eq!(LocaleFormat::for_locale("en-GB").date(&date), "04/03/2025");
+        assert_eq!(LocaleFormat::for_locale("de").date(&date), "04.03.2025");
+        assert_eq!(LocaleFormat::for_locale("ja").date(&date), "2025/03/04");
+        assert_eq!(LocaleFormat::for_locale("xx").date(&date), "03/04/2025");
+    }
+}
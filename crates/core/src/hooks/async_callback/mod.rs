@@ -0,0 +1,234 @@
+//! Async Callback Hook
+//!
+//! This module provides `use_async_callback`, the asynchronous counterpart of
+//! `use_callback`. Emitting the callback spawns the async work and tracks its
+//! outcome, so a "save" or "submit" button can show a spinner while the work
+//! runs and the result or error once it's done. Emitting again while a run is
+//! in flight aborts the previous run, and the run in flight is aborted when
+//! the component unmounts.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::task::AbortHandle;
+
+use crate::hooks::batch::request_render;
+use crate::hooks::effect::{EffectDependencies, use_effect};
+use crate::hooks::future::FutureState;
+use crate::hooks::with_hook_context;
+use crate::tasks::spawn_limited;
+
+#[cfg(test)]
+mod tests;
+
+/// Boxed future returned by the wrapped async function
+type BoxFuture<T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send>>;
+
+/// Type-erased async function of an [`AsyncCallback`]
+type AsyncFn<IN, T, E> = Arc<dyn Fn(IN) -> BoxFuture<T, E> + Send + Sync>;
+
+/// Outcome of the latest run of an [`AsyncCallback`]
+struct RunState<T, E> {
+    /// Incremented for every emit, so an aborted run can't overwrite the
+    /// outcome of its replacement
+    run: u64,
+    /// `None` until the callback is emitted for the first time
+    state: Option<FutureState<T, E>>,
+    abort_handle: Option<AbortHandle>,
+}
+
+impl<T, E> RunState<T, E> {
+    fn abort(&mut self) {
+        if let Some(abort_handle) = self.abort_handle.take() {
+            abort_handle.abort();
+        }
+    }
+}
+
+/// Per-hook storage of [`use_async_callback`]
+struct AsyncCallbackSlot<IN, T, E, Deps> {
+    func: AsyncFn<IN, T, E>,
+    deps: Option<Deps>,
+    runs: Arc<Mutex<RunState<T, E>>>,
+}
+
+/// Callback returned by [`use_async_callback`]
+///
+/// Cheap to clone; clones share the tracked state.
+pub struct AsyncCallback<IN, T, E> {
+    func: AsyncFn<IN, T, E>,
+    runs: Arc<Mutex<RunState<T, E>>>,
+}
+
+impl<IN, T, E> Clone for AsyncCallback<IN, T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            func: self.func.clone(),
+            runs: self.runs.clone(),
+        }
+    }
+}
+
+impl<IN, T, E> std::fmt::Debug for AsyncCallback<IN, T, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncCallback")
+            .field("run", &self.runs.lock().run)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<IN, T, E> AsyncCallback<IN, T, E>
+where
+    T: Clone + Send + 'static,
+    E: Clone + Send + 'static,
+{
+    /// Spawn the async work for `args`
+    ///
+    /// A run that is still in flight is aborted first. The work runs on the
+    /// shared [`TaskPool`](crate::tasks::TaskPool). Must be called within a
+    /// tokio runtime.
+    pub fn emit(&self, args: IN) {
+        let future = (self.func)(args);
+        let run = {
+            let mut runs = self.runs.lock();
+            runs.abort();
+            runs.run += 1;
+            runs.state = Some(FutureState::Pending);
+            runs.run
+        };
+        request_render();
+
+        let runs = self.runs.clone();
+        let abort_handle = spawn_limited(async move {
+            let result = future.await;
+            let mut runs = runs.lock();
+            if runs.run == run {
+                runs.state = Some(match result {
+                    Ok(value) => FutureState::Resolved(value),
+                    Err(error) => FutureState::Error(error),
+                });
+                runs.abort_handle = None;
+                drop(runs);
+                request_render();
+            }
+        })
+        .abort_handle();
+
+        let mut runs = self.runs.lock();
+        if runs.run == run && matches!(runs.state, Some(FutureState::Pending)) {
+            runs.abort_handle = Some(abort_handle);
+        }
+    }
+
+    /// State of the latest run, or `None` if the callback wasn't emitted yet
+    pub fn state(&self) -> Option<FutureState<T, E>> {
+        self.runs.lock().state.clone()
+    }
+
+    /// Returns true if the callback wasn't emitted yet
+    pub fn is_idle(&self) -> bool {
+        self.runs.lock().state.is_none()
+    }
+
+    /// Returns true while the latest run is in flight
+    pub fn is_pending(&self) -> bool {
+        matches!(self.runs.lock().state, Some(FutureState::Pending))
+    }
+
+    /// Returns true if the latest run resolved successfully
+    pub fn is_resolved(&self) -> bool {
+        matches!(self.runs.lock().state, Some(FutureState::Resolved(_)))
+    }
+
+    /// Returns true if the latest run failed
+    pub fn is_error(&self) -> bool {
+        matches!(self.runs.lock().state, Some(FutureState::Error(_)))
+    }
+
+    /// The value of the latest run, if it resolved
+    pub fn value(&self) -> Option<T> {
+        self.state().and_then(|state| state.value().cloned())
+    }
+
+    /// The error of the latest run, if it failed
+    pub fn error(&self) -> Option<E> {
+        self.state().and_then(|state| state.error().cloned())
+    }
+}
+
+/// Hook that wraps an async function into a callback tracking its outcome
+///
+/// `async_fn` is replaced whenever `deps` change, like with `use_callback`.
+/// The tracked state survives dependency changes. The run in flight is
+/// aborted when the component unmounts.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::async_callback::use_async_callback;
+///
+/// # async fn save_document(_: String) -> Result<(), String> { Ok(()) }
+/// // In a component context:
+/// let save = use_async_callback(
+///     |document: String| async move { save_document(document).await },
+///     (),
+/// );
+///
+/// // e.g. on the "save" key:
+/// save.emit("draft".to_string());
+///
+/// let status = if save.is_pending() {
+///     "Saving..."
+/// } else if save.is_error() {
+///     "Save failed"
+/// } else {
+///     ""
+/// };
+/// ```
+pub fn use_async_callback<IN, T, E, F, Fut, Deps>(
+    async_fn: F,
+    deps: impl Into<Option<Deps>>,
+) -> AsyncCallback<IN, T, E>
+where
+    IN: 'static,
+    T: Clone + Send + 'static,
+    E: Clone + Send + 'static,
+    F: Fn(IN) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+    Deps: EffectDependencies + Clone + PartialEq + 'static,
+{
+    let deps = deps.into();
+    let func: AsyncFn<IN, T, E> = Arc::new(move |args| Box::pin(async_fn(args)));
+
+    let callback = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let slot = ctx.get_or_init_state(index, || AsyncCallbackSlot {
+            func: func.clone(),
+            deps: deps.clone(),
+            runs: Arc::new(Mutex::new(RunState {
+                run: 0,
+                state: None,
+                abort_handle: None,
+            })),
+        });
+        let mut slot = slot.borrow_mut();
+
+        // Without dependencies the function is kept from the first render
+        if deps.is_some() && slot.deps != deps {
+            slot.func = func;
+            slot.deps = deps;
+        }
+
+        AsyncCallback {
+            func: slot.func.clone(),
+            runs: slot.runs.clone(),
+        }
+    });
+
+    let runs = callback.runs.clone();
+    use_effect(move || Some(move || runs.lock().abort()), ());
+
+    callback
+}
@@ -0,0 +1,100 @@
+//! Tests for the use_async_callback hook
+
+use super::*;
+use crate::component::{Component, cleanup_unmounted};
+use crate::hooks::test_utils::{with_async_test_isolate, with_component_id};
+use crate::hooks::{HookContext, clear_hook_context, set_hook_context};
+use ratatui::{Frame, Terminal, backend::TestBackend, layout::Rect};
+use std::rc::Rc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+fn render_save() -> AsyncCallback<u32, u32, String> {
+    with_component_id("SaveButton", |_| {
+        use_async_callback(
+            |amount: u32| async move {
+                sleep(Duration::from_millis(5)).await;
+                if amount == 0 {
+                    Err("nothing to save".to_string())
+                } else {
+                    Ok(amount * 2)
+                }
+            },
+            (),
+        )
+    })
+}
+
+#[tokio::test]
+async fn test_emit_runs_async_work_and_tracks_state() {
+    with_async_test_isolate(|| async {
+        let save = render_save();
+        assert!(save.is_idle());
+
+        save.emit(21);
+        assert!(save.is_pending());
+
+        sleep(Duration::from_millis(30)).await;
+        let save = render_save();
+        assert!(save.is_resolved());
+        assert_eq!(save.value(), Some(42));
+
+        save.emit(0);
+        sleep(Duration::from_millis(30)).await;
+        assert!(save.is_error());
+        assert_eq!(save.error(), Some("nothing to save".to_string()));
+        assert_eq!(save.value(), None);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_emit_aborts_previous_run() {
+    with_async_test_isolate(|| async {
+        let save = render_save();
+        save.emit(1);
+        save.emit(2);
+
+        sleep(Duration::from_millis(30)).await;
+        assert_eq!(save.value(), Some(4));
+    })
+    .await;
+}
+
+#[derive(Clone)]
+struct Submitter {
+    callback: Arc<Mutex<Option<AsyncCallback<(), (), ()>>>>,
+}
+
+impl Component for Submitter {
+    fn render(&self, _area: Rect, _frame: &mut Frame) {
+        let submit = use_async_callback(|_: ()| std::future::pending(), ());
+        *self.callback.lock() = Some(submit);
+    }
+}
+
+#[tokio::test]
+async fn test_unmount_aborts_run() {
+    let submitter = Submitter {
+        callback: Arc::new(Mutex::new(None)),
+    };
+    let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+    set_hook_context(Rc::new(HookContext::new()));
+
+    terminal
+        .draw(|frame| submitter.render_with_mount(frame.area(), frame))
+        .unwrap();
+    cleanup_unmounted();
+    let submit = submitter.callback.lock().clone().unwrap();
+    submit.emit(());
+    let task = submit.runs.lock().abort_handle.clone().unwrap();
+    sleep(Duration::from_millis(10)).await;
+    assert!(!task.is_finished());
+
+    // A frame without the submitter unmounts it
+    cleanup_unmounted();
+    sleep(Duration::from_millis(10)).await;
+    assert!(task.is_finished());
+
+    clear_hook_context();
+}
@@ -0,0 +1,212 @@
+//! Async State Hook
+//!
+//! This module provides a `use_async_state` hook for optimistic updates. A new
+//! value is shown immediately while the async operation that persists it is
+//! still running. Once the operation finishes, the value is either committed or
+//! rolled back to the last committed value.
+//!
+//! Several optimistic updates may be in flight at once. The displayed value is
+//! always the most recent pending value; a failed update only removes its own
+//! value, so a later update that is still pending stays visible.
+
+use std::future::Future;
+
+use crate::hooks::future::LoadingGuard;
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+use crate::tasks::spawn_limited;
+
+#[cfg(test)]
+mod tests;
+
+/// Error reported by [`AsyncStateHandle::set_optimistic`] outside of a tokio runtime
+const NO_RUNTIME_ERROR: &str = "optimistic update requires a tokio runtime";
+
+/// An optimistic update waiting for its async result
+#[derive(Clone)]
+struct PendingUpdate<T> {
+    id: u64,
+    value: T,
+}
+
+/// State behind [`AsyncStateHandle`]
+#[derive(Clone)]
+struct AsyncState<T> {
+    /// The last value confirmed by a successful operation (or set directly)
+    committed: T,
+    /// Id of the update `committed` came from, so older commits don't win
+    committed_id: u64,
+    /// Optimistic updates in the order they were made
+    pending: Vec<PendingUpdate<T>>,
+    /// Error of the last rolled back update
+    error: Option<String>,
+    next_id: u64,
+}
+
+impl<T> AsyncState<T> {
+    fn current(&self) -> &T {
+        self.pending
+            .last()
+            .map(|update| &update.value)
+            .unwrap_or(&self.committed)
+    }
+}
+
+/// Handle returned by [`use_async_state`]
+pub struct AsyncStateHandle<T> {
+    state: StateHandle<AsyncState<T>>,
+    setter: StateSetter<AsyncState<T>>,
+}
+
+impl<T> Clone for AsyncStateHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            setter: self.setter.clone(),
+        }
+    }
+}
+
+impl<T> AsyncStateHandle<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// The value to display, including pending optimistic updates
+    pub fn get(&self) -> T {
+        self.state.field(|state| state.current().clone())
+    }
+
+    /// The last committed value, ignoring pending optimistic updates
+    pub fn committed(&self) -> T {
+        self.state.field(|state| state.committed.clone())
+    }
+
+    /// Returns true while any optimistic update is waiting for its result
+    pub fn is_pending(&self) -> bool {
+        self.state.field(|state| !state.pending.is_empty())
+    }
+
+    /// The error of the most recent rolled back update
+    ///
+    /// Cleared by the next successful commit or direct [`Self::set`].
+    pub fn error(&self) -> Option<String> {
+        self.state.field(|state| state.error.clone())
+    }
+
+    /// Set and commit a value directly, without an async operation
+    ///
+    /// Pending optimistic updates stay visible and still commit or roll back
+    /// when they finish.
+    pub fn set(&self, value: T) {
+        self.setter.update(move |state| {
+            let mut state = state.clone();
+            state.committed = value;
+            state.committed_id = state.next_id;
+            state.error = None;
+            state
+        });
+    }
+
+    /// Apply `value` immediately and commit it once `future` succeeds
+    ///
+    /// If `future` fails, the value is removed again and the error is
+    /// available through [`Self::error`]. The update counts towards the
+    /// global loading state while it runs.
+    ///
+    /// The operation runs on the tokio runtime. Called outside of one, the
+    /// value is not applied and [`Self::error`] reports the missing runtime.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use pulse_core::hooks::async_state::use_async_state;
+    ///
+    /// # async fn save_like(_liked: bool) -> Result<(), String> { Ok(()) }
+    /// // In a component context:
+    /// let liked = use_async_state(|| false);
+    /// let next = !liked.get();
+    /// liked.set_optimistic(next, save_like(next));
+    /// ```
+    pub fn set_optimistic<Fut, R, E>(&self, value: T, future: Fut)
+    where
+        Fut: Future<Output = Result<R, E>> + Send + 'static,
+        E: ToString,
+    {
+        if tokio::runtime::Handle::try_current().is_err() {
+            self.setter.update(|state| {
+                let mut state = state.clone();
+                state.error = Some(NO_RUNTIME_ERROR.to_string());
+                state
+            });
+            return;
+        }
+
+        let mut id = 0;
+        self.setter.update(|state| {
+            let mut state = state.clone();
+            state.next_id += 1;
+            id = state.next_id;
+            state.pending.push(PendingUpdate { id, value });
+            state
+        });
+
+        let setter = self.setter.clone();
+        let loading = LoadingGuard::new();
+        spawn_limited(async move {
+            let _loading = loading;
+            let result = future.await.map(|_| ()).map_err(|error| error.to_string());
+            setter.update(move |state| {
+                let mut state = state.clone();
+                let Some(position) = state.pending.iter().position(|update| update.id == id) else {
+                    return state;
+                };
+                let update = state.pending.remove(position);
+                match result {
+                    Ok(()) => {
+                        if update.id > state.committed_id {
+                            state.committed = update.value;
+                            state.committed_id = update.id;
+                        }
+                        state.error = None;
+                    }
+                    Err(error) => state.error = Some(error),
+                }
+                state
+            });
+        });
+    }
+}
+
+/// Hook for state that is updated optimistically by async operations
+///
+/// Returns an [`AsyncStateHandle`]; see [`AsyncStateHandle::set_optimistic`].
+///
+/// # Example
+/// ```rust,no_run
+/// use pulse_core::hooks::async_state::use_async_state;
+///
+/// # async fn rename_on_server(_name: String) -> Result<(), String> { Ok(()) }
+/// // In a component context:
+/// let name = use_async_state(|| "draft".to_string());
+///
+/// name.set_optimistic("final".to_string(), rename_on_server("final".to_string()));
+///
+/// // Shows "final" right away, and "draft" again if the rename fails
+/// let label = if name.is_pending() {
+///     format!("{} (saving...)", name.get())
+/// } else {
+///     name.get()
+/// };
+/// ```
+pub fn use_async_state<T, F>(initial: F) -> AsyncStateHandle<T>
+where
+    T: Clone + Send + Sync + 'static,
+    F: FnOnce() -> T,
+{
+    let (state, setter) = use_state(|| AsyncState {
+        committed: initial(),
+        committed_id: 0,
+        pending: Vec::new(),
+        error: None,
+        next_id: 0,
+    });
+    AsyncStateHandle { state, setter }
+}
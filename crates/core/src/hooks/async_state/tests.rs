@@ -0,0 +1,109 @@
+//! Tests for the use_async_state hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio::time::sleep;
+
+fn likes() -> AsyncStateHandle<u32> {
+    with_component_id("Likes", |_| use_async_state(|| 10))
+}
+
+/// An operation that finishes when the returned sender is used
+fn operation() -> (
+    oneshot::Sender<Result<(), String>>,
+    impl Future<Output = Result<(), String>>,
+) {
+    let (tx, rx) = oneshot::channel();
+    (
+        tx,
+        async move { rx.await.unwrap_or(Err("dropped".to_string())) },
+    )
+}
+
+#[tokio::test]
+async fn test_optimistic_update_commits_on_success() {
+    let handle = with_test_isolate(|| {
+        let handle = likes();
+        assert!(!handle.is_pending());
+        handle
+    });
+
+    let (finish, future) = operation();
+    handle.set_optimistic(11, future);
+
+    // The new value is visible right away
+    assert_eq!(handle.get(), 11);
+    assert_eq!(handle.committed(), 10);
+    assert!(handle.is_pending());
+
+    finish.send(Ok(())).unwrap();
+    sleep(Duration::from_millis(10)).await;
+
+    assert!(!handle.is_pending());
+    assert_eq!(handle.get(), 11);
+    assert_eq!(handle.committed(), 11);
+    assert_eq!(handle.error(), None);
+}
+
+#[tokio::test]
+async fn test_optimistic_update_rolls_back_on_error() {
+    let handle = with_test_isolate(likes);
+
+    let (finish, future) = operation();
+    handle.set_optimistic(11, future);
+    assert_eq!(handle.get(), 11);
+
+    finish.send(Err("network down".to_string())).unwrap();
+    sleep(Duration::from_millis(10)).await;
+
+    assert!(!handle.is_pending());
+    assert_eq!(handle.get(), 10);
+    assert_eq!(handle.committed(), 10);
+    assert_eq!(handle.error(), Some("network down".to_string()));
+}
+
+#[tokio::test]
+async fn test_overlapping_updates() {
+    let handle = with_test_isolate(likes);
+
+    let (finish_first, first) = operation();
+    let (finish_second, second) = operation();
+    handle.set_optimistic(11, first);
+    handle.set_optimistic(12, second);
+    assert_eq!(handle.get(), 12);
+
+    // The first update failing doesn't hide the second one
+    finish_first.send(Err("conflict".to_string())).unwrap();
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(handle.get(), 12);
+    assert!(handle.is_pending());
+
+    finish_second.send(Ok(())).unwrap();
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(handle.get(), 12);
+    assert_eq!(handle.committed(), 12);
+    assert!(!handle.is_pending());
+}
+
+#[test]
+fn test_state_persists_across_renders() {
+    with_test_isolate(|| {
+        likes().set(42);
+        assert_eq!(likes().get(), 42);
+        assert_eq!(likes().committed(), 42);
+    });
+}
+
+#[test]
+fn test_optimistic_update_without_runtime_is_rejected() {
+    let handle = with_test_isolate(likes);
+
+    handle.set_optimistic(11, async { Ok::<(), String>(()) });
+
+    // Nothing could resolve the update, so it is never shown
+    assert_eq!(handle.get(), 10);
+    assert!(!handle.is_pending());
+    assert_eq!(handle.error().as_deref(), Some(NO_RUNTIME_ERROR));
+}
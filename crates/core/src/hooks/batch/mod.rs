@@ -0,0 +1,324 @@
+//! Batched State Updates
+//!
+//! Every state change requests a re-render. When an event handler updates
+//! several independent states in a row, each setter would request its own
+//! re-render; [`batch_updates`] groups them so only a single render request is
+//! issued once the batch completes.
+//!
+//! Batches are tracked per thread and may be nested: the render request is
+//! issued when the outermost batch finishes.
+//!
+//! Render requests carry a [`Priority`]. High-priority requests (the default)
+//! are served by the next frame; low-priority requests are deferred behind
+//! them and only served by a frame with no high-priority work pending. Use
+//! [`with_render_priority`] to mark the updates made by a closure as low
+//! priority, or [`schedule_render`] to request a render directly.
+//!
+//! The runtime only draws a frame when input arrived or a render was
+//! requested. While a frame renders, [`serving_priority`] tells which
+//! priority it serves, so work that may lag behind can wait for a
+//! low-priority frame. A [`RenderScope`] gives the current thread a scheduler
+//! of its own, e.g. for an app running next to others in the same process.
+
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(test)]
+mod tests;
+
+/// Render requests not yet picked up by the runtime
+static SCHEDULER: RenderScheduler = RenderScheduler::new();
+
+thread_local! {
+    /// Nesting depth of `batch_updates` calls on this thread
+    static BATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+    /// Highest priority of the updates made inside the current batch
+    static BATCH_PRIORITY: Cell<Option<Priority>> = const { Cell::new(None) };
+    /// Priority of the render requests issued by state updates on this thread
+    static CURRENT_PRIORITY: Cell<Priority> = const { Cell::new(Priority::High) };
+    /// Number of render requests issued from this thread
+    static RENDER_REQUEST_COUNT: Cell<u64> = const { Cell::new(0) };
+    /// Scheduler of the active [`RenderScope`] on this thread
+    static SCOPED_SCHEDULER: RefCell<Option<Arc<RenderScheduler>>> = const { RefCell::new(None) };
+    /// Priority served by the frame rendering on this thread
+    static SERVING_PRIORITY: Cell<Option<Priority>> = const { Cell::new(None) };
+}
+
+/// Priority of a render request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// Deferred until no high-priority render is pending
+    Low,
+    /// Rendered in the next frame
+    #[default]
+    High,
+}
+
+/// Pending render requests, one flag per priority
+#[derive(Debug, Default)]
+struct RenderScheduler {
+    high: AtomicBool,
+    low: AtomicBool,
+}
+
+impl RenderScheduler {
+    const fn new() -> Self {
+        Self {
+            high: AtomicBool::new(false),
+            low: AtomicBool::new(false),
+        }
+    }
+
+    fn schedule(&self, priority: Priority) {
+        match priority {
+            Priority::High => self.high.store(true, Ordering::Release),
+            Priority::Low => self.low.store(true, Ordering::Release),
+        }
+    }
+
+    fn take_next(&self) -> Option<Priority> {
+        if self.high.swap(false, Ordering::AcqRel) {
+            Some(Priority::High)
+        } else if self.low.swap(false, Ordering::AcqRel) {
+            Some(Priority::Low)
+        } else {
+            None
+        }
+    }
+
+    fn take_all(&self) -> bool {
+        let high = self.high.swap(false, Ordering::AcqRel);
+        let low = self.low.swap(false, Ordering::AcqRel);
+        high || low
+    }
+}
+
+fn with_scheduler<R>(f: impl FnOnce(&RenderScheduler) -> R) -> R {
+    match SCOPED_SCHEDULER.with(|scheduler| scheduler.borrow().clone()) {
+        Some(scheduler) => f(&scheduler),
+        None => f(&SCHEDULER),
+    }
+}
+
+/// A render scheduler of its own for the current thread
+///
+/// While the scope is alive, render requests issued on this thread and the
+/// frames taken on it use a scheduler apart from the app-wide one. Requests
+/// from other threads, such as background tasks, still go to the app-wide
+/// scheduler. The previous scheduler comes back when it is dropped.
+#[derive(Debug)]
+pub struct RenderScope {
+    previous: Option<Arc<RenderScheduler>>,
+    // Bound to the thread whose scheduler it replaces
+    _not_send: PhantomData<*const ()>,
+}
+
+impl RenderScope {
+    /// Start scheduling renders of this thread with nothing pending
+    pub fn start() -> Self {
+        let previous = SCOPED_SCHEDULER
+            .with(|scheduler| scheduler.replace(Some(Arc::new(RenderScheduler::new()))));
+        Self {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for RenderScope {
+    fn drop(&mut self) {
+        SCOPED_SCHEDULER.with(|scheduler| *scheduler.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Request a re-render of the component tree
+///
+/// The request uses the priority set by [`with_render_priority`], which is
+/// [`Priority::High`] by default. Inside [`batch_updates`] the request is
+/// deferred until the batch completes. State setters call this automatically.
+pub fn request_render() {
+    schedule_render(current_priority());
+}
+
+/// Request a re-render of the component tree with the given priority
+///
+/// Inside [`batch_updates`] the request is deferred until the batch
+/// completes, and the batch issues a single request with the highest
+/// priority scheduled inside it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::batch::{Priority, schedule_render};
+///
+/// // Refresh a statistics panel whenever the UI has time for it
+/// schedule_render(Priority::Low);
+/// ```
+pub fn schedule_render(priority: Priority) {
+    if is_batching() {
+        BATCH_PRIORITY.with(|batch| batch.set(batch.get().max(Some(priority))));
+        return;
+    }
+
+    RENDER_REQUEST_COUNT.with(|count| count.set(count.get() + 1));
+    with_scheduler(|scheduler| scheduler.schedule(priority));
+}
+
+/// Take the next render to serve, if any
+///
+/// Returns [`Priority::High`] while a high-priority request is pending,
+/// leaving low-priority requests pending for a later frame. Low-priority
+/// requests are only taken once no high-priority request is pending. Used by
+/// the runtime once per frame.
+pub fn take_scheduled_render() -> Option<Priority> {
+    with_scheduler(RenderScheduler::take_next)
+}
+
+/// Take the render the next frame serves, if it needs one
+///
+/// A frame handling input is `urgent`: it serves [`Priority::High`] whether
+/// or not a render was requested, and leaves low-priority requests pending
+/// just like [`take_scheduled_render`] does. Returns `None` when the frame
+/// can be skipped. Used by the runtime once per frame.
+pub fn take_frame_render(urgent: bool) -> Option<Priority> {
+    with_scheduler(|scheduler| {
+        if urgent {
+            scheduler.high.store(false, Ordering::Release);
+            Some(Priority::High)
+        } else {
+            scheduler.take_next()
+        }
+    })
+}
+
+/// Take all pending render requests, returning true if one was pending
+///
+/// Unlike [`take_scheduled_render`], this clears requests of every priority.
+pub fn take_render_request() -> bool {
+    with_scheduler(RenderScheduler::take_all)
+}
+
+/// Render a frame that serves requests of `priority`
+///
+/// [`serving_priority`] returns `priority` while `render` runs. Used by the
+/// runtime around drawing each frame.
+pub fn serve_render<R>(priority: Priority, render: impl FnOnce() -> R) -> R {
+    struct ServingGuard(Option<Priority>);
+
+    impl Drop for ServingGuard {
+        fn drop(&mut self) {
+            SERVING_PRIORITY.with(|serving| serving.set(self.0));
+        }
+    }
+
+    let _guard = ServingGuard(SERVING_PRIORITY.with(|serving| serving.replace(Some(priority))));
+    render()
+}
+
+/// Priority served by the frame rendering on the current thread
+///
+/// `None` outside of a frame drawn by the runtime, e.g. when a component is
+/// rendered directly in a test.
+pub fn serving_priority() -> Option<Priority> {
+    SERVING_PRIORITY.with(Cell::get)
+}
+
+/// Returns the priority used by render requests on the current thread
+pub fn current_priority() -> Priority {
+    CURRENT_PRIORITY.with(Cell::get)
+}
+
+/// Run `updates` with their render requests issued at `priority`
+///
+/// State setters called inside the closure apply their values immediately;
+/// only the scheduling of the resulting render changes. The previous
+/// priority is restored afterwards, even if the closure panics.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::batch::{Priority, with_render_priority};
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (_stats, set_stats) = use_state(|| 0u64);
+///
+/// // The statistics may lag behind user input
+/// with_render_priority(Priority::Low, || set_stats.set(42));
+/// ```
+pub fn with_render_priority<F, R>(priority: Priority, updates: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct PriorityGuard(Priority);
+
+    impl Drop for PriorityGuard {
+        fn drop(&mut self) {
+            CURRENT_PRIORITY.with(|current| current.set(self.0));
+        }
+    }
+
+    let _guard = PriorityGuard(CURRENT_PRIORITY.with(|current| current.replace(priority)));
+    updates()
+}
+
+/// Returns the number of render requests issued from the current thread
+///
+/// Useful for diagnostics and for verifying that updates are batched.
+pub fn render_request_count() -> u64 {
+    RENDER_REQUEST_COUNT.with(|count| count.get())
+}
+
+/// Returns true if the current thread is inside a [`batch_updates`] call
+pub fn is_batching() -> bool {
+    BATCH_DEPTH.with(|depth| depth.get() > 0)
+}
+
+/// Run `updates` with state changes batched into a single re-render
+///
+/// All state setters called inside the closure apply their values immediately,
+/// but only one render request is issued after the closure returns (and only
+/// if at least one update happened). The request is issued even if the closure
+/// panics.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::batch::batch_updates;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (_name, set_name) = use_state(String::new);
+/// let (_age, set_age) = use_state(|| 0u32);
+///
+/// // Later, e.g. in an event handler:
+/// batch_updates(|| {
+///     set_name.set("Ada".to_string());
+///     set_age.set(36);
+/// });
+/// ```
+pub fn batch_updates<F, R>(updates: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct BatchGuard;
+
+    impl Drop for BatchGuard {
+        fn drop(&mut self) {
+            let depth = BATCH_DEPTH.with(|depth| {
+                depth.set(depth.get() - 1);
+                depth.get()
+            });
+
+            if depth == 0
+                && let Some(priority) = BATCH_PRIORITY.with(|batch| batch.replace(None))
+            {
+                schedule_render(priority);
+            }
+        }
+    }
+
+    BATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let _guard = BatchGuard;
+    updates()
+}
@@ -0,0 +1,165 @@
+//! Tests for batched state updates
+
+use super::*;
+use crate::hooks::state::use_state;
+use crate::hooks::test_utils::{with_hook_context, with_test_isolate};
+
+#[test]
+fn test_unbatched_setters_each_request_render() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let (_a, set_a) = use_state(|| 0);
+            let (_b, set_b) = use_state(|| 0);
+
+            let before = render_request_count();
+            set_a.set(1);
+            set_b.set(2);
+            assert_eq!(render_request_count() - before, 2);
+        });
+    });
+}
+
+#[test]
+fn test_batched_setters_produce_single_render() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let (a, set_a) = use_state(|| 0);
+            let (b, set_b) = use_state(String::new);
+            let (c, set_c) = use_state(|| false);
+
+            let before = render_request_count();
+            batch_updates(|| {
+                set_a.set(1);
+                set_b.set("two".to_string());
+                set_c.update(|value| !value);
+
+                // Values are applied immediately, the render is deferred
+                assert_eq!(a.get(), 1);
+                assert_eq!(render_request_count(), before);
+            });
+
+            assert_eq!(render_request_count() - before, 1);
+            assert_eq!(b.get(), "two");
+            assert!(c.get());
+        });
+    });
+}
+
+#[test]
+fn test_nested_batches_render_once() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let (_value, set_value) = use_state(|| 0);
+
+            let before = render_request_count();
+            batch_updates(|| {
+                set_value.set(1);
+                batch_updates(|| set_value.set(2));
+                assert!(is_batching());
+                set_value.set(3);
+            });
+
+            assert!(!is_batching());
+            assert_eq!(render_request_count() - before, 1);
+        });
+    });
+}
+
+#[test]
+fn test_empty_batch_does_not_request_render() {
+    let before = render_request_count();
+    let result = batch_updates(|| 42);
+    assert_eq!(result, 42);
+    assert_eq!(render_request_count(), before);
+}
+
+#[test]
+fn test_batch_flushes_on_panic() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let (_value, set_value) = use_state(|| 0);
+
+            let before = render_request_count();
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                batch_updates(|| {
+                    set_value.set(1);
+                    panic!("handler failed");
+                })
+            }));
+
+            assert!(result.is_err());
+            assert!(!is_batching());
+            assert_eq!(render_request_count() - before, 1);
+        });
+    });
+}
+
+#[test]
+fn test_low_priority_deferred_behind_high_priority() {
+    let scheduler = RenderScheduler::new();
+
+    scheduler.schedule(Priority::Low);
+    scheduler.schedule(Priority::High);
+    scheduler.schedule(Priority::Low);
+
+    // The first frame serves the high-priority update, the low-priority one
+    // stays pending for the next frame
+    assert_eq!(scheduler.take_next(), Some(Priority::High));
+    assert_eq!(scheduler.take_next(), Some(Priority::Low));
+    assert_eq!(scheduler.take_next(), None);
+
+    scheduler.schedule(Priority::Low);
+    assert!(scheduler.take_all());
+    assert_eq!(scheduler.take_next(), None);
+}
+
+#[test]
+fn test_render_priority_scope() {
+    assert_eq!(current_priority(), Priority::High);
+
+    let inner = with_render_priority(Priority::Low, || {
+        let nested = with_render_priority(Priority::High, current_priority);
+        assert_eq!(nested, Priority::High);
+        current_priority()
+    });
+
+    assert_eq!(inner, Priority::Low);
+    assert_eq!(current_priority(), Priority::High);
+}
+
+#[test]
+fn test_batch_schedules_highest_priority() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let (_value, set_value) = use_state(|| 0);
+
+            let before = render_request_count();
+            batch_updates(|| {
+                with_render_priority(Priority::Low, || set_value.set(1));
+                assert_eq!(BATCH_PRIORITY.with(Cell::get), Some(Priority::Low));
+                set_value.set(2);
+                assert_eq!(BATCH_PRIORITY.with(Cell::get), Some(Priority::High));
+            });
+
+            assert_eq!(BATCH_PRIORITY.with(Cell::get), None);
+            assert_eq!(render_request_count() - before, 1);
+        });
+    });
+}
+
+#[test]
+fn test_urgent_frames_leave_low_priority_pending() {
+    let _scope = RenderScope::start();
+    assert_eq!(take_frame_render(false), None);
+
+    schedule_render(Priority::Low);
+    schedule_render(Priority::High);
+    assert_eq!(take_frame_render(true), Some(Priority::High));
+    assert_eq!(take_frame_render(false), Some(Priority::Low));
+    assert_eq!(take_frame_render(false), None);
+
+    assert_eq!(serving_priority(), None);
+    let served = serve_render(Priority::Low, serving_priority);
+    assert_eq!(served, Some(Priority::Low));
+    assert_eq!(serving_priority(), None);
+}
@@ -0,0 +1,133 @@
+//! Terminal Bell
+//!
+//! This module provides [`ring_bell`] and the [`use_bell`] hook for drawing
+//! attention to alerts, such as a budget being exceeded, by emitting the
+//! terminal bell character (`\x07`). Depending on the terminal this beeps,
+//! flashes the window or marks the tab. Bells are rate-limited so that an
+//! alert condition that holds for many frames doesn't ring on every render.
+
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::clock;
+use crate::hooks::with_hook_context;
+
+#[cfg(test)]
+mod tests;
+
+/// The terminal bell character
+pub const BELL: &[u8] = b"\x07";
+
+/// Minimum time between two bells rung by [`ring_bell`]
+pub const DEFAULT_BELL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Bell shared by all [`ring_bell`] calls
+static GLOBAL_BELL: Bell = Bell::new(DEFAULT_BELL_INTERVAL);
+
+/// A rate-limited terminal bell
+#[derive(Debug)]
+pub struct Bell {
+    min_interval: Duration,
+    last_rung: Mutex<Option<Instant>>,
+}
+
+impl Bell {
+    /// Create a bell that rings at most once per `min_interval`
+    pub const fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_rung: Mutex::new(None),
+        }
+    }
+
+    /// Ring the bell on stdout, returning false if it was rate-limited
+    pub fn ring(&self) -> bool {
+        self.ring_to(&mut io::stdout()).unwrap_or(false)
+    }
+
+    /// Write the bell character to `writer`, unless it rang too recently
+    ///
+    /// Returns whether the bell was written.
+    pub fn ring_to<W: Write>(&self, writer: &mut W) -> io::Result<bool> {
+        let now = clock::now();
+        {
+            let mut last_rung = self.last_rung.lock();
+            if last_rung.is_some_and(|last| now.duration_since(last) < self.min_interval) {
+                return Ok(false);
+            }
+            *last_rung = Some(now);
+        }
+
+        writer.write_all(BELL)?;
+        writer.flush()?;
+        Ok(true)
+    }
+}
+
+/// Ring the terminal bell, at most once per [`DEFAULT_BELL_INTERVAL`]
+///
+/// Returns false if the bell was suppressed because it rang too recently.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::bell::ring_bell;
+///
+/// # let spent = 120.0; let budget = 100.0;
+/// if spent > budget {
+///     ring_bell();
+/// }
+/// ```
+pub fn ring_bell() -> bool {
+    GLOBAL_BELL.ring()
+}
+
+/// Handle returned by [`use_bell`]
+#[derive(Debug, Clone)]
+pub struct BellHandle {
+    bell: Arc<Bell>,
+}
+
+impl BellHandle {
+    /// Ring the bell on stdout, returning false if it was rate-limited
+    pub fn ring(&self) -> bool {
+        self.bell.ring()
+    }
+
+    /// Write the bell character to `writer`, unless it rang too recently
+    pub fn ring_to<W: Write>(&self, writer: &mut W) -> io::Result<bool> {
+        self.bell.ring_to(writer)
+    }
+}
+
+/// Hook that provides a bell rate-limited per component
+///
+/// Each component gets its own limiter, so an alert in one component doesn't
+/// silence another.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::bell::use_bell;
+/// # use std::time::Duration;
+/// # let over_budget = true;
+/// // In a component context:
+/// let bell = use_bell(Duration::from_secs(5));
+///
+/// // Rings at most every five seconds while over budget
+/// if over_budget {
+///     bell.ring();
+/// }
+/// ```
+pub fn use_bell(min_interval: Duration) -> BellHandle {
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let bell = ctx.get_or_init_state(index, || Arc::new(Bell::new(min_interval)));
+        BellHandle {
+            bell: bell.borrow().clone(),
+        }
+    })
+}
@@ -0,0 +1,49 @@
+//! Tests for the terminal bell
+
+use super::*;
+use crate::clock::ManualClock;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+#[test]
+fn test_bell_byte_is_written() {
+    let bell = Bell::new(Duration::from_millis(50));
+    let mut output = Vec::new();
+
+    assert!(bell.ring_to(&mut output).unwrap());
+    assert_eq!(output, b"\x07");
+}
+
+#[test]
+fn test_rapid_repeats_are_suppressed() {
+    let clock = ManualClock::start();
+    let bell = Bell::new(Duration::from_millis(50));
+    let mut output = Vec::new();
+
+    assert!(bell.ring_to(&mut output).unwrap());
+    assert!(!bell.ring_to(&mut output).unwrap());
+    clock.advance(Duration::from_millis(49));
+    assert!(!bell.ring_to(&mut output).unwrap());
+    assert_eq!(output, BELL);
+
+    clock.advance(Duration::from_millis(1));
+    assert!(bell.ring_to(&mut output).unwrap());
+    assert_eq!(output, b"\x07\x07");
+}
+
+#[test]
+fn test_use_bell_keeps_limiter_across_renders() {
+    with_test_isolate(|| {
+        let mut output = Vec::new();
+
+        let first = with_component_id("BellComponent", |_| use_bell(Duration::from_secs(10)));
+        assert!(first.ring_to(&mut output).unwrap());
+
+        let second = with_component_id("BellComponent", |_| use_bell(Duration::from_secs(10)));
+        assert!(!second.ring_to(&mut output).unwrap());
+
+        // Another component has its own limiter
+        let other = with_component_id("OtherBellComponent", |_| use_bell(Duration::from_secs(10)));
+        assert!(other.ring_to(&mut output).unwrap());
+        assert_eq!(output, b"\x07\x07");
+    });
+}
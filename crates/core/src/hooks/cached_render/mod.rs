@@ -0,0 +1,139 @@
+//! Cached Render Hook
+//!
+//! This module provides a `use_cached_render` hook for content that is
+//! expensive to draw but rarely changes, such as a large ASCII-art header.
+//! The content is rendered once, its cells are kept in an off-screen buffer,
+//! and every following frame copies the buffer into place instead of drawing
+//! the content again, until the cache is invalidated or the area changes.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use ratatui::{Frame, buffer::Buffer, layout::Rect};
+
+use crate::Component;
+use crate::component::keep_mounted;
+use crate::hooks::batch::request_render;
+use crate::hooks::{HookContext, with_hook_context};
+use crate::memo::{blit_region, capture_region, render_isolated};
+
+#[cfg(test)]
+mod tests;
+
+/// The cached output
+struct CachedFrame {
+    area: Rect,
+    cells: Buffer,
+    rendered: Vec<usize>,
+}
+
+/// Per-hook state of [`use_cached_render`]
+struct CacheState {
+    context: Rc<HookContext>,
+    cached: Option<CachedFrame>,
+    renders: usize,
+    /// Bumped by `invalidate`, so a draw that was invalidated while it ran
+    /// doesn't cache its output
+    generation: u64,
+}
+
+/// Handle returned by [`use_cached_render`]
+#[derive(Clone)]
+pub struct CachedRender {
+    state: Rc<RefCell<CacheState>>,
+}
+
+impl CachedRender {
+    /// Render `component` into `area`, drawing it only when not cached
+    pub fn render<C: Component>(&self, component: &C, area: Rect, frame: &mut Frame) {
+        self.render_with(area, frame, |area, frame| {
+            component.render_with_mount(area, frame)
+        });
+    }
+
+    /// Render with `draw` into `area`, calling it only when not cached
+    ///
+    /// Hooks used by `draw` run in a hook context of their own, so skipping
+    /// it doesn't change the hook order of the calling component.
+    pub fn render_with<F>(&self, area: Rect, frame: &mut Frame, draw: F)
+    where
+        F: FnOnce(Rect, &mut Frame),
+    {
+        let (context, generation) = {
+            let state = self.state.borrow();
+            if let Some(cached) = &state.cached
+                && cached.area == area
+            {
+                blit_region(frame.buffer_mut(), &cached.cells);
+                keep_mounted(&cached.rendered);
+                return;
+            }
+            (state.context.clone(), state.generation)
+        };
+
+        // Not borrowed while drawing, so `draw` may use this handle
+        let rendered = render_isolated(&context, || draw(area, frame));
+
+        let mut state = self.state.borrow_mut();
+        state.renders += 1;
+        state.cached = (state.generation == generation).then(|| CachedFrame {
+            area,
+            cells: capture_region(frame.buffer_mut(), area),
+            rendered,
+        });
+    }
+
+    /// Drop the cached output so the next frame draws the content again
+    pub fn invalidate(&self) {
+        let mut state = self.state.borrow_mut();
+        state.cached = None;
+        state.generation += 1;
+        drop(state);
+        request_render();
+    }
+
+    /// Returns true if the next render can reuse the cached output
+    pub fn is_cached(&self) -> bool {
+        self.state.borrow().cached.is_some()
+    }
+
+    /// Number of times the content was actually drawn
+    pub fn render_count(&self) -> usize {
+        self.state.borrow().renders
+    }
+}
+
+/// Hook that caches rendered output across frames
+///
+/// The first render draws the content and keeps a copy of its cells; later
+/// renders into the same area copy the cells back. Call
+/// [`invalidate`](CachedRender::invalidate) when the content changes.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::cached_render::use_cached_render;
+/// # use ratatui::{Frame, layout::Rect, widgets::Paragraph};
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// # let banner = String::new();
+/// // In a component context:
+/// let header = use_cached_render();
+///
+/// header.render_with(area, frame, |area, frame| {
+///     // Drawn once, then copied every frame
+///     frame.render_widget(Paragraph::new(banner.as_str()), area);
+/// });
+/// # }
+/// ```
+pub fn use_cached_render() -> CachedRender {
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let state = ctx.get_or_init_state(index, || CacheState {
+            context: Rc::new(HookContext::new()),
+            cached: None,
+            renders: 0,
+            generation: 0,
+        });
+        CachedRender { state }
+    })
+}
@@ -0,0 +1,112 @@
+//! Tests for the use_cached_render hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use ratatui::{Terminal, backend::TestBackend, widgets::Paragraph};
+use std::cell::Cell;
+
+fn draw_frame(
+    terminal: &mut Terminal<TestBackend>,
+    draws: &Cell<usize>,
+    text: &str,
+) -> (CachedRender, String) {
+    let cache = with_component_id("CachedComponent", |_| {
+        let cache = use_cached_render();
+        terminal
+            .draw(|frame| {
+                cache.render_with(frame.area(), frame, |area, frame| {
+                    draws.set(draws.get() + 1);
+                    frame.render_widget(Paragraph::new(text), area);
+                });
+            })
+            .unwrap();
+        cache
+    });
+
+    let shown = terminal
+        .backend()
+        .buffer()
+        .content
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string();
+    (cache, shown)
+}
+
+#[test]
+fn test_content_drawn_once_and_copied() {
+    with_test_isolate(|| {
+        let draws = Cell::new(0);
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+
+        let (cache, shown) = draw_frame(&mut terminal, &draws, "banner");
+        assert_eq!(shown, "banner");
+        assert!(cache.is_cached());
+
+        // The frame buffer starts out empty, the cached cells fill it
+        for _ in 0..3 {
+            let (_, shown) = draw_frame(&mut terminal, &draws, "changed");
+            assert_eq!(shown, "banner");
+        }
+        assert_eq!(draws.get(), 1);
+        assert_eq!(cache.render_count(), 1);
+    });
+}
+
+#[test]
+fn test_invalidate_draws_again() {
+    with_test_isolate(|| {
+        let draws = Cell::new(0);
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+
+        let (cache, _) = draw_frame(&mut terminal, &draws, "old");
+        cache.invalidate();
+        assert!(!cache.is_cached());
+
+        let (_, shown) = draw_frame(&mut terminal, &draws, "new");
+        assert_eq!(shown, "new");
+        assert_eq!(draws.get(), 2);
+    });
+}
+
+#[test]
+fn test_area_change_draws_again() {
+    with_test_isolate(|| {
+        let draws = Cell::new(0);
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        draw_frame(&mut terminal, &draws, "text");
+
+        terminal.backend_mut().resize(8, 1);
+        terminal.autoresize().unwrap();
+        draw_frame(&mut terminal, &draws, "text");
+        assert_eq!(draws.get(), 2);
+    });
+}
+
+#[test]
+fn test_handle_can_be_used_while_drawing() {
+    with_test_isolate(|| {
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+
+        let cache = with_component_id("CachedComponent", |_| {
+            let cache = use_cached_render();
+            terminal
+                .draw(|frame| {
+                    cache.render_with(frame.area(), frame, |area, frame| {
+                        assert!(!cache.is_cached());
+                        assert_eq!(cache.render_count(), 0);
+                        // Invalidated while drawing, so this output isn't kept
+                        cache.invalidate();
+                        frame.render_widget(Paragraph::new("stale"), area);
+                    });
+                })
+                .unwrap();
+            cache
+        });
+
+        assert!(!cache.is_cached());
+        assert_eq!(cache.render_count(), 1);
+    });
+}
@@ -583,5 +583,99 @@ where
     use_callback(CallbackFactory(move || Callback::from(handler)), deps)
 }
 
+/// Handler returned by [`use_effect_event`]
+///
+/// The handler keeps its identity across renders, while calling it runs the
+/// closure passed in the latest render. Clones share the same identity.
+pub struct StableHandler<IN, OUT = ()> {
+    latest: Arc<parking_lot::RwLock<Callback<IN, OUT>>>,
+}
+
+impl<IN, OUT> Clone for StableHandler<IN, OUT> {
+    fn clone(&self) -> Self {
+        Self {
+            latest: self.latest.clone(),
+        }
+    }
+}
+
+impl<IN, OUT> PartialEq for StableHandler<IN, OUT> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.latest, &other.latest)
+    }
+}
+
+impl<IN, OUT> fmt::Debug for StableHandler<IN, OUT> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StableHandler")
+            .field("latest", &Arc::as_ptr(&self.latest))
+            .finish()
+    }
+}
+
+impl<IN, OUT> StableHandler<IN, OUT> {
+    /// Call the closure of the latest render
+    pub fn emit(&self, input: IN) -> OUT {
+        // Clone first so the handler can be replaced while it runs
+        let callback = self.latest.read().clone();
+        callback.emit(input)
+    }
+}
+
+/// Hook that returns a stable handler always calling the latest `handler`
+///
+/// Modelled after React's `useEffectEvent`: the returned handler is the same
+/// on every render, so effects and long-lived callbacks can use it without
+/// listing it, or the values it reads, as dependencies. Calling it still runs
+/// the closure from the latest render, which sees up-to-date values.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::callback::use_effect_event;
+/// # use pulse_core::hooks::effect::use_effect;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (account, _set_account) = use_state(|| "checking".to_string());
+/// let (theme, _set_theme) = use_state(|| "dark".to_string());
+///
+/// let on_connected = use_effect_event({
+///     let theme = theme.get();
+///     move |account: String| println!("Connected to {account} ({theme})")
+/// });
+///
+/// // Reconnects when the account changes, not when the theme does
+/// let version = account.version();
+/// use_effect(
+///     move || {
+///         on_connected.emit(account.get());
+///         None::<Box<dyn FnOnce() + Send>>
+///     },
+///     version,
+/// );
+/// ```
+pub fn use_effect_event<IN, OUT, F>(handler: F) -> StableHandler<IN, OUT>
+where
+    F: Fn(IN) -> OUT + Send + Sync + 'static,
+    IN: 'static,
+    OUT: 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let mut handler = Some(Callback::new(handler));
+        let stable = ctx.get_or_init_state(index, || StableHandler {
+            latest: Arc::new(parking_lot::RwLock::new(
+                handler.take().expect("handler is unused"),
+            )),
+        });
+        let stable = stable.borrow().clone();
+
+        if let Some(handler) = handler {
+            *stable.latest.write() = handler;
+        }
+        stable
+    })
+}
+
 #[cfg(test)]
 mod tests;
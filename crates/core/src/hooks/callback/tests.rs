@@ -620,3 +620,25 @@ fn test_callback_factory_trait() {
         assert_eq!(callback2.emit(5), 15);
     });
 }
+
+#[test]
+fn test_use_effect_event_calls_latest_closure() {
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+    with_test_isolate(|| {
+        let render = |factor: i32| {
+            with_component_id("EffectEventTest", |_ctx| {
+                use_effect_event(move |value: i32| value * factor)
+            })
+        };
+
+        let first = render(2);
+        assert_eq!(first.emit(5), 10);
+
+        // The closure changed, the handler identity didn't
+        let second = render(3);
+        assert_eq!(second, first);
+        assert_eq!(first.emit(5), 15);
+        assert_eq!(second.emit(5), 15);
+    });
+}
@@ -0,0 +1,72 @@
+//! Cancellation Hook
+//!
+//! This module provides `use_cancellation`, which returns a cancellation
+//! token bound to the lifecycle of the calling component. The token is
+//! cancelled when the component unmounts, so async code started by the
+//! component can stop cooperatively, e.g. by racing its work against
+//! `token.cancelled()` in a `tokio::select!`.
+//!
+//! Every call in the same component returns the same token, so all async
+//! hooks of a component (`use_spawn`, `use_future`, `use_async_callback`,
+//! ...) can observe one shared token.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+pub use tokio_util::sync::CancellationToken;
+
+use crate::component::{current_component, register_unmount_cleanup};
+use crate::hooks::effect::CleanupFn;
+
+#[cfg(test)]
+mod tests;
+
+thread_local! {
+    /// Tokens of the mounted components that asked for one
+    static TOKENS: RefCell<HashMap<usize, CancellationToken>> = RefCell::new(HashMap::new());
+}
+
+/// Hook that returns the cancellation token of the current component
+///
+/// The token is cancelled when the component unmounts; a component that
+/// mounts again gets a fresh token. Outside of a mounted component, every
+/// call returns a new token that is never cancelled.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::cancellation::use_cancellation;
+/// use pulse_core::hooks::spawn::use_spawn;
+/// use std::time::Duration;
+///
+/// // In a component context:
+/// let token = use_cancellation();
+/// use_spawn(
+///     move || async move {
+///         tokio::select! {
+///             _ = token.cancelled() => println!("Sync stopped"),
+///             _ = tokio::time::sleep(Duration::from_secs(60)) => println!("Synced"),
+///         }
+///     },
+///     (),
+/// );
+/// ```
+pub fn use_cancellation() -> CancellationToken {
+    let Some(component) = current_component() else {
+        return CancellationToken::new();
+    };
+
+    if let Some(token) = TOKENS.with(|tokens| tokens.borrow().get(&component).cloned()) {
+        return token;
+    }
+
+    let token = CancellationToken::new();
+    TOKENS.with(|tokens| tokens.borrow_mut().insert(component, token.clone()));
+
+    let cancel = token.clone();
+    register_unmount_cleanup(CleanupFn::new(move || {
+        TOKENS.with(|tokens| tokens.borrow_mut().remove(&component));
+        cancel.cancel();
+    }));
+    token
+}
@@ -0,0 +1,65 @@
+//! Tests for the use_cancellation hook
+
+use super::*;
+use crate::component::{Component, cleanup_unmounted};
+use crate::hooks::{HookContext, clear_hook_context, set_hook_context};
+use parking_lot::Mutex;
+use ratatui::{Frame, Terminal, backend::TestBackend, layout::Rect};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Clone)]
+struct Syncer {
+    tokens: Arc<Mutex<Vec<CancellationToken>>>,
+}
+
+impl Component for Syncer {
+    fn render(&self, _area: Rect, _frame: &mut Frame) {
+        let mut tokens = self.tokens.lock();
+        tokens.push(use_cancellation());
+        tokens.push(use_cancellation());
+    }
+}
+
+#[tokio::test]
+async fn test_token_cancelled_on_unmount() {
+    let syncer = Syncer {
+        tokens: Arc::new(Mutex::new(Vec::new())),
+    };
+    let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+    set_hook_context(Rc::new(HookContext::new()));
+
+    for _ in 0..2 {
+        terminal
+            .draw(|frame| syncer.render_with_mount(frame.area(), frame))
+            .unwrap();
+        cleanup_unmounted();
+    }
+    let tokens = std::mem::take(&mut *syncer.tokens.lock());
+    // Every call across renders shares the component's token
+    assert_eq!(tokens.len(), 4);
+    let token = tokens[0].clone();
+    let waiter = tokio::spawn({
+        let token = token.clone();
+        async move { token.cancelled().await }
+    });
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert!(!waiter.is_finished());
+
+    // A frame without the syncer unmounts it
+    cleanup_unmounted();
+    tokio::time::timeout(Duration::from_secs(1), waiter)
+        .await
+        .expect("awaiting the token unblocks")
+        .unwrap();
+    assert!(tokens.iter().all(CancellationToken::is_cancelled));
+
+    // Mounting again hands out a fresh token
+    terminal
+        .draw(|frame| syncer.render_with_mount(frame.area(), frame))
+        .unwrap();
+    assert!(!syncer.tokens.lock()[0].is_cancelled());
+
+    clear_hook_context();
+}
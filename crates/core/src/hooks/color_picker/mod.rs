@@ -0,0 +1,192 @@
+//! Color Picker Hook
+//!
+//! This module provides a `use_color_picker` hook for choosing a color with
+//! red, green and blue sliders. `Up`/`Down` focus a slider and `Left`/`Right`
+//! move it, in steps of [`COARSE_STEP`] with Shift held. Draw the sliders and
+//! a preview swatch with the [`ColorPicker`](crate::widgets::ColorPicker)
+//! widget.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::Color;
+
+use crate::color::color_to_rgb;
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Amount a slider moves per key press with Shift held
+pub const COARSE_STEP: u8 = 16;
+
+/// One of the sliders of a color picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RgbChannel {
+    Red,
+    Green,
+    Blue,
+}
+
+impl RgbChannel {
+    /// All channels in slider order
+    pub const ALL: [RgbChannel; 3] = [RgbChannel::Red, RgbChannel::Green, RgbChannel::Blue];
+
+    /// Single-letter label of the slider
+    pub fn label(self) -> &'static str {
+        match self {
+            RgbChannel::Red => "R",
+            RgbChannel::Green => "G",
+            RgbChannel::Blue => "B",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PickerState {
+    rgb: [u8; 3],
+    focused: RgbChannel,
+}
+
+/// Handle returned by [`use_color_picker`]
+#[derive(Clone)]
+pub struct ColorPickerHandle {
+    state: StateHandle<PickerState>,
+    setter: StateSetter<PickerState>,
+}
+
+impl ColorPickerHandle {
+    /// The selected color, always a [`Color::Rgb`]
+    pub fn color(&self) -> Color {
+        let [r, g, b] = self.state.with(|state| state.rgb);
+        Color::Rgb(r, g, b)
+    }
+
+    /// The value of `channel`'s slider
+    pub fn value(&self, channel: RgbChannel) -> u8 {
+        self.state.with(|state| state.rgb[channel.index()])
+    }
+
+    /// The slider keyboard input applies to
+    pub fn focused(&self) -> RgbChannel {
+        self.state.with(|state| state.focused)
+    }
+
+    /// Focus `channel`'s slider
+    pub fn focus(&self, channel: RgbChannel) {
+        self.setter.update(move |state| PickerState {
+            focused: channel,
+            ..state.clone()
+        });
+    }
+
+    /// Focus the next slider, wrapping from blue to red
+    pub fn focus_next(&self) {
+        self.move_focus(1);
+    }
+
+    /// Focus the previous slider, wrapping from red to blue
+    pub fn focus_previous(&self) {
+        self.move_focus(RgbChannel::ALL.len() - 1);
+    }
+
+    /// Set `channel`'s slider to `value`
+    pub fn set_value(&self, channel: RgbChannel, value: u8) {
+        self.setter.update(move |state| {
+            let mut state = state.clone();
+            state.rgb[channel.index()] = value;
+            state
+        });
+    }
+
+    /// Move the focused slider by `delta`, stopping at 0 and 255
+    pub fn adjust(&self, delta: i16) {
+        self.setter.update(move |state| {
+            let mut state = state.clone();
+            let value = &mut state.rgb[state.focused.index()];
+            *value = (*value as i16 + delta).clamp(0, u8::MAX as i16) as u8;
+            state
+        });
+    }
+
+    /// Move all sliders to `color`
+    ///
+    /// Named and indexed colors use the default xterm palette, and
+    /// [`Color::Reset`] picks black.
+    pub fn set(&self, color: Color) {
+        let rgb = rgb_of(color);
+        self.setter.update(move |state| PickerState {
+            rgb,
+            ..state.clone()
+        });
+    }
+
+    /// Apply `key` to the sliders, returning true if it was handled
+    ///
+    /// `Up`/`Down` focus the previous or next slider and `Left`/`Right` move
+    /// the focused slider by one, or by [`COARSE_STEP`] with Shift held.
+    pub fn handle_key(&self, key: &KeyEvent) -> bool {
+        let step = if key.modifiers.contains(KeyModifiers::SHIFT) {
+            COARSE_STEP as i16
+        } else {
+            1
+        };
+        match key.code {
+            KeyCode::Up => self.focus_previous(),
+            KeyCode::Down => self.focus_next(),
+            KeyCode::Left => self.adjust(-step),
+            KeyCode::Right => self.adjust(step),
+            _ => return false,
+        }
+        true
+    }
+
+    fn move_focus(&self, offset: usize) {
+        self.setter.update(move |state| {
+            let index = (state.focused.index() + offset) % RgbChannel::ALL.len();
+            PickerState {
+                focused: RgbChannel::ALL[index],
+                ..state.clone()
+            }
+        });
+    }
+}
+
+fn rgb_of(color: Color) -> [u8; 3] {
+    let (r, g, b) = color_to_rgb(color).unwrap_or((0, 0, 0));
+    [r, g, b]
+}
+
+/// Hook for choosing a color with RGB sliders
+///
+/// The sliders start at `initial`, with the red slider focused. Pass key
+/// events to [`ColorPickerHandle::handle_key`] while the picker has focus
+/// and read the result with [`ColorPickerHandle::color`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::color_picker::use_color_picker;
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use pulse_core::widgets::ColorPicker;
+/// # use ratatui::{Frame, layout::Rect, style::Color};
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// let accent = use_color_picker(Color::Cyan);
+///
+/// if let Some(key) = use_key_event() {
+///     accent.handle_key(&key);
+/// }
+///
+/// frame.render_widget(ColorPicker::new(&accent), area);
+/// let theme_accent = accent.color();
+/// # }
+/// ```
+pub fn use_color_picker(initial: Color) -> ColorPickerHandle {
+    let (state, setter) = use_state(|| PickerState {
+        rgb: rgb_of(initial),
+        focused: RgbChannel::Red,
+    });
+    ColorPickerHandle { state, setter }
+}
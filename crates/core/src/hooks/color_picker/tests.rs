@@ -0,0 +1,73 @@
+//! Tests for the use_color_picker hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render(initial: Color) -> ColorPickerHandle {
+    with_component_id("AccentPicker", |_| use_color_picker(initial))
+}
+
+#[test]
+fn test_sliders_start_at_the_initial_color() {
+    with_test_isolate(|| {
+        let picker = render(Color::Rgb(10, 120, 250));
+        assert_eq!(picker.value(RgbChannel::Red), 10);
+        assert_eq!(picker.value(RgbChannel::Green), 120);
+        assert_eq!(picker.value(RgbChannel::Blue), 250);
+        assert_eq!(picker.focused(), RgbChannel::Red);
+
+        picker.set(Color::Reset);
+        assert_eq!(picker.color(), Color::Rgb(0, 0, 0));
+        picker.set(Color::Indexed(196));
+        assert_eq!(picker.color(), Color::Rgb(255, 0, 0));
+    });
+}
+
+#[test]
+fn test_slider_adjustment_stops_at_the_ends() {
+    with_test_isolate(|| {
+        let picker = render(Color::Rgb(250, 5, 128));
+
+        picker.adjust(10);
+        assert_eq!(picker.value(RgbChannel::Red), 255);
+
+        picker.focus(RgbChannel::Green);
+        picker.adjust(-3);
+        assert_eq!(picker.value(RgbChannel::Green), 2);
+        picker.adjust(-3);
+        assert_eq!(picker.value(RgbChannel::Green), 0);
+
+        picker.set_value(RgbChannel::Blue, 64);
+        assert_eq!(picker.value(RgbChannel::Blue), 64);
+
+        // Re-rendering keeps the sliders
+        let picker = render(Color::White);
+        assert_eq!(picker.color(), Color::Rgb(255, 0, 64));
+    });
+}
+
+#[test]
+fn test_keys_move_focus_and_sliders() {
+    with_test_isolate(|| {
+        let picker = render(Color::Rgb(100, 100, 100));
+        let press = |code| picker.handle_key(&KeyEvent::from(code));
+        let shift = |code| picker.handle_key(&KeyEvent::new(code, KeyModifiers::SHIFT));
+
+        assert!(press(KeyCode::Right));
+        assert!(press(KeyCode::Down));
+        assert!(shift(KeyCode::Right));
+        assert!(press(KeyCode::Down));
+        assert!(shift(KeyCode::Left));
+        assert!(press(KeyCode::Left));
+        assert_eq!(picker.focused(), RgbChannel::Blue);
+
+        // Focus wraps around
+        assert!(press(KeyCode::Down));
+        assert_eq!(picker.focused(), RgbChannel::Red);
+        assert!(press(KeyCode::Up));
+        assert_eq!(picker.focused(), RgbChannel::Blue);
+
+        assert!(!press(KeyCode::Enter));
+        assert_eq!(picker.color(), Color::Rgb(101, 116, 83));
+    });
+}
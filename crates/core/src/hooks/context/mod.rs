@@ -9,6 +9,7 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::rc::Rc;
 use std::sync::Arc;
 
 #[cfg(test)]
@@ -19,6 +20,8 @@ use crate::hooks::with_hook_context;
 thread_local! {
     static CONTEXT_PROVIDERS: RefCell<HashMap<TypeId, Vec<Box<dyn Any + Send + Sync>>>> =
         RefCell::new(HashMap::new());
+    // Types of the provided values, in the order they were provided
+    static PROVIDED_TYPES: RefCell<Vec<TypeId>> = const { RefCell::new(Vec::new()) };
 }
 
 /// Clear all context providers (called when hook context is reset)
@@ -26,13 +29,80 @@ pub fn clear_context_providers() {
     CONTEXT_PROVIDERS.with(|providers| {
         providers.borrow_mut().clear();
     });
+    PROVIDED_TYPES.with(|types| types.borrow_mut().clear());
+}
+
+/// Removes the values provided since it was created when dropped
+struct ContextScope {
+    depth: usize,
+}
+
+impl ContextScope {
+    fn enter() -> Self {
+        Self {
+            depth: PROVIDED_TYPES.with(|types| types.borrow().len()),
+        }
+    }
+}
+
+impl Drop for ContextScope {
+    fn drop(&mut self) {
+        let provided = PROVIDED_TYPES.with(|types| types.borrow_mut().split_off(self.depth));
+        CONTEXT_PROVIDERS.with(|providers| {
+            let mut providers = providers.borrow_mut();
+            for type_id in provided.into_iter().rev() {
+                if let Some(provider_stack) = providers.get_mut(&type_id) {
+                    provider_stack.pop();
+                }
+            }
+        });
+    }
+}
+
+/// Runs `f` in its own context scope
+///
+/// Context values provided while `f` runs shadow values of the same type
+/// provided outside of it, and are removed again when it returns, so the
+/// outer values are visible afterwards. [`Component::render_with_mount`]
+/// renders every component in a scope of its own, which makes a provider
+/// apply to the subtree of the component calling it only. Use this function
+/// to scope providers of children rendered with plain `render` calls.
+///
+/// [`Component::render_with_mount`]: crate::Component::render_with_mount
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::context::{use_context, use_context_provider, with_context_scope};
+///
+/// #[derive(Clone)]
+/// struct Accent(&'static str);
+///
+/// fn my_component() {
+///     use_context_provider(|| Accent("blue"));
+///
+///     with_context_scope(|| {
+///         // The sidebar and its children see the red accent
+///         use_context_provider(|| Accent("red"));
+///         // ... render the sidebar
+///     });
+///
+///     // Siblings rendered afterwards see the blue one again
+///     assert_eq!(use_context::<Accent>().0, "blue");
+/// }
+/// ```
+pub fn with_context_scope<R>(f: impl FnOnce() -> R) -> R {
+    let _scope = ContextScope::enter();
+    f()
 }
 
 /// Provides a context value for a type
 ///
 /// This function creates a context value that will be available to all components
 /// rendered within the current component's render function. It's similar to React's
-/// Context.Provider component but as a hook.
+/// Context.Provider component but as a hook. A value provided by a nested component
+/// shadows the one of a parent for the nested component's subtree only; see
+/// [`with_context_scope`].
 ///
 /// # Type Parameters
 ///
@@ -80,6 +150,7 @@ where
             let provider_stack = providers.entry(type_id).or_default();
             provider_stack.push(Box::new(value_clone));
         });
+        PROVIDED_TYPES.with(|types| types.borrow_mut().push(type_id));
 
         value
     })
@@ -284,3 +355,106 @@ where
         context.default_value.as_ref().clone()
     })
 }
+
+/// Select from the innermost provided value of `T` without cloning it
+fn select_from_context<T, U>(selector: impl Fn(&T) -> U) -> Option<U>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    CONTEXT_PROVIDERS.with(|providers| {
+        let providers = providers.borrow();
+        providers
+            .get(&TypeId::of::<T>())
+            .and_then(|provider_stack| provider_stack.last())
+            .and_then(|last_provider| last_provider.downcast_ref::<T>())
+            .map(selector)
+    })
+}
+
+/// Consumes a slice of a context value
+///
+/// This function applies `selector` to the context value provided by a parent
+/// component and returns only the selected slice, without cloning the whole
+/// value. The previous slice is kept between renders and compared with the
+/// new one, so a consumer wrapped in [`memo`](crate::memo::memo) is only
+/// rendered again when its slice changes, not whenever any other part of the
+/// context does. If no context value is found, it will panic like
+/// [`use_context`].
+///
+/// # Type Parameters
+///
+/// * `T` - The type of the context value
+/// * `U` - The type of the selected slice
+///
+/// # Arguments
+///
+/// * `selector` - A function selecting the slice from the context value
+///
+/// # Returns
+///
+/// * The selected slice
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::Component;
+/// use pulse_core::hooks::context::use_context_selector;
+/// use pulse_core::memo::memo;
+/// use ratatui::{Frame, layout::Rect};
+///
+/// // A large context type
+/// #[derive(Clone)]
+/// struct SettingsContext {
+///     auto_save: bool,
+///     recent_files: Vec<String>,
+/// }
+///
+/// #[derive(Clone)]
+/// struct AutoSaveBadge;
+///
+/// impl Component for AutoSaveBadge {
+///     fn render(&self, _area: Rect, _frame: &mut Frame) {
+///         // Select the part the badge shows
+///         let auto_save = use_context_selector(|settings: &SettingsContext| settings.auto_save);
+///     }
+/// }
+///
+/// // In the parent component, opening files doesn't re-render the badge
+/// fn parent_component(area: Rect, frame: &mut Frame) {
+///     memo(AutoSaveBadge, ()).render(area, frame);
+/// }
+/// ```
+pub fn use_context_selector<T, U, F>(selector: F) -> U
+where
+    T: Clone + Send + Sync + 'static,
+    U: PartialEq + Clone + 'static,
+    F: Fn(&T) -> U + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+
+        let slice = select_from_context(&selector).unwrap_or_else(|| {
+            panic!(
+                "Context value for type {} not found. Make sure to call use_context_provider in a parent component.",
+                std::any::type_name::<T>()
+            )
+        });
+
+        // Keep the previous slice while the selection is equal
+        let selected = ctx.get_or_init_state(index, || slice.clone());
+        if *selected.borrow() != slice {
+            *selected.borrow_mut() = slice;
+        }
+
+        // Lets a memoized consumer skip frames where its slice is unchanged
+        let previous = selected.clone();
+        ctx.watch_selection(
+            index,
+            Rc::new(move || {
+                select_from_context(&selector).is_none_or(|slice| slice != *previous.borrow())
+            }),
+        );
+
+        selected.borrow().clone()
+    })
+}
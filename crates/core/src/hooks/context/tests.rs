@@ -1,9 +1,15 @@
+use crate::Component;
 use crate::hooks::{
     context::{
-        create_context_with_default, use_context, use_context_provider, use_context_with_default,
+        create_context_with_default, use_context, use_context_provider, use_context_selector,
+        use_context_with_default, with_context_scope,
     },
-    test_utils::with_component_id,
+    test_utils::{with_component_id, with_test_isolate},
 };
+use crate::memo::memo;
+use ratatui::{Frame, Terminal, backend::TestBackend, layout::Rect, widgets::Paragraph};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 
 #[derive(Clone, Debug, PartialEq)]
 struct TestUser {
@@ -250,3 +256,200 @@ fn test_context_with_default() {
         });
     });
 }
+
+#[derive(Clone, Debug, PartialEq)]
+struct TestSettings {
+    auto_save: bool,
+    recent_files: Vec<String>,
+}
+
+/// Consumer selecting only `auto_save` from the settings context, counting its renders
+#[derive(Clone)]
+struct AutoSaveConsumer {
+    renders: Rc<Cell<usize>>,
+}
+
+impl Component for AutoSaveConsumer {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        self.renders.set(self.renders.get() + 1);
+        let auto_save = use_context_selector(|settings: &TestSettings| settings.auto_save);
+        let text = if auto_save { "on" } else { "off" };
+        frame.render_widget(Paragraph::new(text), area);
+    }
+}
+
+#[test]
+fn test_context_selector_skips_unrelated_changes() {
+    with_test_isolate(|| {
+        let renders = Rc::new(Cell::new(0));
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut draw = |theme: &str, settings: TestSettings| {
+            with_component_id("SelectorProvider", |_ctx| {
+                use_context_provider(|| TestTheme {
+                    color: theme.to_string(),
+                    font: "Mono".to_string(),
+                });
+                use_context_provider(|| settings);
+                let consumer = AutoSaveConsumer {
+                    renders: renders.clone(),
+                };
+                terminal
+                    .draw(|frame| memo(consumer, ()).render(frame.area(), frame))
+                    .unwrap();
+            });
+        };
+        let settings = |auto_save, recent_files: &[&str]| TestSettings {
+            auto_save,
+            recent_files: recent_files.iter().map(ToString::to_string).collect(),
+        };
+
+        draw("Blue", settings(true, &[]));
+        assert_eq!(renders.get(), 1);
+
+        // Neither another context nor another part of this one re-renders
+        draw("Red", settings(true, &[]));
+        draw("Red", settings(true, &["notes.txt"]));
+        assert_eq!(renders.get(), 1);
+
+        draw("Red", settings(false, &["notes.txt"]));
+        assert_eq!(renders.get(), 2);
+        assert_eq!(terminal.backend().buffer().content()[1].symbol(), "f");
+    });
+}
+
+#[test]
+#[should_panic(expected = "not found")]
+fn test_context_selector_without_provider_panics() {
+    with_component_id("SelectorWithoutProvider", |_ctx| {
+        use_context_selector(|settings: &TestSettings| settings.auto_save);
+    });
+}
+
+/// Records the theme color it sees
+#[derive(Clone)]
+struct ThemeProbe {
+    name: &'static str,
+    seen: Rc<RefCell<Vec<String>>>,
+}
+
+impl Component for ThemeProbe {
+    fn component_id(&self) -> std::borrow::Cow<'static, str> {
+        self.name.into()
+    }
+
+    fn render(&self, _area: Rect, _frame: &mut Frame) {
+        let theme = use_context::<TestTheme>();
+        self.seen
+            .borrow_mut()
+            .push(format!("{}: {}", self.name, theme.color));
+    }
+}
+
+/// Provides a theme to the probe it renders
+#[derive(Clone)]
+struct ThemeProvider {
+    color: &'static str,
+    child: ThemeProbe,
+}
+
+impl Component for ThemeProvider {
+    fn component_id(&self) -> std::borrow::Cow<'static, str> {
+        format!("ThemeProvider({})", self.color).into()
+    }
+
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        use_context_provider(|| TestTheme {
+            color: self.color.to_string(),
+            font: "Sans".to_string(),
+        });
+        self.child.render_with_mount(area, frame);
+    }
+}
+
+/// Provides a theme, then renders a nested provider of the same type and a
+/// sibling after it
+#[derive(Clone)]
+struct NestedThemes {
+    seen: Rc<RefCell<Vec<String>>>,
+}
+
+impl Component for NestedThemes {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let probe = |name| ThemeProbe {
+            name,
+            seen: self.seen.clone(),
+        };
+        use_context_provider(|| TestTheme {
+            color: "Light".to_string(),
+            font: "Serif".to_string(),
+        });
+        probe("before").render_with_mount(area, frame);
+        ThemeProvider {
+            color: "Dark",
+            child: probe("inner"),
+        }
+        .render_with_mount(area, frame);
+        probe("after").render_with_mount(area, frame);
+    }
+}
+
+#[test]
+fn test_nested_providers_of_same_type_are_scoped() {
+    with_test_isolate(|| {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let app = NestedThemes { seen: seen.clone() };
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+
+        for _ in 0..2 {
+            with_component_id("NestedThemes", |_ctx| {
+                terminal
+                    .draw(|frame| app.render_with_mount(frame.area(), frame))
+                    .unwrap();
+            });
+        }
+
+        assert_eq!(
+            *seen.borrow(),
+            ["before: Light", "inner: Dark", "after: Light"].repeat(2)
+        );
+
+        // Nothing provided during the renders outlives them
+        with_component_id("AfterRender", |_ctx| {
+            let theme = use_context_with_default(&create_context_with_default(TestTheme {
+                color: "Default".to_string(),
+                font: "Default".to_string(),
+            }));
+            assert_eq!(theme.color, "Default");
+        });
+    });
+}
+
+#[test]
+fn test_context_scope_restores_outer_value() {
+    with_component_id("ScopedProvider", |_ctx| {
+        use_context_provider(|| TestTheme {
+            color: "Light".to_string(),
+            font: "Serif".to_string(),
+        });
+
+        let inner = with_context_scope(|| {
+            use_context_provider(|| TestTheme {
+                color: "Dark".to_string(),
+                font: "Sans".to_string(),
+            });
+            use_context_provider(|| TestUser {
+                name: "Inner".to_string(),
+                role: "Guest".to_string(),
+            });
+            use_context::<TestTheme>().color
+        });
+
+        assert_eq!(inner, "Dark");
+        assert_eq!(use_context::<TestTheme>().color, "Light");
+        let user = use_context_with_default(&create_context_with_default(TestUser {
+            name: "Nobody".to_string(),
+            role: "None".to_string(),
+        }));
+        assert_eq!(user.name, "Nobody");
+    });
+}
@@ -0,0 +1,157 @@
+//! Date Picker Hook
+//!
+//! This module provides a `use_date_picker` hook managing the selection of a
+//! calendar. The selected day moves by days and weeks with the arrow keys and
+//! by months and years with Shift held. When a month is shorter than the
+//! selected day, e.g. moving from January 31st to February, the last day of
+//! that month is selected instead. Pair it with the
+//! [`DatePicker`](crate::widgets::DatePicker) widget to draw the month grid.
+
+use chrono::{DateTime, Days, Months, NaiveDate, Utc};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Handle returned by [`use_date_picker`]
+#[derive(Clone)]
+pub struct DatePickerHandle {
+    selected: StateHandle<DateTime<Utc>>,
+    setter: StateSetter<DateTime<Utc>>,
+}
+
+impl DatePickerHandle {
+    /// The selected date and time
+    ///
+    /// The time of day is kept from the initial value while navigating.
+    pub fn selected(&self) -> DateTime<Utc> {
+        self.selected.get()
+    }
+
+    /// The selected day
+    pub fn date(&self) -> NaiveDate {
+        self.selected().date_naive()
+    }
+
+    /// Select `selected`
+    pub fn set(&self, selected: DateTime<Utc>) {
+        self.setter.set(selected);
+    }
+
+    /// Move the selection by `days`, crossing month and year boundaries
+    pub fn move_days(&self, days: i64) {
+        let days_abs = Days::new(days.unsigned_abs());
+        self.setter.update(move |selected| {
+            let moved = if days < 0 {
+                selected.checked_sub_days(days_abs)
+            } else {
+                selected.checked_add_days(days_abs)
+            };
+            moved.unwrap_or(*selected)
+        });
+    }
+
+    /// Move the selection by `months`, keeping the day where the month allows
+    pub fn move_months(&self, months: i32) {
+        let months_abs = Months::new(months.unsigned_abs());
+        self.setter.update(move |selected| {
+            let moved = if months < 0 {
+                selected.checked_sub_months(months_abs)
+            } else {
+                selected.checked_add_months(months_abs)
+            };
+            moved.unwrap_or(*selected)
+        });
+    }
+
+    /// Select the next day
+    pub fn next_day(&self) {
+        self.move_days(1);
+    }
+
+    /// Select the previous day
+    pub fn previous_day(&self) {
+        self.move_days(-1);
+    }
+
+    /// Select the same weekday of the next week
+    pub fn next_week(&self) {
+        self.move_days(7);
+    }
+
+    /// Select the same weekday of the previous week
+    pub fn previous_week(&self) {
+        self.move_days(-7);
+    }
+
+    /// Select the same day of the next month
+    pub fn next_month(&self) {
+        self.move_months(1);
+    }
+
+    /// Select the same day of the previous month
+    pub fn previous_month(&self) {
+        self.move_months(-1);
+    }
+
+    /// Select the same day of the next year, February 29th becoming the 28th
+    pub fn next_year(&self) {
+        self.move_months(12);
+    }
+
+    /// Select the same day of the previous year, February 29th becoming the 28th
+    pub fn previous_year(&self) {
+        self.move_months(-12);
+    }
+
+    /// Apply `key` to the calendar, returning true if it was handled
+    ///
+    /// `Left`/`Right` move by a day and `Up`/`Down` by a week. With Shift,
+    /// `Left`/`Right` move by a month and `Up`/`Down` by a year.
+    pub fn handle_key(&self, key: &KeyEvent) -> bool {
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match (key.code, shift) {
+            (KeyCode::Left, false) => self.previous_day(),
+            (KeyCode::Right, false) => self.next_day(),
+            (KeyCode::Up, false) => self.previous_week(),
+            (KeyCode::Down, false) => self.next_week(),
+            (KeyCode::Left, true) => self.previous_month(),
+            (KeyCode::Right, true) => self.next_month(),
+            (KeyCode::Up, true) => self.previous_year(),
+            (KeyCode::Down, true) => self.next_year(),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Hook for choosing a date from a calendar
+///
+/// The calendar starts at `initial`. Pass key events to
+/// [`DatePickerHandle::handle_key`] while the picker has focus, and draw it
+/// with [`DatePicker`](crate::widgets::DatePicker).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use chrono::Utc;
+/// # use pulse_core::hooks::date_picker::use_date_picker;
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use pulse_core::widgets::DatePicker;
+/// # use ratatui::{Frame, layout::Rect};
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// let picker = use_date_picker(Utc::now());
+///
+/// if let Some(key) = use_key_event() {
+///     picker.handle_key(&key);
+/// }
+///
+/// frame.render_widget(DatePicker::new(picker.date()), area);
+/// # }
+/// ```
+pub fn use_date_picker(initial: DateTime<Utc>) -> DatePickerHandle {
+    let (selected, setter) = use_state(|| initial);
+    DatePickerHandle { selected, setter }
+}
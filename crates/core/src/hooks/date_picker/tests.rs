@@ -0,0 +1,96 @@
+//! Tests for the use_date_picker hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use chrono::TimeZone;
+
+fn render(year: i32, month: u32, day: u32) -> DatePickerHandle {
+    let initial = Utc.with_ymd_and_hms(year, month, day, 9, 30, 0).unwrap();
+    with_component_id("DateField", |_| use_date_picker(initial))
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn test_day_and_week_navigation_crosses_boundaries() {
+    with_test_isolate(|| {
+        let picker = render(2024, 12, 31);
+
+        picker.next_day();
+        assert_eq!(picker.date(), ymd(2025, 1, 1));
+        picker.previous_day();
+        assert_eq!(picker.date(), ymd(2024, 12, 31));
+
+        picker.next_week();
+        assert_eq!(picker.date(), ymd(2025, 1, 7));
+        picker.previous_week();
+        picker.previous_week();
+        assert_eq!(picker.date(), ymd(2024, 12, 24));
+
+        // Leap day is only there in leap years
+        picker.set(Utc.with_ymd_and_hms(2024, 2, 28, 9, 30, 0).unwrap());
+        picker.next_day();
+        assert_eq!(picker.date(), ymd(2024, 2, 29));
+        picker.set(Utc.with_ymd_and_hms(2023, 2, 28, 9, 30, 0).unwrap());
+        picker.next_day();
+        assert_eq!(picker.date(), ymd(2023, 3, 1));
+
+        // The time of day is kept
+        assert_eq!(
+            picker.selected(),
+            Utc.with_ymd_and_hms(2023, 3, 1, 9, 30, 0).unwrap()
+        );
+    });
+}
+
+#[test]
+fn test_month_and_year_navigation_clamps_the_day() {
+    with_test_isolate(|| {
+        let picker = render(2024, 1, 31);
+
+        picker.next_month();
+        assert_eq!(picker.date(), ymd(2024, 2, 29));
+        picker.next_year();
+        assert_eq!(picker.date(), ymd(2025, 2, 28));
+        picker.previous_year();
+        assert_eq!(picker.date(), ymd(2024, 2, 28));
+
+        picker.set(Utc.with_ymd_and_hms(2025, 12, 15, 0, 0, 0).unwrap());
+        picker.next_month();
+        assert_eq!(picker.date(), ymd(2026, 1, 15));
+        picker.previous_month();
+        picker.previous_month();
+        assert_eq!(picker.date(), ymd(2025, 11, 15));
+
+        // Re-rendering keeps the selection
+        let picker = render(2000, 1, 1);
+        assert_eq!(picker.date(), ymd(2025, 11, 15));
+    });
+}
+
+#[test]
+fn test_arrow_keys_navigate() {
+    with_test_isolate(|| {
+        let picker = render(2025, 1, 1);
+        let press = |code| picker.handle_key(&KeyEvent::from(code));
+        let shift = |code| picker.handle_key(&KeyEvent::new(code, KeyModifiers::SHIFT));
+
+        assert!(press(KeyCode::Left));
+        assert_eq!(picker.date(), ymd(2024, 12, 31));
+        assert!(press(KeyCode::Down));
+        assert_eq!(picker.date(), ymd(2025, 1, 7));
+        assert!(shift(KeyCode::Left));
+        assert_eq!(picker.date(), ymd(2024, 12, 7));
+        assert!(shift(KeyCode::Down));
+        assert_eq!(picker.date(), ymd(2025, 12, 7));
+        assert!(press(KeyCode::Up));
+        assert!(shift(KeyCode::Right));
+        assert!(shift(KeyCode::Up));
+        assert_eq!(picker.date(), ymd(2024, 12, 30));
+
+        assert!(!press(KeyCode::Enter));
+        assert_eq!(picker.date(), ymd(2024, 12, 30));
+    });
+}
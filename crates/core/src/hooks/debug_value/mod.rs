@@ -0,0 +1,35 @@
+//! Debug Value Hook
+//!
+//! `use_debug_value` annotates the rendering component with a labeled value.
+//! The value appears next to the component in the dev overlay and in
+//! [`component_tree`](crate::devtools::component_tree), which helps keeping an
+//! eye on important internal state without putting it in the UI.
+
+use std::fmt::Display;
+
+use crate::devtools::{is_tree_tracking, record_debug_value};
+
+#[cfg(test)]
+mod tests;
+
+/// Hook that shows a labeled value for the current component in devtools
+///
+/// The value is only formatted while the component tree is tracked, so the
+/// hook costs next to nothing otherwise. It doesn't occupy a hook slot and may
+/// be called conditionally. Calling it twice with the same label in one
+/// render keeps the last value.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::debug_value::use_debug_value;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (items, _set_items) = use_state(Vec::<String>::new);
+/// use_debug_value("items", items.with(Vec::len));
+/// ```
+pub fn use_debug_value(label: impl Into<String>, value: impl Display) {
+    if is_tree_tracking() {
+        record_debug_value(label.into(), value.to_string());
+    }
+}
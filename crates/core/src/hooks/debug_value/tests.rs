@@ -0,0 +1,83 @@
+//! Tests for the debug value hook
+
+use super::*;
+use crate::Component;
+use crate::devtools::{commit_component_tree, component_tree, set_tree_tracking};
+use crate::hooks::state::use_state;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use ratatui::{Frame, Terminal, backend::TestBackend, layout::Rect};
+use std::borrow::Cow;
+
+#[derive(Clone)]
+struct Cart;
+
+impl Component for Cart {
+    fn component_id(&self) -> Cow<'static, str> {
+        "Cart".into()
+    }
+
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let (items, _) = use_state(|| 3);
+        use_debug_value("items", items.get());
+        use_debug_value("status", "open");
+        use_debug_value("items", items.get() * 2);
+        Checkout.render_with_mount(area, frame);
+    }
+}
+
+#[derive(Clone)]
+struct Checkout;
+
+impl Component for Checkout {
+    fn component_id(&self) -> Cow<'static, str> {
+        "Checkout".into()
+    }
+
+    fn render(&self, _area: Rect, _frame: &mut Frame) {
+        use_debug_value("step", 1);
+    }
+}
+
+fn render_cart() {
+    let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+    with_component_id("DebugValueRoot", |_| {
+        terminal
+            .draw(|frame| Cart.render_with_mount(frame.area(), frame))
+            .unwrap();
+    });
+    commit_component_tree();
+}
+
+fn values(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(label, value)| (label.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_debug_values_appear_in_component_tree() {
+    with_test_isolate(|| {
+        set_tree_tracking(true);
+        render_cart();
+        set_tree_tracking(false);
+
+        let tree = component_tree();
+        assert_eq!(tree.len(), 1);
+        assert_eq!(
+            tree[0].debug_values,
+            values(&[("items", "6"), ("status", "open")])
+        );
+        // The hook doesn't count as a hook of the component
+        assert_eq!(tree[0].hooks, 1);
+        assert_eq!(tree[0].children[0].debug_values, values(&[("step", "1")]));
+    });
+}
+
+#[test]
+fn test_debug_values_ignored_without_tracking() {
+    with_test_isolate(|| {
+        render_cart();
+        assert!(component_tree().is_empty());
+    });
+}
@@ -0,0 +1,69 @@
+//! Deferred Value Hook
+//!
+//! This module provides a `use_deferred_value` hook, modelled after React's
+//! `useDeferredValue`. It returns a lagging copy of a fast-changing value so
+//! that expensive parts of the UI (filtered lists, charts, previews) can keep
+//! rendering the old value while urgent updates such as typed characters show
+//! up first. The deferred copy catches up once the input settles.
+
+use crate::hooks::batch::{Priority, schedule_render, serving_priority};
+use crate::hooks::with_hook_context;
+
+#[cfg(test)]
+mod tests;
+
+/// Per-hook bookkeeping for a deferred value
+struct DeferredState<T> {
+    /// The value handed out to the component
+    deferred: T,
+    /// The most recent input value
+    latest: T,
+}
+
+/// Hook that returns a copy of `value` that trails behind rapid changes
+///
+/// On the first render the deferred value is `value` itself. When `value`
+/// changes, the hook keeps returning the previous deferred value and
+/// schedules a [`Priority::Low`] render. The deferred value catches up in the
+/// first low-priority frame in which `value` did not change again; frames
+/// serving high-priority updates keep showing the old value, so those
+/// updates are drawn first. Rendered outside of the runtime, where
+/// [`serving_priority`] is `None`, it catches up in the next render.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::deferred::use_deferred_value;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (query, _set_query) = use_state(String::new);
+///
+/// // The input shows every keystroke, the expensive results lag behind
+/// let deferred_query = use_deferred_value(query.get());
+/// let is_stale = deferred_query != query.get();
+/// ```
+pub fn use_deferred_value<T>(value: T) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let state = ctx.get_or_init_state(index, || DeferredState {
+            deferred: value.clone(),
+            latest: value.clone(),
+        });
+        let mut state = state.borrow_mut();
+
+        if state.latest != value {
+            // Still changing: keep the old value and come back when idle
+            state.latest = value;
+            schedule_render(Priority::Low);
+        } else if state.deferred != state.latest && serving_priority() != Some(Priority::High) {
+            // The render scheduled on the change stays pending through
+            // high-priority frames, so a low-priority frame follows
+            state.deferred = state.latest.clone();
+        }
+
+        state.deferred.clone()
+    })
+}
@@ -0,0 +1,72 @@
+//! Tests for the use_deferred_value hook
+
+use super::*;
+use crate::hooks::batch::render_request_count;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render(value: i32) -> i32 {
+    with_component_id("DeferredComponent", |_| use_deferred_value(value))
+}
+
+#[test]
+fn test_initial_render_returns_value() {
+    with_test_isolate(|| {
+        assert_eq!(render(7), 7);
+        assert_eq!(render(7), 7);
+    });
+}
+
+#[test]
+fn test_deferred_value_trails_rapid_changes() {
+    with_test_isolate(|| {
+        assert_eq!(render(0), 0);
+
+        // The input changes on every render, the deferred value holds still
+        for value in 1..=5 {
+            assert_eq!(render(value), 0);
+        }
+
+        // Once the input settles, the deferred value catches up
+        assert_eq!(render(5), 5);
+        assert_eq!(render(5), 5);
+    });
+}
+
+#[test]
+fn test_change_schedules_render() {
+    with_test_isolate(|| {
+        render(0);
+
+        let before = render_request_count();
+        render(1);
+        assert_eq!(render_request_count() - before, 1);
+
+        let before = render_request_count();
+        render(1);
+        assert_eq!(render_request_count(), before);
+    });
+}
+
+#[test]
+fn test_value_changing_back_settles_immediately() {
+    with_test_isolate(|| {
+        render(0);
+        assert_eq!(render(1), 0);
+        assert_eq!(render(0), 0);
+        assert_eq!(render(0), 0);
+    });
+}
+
+#[test]
+fn test_catch_up_waits_for_a_low_priority_frame() {
+    use crate::hooks::batch::serve_render;
+
+    with_test_isolate(|| {
+        render(0);
+        assert_eq!(serve_render(Priority::High, || render(1)), 0);
+
+        // Frames serving high-priority updates keep the old value
+        assert_eq!(serve_render(Priority::High, || render(1)), 0);
+        assert_eq!(serve_render(Priority::Low, || render(1)), 1);
+    });
+}
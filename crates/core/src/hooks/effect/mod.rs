@@ -1,12 +1,16 @@
 use parking_lot::Mutex;
 use std::any::Any;
+use std::cell::RefCell;
 use std::sync::Arc;
+use tokio::task::AbortHandle;
 
 #[cfg(test)]
 mod tests;
 
+use crate::component::register_unmount_cleanup;
 use crate::hooks::with_hook_context;
 use crate::panic_handler::spawn_catch_panic;
+use crate::tasks::{spawn_limited, spawn_long_lived};
 
 /// Trait for types that can be used as effect dependencies
 /// This enables dependency comparison for conditional effect re-execution
@@ -236,6 +240,11 @@ impl CleanupFn {
             cleanup_fn();
         }
     }
+
+    /// Returns true once the cleanup function has been called
+    pub fn is_done(&self) -> bool {
+        self.cleanup.lock().is_none()
+    }
 }
 
 impl Clone for CleanupFn {
@@ -279,6 +288,90 @@ impl Clone for AsyncCleanupFn {
     }
 }
 
+thread_local! {
+    /// Tasks spawned by the effect that is currently running
+    static EFFECT_TASKS: RefCell<Option<Vec<AbortHandle>>> = const { RefCell::new(None) };
+}
+
+/// Spawn a tokio task owned by the currently running effect
+///
+/// The task is aborted when the effect runs again (because its dependencies
+/// changed) or when the component that ran the effect unmounts. Outside of an
+/// effect the task is not tracked. Since the task may run until then, it is
+/// started outside the shared [`TaskPool`](crate::tasks::TaskPool) with
+/// [`spawn_long_lived`]; acquire a permit from
+/// [`task_pool`](crate::tasks::task_pool) around expensive units of its work
+/// to keep them limited.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::effect::{spawn_effect_task, use_effect};
+/// use pulse_core::hooks::state::use_state;
+/// use std::time::Duration;
+///
+/// let (ticks, set_ticks) = use_state(|| 0);
+/// use_effect(
+///     move || {
+///         spawn_effect_task(async move {
+///             let mut interval = tokio::time::interval(Duration::from_secs(1));
+///             loop {
+///                 interval.tick().await;
+///                 set_ticks.update(|ticks| ticks + 1);
+///             }
+///         });
+///         None::<fn()>
+///     },
+///     (),
+/// );
+/// ```
+pub fn spawn_effect_task<F>(future: F) -> AbortHandle
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    let handle = spawn_long_lived(future).abort_handle();
+    track_effect_task(handle.clone());
+    handle
+}
+
+/// Abort `handle` together with the currently running effect's cleanup
+///
+/// Use this for tasks spawned by other means than [`spawn_effect_task`], e.g.
+/// on a specific runtime handle. Has no effect outside of an effect.
+pub fn track_effect_task(handle: AbortHandle) {
+    EFFECT_TASKS.with(|tasks| {
+        if let Some(tasks) = tasks.borrow_mut().as_mut() {
+            tasks.push(handle);
+        }
+    });
+}
+
+/// Run an effect, collecting the tasks it spawns into its cleanup function
+fn run_effect<F, C>(effect: F) -> Option<CleanupFn>
+where
+    F: FnOnce() -> Option<C>,
+    C: FnOnce() + Send + 'static,
+{
+    let previous = EFFECT_TASKS.with(|tasks| tasks.borrow_mut().replace(Vec::new()));
+    let cleanup = effect();
+    let tasks = EFFECT_TASKS
+        .with(|tasks| std::mem::replace(&mut *tasks.borrow_mut(), previous))
+        .unwrap_or_default();
+
+    if cleanup.is_none() && tasks.is_empty() {
+        return None;
+    }
+
+    Some(CleanupFn::new(move || {
+        for task in tasks {
+            task.abort();
+        }
+        if let Some(cleanup) = cleanup {
+            cleanup();
+        }
+    }))
+}
+
 /// Internal state for tracking synchronous effects
 struct EffectState {
     /// Previous dependencies for comparison
@@ -303,8 +396,8 @@ impl EffectState {
 struct AsyncEffectState {
     /// Previous dependencies for comparison
     prev_deps: Option<Box<dyn EffectDependencies>>,
-    /// Async cleanup function from the previous effect run
-    cleanup: Option<AsyncCleanupFn>,
+    /// Stops the previous effect run: aborts it or runs its async cleanup
+    cleanup: Option<CleanupFn>,
     /// Whether this effect has been initialized
     initialized: bool,
 }
@@ -319,6 +412,66 @@ impl AsyncEffectState {
     }
 }
 
+/// The async cleanup of one effect run, once its effect resolved
+#[derive(Default)]
+struct AsyncCleanupSlot {
+    cleanup: Option<AsyncCleanupFn>,
+    /// Set when the run was stopped; a cleanup arriving later runs at once
+    stopped: bool,
+}
+
+/// Spawn an async effect, returning the function that stops it
+///
+/// Stopping aborts the effect if it hasn't resolved yet. Otherwise the
+/// cleanup it resolved to is spawned on the runtime the effect ran on, so
+/// stopping works from outside of a runtime too, e.g. when a component
+/// unmounts.
+fn run_async_effect<F, Fut, C, CFut>(effect: F) -> CleanupFn
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: std::future::Future<Output = Option<C>> + Send + 'static,
+    C: FnOnce() -> CFut + Send + 'static,
+    CFut: std::future::Future<Output = ()> + Send + 'static,
+{
+    let runtime = tokio::runtime::Handle::current();
+    let slot = Arc::new(Mutex::new(AsyncCleanupSlot::default()));
+
+    let task_slot = slot.clone();
+    let task = spawn_limited(async move {
+        let _ = spawn_catch_panic(async move {
+            if let Some(cleanup_fn) = effect().await {
+                let cleanup = AsyncCleanupFn::new(cleanup_fn);
+                let stopped = {
+                    let mut slot = task_slot.lock();
+                    if !slot.stopped {
+                        slot.cleanup = Some(cleanup.clone());
+                    }
+                    slot.stopped
+                };
+                if stopped {
+                    cleanup.cleanup().await;
+                }
+            }
+        })
+        .await;
+    })
+    .abort_handle();
+
+    CleanupFn::new(move || {
+        task.abort();
+        let cleanup = {
+            let mut slot = slot.lock();
+            slot.stopped = true;
+            slot.cleanup.take()
+        };
+        if let Some(cleanup) = cleanup {
+            runtime.spawn(spawn_catch_panic(async move {
+                cleanup.cleanup().await;
+            }));
+        }
+    })
+}
+
 // Implement EffectDependencies for tuples (up to 8 elements for practical use)
 macro_rules! impl_effect_deps_for_tuple {
     ($($t:ident),*) => {
@@ -441,8 +594,7 @@ where
 
         // Get or create async effect state for this hook
         let effect_state = states
-            .entry(hook_index)
-            .or_insert_with(|| Box::new(AsyncEffectState::new()))
+            .get_or_insert_with(hook_index, || Box::new(AsyncEffectState::new()))
             .downcast_mut::<AsyncEffectState>()
             .expect("Async effect state type mismatch");
 
@@ -468,13 +620,9 @@ where
         };
 
         if should_run {
-            // Run cleanup from previous effect if it exists
+            // Stop the previous run: abort its effect or clean it up
             if let Some(cleanup) = effect_state.cleanup.take() {
-                // For now, we'll spawn the cleanup to run asynchronously
-                // In a real implementation, this would be properly scheduled
-                tokio::spawn(spawn_catch_panic(async move {
-                    cleanup.cleanup().await;
-                }));
+                cleanup.cleanup();
             }
 
             // Store new dependencies
@@ -484,17 +632,11 @@ where
                 effect_state.prev_deps = None;
             }
 
-            // Schedule async effect to run after render
-            // For now, we'll spawn it immediately, but in a real implementation
-            // this would be scheduled to run after the render phase
-            tokio::spawn(spawn_catch_panic(async move {
-                if let Some(cleanup_fn) = effect().await {
-                    // Store the cleanup function for later use
-                    // In a real implementation, we'd need to store this back in the state
-                    let _async_cleanup = AsyncCleanupFn::new(cleanup_fn);
-                    // TODO: Store this cleanup function in the effect state
-                }
-            }));
+            let cleanup = run_async_effect(effect);
+
+            // Also stop the run when the rendering component unmounts
+            register_unmount_cleanup(cleanup.clone());
+            effect_state.cleanup = Some(cleanup);
 
             effect_state.initialized = true;
         }
@@ -535,8 +677,7 @@ where
 
         // Get or create effect state for this hook
         let effect_state = states
-            .entry(hook_index)
-            .or_insert_with(|| Box::new(EffectState::new()))
+            .get_or_insert_with(hook_index, || Box::new(EffectState::new()))
             .downcast_mut::<EffectState>()
             .expect("Effect state type mismatch");
 
@@ -577,8 +718,11 @@ where
             // Schedule effect to run after render
             // For now, we'll run it immediately, but in a real implementation
             // this would be scheduled to run after the render phase
-            if let Some(cleanup_fn) = effect() {
-                effect_state.cleanup = Some(CleanupFn::new(cleanup_fn));
+            effect_state.cleanup = run_effect(effect);
+
+            // Also run the cleanup when the rendering component unmounts
+            if let Some(cleanup) = &effect_state.cleanup {
+                register_unmount_cleanup(cleanup.clone());
             }
 
             effect_state.initialized = true;
@@ -646,6 +790,55 @@ where
     use_effect(|| Some(effect()), ())
 }
 
+/// Hook with separate callbacks for the mount, update and unmount phases
+///
+/// `on_mount` runs in the first render and `on_unmount` when the component
+/// unmounts. `on_update` runs with the new dependencies in every later render
+/// in which `deps` changed, but not on mount. Only the `on_mount` and
+/// `on_unmount` of the first render are used; read changing values in
+/// `on_unmount` through [`use_latest`](crate::hooks::latest::use_latest).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::effect::use_lifecycle;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (account, _set_account) = use_state(|| "checking".to_string());
+///
+/// use_lifecycle(
+///     || println!("Opened the ledger"),
+///     |account: &String| println!("Switched to {account}"),
+///     || println!("Closed the ledger"),
+///     account.get(),
+/// );
+/// ```
+pub fn use_lifecycle<Deps, M, U, D>(on_mount: M, on_update: U, on_unmount: D, deps: Deps)
+where
+    Deps: EffectDependencies + Clone + PartialEq + 'static,
+    M: FnOnce() + 'static,
+    U: FnOnce(&Deps),
+    D: FnOnce() + Send + 'static,
+{
+    use_effect(
+        move || {
+            on_mount();
+            Some(on_unmount)
+        },
+        (),
+    );
+
+    let last_deps = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        ctx.get_or_init_state(index, || deps.clone())
+    });
+    let mut last_deps = last_deps.borrow_mut();
+    if *last_deps != deps {
+        on_update(&deps);
+        *last_deps = deps;
+    }
+}
+
 /// useAsyncEffect hook that runs only once (on mount)
 ///
 /// This is a convenience function for async effects that should run only once when
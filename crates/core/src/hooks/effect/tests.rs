@@ -12,6 +12,7 @@ use crate::hooks::test_utils::{
     with_async_component_id, with_async_test_isolate, with_component_id, with_hook_context,
     with_test_isolate,
 };
+use std::borrow::Cow;
 
 use super::*;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -1235,3 +1236,66 @@ fn test_use_effect_once_empty_cleanup() {
         });
     });
 }
+
+#[derive(Clone)]
+struct LifecycleComponent {
+    log: Arc<Mutex<Vec<String>>>,
+    account: &'static str,
+}
+
+impl crate::Component for LifecycleComponent {
+    fn component_id(&self) -> Cow<'static, str> {
+        "LifecycleComponent".into()
+    }
+
+    fn render(&self, _area: ratatui::layout::Rect, _frame: &mut ratatui::Frame) {
+        let (mount_log, update_log, unmount_log) =
+            (self.log.clone(), self.log.clone(), self.log.clone());
+        use_lifecycle(
+            move || mount_log.lock().unwrap().push("mount".to_string()),
+            move |account: &&str| update_log.lock().unwrap().push(format!("update {account}")),
+            move || unmount_log.lock().unwrap().push("unmount".to_string()),
+            self.account,
+        );
+    }
+}
+
+/// Test that each lifecycle callback fires in its phase
+#[test]
+fn test_use_lifecycle_phases() {
+    use crate::Component;
+    use crate::component::cleanup_unmounted;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    with_test_isolate(|| {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut render = |account: &'static str| {
+            let component = LifecycleComponent {
+                log: log.clone(),
+                account,
+            };
+            with_component_id("LifecycleParent", |_| {
+                terminal
+                    .draw(|frame| component.render_with_mount(frame.area(), frame))
+                    .unwrap();
+            });
+            cleanup_unmounted();
+        };
+
+        render("checking");
+        render("checking");
+        assert_eq!(*log.lock().unwrap(), vec!["mount"]);
+
+        render("savings");
+        render("savings");
+        assert_eq!(*log.lock().unwrap(), vec!["mount", "update savings"]);
+
+        // A frame without the component unmounts it
+        cleanup_unmounted();
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["mount", "update savings", "unmount"]
+        );
+    });
+}
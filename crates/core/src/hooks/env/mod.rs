@@ -0,0 +1,178 @@
+//! Environment Hooks
+//!
+//! This module provides hooks for reading environment variables and for
+//! surfacing information about the hosting terminal, so components can adapt
+//! to their environment (e.g. avoid RGB colors on limited terminals).
+
+use crate::color::detect_color_support;
+use crate::hooks::{effect::EffectDependencies, state::use_state, with_hook_context};
+
+pub use crate::color::ColorSupport;
+
+#[cfg(test)]
+mod tests;
+
+/// Hook that reads an environment variable
+///
+/// The variable is read on the first render and cached for subsequent renders,
+/// so it is cheap to call on every frame. The value is re-read when `var` changes.
+/// Use [`use_env_with_refresh`] to re-read on demand.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::env::use_env;
+/// // In a component context:
+/// let editor = use_env("EDITOR").unwrap_or_else(|| "vi".to_string());
+/// ```
+pub fn use_env(var: &str) -> Option<String> {
+    use_env_with_refresh(var, ())
+}
+
+/// Hook that reads an environment variable and re-reads it when `trigger` changes
+///
+/// The trigger uses the same dependency system as effect hooks: any change of
+/// its value (for example a counter bumped by a "reload" key) causes the
+/// variable to be read again.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::env::use_env_with_refresh;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (reloads, set_reloads) = use_state(|| 0u32);
+/// let theme = use_env_with_refresh("APP_THEME", reloads.get());
+///
+/// // Later, e.g. in an event handler:
+/// set_reloads.update(|n| n + 1);
+/// ```
+pub fn use_env_with_refresh<Deps>(var: &str, trigger: Deps) -> Option<String>
+where
+    Deps: EffectDependencies + Clone + PartialEq + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let cache = ctx.get_or_init_state(index, || None::<EnvCache>);
+        let mut cache = cache.borrow_mut();
+
+        let is_stale = match cache.as_ref() {
+            None => true,
+            Some(cached) => cached.var != var || !trigger.deps_eq(cached.trigger.as_ref()),
+        };
+
+        if is_stale {
+            *cache = Some(EnvCache {
+                var: var.to_string(),
+                trigger: trigger.clone_deps(),
+                value: std::env::var(var).ok(),
+            });
+        }
+
+        cache.as_ref().and_then(|cached| cached.value.clone())
+    })
+}
+
+/// Cached environment lookup for a single hook
+struct EnvCache {
+    var: String,
+    trigger: Box<dyn EffectDependencies>,
+    value: Option<String>,
+}
+
+/// Information about the terminal the application is running in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemInfo {
+    /// Value of `$TERM`
+    pub term: Option<String>,
+    /// Value of `$TERM_PROGRAM` (e.g. `iTerm.app`, `vscode`, `WezTerm`)
+    pub term_program: Option<String>,
+    /// Value of `$COLORTERM`
+    pub colorterm: Option<String>,
+    /// Detected color support level
+    pub color_support: ColorSupport,
+    /// Whether `$NO_COLOR` is set to a non-empty value
+    pub no_color: bool,
+}
+
+impl SystemInfo {
+    /// Detect system information from the process environment
+    pub fn detect() -> Self {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Detect system information using a custom variable lookup
+    ///
+    /// This is useful for testing or for inspecting a remote environment.
+    pub fn from_lookup<F>(lookup: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let non_empty = |name: &str| lookup(name).filter(|value| !value.is_empty());
+
+        let term = non_empty("TERM");
+        let term_program = non_empty("TERM_PROGRAM");
+        let colorterm = non_empty("COLORTERM");
+        let no_color = non_empty("NO_COLOR").is_some();
+
+        let color_support = detect_color_support(
+            term.as_deref(),
+            term_program.as_deref(),
+            colorterm.as_deref(),
+        );
+
+        Self {
+            term,
+            term_program,
+            colorterm,
+            color_support,
+            no_color,
+        }
+    }
+
+    /// Returns true if RGB colors can be rendered faithfully
+    pub fn supports_rgb(&self) -> bool {
+        self.color_support == ColorSupport::TrueColor
+    }
+}
+
+/// Hook that surfaces information about the hosting terminal
+///
+/// The environment is inspected once on the first render and cached.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::env::use_system_info;
+/// # use ratatui::style::Color;
+/// // In a component context:
+/// let info = use_system_info();
+/// let accent = if info.supports_rgb() {
+///     Color::Rgb(255, 140, 0)
+/// } else {
+///     Color::Yellow
+/// };
+/// ```
+pub fn use_system_info() -> SystemInfo {
+    let (info, _) = use_state(SystemInfo::detect);
+    info.get()
+}
+
+/// Hook that returns the color support level of the hosting terminal
+///
+/// Combine it with [`crate::color::adapt_color`] or [`crate::color::adapt_style`]
+/// to degrade RGB colors gracefully on limited terminals.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::env::use_color_support;
+/// # use pulse_core::color::adapt_color;
+/// # use ratatui::style::Color;
+/// // In a component context:
+/// let support = use_color_support();
+/// let accent = adapt_color(Color::Rgb(255, 140, 0), support);
+/// ```
+pub fn use_color_support() -> ColorSupport {
+    use_system_info().color_support
+}
@@ -0,0 +1,103 @@
+//! Tests for the environment hooks
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_hook_context, with_test_isolate};
+use std::collections::HashMap;
+
+fn lookup_from(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = vars
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    move |name| vars.get(name).cloned()
+}
+
+#[test]
+fn test_use_env_reads_set_variable() {
+    // SAFETY: the variable name is unique to this test
+    unsafe { std::env::set_var("PULSE_TEST_USE_ENV_VALUE", "hello") };
+
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            assert_eq!(
+                use_env("PULSE_TEST_USE_ENV_VALUE"),
+                Some("hello".to_string())
+            );
+        });
+    });
+}
+
+#[test]
+fn test_use_env_missing_variable() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            assert_eq!(use_env("PULSE_TEST_USE_ENV_DEFINITELY_UNSET"), None);
+        });
+    });
+}
+
+#[test]
+fn test_use_env_with_refresh_rereads_on_trigger_change() {
+    // SAFETY: the variable name is unique to this test
+    unsafe { std::env::set_var("PULSE_TEST_USE_ENV_REFRESH", "first") };
+
+    with_test_isolate(|| {
+        let read = |trigger: u32| {
+            with_component_id("EnvRefreshComponent", |_| {
+                use_env_with_refresh("PULSE_TEST_USE_ENV_REFRESH", trigger)
+            })
+        };
+
+        assert_eq!(read(0), Some("first".to_string()));
+
+        unsafe { std::env::set_var("PULSE_TEST_USE_ENV_REFRESH", "second") };
+
+        // Same trigger keeps the cached value
+        assert_eq!(read(0), Some("first".to_string()));
+        // A new trigger value re-reads the environment
+        assert_eq!(read(1), Some("second".to_string()));
+    });
+}
+
+#[test]
+fn test_system_info_detects_truecolor() {
+    let info = SystemInfo::from_lookup(lookup_from(&[
+        ("TERM", "xterm-256color"),
+        ("COLORTERM", "truecolor"),
+        ("TERM_PROGRAM", "WezTerm"),
+    ]));
+
+    assert_eq!(info.term.as_deref(), Some("xterm-256color"));
+    assert_eq!(info.term_program.as_deref(), Some("WezTerm"));
+    assert_eq!(info.color_support, ColorSupport::TrueColor);
+    assert!(info.supports_rgb());
+    assert!(!info.no_color);
+}
+
+#[test]
+fn test_system_info_limited_terminals() {
+    let xterm = SystemInfo::from_lookup(lookup_from(&[("TERM", "xterm-256color")]));
+    assert_eq!(xterm.color_support, ColorSupport::Ansi256);
+
+    let linux = SystemInfo::from_lookup(lookup_from(&[("TERM", "linux")]));
+    assert_eq!(linux.color_support, ColorSupport::Ansi16);
+
+    let dumb = SystemInfo::from_lookup(lookup_from(&[("TERM", "dumb"), ("NO_COLOR", "1")]));
+    assert_eq!(dumb.color_support, ColorSupport::Monochrome);
+    assert!(dumb.no_color);
+
+    let apple = SystemInfo::from_lookup(lookup_from(&[
+        ("TERM", "xterm"),
+        ("TERM_PROGRAM", "Apple_Terminal"),
+    ]));
+    assert_eq!(apple.color_support, ColorSupport::Ansi256);
+}
+
+#[test]
+fn test_use_system_info_is_cached() {
+    with_test_isolate(|| {
+        let first = with_component_id("SystemInfoComponent", |_| use_system_info());
+        let second = with_component_id("SystemInfoComponent", |_| use_system_info());
+        assert_eq!(first, second);
+    });
+}
@@ -0,0 +1,71 @@
+//! Error State Hook
+//!
+//! This module provides `use_error`, which lets a component record an error
+//! and display it instead of panicking or silently dropping it. The error is
+//! kept as a [`ContextError`], so the recorded failure carries the context
+//! it was annotated with and renders as a single readable line. Recording or
+//! clearing an error re-renders the component like any other state change.
+
+use crate::error::ContextError;
+use crate::hooks::state::{StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Records or clears the error of a [`use_error`] hook
+///
+/// Cheap to clone and `Send + Sync`, so it can be moved into callbacks and
+/// background tasks.
+#[derive(Debug, Clone)]
+pub struct ErrorSetter {
+    setter: StateSetter<Option<ContextError>>,
+}
+
+impl ErrorSetter {
+    /// Record `error`, replacing the current one
+    pub fn set(&self, error: impl Into<ContextError>) {
+        self.setter.set(Some(error.into()));
+    }
+
+    /// Clear the current error
+    pub fn clear(&self) {
+        self.setter.set(None);
+    }
+
+    /// Record the error of `result`, if any, and return its value otherwise
+    ///
+    /// A successful result leaves the current error as is.
+    pub fn capture<T>(&self, result: Result<T, ContextError>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.set(error);
+                None
+            }
+        }
+    }
+}
+
+/// Hook that holds an error of the component for display
+///
+/// Returns the current error, if any, and a setter to record or clear it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::error::ResultExt;
+/// # use pulse_core::hooks::error::use_error;
+/// # fn save() -> Result<(), std::io::Error> { Ok(()) }
+/// // In a component context:
+/// let (error, set_error) = use_error();
+///
+/// set_error.capture(save().context("while saving"));
+///
+/// if let Some(error) = &error {
+///     // Render `error.to_string()` in a status line
+/// }
+/// ```
+pub fn use_error() -> (Option<ContextError>, ErrorSetter) {
+    let (error, setter) = use_state(|| None::<ContextError>);
+    (error.get(), ErrorSetter { setter })
+}
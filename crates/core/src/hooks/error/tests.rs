@@ -0,0 +1,41 @@
+//! Tests for the error state hook
+
+use super::*;
+use crate::error::ResultExt;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render() -> (Option<ContextError>, ErrorSetter) {
+    with_component_id("ErrorComponent", |_| use_error())
+}
+
+#[test]
+fn test_set_and_clear_error() {
+    with_test_isolate(|| {
+        let (error, set_error) = render();
+        assert!(error.is_none());
+
+        set_error.set("connection refused");
+        let (error, set_error) = render();
+        assert_eq!(error.unwrap().to_string(), "connection refused");
+
+        set_error.clear();
+        let (error, _) = render();
+        assert!(error.is_none());
+    });
+}
+
+#[test]
+fn test_capture_records_only_errors() {
+    with_test_isolate(|| {
+        let (_, set_error) = render();
+
+        assert_eq!(set_error.capture(Ok::<_, ContextError>(3)), Some(3));
+        assert!(render().0.is_none());
+
+        let failed: Result<u32, _> =
+            Err(std::io::Error::other("disk full")).context("while saving");
+        assert_eq!(set_error.capture(failed), None);
+        let (error, _) = render();
+        assert_eq!(error.unwrap().to_string(), "while saving: disk full");
+    });
+}
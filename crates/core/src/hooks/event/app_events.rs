@@ -0,0 +1,288 @@
+//! Custom event sources for TUI applications
+//!
+//! This module lets code outside the render loop (background threads, file
+//! watchers, sockets, async tasks) inject events into the same loop that drives
+//! `use_event`. Events are posted to a process-wide channel with [`post_event`]
+//! and the runtime merges that channel with the terminal's own event stream.
+//!
+//! Terminal events posted through the channel are handled exactly like events
+//! read from crossterm, while custom events are surfaced to components through
+//! the [`use_app_event`] hook.
+//!
+//! An [`AppEventScope`] gives the current thread a channel of its own, e.g. for
+//! a headless replay running next to a live app in the same process.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, Sender, unbounded};
+use crossterm::event::Event;
+use once_cell::sync::Lazy;
+use tracing::debug;
+
+use crate::hooks::with_hook_context;
+
+/// An event flowing through the application's event loop
+#[derive(Clone, Debug)]
+pub enum AppEvent {
+    /// A terminal event (keyboard, mouse, resize, ...)
+    Terminal(Event),
+    /// A user-defined event carrying an arbitrary payload
+    Custom(CustomEvent),
+}
+
+impl AppEvent {
+    /// Create a custom event carrying the given payload
+    ///
+    /// # Example
+    /// ```
+    /// use pulse_core::hooks::event::app_events::AppEvent;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct ConfigReloaded;
+    ///
+    /// let event = AppEvent::custom(ConfigReloaded);
+    /// assert!(matches!(event, AppEvent::Custom(_)));
+    /// ```
+    pub fn custom<T>(payload: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        AppEvent::Custom(CustomEvent::new(payload))
+    }
+}
+
+impl From<Event> for AppEvent {
+    fn from(event: Event) -> Self {
+        AppEvent::Terminal(event)
+    }
+}
+
+/// A type-erased, cheaply cloneable user event payload
+#[derive(Clone)]
+pub struct CustomEvent {
+    payload: Arc<dyn Any + Send + Sync>,
+    type_name: &'static str,
+}
+
+impl CustomEvent {
+    /// Wrap a payload into a custom event
+    pub fn new<T>(payload: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        Self {
+            payload: Arc::new(payload),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Returns true if the payload is of type `T`
+    pub fn is<T: Any>(&self) -> bool {
+        self.payload.is::<T>()
+    }
+
+    /// Borrow the payload as `T` if it has that type
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.payload.downcast_ref::<T>()
+    }
+
+    /// Get the type name of the payload (useful for debugging)
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl fmt::Debug for CustomEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomEvent")
+            .field("type_name", &self.type_name)
+            .finish()
+    }
+}
+
+/// Process-wide channel that user event sources post into
+static APP_EVENT_CHANNEL: Lazy<(Sender<AppEvent>, Receiver<AppEvent>)> = Lazy::new(unbounded);
+
+thread_local! {
+    /// Channel of the active [`AppEventScope`] on this thread
+    static SCOPED_CHANNEL: RefCell<Option<(Sender<AppEvent>, Receiver<AppEvent>)>> =
+        const { RefCell::new(None) };
+}
+
+/// The channel events are posted to and read from on this thread
+fn with_channel<R>(f: impl FnOnce(&(Sender<AppEvent>, Receiver<AppEvent>)) -> R) -> R {
+    SCOPED_CHANNEL.with(|scoped| match &*scoped.borrow() {
+        Some(channel) => f(channel),
+        None => f(&APP_EVENT_CHANNEL),
+    })
+}
+
+/// An app event channel of its own for the current thread
+///
+/// While the scope is alive, [`post_event`] and [`try_next_app_event`] on
+/// this thread use the scope's channel instead of the process-wide one.
+/// Other threads post into the scope through an [`AppEventSender`]. The
+/// previous channel comes back when the scope is dropped.
+#[derive(Debug)]
+pub struct AppEventScope {
+    previous: Option<(Sender<AppEvent>, Receiver<AppEvent>)>,
+    // Bound to the thread whose channel it replaces
+    _not_send: PhantomData<*const ()>,
+}
+
+impl AppEventScope {
+    /// Start delivering this thread's app events through a new, empty channel
+    pub fn start() -> Self {
+        let previous = SCOPED_CHANNEL.with(|scoped| scoped.replace(Some(unbounded())));
+        Self {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// A sender posting into this scope from any thread
+    pub fn sender(&self) -> AppEventSender {
+        AppEventSender(with_channel(|(sender, _)| sender.clone()))
+    }
+}
+
+impl Drop for AppEventScope {
+    fn drop(&mut self) {
+        SCOPED_CHANNEL.with(|scoped| *scoped.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Posts events into the channel of an [`AppEventScope`]
+#[derive(Debug, Clone)]
+pub struct AppEventSender(Sender<AppEvent>);
+
+impl AppEventSender {
+    /// Post an event into the scope's channel
+    ///
+    /// Events posted after the scope was dropped are discarded.
+    pub fn post(&self, event: AppEvent) {
+        debug!("Posting scoped app event: {:?}", event);
+        let _ = self.0.send(event);
+    }
+}
+
+/// Structure to track the current custom event and which hooks have seen it
+#[derive(Default)]
+struct AppEventState {
+    /// The current custom event
+    event: Option<CustomEvent>,
+    /// Map of hook indices to whether they've processed the event
+    processed_by: HashMap<usize, bool>,
+}
+
+/// Global storage for the custom event of the current render cycle
+static CURRENT_APP_EVENT: Lazy<RwLock<AppEventState>> = Lazy::new(Default::default);
+
+/// Post an event into the application's event loop
+///
+/// This function can be called from any thread. Terminal events are processed
+/// by the runtime as if they came from the terminal itself, and custom events
+/// are delivered to components through [`use_app_event`].
+///
+/// # Example
+/// ```
+/// use pulse_core::hooks::event::app_events::{AppEvent, post_event};
+///
+/// #[derive(Clone)]
+/// struct FileChanged(String);
+///
+/// std::thread::spawn(|| {
+///     post_event(AppEvent::custom(FileChanged("config.toml".into())));
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+pub fn post_event(event: AppEvent) {
+    debug!("Posting app event: {:?}", event);
+    // The receiver lives as long as its sender, so sending can never fail
+    with_channel(|(sender, _)| {
+        let _ = sender.send(event);
+    });
+}
+
+/// Take the next posted event without blocking
+///
+/// This function should be called by the runtime's event loop.
+pub fn try_next_app_event() -> Option<AppEvent> {
+    with_channel(|(_, receiver)| receiver.try_recv().ok())
+}
+
+/// Wait up to `timeout` for the next posted event
+///
+/// This function should be called by the runtime's event loop.
+pub fn next_app_event_timeout(timeout: Duration) -> Option<AppEvent> {
+    with_channel(|(_, receiver)| receiver.clone())
+        .recv_timeout(timeout)
+        .ok()
+}
+
+/// Sets the custom event for the current render cycle
+///
+/// This function should be called by the runtime when a custom event is received,
+/// and with `None` once the event has been rendered.
+pub fn set_current_app_event(event: Option<CustomEvent>) {
+    let mut state = CURRENT_APP_EVENT.write().unwrap();
+    state.event = event;
+    state.processed_by.clear();
+}
+
+/// A hook that returns the custom event of type `T` posted for this render cycle
+///
+/// Events are posted from anywhere with [`post_event`] and merged into the
+/// runtime's event loop. Like `use_event`, each hook instance observes an event
+/// at most once; events whose payload is not a `T` are ignored.
+///
+/// # Example
+/// ```rust,no_run
+/// # use pulse_core::hooks::event::app_events::use_app_event;
+/// #[derive(Clone)]
+/// struct Tick(u64);
+///
+/// // In a component context:
+/// if let Some(Tick(n)) = use_app_event::<Tick>() {
+///     // React to the background tick
+/// }
+/// ```
+///
+/// # Returns
+///
+/// * `Option<T>` - The payload of the current custom event if it is a `T` and
+///   has not been processed by this hook yet
+pub fn use_app_event<T>() -> Option<T>
+where
+    T: Any + Clone + Send + Sync,
+{
+    let hook_index = with_hook_context(|ctx| ctx.next_hook_index());
+
+    let value = {
+        let state = CURRENT_APP_EVENT.read().unwrap();
+        if state
+            .processed_by
+            .get(&hook_index)
+            .copied()
+            .unwrap_or(false)
+        {
+            return None;
+        }
+        state.event.as_ref()?.downcast_ref::<T>()?.clone()
+    };
+
+    CURRENT_APP_EVENT
+        .write()
+        .unwrap()
+        .processed_by
+        .insert(hook_index, true);
+
+    Some(value)
+}
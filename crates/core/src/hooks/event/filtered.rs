@@ -0,0 +1,78 @@
+//! Filtered event hooks
+//!
+//! [`use_event`](super::use_event) yields every terminal event, which leads to
+//! `if let Some(Event::Key(..))` boilerplate in every component. The hooks in
+//! this module only surface the events a component is interested in.
+
+use crossterm::event::{Event, KeyEvent, KeyEventKind, MouseEvent};
+
+use super::use_event;
+
+/// Hook that returns the current event only if it matches `predicate`
+///
+/// Like [`use_event`](super::use_event), each hook instance sees an event at
+/// most once. Events rejected by the predicate are skipped for this hook.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::event::filtered::use_event_filtered;
+/// # use crossterm::event::Event;
+/// // In a component context:
+/// if let Some(Event::Resize(width, height)) =
+///     use_event_filtered(|event| matches!(event, Event::Resize(..)))
+/// {
+///     // Handle the resize
+/// }
+/// ```
+pub fn use_event_filtered<F>(predicate: F) -> Option<Event>
+where
+    F: Fn(&Event) -> bool,
+{
+    use_event().filter(|event| predicate(event))
+}
+
+/// Hook that returns the current key press, if any
+///
+/// Key release events (reported by some platforms) are filtered out, so each
+/// physical key press is seen once. Key repeats are included.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// // In a component context:
+/// if let Some(key) = use_key_event() {
+///     if key.code == KeyCode::Enter {
+///         // Submit
+///     }
+/// }
+/// ```
+pub fn use_key_event() -> Option<KeyEvent> {
+    match use_event()? {
+        Event::Key(key) if key.kind != KeyEventKind::Release => Some(key),
+        _ => None,
+    }
+}
+
+/// Hook that returns the current mouse event, if any
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::event::filtered::use_mouse_event;
+/// # use crossterm::event::{MouseButton, MouseEventKind};
+/// // In a component context:
+/// if let Some(mouse) = use_mouse_event() {
+///     if mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+///         // Handle a click at (mouse.column, mouse.row)
+///     }
+/// }
+/// ```
+pub fn use_mouse_event() -> Option<MouseEvent> {
+    match use_event()? {
+        Event::Mouse(mouse) => Some(mouse),
+        _ => None,
+    }
+}
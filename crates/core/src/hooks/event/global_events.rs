@@ -21,6 +21,11 @@ lazy_static! {
 ///   Return `true` to indicate the event was handled and stop propagation,
 ///   or `false` to allow other handlers to process the event.
 ///
+/// Stopping propagation skips the remaining handlers for the key *and* keeps
+/// the event from reaching components: `use_event` returns `None` for it.
+/// Components can stop propagation among themselves with
+/// [`consume_event`](super::consume_event).
+///
 /// # Example
 /// ```
 /// use crossterm::event::KeyCode;
@@ -6,6 +6,8 @@
 
 use crossterm::event::Event;
 
+pub mod app_events;
+pub mod filtered;
 pub mod global_events;
 
 use std::{
@@ -18,6 +20,9 @@ use tracing::debug;
 
 use crate::hooks::with_hook_context;
 
+#[cfg(test)]
+mod tests;
+
 /// Structure to track an event and whether it has been processed
 #[derive(Default)]
 pub(crate) struct EventState {
@@ -26,6 +31,8 @@ pub(crate) struct EventState {
     /// Map of component IDs to whether they've processed the event
     /// This allows each component to independently process the event
     pub(crate) processed_by: HashMap<usize, bool>,
+    /// Whether a component consumed the event, hiding it from later hooks
+    pub(crate) consumed: bool,
 }
 
 /// Global storage for the current event
@@ -46,6 +53,7 @@ pub fn set_current_event(event: Option<Arc<Event>>) {
     let mut current_event = CURRENT_EVENT.write().unwrap();
     current_event.event = event;
     current_event.processed_by.clear(); // Reset the processed map for the new event
+    current_event.consumed = false;
 
     debug!("Set current event in context: {:?}", event_debug);
     debug!("Reset processed state for all components");
@@ -80,6 +88,12 @@ pub(crate) fn get_current_event() -> Option<Arc<Event>> {
         }
     };
 
+    // A consumed event is hidden from every hook that runs afterwards
+    if event_state.consumed {
+        debug!("Event already consumed, hidden from hook {}", hook_index);
+        return None;
+    }
+
     // Check if this hook has already processed the event
     let already_processed = event_state
         .processed_by
@@ -102,6 +116,19 @@ pub(crate) fn get_current_event() -> Option<Arc<Event>> {
     Some(event)
 }
 
+/// Returns the current event without registering a hook
+///
+/// Unlike [`use_event`], this can be called conditionally (e.g. only while an
+/// error is shown). It does not mark the event as processed, so callers that
+/// act on the event should consume it.
+pub(crate) fn peek_current_event() -> Option<Arc<Event>> {
+    let event_state = CURRENT_EVENT.read().unwrap();
+    if event_state.consumed {
+        return None;
+    }
+    event_state.event.clone()
+}
+
 /// Marks the current event as processed by the specified component
 ///
 /// # Arguments
@@ -113,6 +140,60 @@ pub fn mark_event_processed(component_id: usize) {
     debug!("Marked event as processed by component {}", component_id);
 }
 
+/// Consume the current event so it does not reach other handlers
+///
+/// Once a component has handled an event it can consume it: every `use_event`
+/// call (and the filtered variants) that runs later in the same frame returns
+/// `None`. Components rendered earlier in the frame have already seen the
+/// event, so parents that want to intercept events should read them before
+/// rendering their children.
+///
+/// Has no effect if there is no current event.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::event::{consume_event, use_event};
+/// # use crossterm::event::{Event, KeyCode};
+/// // In a modal dialog that must swallow Escape:
+/// if let Some(Event::Key(key)) = use_event() {
+///     if key.code == KeyCode::Esc {
+///         // Close the dialog
+///         consume_event();
+///     }
+/// }
+/// ```
+pub fn consume_event() {
+    let mut event_state = CURRENT_EVENT.write().unwrap();
+    if event_state.event.is_some() {
+        event_state.consumed = true;
+        debug!("Current event consumed");
+    }
+}
+
+/// Returns true if the current event has been consumed
+pub fn is_event_consumed() -> bool {
+    CURRENT_EVENT.read().unwrap().consumed
+}
+
+/// Extension trait to consume an event obtained from `use_event`
+pub trait ConsumeEvent {
+    /// Consume this event if it is the current event
+    ///
+    /// See [`consume_event`] for the propagation rules.
+    fn consume(&self);
+}
+
+impl ConsumeEvent for Event {
+    fn consume(&self) {
+        let mut event_state = CURRENT_EVENT.write().unwrap();
+        if event_state.event.as_deref() == Some(self) {
+            event_state.consumed = true;
+            debug!("Event consumed: {:?}", self);
+        }
+    }
+}
+
 /// A React-style hook that returns the current terminal event being processed
 ///
 /// This hook allows components to handle terminal events like keyboard, mouse, and resize events
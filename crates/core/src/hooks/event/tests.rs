@@ -0,0 +1,216 @@
+//! Tests for event delivery, consumption, filtering and app event sources
+
+use super::app_events::{
+    AppEvent, AppEventScope, CustomEvent, post_event, set_current_app_event, try_next_app_event,
+    use_app_event,
+};
+use super::filtered::{use_event_filtered, use_key_event, use_mouse_event};
+use super::*;
+use crate::Component;
+use crate::hooks::test_utils::{lock_current_event, with_hook_context, with_test_isolate};
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::{Frame, Terminal, backend::TestBackend, layout::Rect};
+use std::sync::Mutex;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+/// Render a consumer followed by a sibling within one frame, returning what
+/// the sibling saw
+fn render_siblings(event: Event, consumer: impl FnOnce(Option<Event>)) -> Option<Event> {
+    let _lock = lock_current_event();
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            set_current_event(Some(Arc::new(event)));
+            consumer(use_event());
+            let sibling = use_event();
+            set_current_event(None);
+            sibling
+        })
+    })
+}
+
+#[test]
+fn test_siblings_see_unconsumed_event() {
+    let seen = render_siblings(key(KeyCode::Enter), |event| {
+        assert_eq!(event, Some(key(KeyCode::Enter)));
+    });
+    assert_eq!(seen, Some(key(KeyCode::Enter)));
+}
+
+#[test]
+fn test_consumed_event_hidden_from_sibling() {
+    let seen = render_siblings(key(KeyCode::Esc), |event| {
+        assert!(event.is_some());
+        consume_event();
+        assert!(is_event_consumed());
+    });
+    assert_eq!(seen, None);
+}
+
+#[test]
+fn test_event_consume_method() {
+    let seen = render_siblings(key(KeyCode::Tab), |event| {
+        event.unwrap().consume();
+    });
+    assert_eq!(seen, None);
+}
+
+#[test]
+fn test_consuming_stale_event_has_no_effect() {
+    let seen = render_siblings(key(KeyCode::Char('a')), |_| {
+        key(KeyCode::Char('b')).consume();
+    });
+    assert_eq!(seen, Some(key(KeyCode::Char('a'))));
+}
+
+#[test]
+fn test_new_event_resets_consumed_flag() {
+    let _lock = lock_current_event();
+    set_current_event(Some(Arc::new(key(KeyCode::Up))));
+    consume_event();
+    assert!(is_event_consumed());
+
+    set_current_event(Some(Arc::new(key(KeyCode::Down))));
+    assert!(!is_event_consumed());
+    set_current_event(None);
+
+    consume_event();
+    assert!(!is_event_consumed());
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct WatcherNotification(String);
+
+#[derive(Clone)]
+struct Listener {
+    received: Arc<Mutex<Vec<WatcherNotification>>>,
+}
+
+impl Component for Listener {
+    fn render(&self, _area: Rect, _frame: &mut Frame) {
+        if let Some(notification) = use_app_event::<WatcherNotification>() {
+            self.received.lock().unwrap().push(notification);
+        }
+    }
+}
+
+#[test]
+fn test_custom_event_from_another_thread() {
+    with_test_isolate(|| {
+        with_hook_context(|context| {
+            let received = Arc::new(Mutex::new(Vec::new()));
+            let listener = Listener {
+                received: received.clone(),
+            };
+            let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+
+            // A channel of its own keeps events posted by other tests out
+            let scope = AppEventScope::start();
+            let sender = scope.sender();
+            std::thread::spawn(move || {
+                sender.post(AppEvent::custom(WatcherNotification("config.toml".into())));
+            })
+            .join()
+            .unwrap();
+
+            let Some(AppEvent::Custom(event)) = try_next_app_event() else {
+                panic!("the posted event should be queued");
+            };
+            assert!(try_next_app_event().is_none());
+            set_current_app_event(Some(event));
+
+            // The first render observes the event, the second one must not see it again
+            for _ in 0..2 {
+                context.reset_hook_index();
+                terminal
+                    .draw(|frame| listener.render(frame.area(), frame))
+                    .unwrap();
+            }
+            set_current_app_event(None);
+
+            assert_eq!(
+                *received.lock().unwrap(),
+                vec![WatcherNotification("config.toml".into())]
+            );
+        });
+    });
+}
+
+#[test]
+fn test_app_event_scope_restores_previous_channel() {
+    let outer = AppEventScope::start();
+    {
+        let _inner = AppEventScope::start();
+        post_event(AppEvent::custom(1u8));
+        assert!(matches!(try_next_app_event(), Some(AppEvent::Custom(_))));
+    }
+
+    // Events posted to the inner scope don't leak into the outer one
+    assert!(try_next_app_event().is_none());
+    outer.sender().post(AppEvent::custom(2u8));
+    assert!(matches!(try_next_app_event(), Some(AppEvent::Custom(_))));
+}
+
+#[test]
+fn test_custom_event_downcasting() {
+    let event = CustomEvent::new(42u32);
+    assert!(event.is::<u32>());
+    assert_eq!(event.downcast_ref::<u32>(), Some(&42));
+    assert_eq!(event.downcast_ref::<String>(), None);
+    assert_eq!(event.type_name(), "u32");
+}
+
+fn click() -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 3,
+        row: 4,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+/// Run all filtered hooks against `event` in a fresh render
+fn observe(event: Event) -> (Option<Event>, Option<KeyEvent>, Option<MouseEvent>) {
+    let _lock = lock_current_event();
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            set_current_event(Some(Arc::new(event)));
+            let filtered = use_event_filtered(|event| matches!(event, Event::Resize(..)));
+            let key = use_key_event();
+            let mouse = use_mouse_event();
+            set_current_event(None);
+            (filtered, key, mouse)
+        })
+    })
+}
+
+#[test]
+fn test_filtered_hooks_only_surface_matching_events() {
+    let (filtered, key_event, mouse) = observe(key(KeyCode::Char('a')));
+    assert_eq!(filtered, None);
+    assert_eq!(key_event.map(|k| k.code), Some(KeyCode::Char('a')));
+    assert_eq!(mouse, None);
+
+    let (filtered, key_event, mouse) = observe(click());
+    assert_eq!(filtered, None);
+    assert_eq!(key_event, None);
+    assert_eq!(mouse.map(|m| (m.column, m.row)), Some((3, 4)));
+
+    let (filtered, key_event, mouse) = observe(Event::Resize(80, 24));
+    assert_eq!(filtered, Some(Event::Resize(80, 24)));
+    assert_eq!(key_event, None);
+    assert_eq!(mouse, None);
+}
+
+#[test]
+fn test_key_release_is_filtered_out() {
+    let mut release = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+    release.kind = KeyEventKind::Release;
+
+    let (_, key_event, _) = observe(Event::Key(release));
+    assert_eq!(key_event, None);
+}
@@ -0,0 +1,183 @@
+//! File Watching Hook
+//!
+//! This module provides a `use_file_watcher` hook that reports create, modify and
+//! delete events for a watched path. It is designed for config-reloading TUIs and
+//! is backed by the `notify` crate (enable the `watch` feature).
+//!
+//! Rapid bursts of filesystem events (editors often write a file several times
+//! per save) are debounced into a single [`FileChangeEvent`], which is also
+//! posted to the app event loop so the UI re-renders as soon as the change lands.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::hooks::{
+    effect::use_effect,
+    event::app_events::{AppEvent, post_event},
+    state::use_state,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Default quiet period used to coalesce bursts of filesystem events
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// The kind of change observed on a watched path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileChangeKind {
+    /// The path was created
+    Created,
+    /// The path's content or metadata was modified
+    Modified,
+    /// The path was removed
+    Removed,
+}
+
+/// A debounced change observed on a watched path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChangeEvent {
+    /// The path that changed
+    pub path: PathBuf,
+    /// What happened to the path
+    pub kind: FileChangeKind,
+    /// When the change was reported (after debouncing)
+    pub timestamp: Instant,
+}
+
+impl FileChangeKind {
+    /// Map a raw `notify` event kind to a change kind, ignoring access events
+    fn from_notify(kind: &EventKind) -> Option<Self> {
+        match kind {
+            EventKind::Create(_) => Some(FileChangeKind::Created),
+            EventKind::Modify(_) => Some(FileChangeKind::Modified),
+            EventKind::Remove(_) => Some(FileChangeKind::Removed),
+            EventKind::Access(_) | EventKind::Any | EventKind::Other => None,
+        }
+    }
+}
+
+/// Hook for watching a file or directory for changes
+///
+/// Returns the most recent debounced change for `path`, or `None` until the
+/// first change is observed. Uses [`DEFAULT_DEBOUNCE`] as the quiet period.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::file_watcher::{use_file_watcher, FileChangeKind};
+/// // In a component context:
+/// if let Some(change) = use_file_watcher("config.toml") {
+///     if change.kind != FileChangeKind::Removed {
+///         // Reload the configuration
+///     }
+/// }
+/// ```
+pub fn use_file_watcher(path: impl AsRef<Path>) -> Option<FileChangeEvent> {
+    use_file_watcher_with_debounce(path, DEFAULT_DEBOUNCE)
+}
+
+/// Hook for watching a file or directory with a custom debounce period
+///
+/// Filesystem events arriving within `debounce` of each other are coalesced and
+/// reported once, carrying the kind of the last event in the burst.
+///
+/// Files are watched through their parent directory so that atomic saves
+/// (write to a temp file, then rename) and delete/re-create cycles are observed.
+/// The watcher is restarted when `path` changes and is dropped together with
+/// the hook state, which stops the underlying OS watch.
+pub fn use_file_watcher_with_debounce(
+    path: impl AsRef<Path>,
+    debounce: Duration,
+) -> Option<FileChangeEvent> {
+    let path = path.as_ref().to_path_buf();
+    let (latest, set_latest) = use_state(|| None::<FileChangeEvent>);
+
+    let watched_path = path.clone();
+    use_effect(
+        move || {
+            let (raw_tx, raw_rx) = mpsc::channel::<FileChangeEvent>();
+            let target = watched_path.clone();
+
+            let mut watcher =
+                match notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+                    let Ok(event) = result else { return };
+                    let Some(kind) = FileChangeKind::from_notify(&event.kind) else {
+                        return;
+                    };
+                    for changed in event.paths {
+                        if is_watched_path(&target, &changed) {
+                            let _ = raw_tx.send(FileChangeEvent {
+                                path: changed,
+                                kind,
+                                timestamp: Instant::now(),
+                            });
+                        }
+                    }
+                }) {
+                    Ok(watcher) => watcher,
+                    Err(error) => {
+                        tracing::error!(
+                            target: "hooks::file_watcher",
+                            "Failed to create file watcher: {}",
+                            error
+                        );
+                        return None;
+                    }
+                };
+
+            let watch_root = if watched_path.is_dir() {
+                watched_path.clone()
+            } else {
+                match watched_path.parent() {
+                    Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+                    _ => PathBuf::from("."),
+                }
+            };
+
+            if let Err(error) = watcher.watch(&watch_root, RecursiveMode::NonRecursive) {
+                tracing::error!(
+                    target: "hooks::file_watcher",
+                    "Failed to watch {:?}: {}",
+                    watch_root,
+                    error
+                );
+                return None;
+            }
+
+            // Debounce thread: exits once the watcher (and its sender) is dropped
+            thread::spawn(move || {
+                while let Ok(first) = raw_rx.recv() {
+                    let mut last = first;
+                    while let Ok(next) = raw_rx.recv_timeout(debounce) {
+                        last = next;
+                    }
+                    last.timestamp = Instant::now();
+                    set_latest.set(Some(last.clone()));
+                    post_event(AppEvent::custom(last));
+                }
+            });
+
+            // Dropping the watcher stops the OS watch and ends the debounce thread
+            Some(move || drop(watcher))
+        },
+        path.to_string_lossy().into_owned(),
+    );
+
+    latest.get()
+}
+
+/// Returns true if `changed` refers to the watched `target`
+///
+/// Directories match any direct child, files match by file name within the
+/// watched parent directory.
+fn is_watched_path(target: &Path, changed: &Path) -> bool {
+    if changed == target || changed.parent() == Some(target) {
+        return true;
+    }
+    changed.file_name().is_some() && changed.file_name() == target.file_name()
+}
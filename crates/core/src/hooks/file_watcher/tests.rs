@@ -0,0 +1,74 @@
+//! Tests for the file watcher hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use std::fs;
+
+/// Render the watcher component until it reports a change or the timeout expires
+fn wait_for_change(component_id: &'static str, path: &Path) -> Option<FileChangeEvent> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        let change = with_component_id(component_id, |_| {
+            use_file_watcher_with_debounce(path, Duration::from_millis(20))
+        });
+        if change.is_some() {
+            return change;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    None
+}
+
+#[test]
+fn test_modifying_file_yields_change_event() {
+    with_test_isolate(|| {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "value = 1").unwrap();
+
+        // First render starts the watcher and has nothing to report yet
+        let initial = with_component_id("FileWatcherModify", |_| {
+            use_file_watcher_with_debounce(&path, Duration::from_millis(20))
+        });
+        assert!(initial.is_none());
+
+        // Give the OS watch a moment to be registered before touching the file
+        thread::sleep(Duration::from_millis(50));
+        fs::write(&path, "value = 2").unwrap();
+
+        let change = wait_for_change("FileWatcherModify", &path).expect("no change reported");
+        assert_eq!(change.path.file_name(), path.file_name());
+        assert!(matches!(
+            change.kind,
+            FileChangeKind::Modified | FileChangeKind::Created
+        ));
+    });
+}
+
+#[test]
+fn test_removing_file_yields_removed_event() {
+    with_test_isolate(|| {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.json");
+        fs::write(&path, "{}").unwrap();
+
+        with_component_id("FileWatcherRemove", |_| {
+            use_file_watcher_with_debounce(&path, Duration::from_millis(20))
+        });
+        thread::sleep(Duration::from_millis(50));
+        fs::remove_file(&path).unwrap();
+
+        let change = wait_for_change("FileWatcherRemove", &path).expect("no change reported");
+        assert_eq!(change.kind, FileChangeKind::Removed);
+    });
+}
+
+#[test]
+fn test_unrelated_files_are_ignored() {
+    let target = Path::new("/tmp/app/config.toml");
+    assert!(is_watched_path(target, Path::new("/tmp/app/config.toml")));
+    assert!(!is_watched_path(target, Path::new("/tmp/app/other.toml")));
+
+    let dir = Path::new("/tmp/app");
+    assert!(is_watched_path(dir, Path::new("/tmp/app/anything.log")));
+}
@@ -0,0 +1,222 @@
+//! Filter Hook
+//!
+//! This module provides a `use_filter` hook for filterable list views. The
+//! view offers a set of named predicates that can each be switched on and
+//! off; the active ones are combined either with AND, keeping items matching
+//! all of them, or with OR, keeping items matching any of them. With no
+//! active predicate every item is kept.
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// A named, toggleable predicate passed to [`use_filter`]
+#[derive(Debug)]
+pub struct FilterPredicate<T> {
+    name: &'static str,
+    predicate: fn(&T) -> bool,
+}
+
+impl<T> FilterPredicate<T> {
+    /// Create a predicate keeping the items for which `predicate` is true
+    pub fn new(name: &'static str, predicate: fn(&T) -> bool) -> Self {
+        Self { name, predicate }
+    }
+
+    /// The name used to switch this predicate on and off
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T> Clone for FilterPredicate<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for FilterPredicate<T> {}
+
+/// How the active predicates are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterMode {
+    /// Keep items matching every active predicate
+    #[default]
+    And,
+    /// Keep items matching at least one active predicate
+    Or,
+}
+
+/// Names of the active predicates and how they combine
+#[derive(Debug, Clone, Default)]
+struct FilterState {
+    active: Vec<&'static str>,
+    mode: FilterMode,
+}
+
+/// Handle returned by [`use_filter`]
+#[derive(Clone)]
+pub struct FilterHandle {
+    state: StateHandle<FilterState>,
+    setter: StateSetter<FilterState>,
+    names: Vec<&'static str>,
+}
+
+impl FilterHandle {
+    /// Switch the predicate called `name` on if it is off, off otherwise
+    pub fn toggle(&self, name: &str) {
+        let active = !self.is_active(name);
+        self.set_active(name, active);
+    }
+
+    /// Switch the predicate called `name` on or off
+    ///
+    /// Names that aren't among the predicates passed to [`use_filter`] are
+    /// ignored.
+    pub fn set_active(&self, name: &str, active: bool) {
+        let Some(name) = self.known(name) else {
+            return;
+        };
+        self.setter.update(move |state| {
+            let mut state = state.clone();
+            state.active.retain(|active| *active != name);
+            if active {
+                state.active.push(name);
+            }
+            state
+        });
+    }
+
+    /// Make the predicate called `name` the only active one
+    pub fn set_only(&self, name: &str) {
+        let Some(name) = self.known(name) else {
+            return;
+        };
+        self.setter.update(move |state| FilterState {
+            active: vec![name],
+            ..state.clone()
+        });
+    }
+
+    /// Returns true if the predicate called `name` is active
+    pub fn is_active(&self, name: &str) -> bool {
+        self.state.with(|state| state.active.contains(&name))
+    }
+
+    /// Names of the active predicates, in the order they were given
+    pub fn active(&self) -> Vec<&'static str> {
+        self.state.with(|state| {
+            self.names
+                .iter()
+                .copied()
+                .filter(|name| state.active.contains(name))
+                .collect()
+        })
+    }
+
+    /// Switch every predicate off, keeping all items
+    pub fn clear(&self) {
+        self.setter.update(|state| FilterState {
+            active: Vec::new(),
+            ..state.clone()
+        });
+    }
+
+    /// How the active predicates are combined
+    pub fn mode(&self) -> FilterMode {
+        self.state.with(|state| state.mode)
+    }
+
+    /// Set how the active predicates are combined
+    pub fn set_mode(&self, mode: FilterMode) {
+        self.setter.update(move |state| FilterState {
+            mode,
+            ..state.clone()
+        });
+    }
+
+    /// Switch between combining with AND and with OR
+    pub fn toggle_mode(&self) {
+        let mode = match self.mode() {
+            FilterMode::And => FilterMode::Or,
+            FilterMode::Or => FilterMode::And,
+        };
+        self.set_mode(mode);
+    }
+
+    fn known(&self, name: &str) -> Option<&'static str> {
+        self.names.iter().copied().find(|known| *known == name)
+    }
+}
+
+/// Hook that filters `items` by the active ones of `filters`
+///
+/// Returns the items passing the active predicates, in their original
+/// order, and a [`FilterHandle`] to switch predicates on and off. Initially
+/// no predicate is active and the predicates combine with AND.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::filter::{FilterPredicate, use_filter};
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// #[derive(Clone)]
+/// struct Task {
+///     done: bool,
+///     urgent: bool,
+/// }
+///
+/// # let tasks: Vec<Task> = Vec::new();
+/// // In a component context:
+/// let (visible, filter) = use_filter(
+///     &tasks,
+///     &[
+///         FilterPredicate::new("Open", |task: &Task| !task.done),
+///         FilterPredicate::new("Urgent", |task: &Task| task.urgent),
+///     ],
+/// );
+///
+/// if let Some(key) = use_key_event() {
+///     match key.code {
+///         KeyCode::Char('o') => filter.toggle("Open"),
+///         KeyCode::Char('u') => filter.toggle("Urgent"),
+///         KeyCode::Char('m') => filter.toggle_mode(),
+///         _ => {}
+///     }
+/// }
+///
+/// let title = format!("Tasks [{}] {}/{}", filter.active().join(", "), visible.len(), tasks.len());
+/// ```
+pub fn use_filter<T: Clone>(items: &[T], filters: &[FilterPredicate<T>]) -> (Vec<T>, FilterHandle) {
+    let (state, setter) = use_state(FilterState::default);
+    let handle = FilterHandle {
+        state,
+        setter,
+        names: filters.iter().map(FilterPredicate::name).collect(),
+    };
+
+    let (active, mode) = handle.state.with(|state| {
+        let active: Vec<_> = filters
+            .iter()
+            .filter(|filter| state.active.contains(&filter.name))
+            .map(|filter| filter.predicate)
+            .collect();
+        (active, state.mode)
+    });
+
+    let visible = items
+        .iter()
+        .filter(|item| {
+            active.is_empty()
+                || match mode {
+                    FilterMode::And => active.iter().all(|predicate| predicate(item)),
+                    FilterMode::Or => active.iter().any(|predicate| predicate(item)),
+                }
+        })
+        .cloned()
+        .collect();
+
+    (visible, handle)
+}
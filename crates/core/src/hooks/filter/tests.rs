@@ -0,0 +1,96 @@
+//! Tests for the use_filter hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+#[derive(Clone, Debug)]
+struct Task {
+    name: &'static str,
+    done: bool,
+    urgent: bool,
+}
+
+fn tasks() -> Vec<Task> {
+    [
+        ("taxes", false, true),
+        ("dishes", true, false),
+        ("report", false, false),
+        ("visa", true, true),
+    ]
+    .into_iter()
+    .map(|(name, done, urgent)| Task { name, done, urgent })
+    .collect()
+}
+
+fn render(items: &[Task]) -> (Vec<&'static str>, FilterHandle) {
+    with_component_id("FilteredTasks", |_| {
+        let (visible, filter) = use_filter(
+            items,
+            &[
+                FilterPredicate::new("Open", |task: &Task| !task.done),
+                FilterPredicate::new("Urgent", |task: &Task| task.urgent),
+            ],
+        );
+        (visible.iter().map(|task| task.name).collect(), filter)
+    })
+}
+
+#[test]
+fn test_two_predicates_combined_with_and() {
+    with_test_isolate(|| {
+        let items = tasks();
+        let (visible, filter) = render(&items);
+        assert_eq!(visible, ["taxes", "dishes", "report", "visa"]);
+        assert_eq!(filter.mode(), FilterMode::And);
+
+        filter.toggle("Urgent");
+        filter.toggle("Open");
+        let (visible, filter) = render(&items);
+        assert_eq!(visible, ["taxes"]);
+        assert_eq!(filter.active(), ["Open", "Urgent"]);
+
+        filter.toggle("Urgent");
+        let (visible, filter) = render(&items);
+        assert_eq!(visible, ["taxes", "report"]);
+        assert!(!filter.is_active("Urgent"));
+    });
+}
+
+#[test]
+fn test_two_predicates_combined_with_or() {
+    with_test_isolate(|| {
+        let items = tasks();
+        let (_, filter) = render(&items);
+        filter.set_mode(FilterMode::Or);
+        filter.toggle("Open");
+        filter.toggle("Urgent");
+
+        let (visible, filter) = render(&items);
+        assert_eq!(visible, ["taxes", "report", "visa"]);
+
+        filter.toggle_mode();
+        assert_eq!(render(&items).0, ["taxes"]);
+    });
+}
+
+#[test]
+fn test_set_only_clear_and_unknown_names() {
+    with_test_isolate(|| {
+        let items = tasks();
+        let (_, filter) = render(&items);
+        filter.toggle("Open");
+        filter.set_only("Urgent");
+        let (visible, filter) = render(&items);
+        assert_eq!(visible, ["taxes", "visa"]);
+        assert_eq!(filter.active(), ["Urgent"]);
+
+        filter.toggle("Overdue");
+        filter.set_only("Overdue");
+        assert_eq!(render(&items).1.active(), ["Urgent"]);
+
+        filter.clear();
+        let (visible, filter) = render(&items);
+        assert_eq!(visible.len(), 4);
+        assert!(filter.active().is_empty());
+    });
+}
@@ -0,0 +1,543 @@
+//! Form Hook
+//!
+//! This module provides a `use_form` hook for input-heavy screens. A form is a
+//! list of named text fields, each with an optional validator. The form tracks
+//! the value, the touched flag and the validation error of every field, keeps
+//! track of the focused field for text entry, and only submits when all fields
+//! are valid.
+//!
+//! Validation runs whenever a field's value changes. Errors are only reported
+//! for fields the user has touched, or for every field after a submit attempt,
+//! so a fresh form does not greet the user with a wall of errors.
+//!
+//! Fields can also have an async validator (e.g. checking that a username is
+//! still available). It runs once the user stopped typing for the field's
+//! debounce period; until its result arrives the field is *pending*. Results of
+//! validations started before a later edit are discarded.
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// A synchronous field validator
+pub type Validator = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// Future returned by an async field validator
+pub type ValidationFuture = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// An async, debounced field validator
+#[derive(Clone)]
+pub struct AsyncValidator {
+    debounce: Duration,
+    validate: Arc<dyn Fn(String) -> ValidationFuture + Send + Sync>,
+}
+
+/// Submitted form values, keyed by field name
+pub type FormValues = HashMap<String, String>;
+
+/// Declaration of a form field passed to [`use_form`]
+#[derive(Clone)]
+pub struct Field {
+    name: String,
+    initial: String,
+    validator: Option<Validator>,
+    async_validator: Option<AsyncValidator>,
+}
+
+impl Field {
+    /// Declare a field with an empty initial value and no validation
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            initial: String::new(),
+            validator: None,
+            async_validator: None,
+        }
+    }
+
+    /// Set the initial value of the field
+    pub fn initial(mut self, value: impl Into<String>) -> Self {
+        self.initial = value.into();
+        self
+    }
+
+    /// Set the validator of the field
+    pub fn validate<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validator = Some(Arc::new(validator));
+        self
+    }
+
+    /// Set an async validator that runs `debounce` after the last edit
+    ///
+    /// The async validator only runs when the synchronous validator (if any)
+    /// passes. It requires a tokio runtime; outside of one, it runs on a
+    /// background thread.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use pulse_core::hooks::form::Field;
+    /// use std::time::Duration;
+    ///
+    /// let field = Field::new("username").validate_async(Duration::from_millis(300), |name| async move {
+    ///     // Query the database...
+    ///     if name == "admin" {
+    ///         Err("Username is taken".to_string())
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// ```
+    pub fn validate_async<F, Fut>(mut self, debounce: Duration, validator: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.async_validator = Some(AsyncValidator {
+            debounce,
+            validate: Arc::new(move |value| Box::pin(validator(value))),
+        });
+        self
+    }
+}
+
+/// Common validators
+pub mod validators {
+    /// Require a non-blank value
+    pub fn required(value: &str) -> Result<(), String> {
+        if value.trim().is_empty() {
+            Err("This field is required".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Require at least `min` characters
+    pub fn min_length(min: usize) -> impl Fn(&str) -> Result<(), String> + Send + Sync {
+        move |value| {
+            if value.chars().count() < min {
+                Err(format!("Must be at least {} characters", min))
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Runtime state of a single field
+#[derive(Clone)]
+struct FieldState {
+    name: String,
+    value: String,
+    initial: String,
+    touched: bool,
+    error: Option<String>,
+    validator: Option<Validator>,
+    async_validator: Option<AsyncValidator>,
+    /// Whether an async validation is scheduled or running
+    pending: bool,
+    /// Incremented on every edit, used to discard stale async results
+    generation: u64,
+}
+
+impl FieldState {
+    /// Run the synchronous validator
+    ///
+    /// Returns true if an async validation should follow.
+    fn validate(&mut self) -> bool {
+        self.error = self
+            .validator
+            .as_ref()
+            .and_then(|validator| validator(&self.value).err());
+        self.pending = self.error.is_none() && self.async_validator.is_some();
+        self.pending
+    }
+}
+
+/// State of a whole form
+#[derive(Clone)]
+struct FormState {
+    fields: Vec<FieldState>,
+    focused: usize,
+    submit_attempted: bool,
+}
+
+impl FormState {
+    fn field_mut(&mut self, name: &str) -> Option<&mut FieldState> {
+        self.fields.iter_mut().find(|field| field.name == name)
+    }
+}
+
+/// Handle returned by [`use_form`]
+#[derive(Clone)]
+pub struct FormHandle {
+    state: StateHandle<FormState>,
+    setter: StateSetter<FormState>,
+    /// Async validations started, by field name and generation
+    scheduled: Arc<Mutex<HashSet<(String, u64)>>>,
+}
+
+/// Run an async validation after its debounce period and apply the result
+///
+/// The validation is skipped if the field was edited during the debounce
+/// period, and its result is discarded if the field was edited meanwhile.
+fn spawn_validation(
+    form: FormHandle,
+    key: (String, u64),
+    value: String,
+    validator: AsyncValidator,
+) {
+    let task = async move {
+        let (name, generation) = key.clone();
+        let is_current = |form: &FormHandle| {
+            form.with_field(&name, |field| field.generation == generation)
+                .unwrap_or(false)
+        };
+
+        tokio::time::sleep(validator.debounce).await;
+        if is_current(&form) {
+            let result = (validator.validate)(value).await;
+            form.setter.update(|state| {
+                let mut state = state.clone();
+                if let Some(field) = state
+                    .field_mut(&name)
+                    .filter(|field| field.generation == generation)
+                {
+                    field.pending = false;
+                    field.error = result.err();
+                }
+                state
+            });
+        }
+        form.scheduled.lock().remove(&key);
+    };
+
+    match tokio::runtime::Handle::try_current() {
+        Ok(runtime) => {
+            runtime.spawn(task);
+        }
+        Err(_) => {
+            std::thread::spawn(move || {
+                match tokio::runtime::Builder::new_current_thread()
+                    .enable_time()
+                    .build()
+                {
+                    Ok(runtime) => runtime.block_on(task),
+                    Err(error) => tracing::error!(
+                        target: "hooks::form",
+                        "Failed to start async validation: {}",
+                        error
+                    ),
+                }
+            });
+        }
+    }
+}
+
+impl FormHandle {
+    /// Names of all fields, in declaration order
+    pub fn field_names(&self) -> Vec<String> {
+        self.state
+            .get()
+            .fields
+            .into_iter()
+            .map(|field| field.name)
+            .collect()
+    }
+
+    /// Current value of a field
+    pub fn value(&self, name: &str) -> Option<String> {
+        self.with_field(name, |field| field.value.clone())
+    }
+
+    /// Set the value of a field, marking it touched and validating it
+    pub fn set_value(&self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        self.edit(name, move |field| field.value = value);
+    }
+
+    /// Returns true if the user has changed the field
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.with_field(name, |field| field.touched)
+            .unwrap_or(false)
+    }
+
+    /// The error to display for a field
+    ///
+    /// Errors are shown once the field was touched or a submit was attempted.
+    pub fn error(&self, name: &str) -> Option<String> {
+        let state = self.state.get();
+        state
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .filter(|field| field.touched || state.submit_attempted)
+            .and_then(|field| field.error.clone())
+    }
+
+    /// All errors currently displayed, as `(field, message)` pairs
+    pub fn errors(&self) -> Vec<(String, String)> {
+        let state = self.state.get();
+        state
+            .fields
+            .iter()
+            .filter(|field| field.touched || state.submit_attempted)
+            .filter_map(|field| Some((field.name.clone(), field.error.clone()?)))
+            .collect()
+    }
+
+    /// Returns true if every field passes validation
+    ///
+    /// Unlike [`Self::error`], this considers untouched fields too. Fields
+    /// whose async validation is still pending are not valid yet.
+    pub fn is_valid(&self) -> bool {
+        self.state
+            .get()
+            .fields
+            .iter()
+            .all(|field| field.error.is_none() && !field.pending)
+    }
+
+    /// Returns true while the field's async validation is pending
+    pub fn is_pending(&self, name: &str) -> bool {
+        self.with_field(name, |field| field.pending)
+            .unwrap_or(false)
+    }
+
+    /// Returns true while any async validation is pending
+    pub fn is_validating(&self) -> bool {
+        self.state.get().fields.iter().any(|field| field.pending)
+    }
+
+    /// Name of the focused field
+    pub fn focused(&self) -> Option<String> {
+        let state = self.state.get();
+        state
+            .fields
+            .get(state.focused)
+            .map(|field| field.name.clone())
+    }
+
+    /// Focus a field by name
+    pub fn focus(&self, name: &str) {
+        let name = name.to_string();
+        self.setter.update(move |state| {
+            let mut state = state.clone();
+            if let Some(index) = state.fields.iter().position(|field| field.name == name) {
+                state.focused = index;
+            }
+            state
+        });
+    }
+
+    /// Move focus to the next field, wrapping around
+    pub fn focus_next(&self) {
+        self.setter.update(|state| {
+            let mut state = state.clone();
+            state.focused = (state.focused + 1) % state.fields.len().max(1);
+            state
+        });
+    }
+
+    /// Move focus to the previous field, wrapping around
+    pub fn focus_prev(&self) {
+        self.setter.update(|state| {
+            let mut state = state.clone();
+            let len = state.fields.len().max(1);
+            state.focused = (state.focused + len - 1) % len;
+            state
+        });
+    }
+
+    /// Append a typed character to the focused field
+    pub fn insert_char(&self, c: char) {
+        if let Some(name) = self.focused() {
+            self.edit(&name, move |field| field.value.push(c));
+        }
+    }
+
+    /// Delete the last character of the focused field
+    pub fn backspace(&self) {
+        if let Some(name) = self.focused() {
+            self.edit(&name, |field| {
+                field.value.pop();
+            });
+        }
+    }
+
+    /// Submit the form
+    ///
+    /// Marks the submit attempt (so all errors become visible) and calls
+    /// `on_valid` with the field values only if every field is valid. A form
+    /// with pending async validations is not submitted.
+    /// Returns true if the form was submitted.
+    pub fn submit<F>(&self, on_valid: F) -> bool
+    where
+        F: FnOnce(FormValues),
+    {
+        self.setter.update(|state| {
+            let mut state = state.clone();
+            state.submit_attempted = true;
+            state
+        });
+
+        if !self.is_valid() {
+            return false;
+        }
+
+        let values = self
+            .state
+            .get()
+            .fields
+            .into_iter()
+            .map(|field| (field.name, field.value))
+            .collect();
+        on_valid(values);
+        true
+    }
+
+    /// Restore the initial values and clear touched and error state
+    pub fn reset(&self) {
+        self.setter.update(|state| {
+            let mut state = state.clone();
+            state.submit_attempted = false;
+            state.focused = 0;
+            for field in &mut state.fields {
+                field.value = field.initial.clone();
+                field.touched = false;
+                field.generation += 1;
+                field.validate();
+            }
+            state
+        });
+        self.schedule_async_validations();
+    }
+
+    /// Read a field by name
+    fn with_field<R>(&self, name: &str, read: impl FnOnce(&FieldState) -> R) -> Option<R> {
+        self.state
+            .get()
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(read)
+    }
+
+    /// Apply an edit to a field, then mark it touched and re-validate it
+    fn edit(&self, name: &str, edit: impl FnOnce(&mut FieldState)) {
+        let name = name.to_string();
+        self.setter.update(move |state| {
+            let mut state = state.clone();
+            if let Some(field) = state.field_mut(&name) {
+                edit(field);
+                field.touched = true;
+                field.generation += 1;
+                field.validate();
+            }
+            state
+        });
+        self.schedule_async_validations();
+    }
+
+    /// Start async validations for pending fields that don't have one running
+    fn schedule_async_validations(&self) {
+        let state = self.state.get();
+        for field in state.fields.iter().filter(|field| field.pending) {
+            let Some(validator) = field.async_validator.clone() else {
+                continue;
+            };
+            let key = (field.name.clone(), field.generation);
+            if !self.scheduled.lock().insert(key.clone()) {
+                continue;
+            }
+            spawn_validation(self.clone(), key, field.value.clone(), validator);
+        }
+    }
+}
+
+/// Hook that manages the values, validation and submission of a form
+///
+/// The field declarations are only read on the first render.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::form::{Field, use_form, validators};
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// // In a component context:
+/// let form = use_form([
+///     Field::new("username").validate(validators::required),
+///     Field::new("email").validate(|value| {
+///         if value.contains('@') {
+///             Ok(())
+///         } else {
+///             Err("Not an email address".to_string())
+///         }
+///     }),
+/// ]);
+///
+/// if let Some(key) = use_key_event() {
+///     match key.code {
+///         KeyCode::Tab => form.focus_next(),
+///         KeyCode::Char(c) => form.insert_char(c),
+///         KeyCode::Backspace => form.backspace(),
+///         KeyCode::Enter => {
+///             form.submit(|values| {
+///                 // Save values["username"] and values["email"]
+///             });
+///         }
+///         _ => {}
+///     }
+/// }
+///
+/// if let Some(error) = form.error("email") {
+///     // Render the error below the field
+/// } else if form.is_pending("email") {
+///     // Render a "checking..." indicator
+/// }
+/// ```
+pub fn use_form(fields: impl IntoIterator<Item = Field>) -> FormHandle {
+    let (state, setter) = use_state(move || FormState {
+        fields: fields
+            .into_iter()
+            .map(|field| {
+                let mut state = FieldState {
+                    name: field.name,
+                    value: field.initial.clone(),
+                    initial: field.initial,
+                    touched: false,
+                    error: None,
+                    validator: field.validator,
+                    async_validator: field.async_validator,
+                    pending: false,
+                    generation: 0,
+                };
+                state.validate();
+                state
+            })
+            .collect(),
+        focused: 0,
+        submit_attempted: false,
+    });
+    let (scheduled, _) = use_state(|| Arc::new(Mutex::new(HashSet::new())));
+
+    let form = FormHandle {
+        state,
+        setter,
+        scheduled: scheduled.get(),
+    };
+    form.schedule_async_validations();
+    form
+}
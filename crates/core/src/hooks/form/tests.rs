@@ -0,0 +1,192 @@
+//! Tests for the use_form hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use std::cell::RefCell;
+
+fn signup() -> FormHandle {
+    with_component_id("SignupForm", |_| {
+        use_form([
+            Field::new("username")
+                .validate(validators::required)
+                .initial(""),
+            Field::new("password").validate(validators::min_length(8)),
+            Field::new("nickname").initial("anon"),
+        ])
+    })
+}
+
+#[test]
+fn test_validation_runs_on_change() {
+    with_test_isolate(|| {
+        let form = signup();
+        assert!(!form.is_valid());
+        // Untouched fields don't display errors yet
+        assert_eq!(form.error("username"), None);
+        assert!(form.errors().is_empty());
+
+        form.set_value("password", "short");
+        let form = signup();
+        assert!(form.is_touched("password"));
+        assert_eq!(
+            form.error("password").as_deref(),
+            Some("Must be at least 8 characters")
+        );
+
+        form.set_value("password", "long enough");
+        let form = signup();
+        assert_eq!(form.error("password"), None);
+
+        form.set_value("username", "ada");
+        let form = signup();
+        assert!(form.is_valid());
+        assert_eq!(form.value("nickname").as_deref(), Some("anon"));
+    });
+}
+
+#[test]
+fn test_submit_blocked_when_invalid() {
+    with_test_isolate(|| {
+        let submitted = RefCell::new(None);
+
+        let form = signup();
+        form.set_value("password", "hunter2hunter2");
+        assert!(!form.submit(|values| *submitted.borrow_mut() = Some(values)));
+        assert!(submitted.borrow().is_none());
+
+        // After a submit attempt, errors of untouched fields are shown
+        let form = signup();
+        assert_eq!(
+            form.errors(),
+            vec![("username".to_string(), "This field is required".to_string())]
+        );
+
+        form.set_value("username", "grace");
+        assert!(signup().submit(|values| *submitted.borrow_mut() = Some(values)));
+
+        let values = submitted.borrow().clone().unwrap();
+        assert_eq!(values["username"], "grace");
+        assert_eq!(values["password"], "hunter2hunter2");
+        assert_eq!(values["nickname"], "anon");
+    });
+}
+
+#[test]
+fn test_text_entry_follows_focus() {
+    with_test_isolate(|| {
+        let form = signup();
+        assert_eq!(form.focused().as_deref(), Some("username"));
+
+        for c in "bob".chars() {
+            form.insert_char(c);
+        }
+        form.backspace();
+        form.focus_next();
+        form.insert_char('x');
+
+        let form = signup();
+        assert_eq!(form.value("username").as_deref(), Some("bo"));
+        assert_eq!(form.value("password").as_deref(), Some("x"));
+        assert_eq!(form.focused().as_deref(), Some("password"));
+
+        form.focus_prev();
+        form.focus_prev();
+        assert_eq!(signup().focused().as_deref(), Some("nickname"));
+
+        form.focus("username");
+        assert_eq!(signup().focused().as_deref(), Some("username"));
+    });
+}
+
+#[test]
+fn test_reset_restores_initial_state() {
+    with_test_isolate(|| {
+        let form = signup();
+        form.set_value("nickname", "changed");
+        form.submit(|_| {});
+
+        form.reset();
+        let form = signup();
+        assert_eq!(form.value("nickname").as_deref(), Some("anon"));
+        assert!(!form.is_touched("nickname"));
+        assert!(form.errors().is_empty());
+        assert!(!form.is_valid());
+    });
+}
+
+fn username_form(
+    delay: impl Fn(&str) -> u64 + Send + Sync + 'static,
+) -> (FormHandle, Arc<Mutex<Vec<String>>>) {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let delay = Arc::new(delay);
+    let recorded = calls.clone();
+    let form = with_component_id("UsernameForm", move |_| {
+        use_form([Field::new("username")
+            .validate(validators::required)
+            .validate_async(Duration::from_millis(30), move |value| {
+                recorded.lock().push(value.clone());
+                let delay = Duration::from_millis(delay(&value));
+                async move {
+                    tokio::time::sleep(delay).await;
+                    if value.starts_with("admin") {
+                        Err(format!("{} is taken", value))
+                    } else {
+                        Ok(())
+                    }
+                }
+            })])
+    });
+    (form, calls)
+}
+
+#[tokio::test]
+async fn test_async_validation_is_debounced() {
+    let (form, calls) = with_test_isolate(|| {
+        let (form, calls) = username_form(|_| 0);
+        for c in "admin".chars() {
+            form.insert_char(c);
+        }
+        (form, calls)
+    });
+
+    assert!(form.is_pending("username"));
+    assert!(form.is_validating());
+    assert!(!form.is_valid());
+    assert!(!form.submit(|_| {}));
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    // Only the final value was validated
+    assert_eq!(*calls.lock(), vec!["admin".to_string()]);
+    assert!(!form.is_pending("username"));
+    assert_eq!(form.error("username"), Some("admin is taken".to_string()));
+}
+
+#[tokio::test]
+async fn test_stale_async_result_is_discarded() {
+    // The first value takes much longer to validate than the second
+    let (form, calls) = with_test_isolate(|| {
+        let (form, calls) = username_form(|value| if value == "admin" { 200 } else { 10 });
+        form.set_value("username", "admin");
+        (form, calls)
+    });
+
+    // Let the slow validation start, then edit again
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    form.set_value("username", "alice");
+    assert!(form.is_pending("username"));
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(!form.is_pending("username"));
+    assert_eq!(form.error("username"), None);
+    assert!(form.is_valid());
+
+    // The slow result for "admin" arrives later and must not win
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(
+        *calls.lock(),
+        vec!["admin".to_string(), "alice".to_string()]
+    );
+    assert_eq!(form.error("username"), None);
+    assert!(form.submit(|values| assert_eq!(values["username"], "alice")));
+}
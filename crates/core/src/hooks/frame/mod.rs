@@ -0,0 +1,74 @@
+//! Frame Callback Hook
+//!
+//! This module provides a `use_frame` hook that runs a callback once per
+//! rendered frame with the time elapsed since the previous frame, like the
+//! update step of a game loop. The callback is driven by the render loop
+//! itself rather than a separate timer, so animations advance in step with
+//! the frames that are actually drawn.
+//!
+//! [`use_fps`] reports the smoothed frame rate of the render loop, for
+//! performance displays.
+
+use std::time::{Duration, Instant};
+
+use crate::clock;
+use crate::hooks::batch::{Priority, schedule_render};
+use crate::hooks::with_hook_context;
+use crate::redraw::current_fps;
+
+#[cfg(test)]
+mod tests;
+
+/// Hook that calls `callback` on every render with the frame delta
+///
+/// The delta is the time since the previous render of the component, and
+/// `Duration::ZERO` on the first render. The callback runs during render, so
+/// it can update state that the same render reads afterwards. While the
+/// component is mounted, every render requests a low-priority render, so
+/// frames keep coming without input.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::frame::use_frame;
+/// # use pulse_core::hooks::state::use_state;
+/// # use std::time::Duration;
+/// // In a component context:
+/// let (angle, set_angle) = use_state(|| 0.0f64);
+///
+/// // Spin at one turn per second, whatever the frame rate
+/// use_frame(move |dt: Duration| {
+///     set_angle.update(|angle| (angle + dt.as_secs_f64() * 360.0) % 360.0);
+/// });
+/// ```
+pub fn use_frame<F>(callback: F)
+where
+    F: FnOnce(Duration),
+{
+    let now = clock::now();
+    let last_frame = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        ctx.get_or_init_state(index, || None::<Instant>)
+    });
+    let previous = last_frame.borrow_mut().replace(now);
+
+    let dt = previous.map_or(Duration::ZERO, |previous| now.duration_since(previous));
+    callback(dt);
+    schedule_render(Priority::Low);
+}
+
+/// Hook that returns the smoothed frames per second of the render loop
+///
+/// The rate is `0.0` until the runtime has drawn two frames. See
+/// [`FpsMeter`](crate::redraw::FpsMeter) for how it is smoothed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::frame::use_fps;
+/// // In a component context:
+/// let status = format!("{:.0} fps", use_fps());
+/// ```
+pub fn use_fps() -> f64 {
+    current_fps()
+}
@@ -0,0 +1,79 @@
+//! Tests for the use_frame hook
+
+use super::*;
+use crate::clock::ManualClock;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use crate::redraw::FpsMeter;
+
+fn render() -> Duration {
+    with_component_id("FrameComponent", |_| {
+        let mut delta = None;
+        use_frame(|dt| delta = Some(dt));
+        delta.expect("the frame callback runs on every render")
+    })
+}
+
+#[test]
+fn test_first_frame_has_zero_delta() {
+    with_test_isolate(|| {
+        assert_eq!(render(), Duration::ZERO);
+    });
+}
+
+#[test]
+fn test_deltas_follow_frame_timing() {
+    with_test_isolate(|| {
+        let clock = ManualClock::start();
+        render();
+
+        // Each delta is exactly the time between two frames
+        for pause in [5, 20, 40] {
+            clock.advance(Duration::from_millis(pause));
+            assert_eq!(render(), Duration::from_millis(pause));
+        }
+    });
+}
+
+/// Tick a meter at a steady `rate` for `frames` frames starting at `start`
+fn tick_steady(meter: &mut FpsMeter, start: Instant, rate: f64, frames: u32) -> Instant {
+    let interval = Duration::from_secs_f64(1.0 / rate);
+    let mut at = start;
+    for _ in 0..frames {
+        at += interval;
+        meter.tick(at);
+    }
+    at
+}
+
+#[test]
+fn test_fps_meter_follows_frame_timings() {
+    let mut meter = FpsMeter::new();
+    let start = Instant::now();
+    meter.tick(start);
+    assert_eq!(meter.fps(), 0.0);
+
+    let at = tick_steady(&mut meter, start, 60.0, 30);
+    assert!((meter.fps() - 60.0).abs() < 0.5, "fps: {}", meter.fps());
+
+    // A single slow frame only dents the smoothed rate
+    meter.tick(at + Duration::from_millis(100));
+    assert!(meter.fps() > 50.0, "fps: {}", meter.fps());
+
+    // A lasting drop to 30 fps is followed within a few dozen frames
+    tick_steady(&mut meter, at + Duration::from_millis(100), 30.0, 60);
+    assert!((meter.fps() - 30.0).abs() < 0.5, "fps: {}", meter.fps());
+}
+
+#[test]
+fn test_use_fps_reports_recorded_frames() {
+    use crate::redraw::record_frame_tick;
+
+    let start = Instant::now();
+    for frame in 0..40 {
+        record_frame_tick(start + Duration::from_millis(20) * frame);
+    }
+    with_test_isolate(|| {
+        let fps = with_component_id("FpsComponent", |_| use_fps());
+        assert!((fps - 50.0).abs() < 0.5, "fps: {}", fps);
+    });
+}
@@ -0,0 +1,108 @@
+//! Global loading registry
+//!
+//! Every future started by a future hook registers itself here while it runs,
+//! so the app can tell whether anything is loading without knowing which
+//! components started work. A root component can use [`use_any_loading`] to
+//! show a single global spinner. A [`LoadingScope`] gives the current
+//! thread a registry of its own, e.g. for an app running next to others in
+//! the same process.
+
+use once_cell::sync::Lazy;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Number of in-flight futures across the whole app
+static LOADING_COUNT: Lazy<Arc<AtomicUsize>> = Lazy::new(|| Arc::new(AtomicUsize::new(0)));
+
+thread_local! {
+    /// Registry of the active [`LoadingScope`] on this thread
+    static SCOPED_REGISTRY: RefCell<Option<Arc<AtomicUsize>>> = const { RefCell::new(None) };
+}
+
+fn registry() -> Arc<AtomicUsize> {
+    SCOPED_REGISTRY
+        .with(|registry| registry.borrow().clone())
+        .unwrap_or_else(|| LOADING_COUNT.clone())
+}
+
+/// A loading registry of its own for the current thread
+///
+/// While the scope is alive, futures started on this thread are counted
+/// apart from the app-wide registry, and [`loading_count`] on this thread
+/// only sees them. The previous registry comes back when it is dropped.
+#[derive(Debug)]
+pub struct LoadingScope {
+    previous: Option<Arc<AtomicUsize>>,
+    // Bound to the thread whose registry it replaces
+    _not_send: PhantomData<*const ()>,
+}
+
+impl LoadingScope {
+    /// Start counting loading operations of this thread from zero
+    pub fn start() -> Self {
+        let previous =
+            SCOPED_REGISTRY.with(|registry| registry.replace(Some(Arc::new(AtomicUsize::new(0)))));
+        Self {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for LoadingScope {
+    fn drop(&mut self) {
+        SCOPED_REGISTRY.with(|registry| *registry.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Marks one operation as loading until dropped
+///
+/// Future hooks hold a guard inside their spawned task, so the count is
+/// released when the future completes, fails, panics or is cancelled.
+#[derive(Debug)]
+pub struct LoadingGuard {
+    registry: Arc<AtomicUsize>,
+}
+
+impl LoadingGuard {
+    /// Register a new loading operation
+    pub fn new() -> Self {
+        let registry = registry();
+        registry.fetch_add(1, Ordering::SeqCst);
+        Self { registry }
+    }
+}
+
+impl Default for LoadingGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for LoadingGuard {
+    fn drop(&mut self) {
+        self.registry.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Number of operations currently loading app-wide
+pub fn loading_count() -> usize {
+    registry().load(Ordering::SeqCst)
+}
+
+/// Hook that returns true while any future hook in the app is loading
+///
+/// # Example
+/// ```rust,no_run
+/// use pulse_core::hooks::future::use_any_loading;
+///
+/// // In the root component:
+/// if use_any_loading() {
+///     // Render a spinner in the status bar
+/// }
+/// ```
+pub fn use_any_loading() -> bool {
+    loading_count() > 0
+}
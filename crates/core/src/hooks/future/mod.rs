@@ -1,15 +1,20 @@
 use parking_lot::{Mutex, RwLock};
 use std::fmt;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use tokio::task::JoinHandle;
 
+mod loading;
 #[cfg(test)]
 mod tests;
 
+pub use loading::{LoadingGuard, LoadingScope, loading_count, use_any_loading};
+
+use crate::error::ContextError;
 use crate::hooks::effect::EffectDependencies;
 use crate::hooks::with_hook_context;
 use crate::panic_handler::spawn_catch_panic;
+use crate::tasks::spawn_limited;
 
 /// Default error type for futures - provides good ergonomics for most use cases
 pub type DefaultError = Box<dyn std::error::Error + Send + Sync>;
@@ -156,6 +161,10 @@ pub struct FutureHandle<T, E = Box<dyn std::error::Error + Send + Sync>> {
     state: Arc<RwLock<FutureState<T, E>>>,
     /// Handle to the running task (for cancellation)
     task_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+    /// Set by `retry()`; the hook re-runs the future on its next render
+    retry_requested: Arc<AtomicBool>,
+    /// Change counter of the hook context that owns this future
+    owner: Arc<AtomicU64>,
 }
 
 impl<T, E> FutureHandle<T, E>
@@ -164,10 +173,15 @@ where
     E: Clone,
 {
     /// Create a new future handle with initial pending state
-    fn new() -> Self {
+    ///
+    /// State changes are also counted in `owner`, the change counter of the
+    /// hook context the future belongs to.
+    pub(crate) fn new(owner: Arc<AtomicU64>) -> Self {
         Self {
             state: Arc::new(RwLock::new(FutureState::Pending)),
             task_handle: Arc::new(Mutex::new(None)),
+            retry_requested: Arc::new(AtomicBool::new(false)),
+            owner,
         }
     }
 
@@ -265,10 +279,26 @@ where
         }
     }
 
+    /// Run the future again on the next render
+    ///
+    /// Typically used to retry a failed future. The hook cancels the running
+    /// task (if any), resets the state to pending and calls the future
+    /// factory again, as if its dependencies had changed.
+    pub fn retry(&self) {
+        self.retry_requested.store(true, Ordering::SeqCst);
+        crate::hooks::batch::request_render();
+    }
+
+    /// Returns true once after `retry()` was called
+    fn take_retry_request(&self) -> bool {
+        self.retry_requested.swap(false, Ordering::SeqCst)
+    }
+
     /// Internal method to update the state
     /// Uses write lock for state mutations
     fn set_state(&self, new_state: FutureState<T, E>) {
         *self.state.write() = new_state;
+        self.owner.fetch_add(1, Ordering::SeqCst);
     }
 
     /// Update the progress of the future (0.0 to 1.0)
@@ -292,18 +322,25 @@ impl<T, E> Clone for FutureHandle<T, E> {
         Self {
             state: self.state.clone(),
             task_handle: self.task_handle.clone(),
+            retry_requested: self.retry_requested.clone(),
+            owner: self.owner.clone(),
         }
     }
 }
 
 /// Enhanced Resource Management: Automatic cleanup on drop
 ///
-/// This ensures that futures are properly cancelled when the handle
-/// is dropped, preventing resource leaks and zombie tasks.
+/// This ensures that futures are properly cancelled when the last handle
+/// is dropped, preventing resource leaks and zombie tasks. Dropping one of
+/// several clones leaves the task running: components get a clone on every
+/// render and drop it afterwards, while the hook keeps its own.
 impl<T, E> Drop for FutureHandle<T, E> {
     fn drop(&mut self) {
-        // Ensure cleanup on drop - cancel any running task
+        // Other clones still share the task
         // We directly access the task_handle to avoid trait bound issues
+        if Arc::strong_count(&self.task_handle) > 1 {
+            return;
+        }
         if let Some(task_handle) = self.task_handle.lock().take() {
             task_handle.abort();
         }
@@ -327,10 +364,10 @@ where
     T: Clone,
     E: Clone,
 {
-    fn new() -> Self {
+    fn new(owner: Arc<AtomicU64>) -> Self {
         Self {
             prev_deps: None,
-            handle: FutureHandle::new(),
+            handle: FutureHandle::new(owner),
             initialized: false,
             active_futures: Arc::new(AtomicUsize::new(0)),
         }
@@ -358,11 +395,29 @@ impl<T, E> Drop for FutureHookState<T, E> {
 }
 
 /// Error type for future operations that don't return a Result
+///
+/// Converts into a [`ContextError`], so futures that describe their steps
+/// with [`ResultExt::context`](crate::error::ResultExt::context) can still
+/// propagate it with `?`.
 #[derive(Debug, Clone)]
 pub struct FutureError {
     message: String,
 }
 
+impl FutureError {
+    /// Create an error with the given message
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// The error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
 impl fmt::Display for FutureError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Future error: {}", self.message)
@@ -371,6 +426,12 @@ impl fmt::Display for FutureError {
 
 impl std::error::Error for FutureError {}
 
+impl From<FutureError> for ContextError {
+    fn from(error: FutureError) -> Self {
+        ContextError::msg(error.message)
+    }
+}
+
 /// React-style useFuture hook that provides async future management for components
 ///
 /// This function provides future-based async operations with React-like behavior:
@@ -424,6 +485,23 @@ impl std::error::Error for FutureError {}
 /// }
 /// ```
 ///
+/// ## Errors with Context
+/// ```rust,no_run
+/// use pulse_core::error::{ContextError, ResultExt};
+/// use pulse_core::hooks::future::use_future;
+///
+/// # async fn fetch_quotes() -> Result<Vec<f64>, std::io::Error> { Ok(vec![]) }
+/// let finance = use_future(|| async {
+///     let quotes = fetch_quotes().await.context("while fetching quotes")?;
+///     Ok::<_, ContextError>(quotes)
+/// }, ());
+///
+/// if let Some(error) = finance.error() {
+///     // "while fetching quotes: connection refused"
+///     println!("{}", error.context("while loading finance data"));
+/// }
+/// ```
+///
 /// ## Future with Manual Triggering
 /// ```rust,no_run
 /// use pulse_core::hooks::future::{use_future, FutureState};
@@ -471,41 +549,42 @@ where
     let deps = deps.into();
     with_hook_context(|ctx| {
         let hook_index = ctx.next_hook_index();
+        let owner = ctx.state_changes();
         let mut states = ctx.states.borrow_mut();
 
         // Get or create future state for this hook
         let future_state = states
-            .entry(hook_index)
-            .or_insert_with(|| Box::new(FutureHookState::<T, E>::new()))
+            .get_or_insert_with(hook_index, || Box::new(FutureHookState::<T, E>::new(owner)))
             .downcast_mut::<FutureHookState<T, E>>()
             .expect("Future state type mismatch");
 
         // Determine if future should run
-        let should_run = match &deps {
-            None => {
-                // No dependencies - run on every render (usually not recommended for futures)
-                true
-            }
-            Some(current_deps) => {
-                // Check if dependencies have changed
-                match &future_state.prev_deps {
-                    None => {
-                        // First run - always execute
-                        true
-                    }
-                    Some(prev_deps) => {
-                        // Optimized dependency comparison with hash-based fast path
-                        if prev_deps.deps_hash() != current_deps.deps_hash() {
-                            // Hash mismatch - dependencies definitely changed
+        let should_run = future_state.handle.take_retry_request()
+            || match &deps {
+                None => {
+                    // No dependencies - run on every render (usually not recommended for futures)
+                    true
+                }
+                Some(current_deps) => {
+                    // Check if dependencies have changed
+                    match &future_state.prev_deps {
+                        None => {
+                            // First run - always execute
                             true
-                        } else {
-                            // Hash match - might be collision, do detailed comparison
-                            !current_deps.deps_eq(prev_deps.as_ref())
+                        }
+                        Some(prev_deps) => {
+                            // Optimized dependency comparison with hash-based fast path
+                            if prev_deps.deps_hash() != current_deps.deps_hash() {
+                                // Hash mismatch - dependencies definitely changed
+                                true
+                            } else {
+                                // Hash match - might be collision, do detailed comparison
+                                !current_deps.deps_eq(prev_deps.as_ref())
+                            }
                         }
                     }
                 }
-            }
-        };
+            };
 
         if should_run {
             // Security Check: Prevent resource exhaustion attacks
@@ -556,9 +635,13 @@ where
             let handle_clone_for_error = future_state.handle.clone();
             let handle_clone_for_panic = future_state.handle.clone();
             let active_futures_clone = future_state.active_futures.clone();
+            let loading = LoadingGuard::new();
 
             // Spawn the future
-            let task_handle = tokio::spawn(async move {
+            // Queued on the task pool if too many hook tasks are running
+            let task_handle = spawn_limited(async move {
+                // Counts as loading until this task completes or is aborted
+                let _loading = loading;
                 let result = spawn_catch_panic(async move {
                     match future_factory().await {
                         Ok(value) => {
@@ -647,41 +730,42 @@ where
     let deps = deps.into();
     with_hook_context(|ctx| {
         let hook_index = ctx.next_hook_index();
+        let owner = ctx.state_changes();
         let mut states = ctx.states.borrow_mut();
 
         // Get or create the future state for this hook
         let future_state = states
-            .entry(hook_index)
-            .or_insert_with(|| Box::new(FutureHookState::<T, E>::new()))
+            .get_or_insert_with(hook_index, || Box::new(FutureHookState::<T, E>::new(owner)))
             .downcast_mut::<FutureHookState<T, E>>()
             .expect("Hook state type mismatch");
 
         // Determine if future should run
-        let should_run = match &deps {
-            None => {
-                // No dependencies - run on every render (usually not recommended for futures)
-                true
-            }
-            Some(current_deps) => {
-                // Check if dependencies have changed
-                match &future_state.prev_deps {
-                    None => {
-                        // First run - always execute
-                        true
-                    }
-                    Some(prev_deps) => {
-                        // Optimized dependency comparison with hash-based fast path
-                        if prev_deps.deps_hash() != current_deps.deps_hash() {
-                            // Hash mismatch - dependencies definitely changed
+        let should_run = future_state.handle.take_retry_request()
+            || match &deps {
+                None => {
+                    // No dependencies - run on every render (usually not recommended for futures)
+                    true
+                }
+                Some(current_deps) => {
+                    // Check if dependencies have changed
+                    match &future_state.prev_deps {
+                        None => {
+                            // First run - always execute
                             true
-                        } else {
-                            // Hash match - might be collision, do detailed comparison
-                            !current_deps.deps_eq(prev_deps.as_ref())
+                        }
+                        Some(prev_deps) => {
+                            // Optimized dependency comparison with hash-based fast path
+                            if prev_deps.deps_hash() != current_deps.deps_hash() {
+                                // Hash mismatch - dependencies definitely changed
+                                true
+                            } else {
+                                // Hash match - might be collision, do detailed comparison
+                                !current_deps.deps_eq(prev_deps.as_ref())
+                            }
                         }
                     }
                 }
-            }
-        };
+            };
 
         if should_run {
             // Security Check: Prevent resource exhaustion attacks
@@ -733,6 +817,7 @@ where
             let handle_clone_for_panic = future_state.handle.clone();
             let handle_clone_for_progress = future_state.handle.clone();
             let active_futures_clone = future_state.active_futures.clone();
+            let loading = LoadingGuard::new();
 
             // Create progress callback
             let progress_callback: ProgressCallback = Arc::new(move |progress| {
@@ -740,7 +825,10 @@ where
             });
 
             // Spawn the future with progress support
-            let task_handle = tokio::spawn(async move {
+            // Queued on the task pool if too many hook tasks are running
+            let task_handle = spawn_limited(async move {
+                // Counts as loading until this task completes or is aborted
+                let _loading = loading;
                 let result = spawn_catch_panic(async move {
                     match future_factory(progress_callback).await {
                         Ok(value) => {
@@ -370,6 +370,24 @@ async fn test_cancellation_cleanup() {
     .await;
 }
 
+/// Test that only dropping the last handle cancels the running task
+#[tokio::test]
+async fn test_dropping_last_handle_cancels_task() {
+    let handle = FutureHandle::<String, String>::new(Default::default());
+    let task = tokio::spawn(sleep(Duration::from_secs(10)));
+    let abort_handle = task.abort_handle();
+    *handle.task_handle.lock() = Some(task);
+
+    // A clone dropped after a render leaves the task running
+    drop(handle.clone());
+    sleep(Duration::from_millis(10)).await;
+    assert!(!abort_handle.is_finished());
+
+    drop(handle);
+    sleep(Duration::from_millis(10)).await;
+    assert!(abort_handle.is_finished());
+}
+
 /// Test performance of state access methods under concurrent load
 #[tokio::test]
 async fn test_concurrent_state_access_performance() {
@@ -419,7 +437,7 @@ fn test_state_access_performance_comparison() {
     use std::time::Instant;
 
     // Create a mock future handle with resolved state
-    let handle = FutureHandle::<String, String>::new();
+    let handle = FutureHandle::<String, String>::new(Default::default());
     handle.set_state(FutureState::Resolved("test_value".to_string()));
 
     let iterations = 10000;
@@ -646,3 +664,115 @@ async fn test_progress_clamping() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn test_loading_registry_tracks_concurrent_futures() {
+    let _scope = LoadingScope::start();
+
+    with_async_test_isolate(|| async {
+        assert_eq!(loading_count(), 0);
+        assert!(!use_any_loading());
+
+        let (fast, slow, failing) = with_async_component_id("LoadingComponent", |_| async {
+            let fast = use_future(
+                || async {
+                    sleep(Duration::from_millis(10)).await;
+                    Ok::<_, String>(1)
+                },
+                (),
+            );
+            let slow = use_future(
+                || async {
+                    sleep(Duration::from_millis(80)).await;
+                    Ok::<_, String>(2)
+                },
+                (),
+            );
+            let failing = use_future(
+                || async {
+                    sleep(Duration::from_millis(40)).await;
+                    Err::<i32, _>("boom".to_string())
+                },
+                (),
+            );
+            (fast, slow, failing)
+        })
+        .await;
+
+        assert_eq!(loading_count(), 3);
+        assert!(use_any_loading());
+
+        sleep(Duration::from_millis(25)).await;
+        assert!(fast.is_resolved());
+        assert_eq!(loading_count(), 2);
+
+        sleep(Duration::from_millis(30)).await;
+        assert!(failing.is_error());
+        assert_eq!(loading_count(), 1);
+        assert!(use_any_loading());
+
+        // Cancelling a future stops it from counting as loading
+        slow.cancel();
+        sleep(Duration::from_millis(5)).await;
+        assert_eq!(loading_count(), 0);
+        assert!(!use_any_loading());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_error_context_is_preserved() {
+    use crate::error::ResultExt;
+
+    async fn connect() -> Result<(), std::io::Error> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::ConnectionRefused,
+            "connection refused",
+        ))
+    }
+
+    async fn fetch_quotes() -> Result<Vec<f64>, ContextError> {
+        connect()
+            .await
+            .context("while connecting to the quote server")?;
+        Ok(vec![1.0])
+    }
+
+    // Plain future errors still propagate into a context chain
+    fn parse_quotes() -> Result<Vec<f64>, ContextError> {
+        Err(FutureError::new("no quotes"))?
+    }
+    assert_eq!(parse_quotes().unwrap_err().chain(), vec!["no quotes"]);
+
+    with_async_test_isolate(|| async {
+        let handle = with_async_component_id("FinanceComponent", |_| async {
+            use_future(
+                || async { fetch_quotes().await.context("while loading finance data") },
+                (),
+            )
+        })
+        .await;
+
+        sleep(Duration::from_millis(20)).await;
+
+        let error = handle.error().expect("future should fail");
+        assert_eq!(
+            error.to_string(),
+            "while loading finance data: while connecting to the quote server: connection refused"
+        );
+        assert_eq!(
+            error.chain(),
+            vec![
+                "while loading finance data",
+                "while connecting to the quote server",
+                "connection refused",
+            ]
+        );
+        let root = error.root_cause().downcast_ref::<std::io::Error>();
+        assert_eq!(
+            root.map(|error| error.kind()),
+            Some(std::io::ErrorKind::ConnectionRefused)
+        );
+    })
+    .await;
+}
@@ -0,0 +1,123 @@
+//! Heatmap Data Hook
+//!
+//! This module provides a `use_heatmap_data` hook collecting per-day values,
+//! such as completed habits or commits, and laying them out as a calendar
+//! grid for the [`Heatmap`](crate::widgets::Heatmap) widget: one row per
+//! weekday, Monday first, and one column per week, the current week last.
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Days, NaiveDate};
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Handle returned by [`use_heatmap_data`]
+#[derive(Clone)]
+pub struct HeatmapDataHandle {
+    values: StateHandle<BTreeMap<NaiveDate, u64>>,
+    setter: StateSetter<BTreeMap<NaiveDate, u64>>,
+    weeks: usize,
+}
+
+impl HeatmapDataHandle {
+    /// Add `amount` to the value of `date`
+    pub fn record(&self, date: NaiveDate, amount: u64) {
+        self.setter.update(move |values| {
+            let mut values = values.clone();
+            *values.entry(date).or_default() += amount;
+            values
+        });
+    }
+
+    /// Replace the value of `date`
+    pub fn set(&self, date: NaiveDate, value: u64) {
+        self.setter.update(move |values| {
+            let mut values = values.clone();
+            values.insert(date, value);
+            values
+        });
+    }
+
+    /// The value of `date`, zero if nothing was recorded
+    pub fn value(&self, date: NaiveDate) -> u64 {
+        self.values
+            .with(|values| values.get(&date).copied().unwrap_or(0))
+    }
+
+    /// Remove all values
+    pub fn clear(&self) {
+        self.setter.set(BTreeMap::new());
+    }
+
+    /// Number of weeks in the grid
+    pub fn weeks(&self) -> usize {
+        self.weeks
+    }
+
+    /// The grid of the weeks up to the one containing `today`
+    ///
+    /// Returns 7 rows, Monday first, of one value per week, oldest first.
+    /// Days after `today` are zero.
+    pub fn grid(&self, today: NaiveDate) -> Vec<Vec<u64>> {
+        let monday = today - Days::new(today.weekday().num_days_from_monday() as u64);
+        let start = monday - Days::new(7 * (self.weeks as u64 - 1));
+
+        self.values.with(|values| {
+            (0..7)
+                .map(|weekday| {
+                    (0..self.weeks)
+                        .map(|week| {
+                            let date = start + Days::new((week * 7 + weekday) as u64);
+                            if date > today {
+                                0
+                            } else {
+                                values.get(&date).copied().unwrap_or(0)
+                            }
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+    }
+}
+
+/// Hook collecting daily values for a contributions-style heatmap
+///
+/// Record values by date and pass [`HeatmapDataHandle::grid`] to
+/// [`Heatmap`](crate::widgets::Heatmap) to show the last `weeks` weeks.
+///
+/// # Panics
+///
+/// Panics if `weeks` is zero.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use chrono::Local;
+/// # use pulse_core::hooks::heatmap::use_heatmap_data;
+/// # use pulse_core::widgets::Heatmap;
+/// # use ratatui::{Frame, layout::Rect};
+/// # fn render(area: Rect, frame: &mut Frame, completed_today: bool) {
+/// let habits = use_heatmap_data(26);
+/// let today = Local::now().date_naive();
+///
+/// if completed_today {
+///     habits.record(today, 1);
+/// }
+///
+/// frame.render_widget(Heatmap::new(habits.grid(today)), area);
+/// # }
+/// ```
+pub fn use_heatmap_data(weeks: usize) -> HeatmapDataHandle {
+    assert!(weeks > 0, "heatmap must show at least one week");
+    let (values, setter) = use_state(BTreeMap::new);
+
+    HeatmapDataHandle {
+        values,
+        setter,
+        weeks,
+    }
+}
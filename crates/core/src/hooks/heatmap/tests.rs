@@ -0,0 +1,50 @@
+//! Tests for the use_heatmap_data hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render(weeks: usize) -> HeatmapDataHandle {
+    with_component_id("HabitHeatmap", |_| use_heatmap_data(weeks))
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn test_values_accumulate_per_day() {
+    with_test_isolate(|| {
+        let data = render(4);
+        data.record(ymd(2025, 3, 5), 2);
+        data.record(ymd(2025, 3, 5), 3);
+        data.record(ymd(2025, 3, 6), 1);
+        assert_eq!(data.value(ymd(2025, 3, 5)), 5);
+        assert_eq!(data.value(ymd(2025, 3, 7)), 0);
+
+        data.set(ymd(2025, 3, 6), 9);
+        assert_eq!(render(4).value(ymd(2025, 3, 6)), 9);
+
+        data.clear();
+        assert_eq!(data.value(ymd(2025, 3, 5)), 0);
+    });
+}
+
+#[test]
+fn test_grid_ends_with_the_current_week() {
+    with_test_isolate(|| {
+        let data = render(3);
+        // Wednesday 2025-03-12 is today; the grid starts Monday 2025-02-24
+        data.record(ymd(2025, 2, 24), 1);
+        data.record(ymd(2025, 3, 2), 2);
+        data.record(ymd(2025, 3, 12), 3);
+        data.record(ymd(2025, 2, 23), 7);
+        data.record(ymd(2025, 3, 13), 7);
+
+        let grid = data.grid(ymd(2025, 3, 12));
+        assert_eq!(grid.len(), 7);
+        assert_eq!(grid[0], [1, 0, 0]);
+        assert_eq!(grid[2], [0, 0, 3]);
+        assert_eq!(grid[3], [0, 0, 0]);
+        assert_eq!(grid[6], [2, 0, 0]);
+    });
+}
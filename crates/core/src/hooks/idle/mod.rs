@@ -12,7 +12,11 @@ use std::time::{Duration, Instant};
 use crossterm::event::{Event, KeyEventKind, MouseEventKind};
 
 use crate::hooks::{
-    effect::use_effect, event::use_event, interval::use_interval, state::use_state,
+    batch::{Priority, schedule_render},
+    effect::use_effect,
+    event::use_event,
+    interval::use_interval,
+    state::use_state,
 };
 
 #[cfg(test)]
@@ -261,6 +265,8 @@ pub fn use_idle_timing(timeout_ms: u64) -> (Duration, Duration) {
     let remaining = if elapsed >= timeout_duration {
         Duration::ZERO
     } else {
+        // Keep the countdown moving without input
+        schedule_render(Priority::Low);
         timeout_duration - elapsed
     };
 
@@ -7,6 +7,11 @@
 //! ## Key Features:
 //! - **Synchronous intervals**: `use_interval` for simple periodic callbacks
 //! - **Asynchronous intervals**: `use_async_interval` for async periodic operations
+//! - **Jittered intervals**: `use_interval_jittered` spreads ticks around a base period
+//! - **Controllable intervals**: `use_interval_async` returns a handle to pause,
+//!   resume, cancel or re-time the interval
+//! - **Frame-driven intervals**: `use_frame_interval` fires from the render loop
+//!   itself, without any thread or task
 //! - Automatic cleanup when component unmounts or dependencies change
 //! - Proper async/await integration with tokio runtime
 //! - Thread-safe execution with proper error handling
@@ -235,6 +240,7 @@ where
     Fut: std::future::Future<Output = ()> + Send + 'static,
 {
     use crate::hooks::effect::use_effect;
+    use crate::tasks::task_pool;
 
     // Use effect to manage the async interval lifecycle with proper cleanup
     use_effect(
@@ -261,7 +267,9 @@ where
 
                 loop {
                     interval_timer.tick().await;
-                    // Execute the async callback and wait for completion
+                    // Execute the async callback and wait for completion,
+                    // holding a task pool permit only while it runs
+                    let _permit = task_pool().acquire().await;
                     callback().await;
                 }
             });
@@ -274,3 +282,326 @@ where
         duration, // Effect depends on duration - restarts when duration changes
     );
 }
+
+/// Shared state between an [`AsyncIntervalHandle`] and its task
+#[derive(Debug)]
+struct IntervalControl {
+    period: parking_lot::Mutex<Duration>,
+    paused: std::sync::atomic::AtomicBool,
+    cancelled: std::sync::atomic::AtomicBool,
+    /// Wakes the task so it picks up a pause, resume or period change
+    changed: tokio::sync::Notify,
+}
+
+impl IntervalControl {
+    fn new(period: Duration) -> Self {
+        Self {
+            period: parking_lot::Mutex::new(period),
+            paused: std::sync::atomic::AtomicBool::new(false),
+            cancelled: std::sync::atomic::AtomicBool::new(false),
+            changed: tokio::sync::Notify::new(),
+        }
+    }
+
+    fn period(&self) -> Duration {
+        let period = *self.period.lock();
+        if period.is_zero() {
+            Duration::from_millis(1)
+        } else {
+            period
+        }
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused.load(std::sync::atomic::Ordering::Acquire)
+    }
+
+    fn notify(&self) {
+        // notify_one keeps a permit if the task isn't waiting right now
+        self.changed.notify_one();
+    }
+}
+
+/// Handle returned by [`use_interval_async`]
+#[derive(Debug, Clone)]
+pub struct AsyncIntervalHandle {
+    control: std::sync::Arc<IntervalControl>,
+    task: crate::hooks::spawn::SpawnHandle,
+}
+
+impl AsyncIntervalHandle {
+    /// Stop invoking the callback until [`resume`](Self::resume) is called
+    ///
+    /// A callback that is already running completes.
+    pub fn pause(&self) {
+        self.control
+            .paused
+            .store(true, std::sync::atomic::Ordering::Release);
+        self.control.notify();
+    }
+
+    /// Resume a paused interval; the next tick is one period from now
+    pub fn resume(&self) {
+        self.control
+            .paused
+            .store(false, std::sync::atomic::Ordering::Release);
+        self.control.notify();
+    }
+
+    /// Returns true while the interval is paused
+    pub fn is_paused(&self) -> bool {
+        self.control.is_paused()
+    }
+
+    /// Stop the interval for good by aborting its task
+    ///
+    /// Unlike [`pause`](Self::pause), a cancelled interval can't be resumed.
+    pub fn cancel(&self) {
+        self.control
+            .cancelled
+            .store(true, std::sync::atomic::Ordering::Release);
+        self.task.abort();
+    }
+
+    /// Returns true once the interval was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.control
+            .cancelled
+            .load(std::sync::atomic::Ordering::Acquire)
+    }
+
+    /// Returns true while the interval's task is alive
+    ///
+    /// The task stops when the interval is cancelled or the component
+    /// unmounts.
+    pub fn is_running(&self) -> bool {
+        self.task.is_running()
+    }
+
+    /// The time between two invocations
+    pub fn period(&self) -> Duration {
+        *self.control.period.lock()
+    }
+
+    /// Change the time between two invocations
+    ///
+    /// The next tick is one new period from now.
+    pub fn set_period(&self, period: Duration) {
+        *self.control.period.lock() = period;
+        self.control.notify();
+    }
+}
+
+/// Asynchronous interval hook returning a handle to control the interval
+///
+/// Like [`use_async_interval`], but the returned [`AsyncIntervalHandle`] can
+/// pause, resume or cancel the interval and change its period. The interval
+/// task is aborted when the component unmounts. Passing a different `period`
+/// on a later render changes the period like
+/// [`set_period`](AsyncIntervalHandle::set_period). Must be used within a
+/// tokio runtime.
+///
+/// ## Example:
+/// ```rust,no_run
+/// use pulse_core::hooks::interval::use_interval_async;
+/// use pulse_core::hooks::state::use_state;
+/// use std::time::Duration;
+///
+/// let (quotes, set_quotes) = use_state(Vec::<f64>::new);
+/// let refresh = use_interval_async(
+///     move || {
+///         let set_quotes = set_quotes.clone();
+///         async move {
+///             // Fetch the latest quotes
+///             set_quotes.update(|quotes| quotes.clone());
+///         }
+///     },
+///     Duration::from_secs(5),
+/// );
+///
+/// // e.g. on a "pause" key:
+/// refresh.pause();
+/// ```
+pub fn use_interval_async<F, Fut>(callback: F, period: Duration) -> AsyncIntervalHandle
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: std::future::Future<Output = ()> + Send + 'static,
+{
+    use crate::hooks::spawn::use_spawn;
+    use crate::hooks::with_hook_context;
+    use crate::tasks::task_pool;
+    use std::sync::Arc;
+
+    let (control, period_changed) = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let state =
+            ctx.get_or_init_state(index, || (Arc::new(IntervalControl::new(period)), period));
+        let mut state = state.borrow_mut();
+        let period_changed = state.1 != period;
+        state.1 = period;
+        (state.0.clone(), period_changed)
+    });
+
+    let task_control = control.clone();
+    let task = use_spawn(
+        move || async move {
+            let control = task_control;
+            loop {
+                if control.is_paused() {
+                    control.changed.notified().await;
+                    continue;
+                }
+
+                tokio::select! {
+                    _ = tokio::time::sleep(control.period()) => {}
+                    // Start over with the new settings
+                    _ = control.changed.notified() => continue,
+                }
+
+                let _permit = task_pool().acquire().await;
+                callback().await;
+            }
+        },
+        (),
+    );
+
+    let handle = AsyncIntervalHandle { control, task };
+    if period_changed {
+        handle.set_period(period);
+    }
+    handle
+}
+
+/// Pick a delay uniformly from `base ± jitter`, at least one millisecond
+fn jittered_delay<R: rand::Rng + ?Sized>(
+    base: Duration,
+    jitter: Duration,
+    rng: &mut R,
+) -> Duration {
+    let jitter = jitter.min(base);
+    let low = base - jitter;
+    let high = base + jitter;
+    let delay = if low == high {
+        base
+    } else {
+        rng.random_range(low..=high)
+    };
+    delay.max(Duration::from_millis(1))
+}
+
+/// Synchronous interval hook whose ticks are randomly spread around `base`
+///
+/// Each delay between two invocations is drawn uniformly from
+/// `base - jitter ..= base + jitter` (a `jitter` larger than `base` is
+/// capped at `base`). When many components poll with the same period, this
+/// keeps them from all firing in the same frame and causing render spikes.
+/// Otherwise behaves like [`use_interval`].
+///
+/// ## Example:
+/// ```rust,no_run
+/// use pulse_core::hooks::interval::use_interval_jittered;
+/// use pulse_core::hooks::state::use_state;
+/// use std::time::Duration;
+///
+/// let (_load, set_load) = use_state(|| 0.0f64);
+/// // Refresh roughly every second, somewhere between 0.8s and 1.2s
+/// use_interval_jittered(
+///     move || set_load.set(0.42),
+///     Duration::from_secs(1),
+///     Duration::from_millis(200),
+/// );
+/// ```
+pub fn use_interval_jittered<F>(callback: F, base: Duration, jitter: Duration)
+where
+    F: Fn() + Send + 'static,
+{
+    use crate::hooks::effect::use_effect;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+
+    use_effect(
+        move || {
+            let should_stop = Arc::new(AtomicBool::new(false));
+            let should_stop_clone = should_stop.clone();
+
+            thread::spawn(move || {
+                let mut rng = rand::rng();
+                while !should_stop_clone.load(Ordering::Relaxed) {
+                    thread::sleep(jittered_delay(base, jitter, &mut rng));
+                    if !should_stop_clone.load(Ordering::Relaxed) {
+                        callback();
+                    }
+                }
+            });
+
+            Some(Box::new(move || {
+                should_stop.store(true, Ordering::Relaxed);
+            }) as Box<dyn FnOnce() + Send>)
+        },
+        (base, jitter), // Restart when the timing changes
+    );
+}
+
+/// Interval hook driven by the render loop instead of a thread or task
+///
+/// Every render compares the [`clock`](crate::clock) against the next due
+/// time and calls `callback` during the render once `period` has elapsed, so
+/// the callback runs on the render thread in step with the frames and can be
+/// tested with a [`ManualClock`](crate::clock::ManualClock). Ticks are only
+/// as precise as the frame rate: a frame arriving after several periods
+/// fires once and schedules the next tick a full period later. Changing
+/// `period` restarts the interval. Every render requests a low-priority
+/// render, so the interval keeps ticking without input.
+///
+/// ## Example:
+/// ```rust,no_run
+/// use pulse_core::hooks::interval::use_frame_interval;
+/// use pulse_core::hooks::state::use_state;
+/// use std::time::Duration;
+///
+/// let (ticks, set_ticks) = use_state(|| 0u32);
+/// use_frame_interval(
+///     || set_ticks.update(|ticks| ticks + 1),
+///     Duration::from_millis(500),
+/// );
+/// ```
+pub fn use_frame_interval<F>(callback: F, period: Duration)
+where
+    F: FnOnce(),
+{
+    use crate::clock;
+    use crate::hooks::batch::{Priority, schedule_render};
+    use crate::hooks::with_hook_context;
+    use std::time::Instant;
+
+    let now = clock::now();
+    let schedule = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        ctx.get_or_init_state(index, || (period, now + period))
+    });
+
+    let fire = {
+        let mut schedule = schedule.borrow_mut();
+        let (scheduled_period, due): &mut (Duration, Instant) = &mut schedule;
+        if *scheduled_period != period {
+            *scheduled_period = period;
+            *due = now + period;
+            false
+        } else if now >= *due {
+            // Skip ticks missed between slow frames rather than bursting
+            *due = if now - *due >= period {
+                now + period
+            } else {
+                *due + period
+            };
+            true
+        } else {
+            false
+        }
+    };
+    if fire {
+        callback();
+    }
+    schedule_render(Priority::Low);
+}
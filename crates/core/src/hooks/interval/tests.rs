@@ -497,3 +497,276 @@ async fn test_mixed_sync_and_async_intervals() {
     })
     .await;
 }
+
+fn counting_interval(counter: &Arc<AtomicUsize>, period: Duration) -> AsyncIntervalHandle {
+    let counter = counter.clone();
+    use_interval_async(
+        move || {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        },
+        period,
+    )
+}
+
+/// Test that cancelling an async interval stops further invocations
+#[tokio::test]
+async fn test_use_interval_async_cancel() {
+    with_test_isolate(|| async {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let handle = with_component_id("CancelIntervalComponent", |_| {
+            counting_interval(&counter, Duration::from_millis(10))
+        });
+
+        sleep(Duration::from_millis(45)).await;
+        assert!(counter.load(Ordering::SeqCst) >= 2);
+        assert!(handle.is_running());
+
+        handle.cancel();
+        sleep(Duration::from_millis(5)).await;
+        let after_cancel = counter.load(Ordering::SeqCst);
+        assert!(handle.is_cancelled());
+        assert!(!handle.is_running());
+
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(counter.load(Ordering::SeqCst), after_cancel);
+
+        // Re-rendering doesn't bring a cancelled interval back
+        with_component_id("CancelIntervalComponent", |_| {
+            counting_interval(&counter, Duration::from_millis(10))
+        });
+        sleep(Duration::from_millis(30)).await;
+        assert_eq!(counter.load(Ordering::SeqCst), after_cancel);
+    })
+    .await;
+}
+
+/// Test pausing, resuming and changing the period of an async interval
+#[tokio::test]
+async fn test_use_interval_async_pause_resume_and_period() {
+    with_test_isolate(|| async {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let handle = with_component_id("PauseIntervalComponent", |_| {
+            counting_interval(&counter, Duration::from_millis(10))
+        });
+
+        handle.pause();
+        assert!(handle.is_paused());
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+
+        handle.resume();
+        sleep(Duration::from_millis(45)).await;
+        assert!(counter.load(Ordering::SeqCst) >= 2);
+
+        handle.set_period(Duration::from_secs(10));
+        assert_eq!(handle.period(), Duration::from_secs(10));
+        sleep(Duration::from_millis(5)).await;
+        let slowed = counter.load(Ordering::SeqCst);
+        sleep(Duration::from_millis(40)).await;
+        assert_eq!(counter.load(Ordering::SeqCst), slowed);
+
+        // Rendering with the same period keeps the one that was set
+        let handle = with_component_id("PauseIntervalComponent", |_| {
+            counting_interval(&counter, Duration::from_millis(10))
+        });
+        assert_eq!(handle.period(), Duration::from_secs(10));
+
+        // A new period passed on render takes effect
+        let handle = with_component_id("PauseIntervalComponent", |_| {
+            counting_interval(&counter, Duration::from_millis(12))
+        });
+        assert_eq!(handle.period(), Duration::from_millis(12));
+        sleep(Duration::from_millis(45)).await;
+        assert!(counter.load(Ordering::SeqCst) >= slowed + 2);
+    })
+    .await;
+}
+
+#[derive(Clone)]
+struct IntervalOwner {
+    counter: Arc<AtomicUsize>,
+    handle: Arc<parking_lot::Mutex<Option<AsyncIntervalHandle>>>,
+}
+
+impl crate::Component for IntervalOwner {
+    fn render(&self, _area: ratatui::layout::Rect, _frame: &mut ratatui::Frame) {
+        let handle = counting_interval(&self.counter, Duration::from_millis(10));
+        *self.handle.lock() = Some(handle);
+    }
+}
+
+/// Test that unmounting the component aborts the interval task
+#[tokio::test]
+async fn test_use_interval_async_aborted_on_unmount() {
+    use crate::Component;
+    use crate::component::cleanup_unmounted;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    with_test_isolate(|| async {
+        let owner = IntervalOwner {
+            counter: Arc::new(AtomicUsize::new(0)),
+            handle: Arc::new(parking_lot::Mutex::new(None)),
+        };
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+
+        with_component_id("IntervalOwnerRoot", |_| {
+            terminal
+                .draw(|frame| owner.render_with_mount(frame.area(), frame))
+                .unwrap();
+        });
+        cleanup_unmounted();
+        sleep(Duration::from_millis(35)).await;
+        let handle = owner.handle.lock().clone().unwrap();
+        assert!(handle.is_running());
+        assert!(owner.counter.load(Ordering::SeqCst) >= 1);
+
+        // A frame without the owner unmounts it
+        cleanup_unmounted();
+        sleep(Duration::from_millis(5)).await;
+        assert!(!handle.is_running());
+        let after_unmount = owner.counter.load(Ordering::SeqCst);
+        sleep(Duration::from_millis(30)).await;
+        assert_eq!(owner.counter.load(Ordering::SeqCst), after_unmount);
+    })
+    .await;
+}
+
+/// Test that jittered delays stay within `base ± jitter`
+#[test]
+fn test_jittered_delay_bounds() {
+    let mut rng = rand::rng();
+    let base = Duration::from_millis(100);
+    let jitter = Duration::from_millis(30);
+
+    let delays: Vec<_> = (0..1000)
+        .map(|_| jittered_delay(base, jitter, &mut rng))
+        .collect();
+    assert!(delays.iter().all(|delay| {
+        *delay >= Duration::from_millis(70) && *delay <= Duration::from_millis(130)
+    }));
+    // The delays are actually spread out
+    assert!(
+        delays
+            .iter()
+            .any(|delay| *delay < Duration::from_millis(90))
+    );
+    assert!(
+        delays
+            .iter()
+            .any(|delay| *delay > Duration::from_millis(110))
+    );
+
+    assert_eq!(
+        jittered_delay(base, Duration::ZERO, &mut rng),
+        Duration::from_millis(100)
+    );
+    // Jitter larger than the base never produces a zero delay
+    let delay = jittered_delay(Duration::from_millis(5), Duration::from_secs(1), &mut rng);
+    assert!(delay >= Duration::from_millis(1) && delay <= Duration::from_millis(10));
+}
+
+/// Test that jittered interval ticks fall within the jitter bounds
+#[tokio::test]
+async fn test_use_interval_jittered_ticks() {
+    with_test_isolate(|| async {
+        let ticks = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let started = std::time::Instant::now();
+
+        with_component_id("JitteredIntervalComponent", |_| {
+            let ticks = ticks.clone();
+            use_interval_jittered(
+                move || ticks.lock().push(std::time::Instant::now()),
+                Duration::from_millis(20),
+                Duration::from_millis(10),
+            );
+        });
+
+        sleep(Duration::from_millis(150)).await;
+        let ticks = ticks.lock().clone();
+        assert!(
+            ticks.len() >= 3,
+            "Expected at least 3 ticks, got {}",
+            ticks.len()
+        );
+
+        let mut previous = started;
+        for tick in ticks {
+            let interval = tick - previous;
+            // Sleeping never returns early; allow for scheduling delays
+            assert!(interval >= Duration::from_millis(10), "{interval:?}");
+            assert!(interval <= Duration::from_millis(30 + 25), "{interval:?}");
+            previous = tick;
+        }
+    })
+    .await;
+}
+
+/// Render a component with a frame interval, returning whether it fired
+fn render_frame_interval(period: Duration) -> bool {
+    with_component_id("FrameIntervalComponent", |_| {
+        let mut fired = false;
+        use_frame_interval(|| fired = true, period);
+        fired
+    })
+}
+
+/// Test that a frame interval fires on the first frame after each period
+#[test]
+fn test_use_frame_interval_fires_on_due_frame() {
+    use crate::clock::ManualClock;
+
+    with_test_isolate(|| {
+        let clock = ManualClock::start();
+        let period = Duration::from_millis(100);
+
+        // Frames every 16ms: 112ms is the first frame at or past 100ms, and
+        // the next tick is due at 200ms, first reached at 208ms
+        let mut fired_frames = Vec::new();
+        for frame in 0..=13 {
+            if render_frame_interval(period) {
+                fired_frames.push(frame);
+            }
+            clock.advance(Duration::from_millis(16));
+        }
+        assert_eq!(fired_frames, vec![7, 13]);
+    });
+}
+
+/// Test that a long pause fires once instead of catching up on missed ticks
+#[test]
+fn test_use_frame_interval_skips_missed_ticks() {
+    use crate::clock::ManualClock;
+
+    with_test_isolate(|| {
+        let clock = ManualClock::start();
+        let period = Duration::from_millis(100);
+
+        assert!(!render_frame_interval(period));
+        clock.advance(Duration::from_millis(550));
+        assert!(render_frame_interval(period));
+        assert!(!render_frame_interval(period));
+        clock.advance(Duration::from_millis(99));
+        assert!(!render_frame_interval(period));
+        clock.advance(Duration::from_millis(1));
+        assert!(render_frame_interval(period));
+    });
+}
+
+/// Test that changing the period restarts a frame interval
+#[test]
+fn test_use_frame_interval_restarts_on_period_change() {
+    use crate::clock::ManualClock;
+
+    with_test_isolate(|| {
+        let clock = ManualClock::start();
+
+        assert!(!render_frame_interval(Duration::from_millis(100)));
+        clock.advance(Duration::from_millis(100));
+        assert!(!render_frame_interval(Duration::from_millis(50)));
+        clock.advance(Duration::from_millis(50));
+        assert!(render_frame_interval(Duration::from_millis(50)));
+    });
+}
@@ -0,0 +1,85 @@
+//! Latest Value Hook
+//!
+//! This module provides a `use_latest` hook that solves stale closures:
+//! callbacks created once, e.g. by `use_callback_once`, capture the values of
+//! the render they were created in. A [`Latest`] ref captured instead is
+//! updated on every render, so long-lived callbacks and tasks read the
+//! current value without being recreated.
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::hooks::with_hook_context;
+
+#[cfg(test)]
+mod tests;
+
+/// A ref that holds the value passed to [`use_latest`] in the latest render
+///
+/// The ref is shared between renders and thread-safe, so it can be captured
+/// by callbacks and spawned tasks.
+pub struct Latest<T> {
+    value: Arc<RwLock<T>>,
+}
+
+impl<T> Clone for Latest<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<T> Latest<T> {
+    /// Get a clone of the latest value
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.value.read().clone()
+    }
+
+    /// Read the latest value without cloning it
+    pub fn with<R>(&self, reader: impl FnOnce(&T) -> R) -> R {
+        reader(&self.value.read())
+    }
+}
+
+/// Hook that keeps a ref up to date with the latest `value`
+///
+/// Every call returns the same ref, updated to `value`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::callback::use_callback_once;
+/// # use pulse_core::hooks::latest::use_latest;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (query, _set_query) = use_state(String::new);
+/// let latest_query = use_latest(query.get());
+///
+/// // Created once, but always submits the query of the latest render
+/// let submit = use_callback_once(move |_: ()| {
+///     println!("Searching for {}", latest_query.get());
+/// });
+/// ```
+pub fn use_latest<T>(value: T) -> Latest<T>
+where
+    T: Send + Sync + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let mut value = Some(value);
+        let latest = ctx.get_or_init_state(index, || Latest {
+            value: Arc::new(RwLock::new(value.take().expect("value is unused"))),
+        });
+        let latest = latest.borrow().clone();
+
+        if let Some(value) = value {
+            *latest.value.write() = value;
+        }
+        latest
+    })
+}
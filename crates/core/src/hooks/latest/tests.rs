@@ -0,0 +1,41 @@
+//! Tests for the use_latest hook
+
+use super::*;
+use crate::hooks::callback::{MemoizedCallback, use_callback_once};
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render(value: u32) -> (Latest<u32>, MemoizedCallback<(), u32>) {
+    with_component_id("LatestComponent", |_| {
+        let latest = use_latest(value);
+        let read = {
+            let latest = latest.clone();
+            use_callback_once(move |_: ()| latest.get())
+        };
+        (latest, read)
+    })
+}
+
+#[test]
+fn test_latest_follows_renders() {
+    with_test_isolate(|| {
+        let (latest, _) = render(1);
+        assert_eq!(latest.get(), 1);
+
+        render(2);
+        assert_eq!(latest.get(), 2);
+        assert_eq!(latest.with(|value| value * 10), 20);
+    });
+}
+
+#[test]
+fn test_once_callback_reads_latest_value() {
+    with_test_isolate(|| {
+        let (_, first_callback) = render(1);
+        assert_eq!(first_callback.emit(()), 1);
+
+        // The callback was created in the first render but sees the new value
+        let (_, callback) = render(5);
+        assert_eq!(callback.emit(()), 5);
+        assert_eq!(first_callback.emit(()), 5);
+    });
+}
@@ -0,0 +1,90 @@
+//! Layout Hook
+//!
+//! This module provides a `use_layout` hook that memoizes `Layout::split`
+//! results per call site. Static layouts are split once and the cached areas
+//! are reused on every following frame until the constraints, direction or
+//! area change (e.g. when the terminal is resized).
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+
+use crate::hooks::with_hook_context;
+
+#[cfg(test)]
+mod tests;
+
+thread_local! {
+    /// Number of layout splits actually computed on this thread
+    static SPLIT_COUNT: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Cached split result for a single hook
+struct LayoutCache {
+    constraints: Vec<Constraint>,
+    direction: Direction,
+    area: Rect,
+    rects: Rc<[Rect]>,
+}
+
+/// Hook that splits `area` according to `constraints`, caching the result
+///
+/// The split is recomputed only when the constraints, the direction or the
+/// area differ from the previous render of this call site.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::layout::use_layout;
+/// # use ratatui::layout::{Constraint, Direction, Rect};
+/// # let area = Rect::new(0, 0, 80, 24);
+/// // In a component's render method:
+/// let chunks = use_layout(
+///     [Constraint::Length(3), Constraint::Min(0)],
+///     Direction::Vertical,
+///     area,
+/// );
+/// let (header, body) = (chunks[0], chunks[1]);
+/// ```
+pub fn use_layout(
+    constraints: impl AsRef<[Constraint]>,
+    direction: Direction,
+    area: Rect,
+) -> Rc<[Rect]> {
+    let constraints = constraints.as_ref();
+
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let cache = ctx.get_or_init_state(index, || None::<LayoutCache>);
+        let mut cache = cache.borrow_mut();
+
+        if let Some(cached) = cache.as_ref()
+            && cached.area == area
+            && cached.direction == direction
+            && cached.constraints == constraints
+        {
+            return cached.rects.clone();
+        }
+
+        SPLIT_COUNT.with(|count| count.set(count.get() + 1));
+        let rects = Layout::default()
+            .direction(direction)
+            .constraints(constraints)
+            .split(area);
+
+        *cache = Some(LayoutCache {
+            constraints: constraints.to_vec(),
+            direction,
+            area,
+            rects: rects.clone(),
+        });
+        rects
+    })
+}
+
+/// Number of layout splits computed by `use_layout` on the current thread
+#[cfg(test)]
+fn split_count() -> u64 {
+    SPLIT_COUNT.with(|count| count.get())
+}
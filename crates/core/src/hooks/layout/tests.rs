@@ -0,0 +1,99 @@
+//! Tests for the use_layout hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render(constraints: &[Constraint], direction: Direction, area: Rect) -> Rc<[Rect]> {
+    with_component_id("LayoutComponent", |_| {
+        use_layout(constraints, direction, area)
+    })
+}
+
+#[test]
+fn test_use_layout_matches_layout_split() {
+    with_test_isolate(|| {
+        let area = Rect::new(0, 0, 80, 24);
+        let constraints = [Constraint::Length(3), Constraint::Min(0)];
+
+        let rects = render(&constraints, Direction::Vertical, area);
+        let expected = Layout::vertical(constraints).split(area);
+        assert_eq!(rects, expected);
+    });
+}
+
+#[test]
+fn test_use_layout_is_not_recomputed_for_unchanged_inputs() {
+    with_test_isolate(|| {
+        let area = Rect::new(0, 0, 120, 40);
+        let constraints = [
+            Constraint::Percentage(30),
+            Constraint::Fill(1),
+            Constraint::Length(10),
+        ];
+
+        let before = split_count();
+        let first = render(&constraints, Direction::Horizontal, area);
+
+        for _ in 0..1000 {
+            let rects = render(&constraints, Direction::Horizontal, area);
+            assert!(Rc::ptr_eq(&rects, &first));
+        }
+
+        assert_eq!(split_count() - before, 1);
+    });
+}
+
+#[test]
+fn test_use_layout_recomputes_when_inputs_change() {
+    with_test_isolate(|| {
+        let constraints = [Constraint::Length(1), Constraint::Min(0)];
+        let before = split_count();
+
+        let small = render(&constraints, Direction::Vertical, Rect::new(0, 0, 10, 10));
+        let resized = render(&constraints, Direction::Vertical, Rect::new(0, 0, 20, 20));
+        assert_eq!(resized[1].height, 19);
+        assert_ne!(small, resized);
+
+        let flipped = render(&constraints, Direction::Horizontal, Rect::new(0, 0, 20, 20));
+        assert_eq!(flipped[0].width, 1);
+
+        let changed = render(
+            &[Constraint::Length(2), Constraint::Min(0)],
+            Direction::Horizontal,
+            Rect::new(0, 0, 20, 20),
+        );
+        assert_eq!(changed[0].width, 2);
+
+        assert_eq!(split_count() - before, 4);
+    });
+}
+
+#[test]
+fn test_use_layout_caches_per_call_site() {
+    with_test_isolate(|| {
+        let area = Rect::new(0, 0, 40, 10);
+        let render_two = || {
+            with_component_id("TwoLayoutsComponent", |_| {
+                let rows = use_layout(
+                    [Constraint::Length(1), Constraint::Min(0)],
+                    Direction::Vertical,
+                    area,
+                );
+                let cols = use_layout(
+                    [Constraint::Length(5), Constraint::Min(0)],
+                    Direction::Horizontal,
+                    area,
+                );
+                (rows, cols)
+            })
+        };
+
+        let before = split_count();
+        let (rows, cols) = render_two();
+        let (rows_again, cols_again) = render_two();
+
+        assert!(Rc::ptr_eq(&rows, &rows_again));
+        assert!(Rc::ptr_eq(&cols, &cols_again));
+        assert_eq!(split_count() - before, 2);
+    });
+}
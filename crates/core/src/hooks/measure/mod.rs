@@ -0,0 +1,139 @@
+//! Text Measurement Hook
+//!
+//! This module provides a `use_measure` hook that reports how much space a
+//! piece of text occupies once word-wrapped to a given width, so layouts can
+//! allocate exactly the rows a paragraph needs instead of guessing
+//! `Constraint::Length` values.
+//!
+//! Measurement uses ratatui's own paragraph wrapping, so the result matches
+//! what `Paragraph::new(text).wrap(Wrap { trim: false })` renders, including
+//! wide (e.g. CJK) characters that occupy two columns.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span, Text},
+    widgets::{Paragraph, Widget, Wrap},
+};
+
+use crate::hooks::with_hook_context;
+
+#[cfg(test)]
+mod tests;
+
+/// Measure the size of `content` when wrapped to `width` columns
+///
+/// Returns `(width, height)`: the widest wrapped line in columns and the
+/// number of wrapped lines. A zero `width` measures as `(0, 0)`.
+///
+/// # Example
+/// ```
+/// use pulse_core::hooks::measure::measure_text;
+/// use ratatui::text::Text;
+///
+/// let text = Text::from("hello wide world");
+/// assert_eq!(measure_text(&text, 80), (16, 1));
+/// assert_eq!(measure_text(&text, 10), (10, 2));
+/// ```
+pub fn measure_text(content: &Text, width: u16) -> (u16, u16) {
+    if width == 0 {
+        return (0, 0);
+    }
+
+    let paragraph = Paragraph::new(content.clone()).wrap(Wrap { trim: false });
+    let height = paragraph.line_count(width).min(u16::MAX as usize) as u16;
+    let natural_width = paragraph.line_width();
+
+    // Lines that fit need no wrapping, so their natural width is exact
+    if natural_width <= width as usize {
+        return (natural_width as u16, height);
+    }
+
+    // Otherwise render the wrapped text and find the widest row
+    let area = Rect::new(0, 0, width, height);
+    let mut buffer = Buffer::empty(area);
+    paragraph.render(area, &mut buffer);
+
+    let widest = (0..height)
+        .map(|y| {
+            (0..width)
+                .rev()
+                .find_map(|x| {
+                    let symbol = buffer[(x, y)].symbol();
+                    (!symbol.trim().is_empty()).then(|| x + Span::raw(symbol).width() as u16)
+                })
+                .unwrap_or(0)
+        })
+        .max()
+        .unwrap_or(0);
+
+    (widest.min(width), height)
+}
+
+/// Cached measurement for a single hook
+struct MeasureCache {
+    content: Text<'static>,
+    width: u16,
+    size: (u16, u16),
+}
+
+/// Hook that measures the rendered size of `content` at `width`
+///
+/// Returns `(width, height)` as computed by [`measure_text`]. The result is
+/// cached and only recomputed when the content or the width changes.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::measure::use_measure;
+/// # use ratatui::layout::{Constraint, Layout, Rect};
+/// # use ratatui::text::Text;
+/// # let area = Rect::new(0, 0, 40, 20);
+/// // In a component's render method:
+/// let help = Text::from("Press q to quit, arrows to move, enter to select.");
+/// let (_, help_height) = use_measure(&help, area.width);
+/// let [body, footer] =
+///     Layout::vertical([Constraint::Min(0), Constraint::Length(help_height)]).areas(area);
+/// ```
+pub fn use_measure(content: &Text, width: u16) -> (u16, u16) {
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let cache = ctx.get_or_init_state(index, || None::<MeasureCache>);
+        let mut cache = cache.borrow_mut();
+
+        if let Some(cached) = cache.as_ref()
+            && cached.width == width
+            && cached.content == *content
+        {
+            return cached.size;
+        }
+
+        let size = measure_text(content, width);
+        *cache = Some(MeasureCache {
+            content: to_owned_text(content),
+            width,
+            size,
+        });
+        size
+    })
+}
+
+/// Copy text into an owned `Text<'static>` so it can be cached
+fn to_owned_text(text: &Text) -> Text<'static> {
+    let lines = text.lines.iter().map(|line| {
+        let spans = line
+            .spans
+            .iter()
+            .map(|span| Span::styled(span.content.to_string(), span.style));
+        Line {
+            spans: spans.collect(),
+            style: line.style,
+            alignment: line.alignment,
+        }
+    });
+    Text {
+        lines: lines.collect(),
+        style: text.style,
+        alignment: text.alignment,
+    }
+}
@@ -0,0 +1,68 @@
+//! Tests for the use_measure hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use ratatui::style::{Style, Stylize};
+
+#[test]
+fn test_measure_single_line_fits() {
+    assert_eq!(measure_text(&Text::from("hello"), 20), (5, 1));
+    assert_eq!(measure_text(&Text::from(""), 20), (0, 1));
+    assert_eq!(measure_text(&Text::from("hello"), 0), (0, 0));
+}
+
+#[test]
+fn test_measure_multiple_lines() {
+    let text = Text::from("short\na longer line\nmid");
+    assert_eq!(measure_text(&text, 40), (13, 3));
+}
+
+#[test]
+fn test_measure_wraps_on_word_boundaries() {
+    let text = Text::from("the quick brown fox jumps");
+    // "the quick " / "brown fox " / "jumps"
+    assert_eq!(measure_text(&text, 10), (9, 3));
+    assert_eq!(measure_text(&text, 15), (15, 2));
+}
+
+#[test]
+fn test_measure_breaks_long_words() {
+    let text = Text::from("abcdefghijkl");
+    assert_eq!(measure_text(&text, 5), (5, 3));
+}
+
+#[test]
+fn test_measure_wide_characters() {
+    // Each CJK character occupies two columns
+    let text = Text::from("你好世界");
+    assert_eq!(measure_text(&text, 20), (8, 1));
+    assert_eq!(measure_text(&text, 5), (4, 2));
+
+    let mixed = Text::from("ab你好");
+    assert_eq!(measure_text(&mixed, 40), (6, 1));
+}
+
+#[test]
+fn test_measure_styled_spans() {
+    let text = Text::from(Line::from(vec![
+        "bold".bold(),
+        Span::styled(" and plain", Style::default()),
+    ]));
+    assert_eq!(measure_text(&text, 80), (14, 1));
+}
+
+#[test]
+fn test_use_measure_recomputes_on_change() {
+    with_test_isolate(|| {
+        let render = |content: &str, width: u16| {
+            with_component_id("MeasureComponent", |_| {
+                use_measure(&Text::from(content.to_string()), width)
+            })
+        };
+
+        assert_eq!(render("one two three", 40), (13, 1));
+        assert_eq!(render("one two three", 40), (13, 1));
+        assert_eq!(render("one two three", 8), (7, 2));
+        assert_eq!(render("one", 8), (3, 1));
+    });
+}
@@ -1,42 +1,173 @@
-use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
-
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+pub mod async_callback;
+pub mod async_state;
+pub mod batch;
 pub mod battery;
+pub mod bell;
+pub mod cached_render;
 pub mod callback;
+pub mod cancellation;
+pub mod color_picker;
 pub mod context;
+pub mod date_picker;
+pub mod debug_value;
+pub mod deferred;
 pub mod effect;
+pub mod env;
+pub mod error;
 pub mod event;
+#[cfg(feature = "watch")]
+pub mod file_watcher;
+pub mod filter;
+pub mod form;
+pub mod frame;
 pub mod future;
+pub mod heatmap;
 pub mod hover;
 pub mod idle;
 pub mod interval;
+pub mod latest;
+pub mod layout;
+pub mod measure;
+pub mod mode;
+pub mod navigation;
+pub mod number_input;
 pub mod once;
+pub mod pagination;
+pub mod previous;
 pub mod reducer;
+pub mod scroll;
+pub mod search;
 pub mod signal;
+pub mod sort;
+pub mod spawn;
 pub mod state;
 pub mod storage;
+pub mod theme;
+pub mod time_series;
+pub mod toggle_list;
+pub mod transition;
+pub mod tree;
+pub mod vim;
 
 #[cfg(test)]
 pub mod test_utils;
 
 thread_local! {
     static HOOK_CONTEXT: RefCell<Option<Rc<HookContext>>> = const { RefCell::new(None) };
+    /// Set while this thread renders a `ParallelPanels` panel
+    #[cfg(feature = "parallel")]
+    static IN_PARALLEL_RENDER: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Hook state slots indexed by hook-call order
+///
+/// Hooks are called in the same order on every render, so their state lives
+/// in a vector addressed by call index rather than a hashed map. Looking up a
+/// slot is a bounds-checked index with no hashing on the render path.
+#[derive(Default)]
+pub(crate) struct HookSlots {
+    slots: Vec<Option<Box<dyn Any>>>,
+}
+
+impl HookSlots {
+    /// Get the state stored at `index`
+    pub(crate) fn get(&self, index: usize) -> Option<&dyn Any> {
+        self.slots.get(index)?.as_deref()
+    }
+
+    /// Store `value` at `index`, replacing any previous state
+    pub(crate) fn insert(&mut self, index: usize, value: Box<dyn Any>) {
+        if index >= self.slots.len() {
+            self.slots.resize_with(index + 1, || None);
+        }
+        self.slots[index] = Some(value);
+    }
+
+    /// Get the state at `index`, initializing the slot if it is empty
+    pub(crate) fn get_or_insert_with(
+        &mut self,
+        index: usize,
+        init: impl FnOnce() -> Box<dyn Any>,
+    ) -> &mut Box<dyn Any> {
+        if index >= self.slots.len() {
+            self.slots.resize_with(index + 1, || None);
+        }
+        self.slots[index].get_or_insert_with(init)
+    }
+
+    /// Check if a slot holds state
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// Check if no slot holds state
+    #[cfg(test)]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.slots.iter().all(Option::is_none)
+    }
+
+    /// Drop every slot
+    pub(crate) fn clear(&mut self) {
+        self.slots.clear();
+    }
 }
 
 /// A hook context that manages state for components
 pub struct HookContext {
-    states: RefCell<HashMap<usize, Box<dyn Any>>>,
+    states: RefCell<HookSlots>,
     current_hook: RefCell<usize>,
+    /// Bumped whenever a state, reducer or future of this context changes
+    state_changes: Arc<AtomicU64>,
+    /// Checks of the context selectors of this context, by hook index
+    selections: RefCell<HashMap<usize, Rc<dyn Fn() -> bool>>>,
 }
 
 impl HookContext {
     /// Create a new hook context
     pub fn new() -> Self {
         Self {
-            states: RefCell::new(HashMap::new()),
+            states: RefCell::new(HookSlots::default()),
             current_hook: RefCell::new(0),
+            state_changes: Arc::new(AtomicU64::new(0)),
+            selections: RefCell::new(HashMap::new()),
         }
     }
 
+    /// Counter bumped by the states, reducers and futures of this context when they change
+    pub(crate) fn state_changes(&self) -> Arc<AtomicU64> {
+        self.state_changes.clone()
+    }
+
+    /// Number of state, reducer and future changes in this context so far
+    pub(crate) fn state_version(&self) -> u64 {
+        self.state_changes.load(Ordering::SeqCst)
+    }
+
+    /// Register the check of the context selector at hook `index`
+    ///
+    /// `changed` returns true once the slice it selects differs from the one
+    /// the last render used.
+    pub(crate) fn watch_selection(&self, index: usize, changed: Rc<dyn Fn() -> bool>) {
+        self.selections.borrow_mut().insert(index, changed);
+    }
+
+    /// Returns true if a context selector of this context selects a new slice
+    pub(crate) fn selections_changed(&self) -> bool {
+        let checks: Vec<_> = self.selections.borrow().values().cloned().collect();
+        checks.iter().any(|changed| changed())
+    }
+
     /// Get the current hook index and increment it
     pub fn next_hook_index(&self) -> usize {
         let mut current = self.current_hook.borrow_mut();
@@ -45,6 +176,11 @@ impl HookContext {
         index
     }
 
+    /// The index the next hook call will get
+    pub(crate) fn current_hook_index(&self) -> usize {
+        *self.current_hook.borrow()
+    }
+
     /// Reset the hook index for a new render cycle
     pub fn reset_hook_index(&self) {
         *self.current_hook.borrow_mut() = 0;
@@ -54,8 +190,8 @@ impl HookContext {
     pub fn get_state<T: 'static + Clone>(&self, index: usize) -> Option<T> {
         self.states
             .borrow()
-            .get(&index)
-            .and_then(|boxed| boxed.downcast_ref::<T>())
+            .get(index)
+            .and_then(|state| state.downcast_ref::<T>())
             .cloned()
     }
 
@@ -78,7 +214,7 @@ impl HookContext {
 
         let mut states = self.states.borrow_mut();
 
-        if let Some(existing) = states.get(&index) {
+        if let Some(existing) = states.get(index) {
             // Try to downcast to the expected type
             if let Some(typed_state) = existing.downcast_ref::<Rc<RefCell<T>>>() {
                 return typed_state.clone();
@@ -93,12 +229,13 @@ impl HookContext {
 
     /// Check if state exists for a hook index
     pub fn has_state(&self, index: usize) -> bool {
-        self.states.borrow().contains_key(&index)
+        self.states.borrow().contains(index)
     }
 
     /// Clear all state (useful for cleanup)
     pub fn clear(&self) {
         self.states.borrow_mut().clear();
+        self.selections.borrow_mut().clear();
         self.reset_hook_index();
     }
 }
@@ -128,8 +265,28 @@ pub fn clear_hook_context() {
     });
 }
 
+/// Run `f` with hooks disabled on this thread
+///
+/// Parallel panels render on worker threads that can't reach the component's
+/// hook state, so any hook called inside `f` panics with an explanation.
+#[cfg(feature = "parallel")]
+pub(crate) fn without_hooks<R>(f: impl FnOnce() -> R) -> R {
+    let previous = IN_PARALLEL_RENDER.with(|flag| flag.replace(true));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    IN_PARALLEL_RENDER.with(|flag| flag.set(previous));
+    result.unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+}
+
 /// Get the current hook context
 pub fn with_hook_context<R>(f: impl FnOnce(&HookContext) -> R) -> R {
+    #[cfg(feature = "parallel")]
+    if IN_PARALLEL_RENDER.with(std::cell::Cell::get) {
+        panic!(
+            "hooks can't be called from a ParallelPanels panel; \
+             read hook state in the parent component and pass it to the panel"
+        );
+    }
+
     let context =
         get_hook_context().expect("with_hook_context must be called within a hook context");
     f(&context)
@@ -257,6 +414,53 @@ mod tests {
         assert_eq!(context.get_state::<i32>(0), Some(42));
     }
 
+    #[test]
+    fn test_hook_identity_follows_call_order() {
+        let context = Rc::new(HookContext::new());
+        set_hook_context(context.clone());
+
+        // First render registers three hooks of different types
+        let render = || {
+            let (count, set_count) = state::use_state(|| 0i32);
+            let (name, _) = state::use_state(|| "pulse".to_string());
+            let (flag, set_flag) = state::use_state(|| false);
+            (count, set_count, name, flag, set_flag)
+        };
+        let (_, set_count, _, _, set_flag) = render();
+        set_count.set(7);
+        set_flag.set(true);
+
+        // Later renders get back the same state at the same call position
+        for _ in 0..3 {
+            context.reset_hook_index();
+            let (count, _, name, flag, _) = render();
+            assert_eq!(count.get(), 7);
+            assert_eq!(name.get(), "pulse");
+            assert!(flag.get());
+        }
+
+        clear_hook_context();
+    }
+
+    #[test]
+    fn test_slots_grow_and_keep_sparse_indices() {
+        let context = HookContext::new();
+
+        context.set_state(5, 50i32);
+        assert!(context.has_state(5));
+        assert!(!context.has_state(2));
+        assert!(!context.has_state(6));
+
+        // Filling a gap leaves later slots untouched
+        context.set_state(2, 20i32);
+        assert_eq!(context.get_state::<i32>(2), Some(20));
+        assert_eq!(context.get_state::<i32>(5), Some(50));
+
+        // A slot whose type changed is reinitialized rather than reused
+        let slot = context.get_or_init_state(5, || "new".to_string());
+        assert_eq!(*slot.borrow(), "new");
+    }
+
     #[test]
     fn test_default_implementation() {
         let context = HookContext::default();
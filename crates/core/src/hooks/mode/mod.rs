@@ -0,0 +1,304 @@
+//! Input Mode Hooks
+//!
+//! Many TUIs switch between modes — normal navigation, text input, a
+//! confirmation dialog — and the same key means different things in each.
+//! Handling that with one big `match` full of `if mode == ...` guards gets
+//! unwieldy quickly. This module provides a `use_mode` hook holding the current
+//! mode and a declarative binding API: [`when`] attaches a [`Keymap`] to a
+//! mode, and only the keymap of the active mode handles a key.
+//!
+//! Bindings can carry a short description; [`ModeHandle::hints`] lists the
+//! described bindings of the active mode, ready for a
+//! [`KeyHints`](crate::widgets::KeyHints) footer.
+
+use std::borrow::Borrow;
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// A key together with the modifiers that must be held
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Create a binding for a key with modifiers
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Create a binding for a key pressed together with Ctrl
+    pub fn ctrl(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::CONTROL)
+    }
+
+    /// Returns true if `key` triggers this binding
+    ///
+    /// Shift is ignored for character keys, since it is already reflected in
+    /// the character itself (`'A'` vs `'a'`).
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        let modifiers = match key.code {
+            KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+            _ => key.modifiers,
+        };
+        self.code == key.code && self.modifiers == modifiers
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    /// Formats the binding as it is usually written, e.g. `Ctrl+s` or `Esc`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "Ctrl"),
+            (KeyModifiers::ALT, "Alt"),
+            (KeyModifiers::SHIFT, "Shift"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+        write!(f, "{}", self.code)
+    }
+}
+
+impl From<KeyCode> for KeyBinding {
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+}
+
+impl From<char> for KeyBinding {
+    fn from(c: char) -> Self {
+        Self::from(KeyCode::Char(c))
+    }
+}
+
+type KeyAction<'a> = Box<dyn Fn(&KeyEvent) + 'a>;
+
+/// A described key binding, for showing the available keys
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyHint {
+    /// The key that triggers the action
+    pub binding: KeyBinding,
+    /// What the action does, e.g. `"Save"`
+    pub description: String,
+}
+
+/// A binding of a [`Keymap`] with its action
+struct BoundKey<'a> {
+    binding: KeyBinding,
+    description: Option<String>,
+    action: KeyAction<'a>,
+}
+
+/// A set of key bindings and their actions
+///
+/// Bindings are checked in the order they were added; the first match wins.
+/// An optional fallback handles keys no binding matched, e.g. to insert typed
+/// characters in an input mode.
+#[derive(Default)]
+pub struct Keymap<'a> {
+    bindings: Vec<BoundKey<'a>>,
+    fallback: Option<KeyAction<'a>>,
+}
+
+impl<'a> Keymap<'a> {
+    /// Create an empty keymap
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            fallback: None,
+        }
+    }
+
+    /// Bind a key to an action
+    pub fn bind<F>(mut self, binding: impl Into<KeyBinding>, action: F) -> Self
+    where
+        F: Fn(&KeyEvent) + 'a,
+    {
+        self.bindings.push(BoundKey {
+            binding: binding.into(),
+            description: None,
+            action: Box::new(action),
+        });
+        self
+    }
+
+    /// Describe the binding added last, making it show up in [`Keymap::hints`]
+    pub fn describe(mut self, description: impl Into<String>) -> Self {
+        if let Some(bound) = self.bindings.last_mut() {
+            bound.description = Some(description.into());
+        }
+        self
+    }
+
+    /// The described bindings, in the order they were added
+    pub fn hints(&self) -> Vec<KeyHint> {
+        self.bindings
+            .iter()
+            .filter_map(|bound| {
+                bound.description.as_ref().map(|description| KeyHint {
+                    binding: bound.binding,
+                    description: description.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Handle keys that no binding matched
+    pub fn fallback<F>(mut self, action: F) -> Self
+    where
+        F: Fn(&KeyEvent) + 'a,
+    {
+        self.fallback = Some(Box::new(action));
+        self
+    }
+
+    /// Run the action bound to `key`, returning true if one ran
+    pub fn handle(&self, key: &KeyEvent) -> bool {
+        if let Some(bound) = self
+            .bindings
+            .iter()
+            .find(|bound| bound.binding.matches(key))
+        {
+            (bound.action)(key);
+            return true;
+        }
+        if let Some(fallback) = &self.fallback {
+            fallback(key);
+            return true;
+        }
+        false
+    }
+}
+
+/// A keymap that is only active in a given mode
+pub struct ModeBindings<'a, M> {
+    mode: M,
+    keymap: Keymap<'a>,
+}
+
+/// Activate `keymap` only while the current mode equals `mode`
+///
+/// # Example
+/// ```
+/// use pulse_core::hooks::mode::{Keymap, when};
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Mode { Normal, Insert }
+///
+/// let bindings = when(Mode::Normal, Keymap::new().bind('i', |_| { /* enter insert mode */ }));
+/// ```
+pub fn when<M>(mode: M, keymap: Keymap<'_>) -> ModeBindings<'_, M> {
+    ModeBindings { mode, keymap }
+}
+
+/// Handle to the current input mode returned by [`use_mode`]
+#[derive(Clone)]
+pub struct ModeHandle<M> {
+    mode: StateHandle<M>,
+    setter: StateSetter<M>,
+}
+
+impl<M> ModeHandle<M>
+where
+    M: Clone + PartialEq,
+{
+    /// Get the current mode
+    pub fn get(&self) -> M {
+        self.mode.get()
+    }
+
+    /// Returns true if the current mode is `mode`
+    pub fn is(&self, mode: &M) -> bool {
+        self.mode.get() == *mode
+    }
+
+    /// Switch to another mode
+    pub fn set(&self, mode: M) {
+        self.setter.set(mode);
+    }
+
+    /// Dispatch `key` to the bindings declared for the current mode
+    ///
+    /// Only bindings whose mode matches the current mode are considered, in
+    /// order. Returns true if an action handled the key. The mode is read once
+    /// before dispatching, so an action that switches modes does not cause the
+    /// same key to be handled again by the new mode's bindings.
+    ///
+    /// Takes the bindings by value or by reference, so the same bindings can
+    /// also be passed to [`ModeHandle::hints`].
+    pub fn handle_key<'a, B>(&self, key: &KeyEvent, bindings: impl IntoIterator<Item = B>) -> bool
+    where
+        B: Borrow<ModeBindings<'a, M>>,
+    {
+        let current = self.get();
+        bindings
+            .into_iter()
+            .filter(|bindings| bindings.borrow().mode == current)
+            .any(|bindings| bindings.borrow().keymap.handle(key))
+    }
+
+    /// The described bindings of the current mode
+    pub fn hints<'a, B>(&self, bindings: impl IntoIterator<Item = B>) -> Vec<KeyHint>
+    where
+        B: Borrow<ModeBindings<'a, M>>,
+    {
+        let current = self.get();
+        bindings
+            .into_iter()
+            .filter(|bindings| bindings.borrow().mode == current)
+            .flat_map(|bindings| bindings.borrow().keymap.hints())
+            .collect()
+    }
+}
+
+/// Hook that holds the current input mode of a component
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::mode::{Keymap, use_mode, when};
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// #[derive(Clone, PartialEq)]
+/// enum Mode {
+///     Normal,
+///     Editing,
+/// }
+///
+/// // In a component context:
+/// let mode = use_mode(Mode::Normal);
+///
+/// if let Some(key) = use_key_event() {
+///     mode.handle_key(
+///         &key,
+///         [
+///             when(
+///                 Mode::Normal,
+///                 Keymap::new().bind('a', |_| mode.set(Mode::Editing)),
+///             ),
+///             when(
+///                 Mode::Editing,
+///                 Keymap::new()
+///                     .bind(KeyCode::Esc, |_| mode.set(Mode::Normal))
+///                     .fallback(|_key| { /* append typed character */ }),
+///             ),
+///         ],
+///     );
+/// }
+/// ```
+pub fn use_mode<M>(initial: M) -> ModeHandle<M>
+where
+    M: Clone + PartialEq + 'static,
+{
+    let (mode, setter) = use_state(|| initial);
+    ModeHandle { mode, setter }
+}
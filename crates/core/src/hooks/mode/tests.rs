@@ -0,0 +1,149 @@
+//! Tests for the input mode hooks
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use std::cell::RefCell;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Mode {
+    Normal,
+    Insert,
+    Dialog,
+}
+
+fn press(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+/// Render a component that logs which bindings fired for `key`
+fn render(key: KeyEvent, log: &RefCell<Vec<String>>) -> (Mode, bool) {
+    with_component_id("ModeComponent", |_| {
+        let mode = use_mode(Mode::Normal);
+        let handled = mode.handle_key(
+            &key,
+            [
+                when(
+                    Mode::Normal,
+                    Keymap::new()
+                        .bind('i', |_| {
+                            log.borrow_mut().push("normal:i".into());
+                            mode.set(Mode::Insert);
+                        })
+                        .bind('d', |_| {
+                            log.borrow_mut().push("normal:d".into());
+                            mode.set(Mode::Dialog);
+                        }),
+                ),
+                when(
+                    Mode::Insert,
+                    Keymap::new()
+                        .bind(KeyCode::Esc, |_| {
+                            log.borrow_mut().push("insert:esc".into());
+                            mode.set(Mode::Normal);
+                        })
+                        .fallback(|key| {
+                            if let KeyCode::Char(c) = key.code {
+                                log.borrow_mut().push(format!("insert:{}", c));
+                            }
+                        }),
+                ),
+                when(
+                    Mode::Dialog,
+                    Keymap::new().bind('y', |_| {
+                        log.borrow_mut().push("dialog:y".into());
+                        mode.set(Mode::Normal);
+                    }),
+                ),
+            ],
+        );
+        (mode.get(), handled)
+    })
+}
+
+#[test]
+fn test_bindings_only_fire_in_declared_mode() {
+    with_test_isolate(|| {
+        let log = RefCell::new(Vec::new());
+
+        // 'y' is only bound in dialog mode
+        assert_eq!(
+            render(press(KeyCode::Char('y')), &log),
+            (Mode::Normal, false)
+        );
+        assert!(log.borrow().is_empty());
+
+        // 'i' switches to insert mode; the same key is not re-handled as text
+        assert_eq!(
+            render(press(KeyCode::Char('i')), &log),
+            (Mode::Insert, true)
+        );
+        assert_eq!(*log.borrow(), vec!["normal:i"]);
+
+        // In insert mode, 'd' is text rather than the normal-mode binding
+        assert_eq!(
+            render(press(KeyCode::Char('d')), &log),
+            (Mode::Insert, true)
+        );
+        assert_eq!(log.borrow().last().unwrap(), "insert:d");
+
+        render(press(KeyCode::Esc), &log);
+        render(press(KeyCode::Char('d')), &log);
+        assert_eq!(log.borrow().last().unwrap(), "normal:d");
+
+        assert_eq!(
+            render(press(KeyCode::Char('i')), &log),
+            (Mode::Dialog, false)
+        );
+        assert_eq!(
+            render(press(KeyCode::Char('y')), &log),
+            (Mode::Normal, true)
+        );
+        assert_eq!(
+            render(press(KeyCode::Char('x')), &log),
+            (Mode::Normal, false)
+        );
+
+        assert_eq!(
+            *log.borrow(),
+            vec!["normal:i", "insert:d", "insert:esc", "normal:d", "dialog:y"]
+        );
+    });
+}
+
+#[test]
+fn test_key_binding_modifiers() {
+    let ctrl_s = KeyBinding::ctrl(KeyCode::Char('s'));
+    assert!(ctrl_s.matches(&KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)));
+    assert!(!ctrl_s.matches(&press(KeyCode::Char('s'))));
+
+    // Shift is part of the character for char keys
+    let upper = KeyBinding::from('G');
+    assert!(upper.matches(&KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT)));
+    assert!(upper.matches(&press(KeyCode::Char('G'))));
+
+    let tab = KeyBinding::from(KeyCode::Tab);
+    assert!(!tab.matches(&KeyEvent::new(KeyCode::Tab, KeyModifiers::SHIFT)));
+}
+
+#[test]
+fn test_keymap_first_binding_wins() {
+    let hits = RefCell::new(Vec::new());
+    let keymap = Keymap::new()
+        .bind('a', |_| hits.borrow_mut().push(1))
+        .bind('a', |_| hits.borrow_mut().push(2));
+
+    assert!(keymap.handle(&press(KeyCode::Char('a'))));
+    assert!(!keymap.handle(&press(KeyCode::Char('b'))));
+    assert_eq!(*hits.borrow(), vec![1]);
+}
+
+#[test]
+fn test_key_binding_display() {
+    assert_eq!(KeyBinding::from('q').to_string(), "q");
+    assert_eq!(KeyBinding::ctrl(KeyCode::Char('s')).to_string(), "Ctrl+s");
+    assert_eq!(
+        KeyBinding::new(KeyCode::Tab, KeyModifiers::SHIFT | KeyModifiers::ALT).to_string(),
+        "Alt+Shift+Tab"
+    );
+    assert_eq!(KeyBinding::from(KeyCode::Esc).to_string(), "Esc");
+}
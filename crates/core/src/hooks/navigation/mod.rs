@@ -0,0 +1,139 @@
+//! Navigation Hook
+//!
+//! This module provides a `use_navigation` hook that keeps a back-stack of
+//! routes for multi-screen applications. Screens are pushed on top of each
+//! other and popping one restores the route underneath, so apps don't need to
+//! hand-roll an enum plus history bookkeeping for every screen change.
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Handle returned by [`use_navigation`]
+#[derive(Clone)]
+pub struct NavHandle<Route: 'static> {
+    stack: StateHandle<Vec<Route>>,
+    setter: StateSetter<Vec<Route>>,
+}
+
+impl<Route: Clone + 'static> NavHandle<Route> {
+    /// The route on top of the stack, if any
+    pub fn current(&self) -> Option<Route> {
+        self.stack.field(|stack| stack.last().cloned())
+    }
+
+    /// All routes, from the first visited to the current one
+    pub fn stack(&self) -> Vec<Route> {
+        self.stack.get()
+    }
+
+    /// Number of routes on the stack
+    pub fn depth(&self) -> usize {
+        self.stack.field(Vec::len)
+    }
+
+    /// Returns true if [`pop`](Self::pop) would go back to a previous route
+    pub fn can_go_back(&self) -> bool {
+        self.depth() > 1
+    }
+
+    /// Navigate to `route`, keeping the current route to go back to
+    pub fn push(&self, route: Route) {
+        self.setter.update(move |stack| {
+            let mut stack = stack.clone();
+            stack.push(route);
+            stack
+        });
+    }
+
+    /// Go back to the previous route, returning the route that was left
+    ///
+    /// The first route is never popped, so there is always a route to show
+    /// once one was pushed; in that case `None` is returned.
+    pub fn pop(&self) -> Option<Route> {
+        if !self.can_go_back() {
+            return None;
+        }
+
+        let popped = self.current();
+        self.setter.update(|stack| {
+            let mut stack = stack.clone();
+            if stack.len() > 1 {
+                stack.pop();
+            }
+            stack
+        });
+        popped
+    }
+
+    /// Swap the current route for `route` without adding a history entry
+    ///
+    /// On an empty stack this behaves like [`push`](Self::push).
+    pub fn replace(&self, route: Route) {
+        self.setter.update(move |stack| {
+            let mut stack = stack.clone();
+            stack.pop();
+            stack.push(route);
+            stack
+        });
+    }
+
+    /// Clear the history and start over at `route`
+    pub fn reset(&self, route: Route) {
+        self.setter.set(vec![route]);
+    }
+}
+
+/// Hook that manages a navigation back-stack, starting out empty
+///
+/// Push the first screen on mount or use [`use_navigation_with`] to start at
+/// an initial route.
+pub fn use_navigation<Route>() -> NavHandle<Route>
+where
+    Route: Clone + 'static,
+{
+    let (stack, setter) = use_state(Vec::new);
+    NavHandle { stack, setter }
+}
+
+/// Hook that manages a navigation back-stack starting at `initial`
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::navigation::use_navigation_with;
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// #[derive(Clone, PartialEq)]
+/// enum Route {
+///     Inbox,
+///     Message(usize),
+/// }
+///
+/// // In a component context:
+/// let nav = use_navigation_with(|| Route::Inbox);
+///
+/// if let Some(key) = use_key_event() {
+///     match key.code {
+///         KeyCode::Enter => nav.push(Route::Message(3)),
+///         KeyCode::Esc => {
+///             nav.pop();
+///         }
+///         _ => {}
+///     }
+/// }
+///
+/// match nav.current() {
+///     Some(Route::Inbox) | None => { /* render the inbox */ }
+///     Some(Route::Message(id)) => { /* render message `id` */ }
+/// }
+/// ```
+pub fn use_navigation_with<Route, F>(initial: F) -> NavHandle<Route>
+where
+    Route: Clone + 'static,
+    F: FnOnce() -> Route,
+{
+    let (stack, setter) = use_state(|| vec![initial()]);
+    NavHandle { stack, setter }
+}
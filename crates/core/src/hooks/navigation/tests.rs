@@ -0,0 +1,99 @@
+//! Tests for the use_navigation hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_hook_context, with_test_isolate};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Route {
+    Home,
+    List,
+    Detail(u32),
+}
+
+#[test]
+fn test_starts_empty() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let nav = use_navigation::<Route>();
+            assert_eq!(nav.current(), None);
+            assert_eq!(nav.depth(), 0);
+            assert_eq!(nav.pop(), None);
+
+            nav.push(Route::Home);
+            assert_eq!(nav.current(), Some(Route::Home));
+        });
+    });
+}
+
+#[test]
+fn test_push_and_pop_restore_previous_route() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let nav = use_navigation_with(|| Route::Home);
+            nav.push(Route::List);
+            nav.push(Route::Detail(7));
+            assert_eq!(
+                nav.stack(),
+                vec![Route::Home, Route::List, Route::Detail(7)]
+            );
+
+            assert_eq!(nav.pop(), Some(Route::Detail(7)));
+            assert_eq!(nav.current(), Some(Route::List));
+            assert_eq!(nav.pop(), Some(Route::List));
+            assert_eq!(nav.current(), Some(Route::Home));
+
+            // The first route stays
+            assert!(!nav.can_go_back());
+            assert_eq!(nav.pop(), None);
+            assert_eq!(nav.current(), Some(Route::Home));
+        });
+    });
+}
+
+#[test]
+fn test_replace_does_not_add_history() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let nav = use_navigation_with(|| Route::Home);
+            nav.push(Route::Detail(1));
+            nav.replace(Route::Detail(2));
+            assert_eq!(nav.stack(), vec![Route::Home, Route::Detail(2)]);
+
+            assert_eq!(nav.pop(), Some(Route::Detail(2)));
+            assert_eq!(nav.current(), Some(Route::Home));
+
+            let empty = use_navigation::<Route>();
+            empty.replace(Route::List);
+            assert_eq!(empty.stack(), vec![Route::List]);
+        });
+    });
+}
+
+#[test]
+fn test_reset_clears_history() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let nav = use_navigation_with(|| Route::Home);
+            nav.push(Route::List);
+            nav.reset(Route::Detail(3));
+            assert_eq!(nav.stack(), vec![Route::Detail(3)]);
+            assert!(!nav.can_go_back());
+        });
+    });
+}
+
+#[test]
+fn test_stack_persists_across_renders() {
+    with_test_isolate(|| {
+        with_component_id("NavigationComponent", |_| {
+            let nav = use_navigation_with(|| Route::Home);
+            nav.push(Route::List);
+        });
+
+        with_component_id("NavigationComponent", |_| {
+            let nav = use_navigation_with(|| Route::Home);
+            assert_eq!(nav.current(), Some(Route::List));
+            assert_eq!(nav.depth(), 2);
+        });
+    });
+}
@@ -0,0 +1,179 @@
+//! Number Input Hook
+//!
+//! This module provides a `use_number_input` hook for keyboard-driven numeric
+//! fields such as amounts. Digits, one decimal point and a leading minus sign
+//! (when negative values are allowed) can be typed; anything else, including
+//! characters that would take the value past its bounds, is ignored. The
+//! arrow keys step the value up and down, staying within the bounds.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Handle returned by [`use_number_input`]
+#[derive(Clone)]
+pub struct NumberInputHandle {
+    text: StateHandle<String>,
+    setter: StateSetter<String>,
+    step: f64,
+    min: f64,
+    max: f64,
+}
+
+impl NumberInputHandle {
+    /// The text as typed, e.g. `"12."` while typing `12.5`
+    pub fn text(&self) -> String {
+        self.text.get()
+    }
+
+    /// The parsed value, clamped to the bounds
+    ///
+    /// Incomplete input such as an empty field or a lone `-` counts as zero.
+    pub fn value(&self) -> f64 {
+        self.text.with(|text| self.parse(text))
+    }
+
+    /// Append a typed character, returning false if it was rejected
+    ///
+    /// Accepts digits, a single decimal point and a leading `-` if the
+    /// minimum is negative. Characters that would take the value past the
+    /// bounds are rejected too, since typing more digits can't bring it back.
+    pub fn insert_char(&self, c: char) -> bool {
+        let mut text = self.text();
+        let valid = match c {
+            '0'..='9' => true,
+            '.' => !text.contains('.'),
+            '-' => text.is_empty() && self.min < 0.0,
+            _ => false,
+        };
+        if !valid {
+            return false;
+        }
+
+        text.push(c);
+        let typed = text.parse::<f64>().unwrap_or(0.0);
+        if typed > self.max.max(0.0) || typed < self.min.min(0.0) {
+            return false;
+        }
+        self.setter.set(text);
+        true
+    }
+
+    /// Delete the last typed character
+    pub fn backspace(&self) {
+        self.setter.update(|text| {
+            let mut text = text.clone();
+            text.pop();
+            text
+        });
+    }
+
+    /// Increase the value by one step, up to the maximum
+    pub fn increment(&self) {
+        self.set(self.value() + self.step);
+    }
+
+    /// Decrease the value by one step, down to the minimum
+    pub fn decrement(&self) {
+        self.set(self.value() - self.step);
+    }
+
+    /// Replace the input with `value`, clamped to the bounds
+    ///
+    /// The value is rounded to the decimals of the step, so stepping by
+    /// `0.1` shows `0.3` rather than `0.30000000000000004`.
+    pub fn set(&self, value: f64) {
+        let scale = 10f64.powi(decimals(self.step) as i32);
+        let value = ((value * scale).round() / scale).clamp(self.min, self.max);
+        self.setter.set(format_number(value));
+    }
+
+    /// Apply `key` to the input, returning true if it was handled
+    ///
+    /// Handles typed characters, `Backspace`, and `Up`/`Down` to step the
+    /// value. Keys with Ctrl or Alt are left to other handlers.
+    pub fn handle_key(&self, key: &KeyEvent) -> bool {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return false;
+        }
+
+        match key.code {
+            KeyCode::Char(c) => self.insert_char(c),
+            KeyCode::Backspace => {
+                self.backspace();
+                true
+            }
+            KeyCode::Up => {
+                self.increment();
+                true
+            }
+            KeyCode::Down => {
+                self.decrement();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn parse(&self, text: &str) -> f64 {
+        text.parse::<f64>().unwrap_or(0.0).clamp(self.min, self.max)
+    }
+}
+
+/// Number of decimals `step` is given with
+fn decimals(step: f64) -> usize {
+    let text = format_number(step);
+    text.split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+/// Shortest text reading back as `value`, without a trailing `.0`
+fn format_number(value: f64) -> String {
+    // Avoid showing "-0" after stepping down to zero
+    let value = if value == 0.0 { 0.0 } else { value };
+    format!("{}", value)
+}
+
+/// Hook for a numeric field edited with the keyboard
+///
+/// The field starts with `initial`; the arrow keys change it by `step`, and
+/// the value never leaves `min..=max`. Inverted bounds are swapped, and a NaN
+/// bound leaves that side unbounded. Pass key events to
+/// [`NumberInputHandle::handle_key`] while the field has focus.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::number_input::use_number_input;
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// // In a component context:
+/// let amount = use_number_input(0.0, 0.5, 0.0, 10_000.0);
+///
+/// if let Some(key) = use_key_event() {
+///     amount.handle_key(&key);
+/// }
+///
+/// let field = format!("Amount: {}▏", amount.text());
+/// let total = amount.value();
+/// ```
+pub fn use_number_input(initial: f64, step: f64, min: f64, max: f64) -> NumberInputHandle {
+    // f64::clamp panics on NaN or inverted bounds
+    let min = if min.is_nan() { f64::NEG_INFINITY } else { min };
+    let max = if max.is_nan() { f64::INFINITY } else { max };
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    let (text, setter) = use_state(|| format_number(initial.clamp(min, max)));
+
+    NumberInputHandle {
+        text,
+        setter,
+        step: step.abs(),
+        min,
+        max,
+    }
+}
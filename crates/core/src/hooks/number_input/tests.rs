@@ -0,0 +1,120 @@
+//! Tests for the use_number_input hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render(initial: f64, step: f64, min: f64, max: f64) -> NumberInputHandle {
+    with_component_id("AmountField", |_| use_number_input(initial, step, min, max))
+}
+
+fn type_text(input: &NumberInputHandle, text: &str) -> Vec<bool> {
+    text.chars().map(|c| input.insert_char(c)).collect()
+}
+
+#[test]
+fn test_typing_a_decimal() {
+    with_test_isolate(|| {
+        let input = render(0.0, 1.0, 0.0, 1000.0);
+        input.backspace();
+        assert_eq!(input.text(), "");
+        assert_eq!(input.value(), 0.0);
+
+        assert_eq!(type_text(&input, "12."), [true, true, true]);
+        assert_eq!(input.text(), "12.");
+        assert_eq!(input.value(), 12.0);
+
+        // A second decimal point and letters are ignored
+        assert_eq!(type_text(&input, ".5x"), [false, true, false]);
+        assert_eq!(input.text(), "12.5");
+        assert_eq!(input.value(), 12.5);
+
+        input.backspace();
+        assert_eq!(input.value(), 12.0);
+
+        // No sign without negative values
+        input.setter.set(String::new());
+        assert!(!input.insert_char('-'));
+    });
+}
+
+#[test]
+fn test_clamping_to_bounds() {
+    with_test_isolate(|| {
+        let input = render(250.0, 1.0, 0.0, 100.0);
+        assert_eq!(input.text(), "100");
+
+        input.set(-4.0);
+        assert_eq!(input.value(), 0.0);
+
+        // Digits taking the value past the maximum are rejected
+        input.backspace();
+        assert_eq!(type_text(&input, "150"), [true, true, false]);
+        assert_eq!(input.value(), 15.0);
+
+        let signed = with_component_id("SignedField", |_| use_number_input(0.0, 1.0, 5.0, -50.0));
+        signed.backspace();
+        assert_eq!(type_text(&signed, "-75"), [true, true, false]);
+        assert_eq!(signed.value(), -7.0);
+        assert_eq!(type_text(&signed, "-"), [false]);
+        signed.set(20.0);
+        assert_eq!(signed.text(), "5");
+    });
+}
+
+#[test]
+fn test_nan_bounds_leave_the_value_unbounded() {
+    with_test_isolate(|| {
+        let input = render(5.0, 1.0, f64::NAN, f64::NAN);
+        assert_eq!(input.value(), 5.0);
+        input.set(-1e9);
+        assert_eq!(input.text(), "-1000000000");
+
+        // Only the NaN side is unbounded
+        let capped = with_component_id("CappedField", |_| {
+            use_number_input(50.0, 1.0, 10.0, f64::NAN)
+        });
+        assert_eq!(capped.value(), 50.0);
+        capped.set(-3.0);
+        assert_eq!(capped.value(), 10.0);
+        let floored = with_component_id("FlooredField", |_| {
+            use_number_input(0.0, 1.0, f64::NAN, -5.0)
+        });
+        floored.set(8.0);
+        assert_eq!(floored.value(), -5.0);
+        floored.set(-80.0);
+        assert_eq!(floored.value(), -80.0);
+    });
+}
+
+#[test]
+fn test_arrow_keys_step_within_bounds() {
+    with_test_isolate(|| {
+        let input = render(99.5, 1.0, 0.0, 100.0);
+        let key = |code| KeyEvent::from(code);
+
+        assert!(input.handle_key(&key(KeyCode::Up)));
+        assert_eq!(input.value(), 100.0);
+        assert!(input.handle_key(&key(KeyCode::Up)));
+        assert_eq!(input.value(), 100.0);
+
+        assert!(input.handle_key(&key(KeyCode::Down)));
+        assert!(input.handle_key(&key(KeyCode::Down)));
+        assert_eq!(input.text(), "98");
+
+        assert!(!input.handle_key(&key(KeyCode::Left)));
+        let ctrl_c = KeyEvent::new(KeyCode::Char('1'), KeyModifiers::CONTROL);
+        assert!(!input.handle_key(&ctrl_c));
+        assert_eq!(input.text(), "98");
+
+        // Fractional steps don't accumulate rounding errors
+        let cents = with_component_id("FractionField", |_| use_number_input(0.0, 0.1, 0.0, 1.0));
+        for _ in 0..3 {
+            cents.handle_key(&key(KeyCode::Up));
+        }
+        assert_eq!(cents.text(), "0.3");
+        for _ in 0..5 {
+            cents.handle_key(&key(KeyCode::Down));
+        }
+        assert_eq!(cents.text(), "0");
+    });
+}
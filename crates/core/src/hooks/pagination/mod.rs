@@ -0,0 +1,140 @@
+//! Pagination Hook
+//!
+//! This module provides a `use_pagination` hook for browsing large lists one
+//! page at a time. Page numbers are zero-based and always clamped to the
+//! available pages, including after the number of items changes.
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Handle returned by [`use_pagination`]
+#[derive(Clone)]
+pub struct PaginationHandle {
+    page: StateHandle<usize>,
+    setter: StateSetter<usize>,
+    total: usize,
+    page_size: usize,
+}
+
+impl PaginationHandle {
+    /// The current page (zero-based)
+    pub fn current_page(&self) -> usize {
+        self.page.get().min(self.last_page())
+    }
+
+    /// Number of pages; an empty list still has one (empty) page
+    pub fn total_pages(&self) -> usize {
+        total_pages(self.total, self.page_size)
+    }
+
+    /// Total number of items
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Number of items per page
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// The `(start, end)` item range of the current page, `end` exclusive
+    pub fn range(&self) -> (usize, usize) {
+        let start = (self.current_page() * self.page_size).min(self.total);
+        let end = (start + self.page_size).min(self.total);
+        (start, end)
+    }
+
+    /// Returns true if there is a page after the current one
+    pub fn has_next(&self) -> bool {
+        self.current_page() < self.last_page()
+    }
+
+    /// Returns true if there is a page before the current one
+    pub fn has_prev(&self) -> bool {
+        self.current_page() > 0
+    }
+
+    /// Go to the next page, staying on the last page
+    pub fn next_page(&self) {
+        let last = self.last_page();
+        self.setter.update(move |page| (page + 1).min(last));
+    }
+
+    /// Go to the previous page, staying on the first page
+    pub fn prev_page(&self) {
+        let last = self.last_page();
+        self.setter
+            .update(move |page| (*page).min(last).saturating_sub(1));
+    }
+
+    /// Go to `page`, clamped to the available pages
+    pub fn goto(&self, page: usize) {
+        self.setter.set(page.min(self.last_page()));
+    }
+
+    /// Go to the first page
+    pub fn first(&self) {
+        self.setter.set(0);
+    }
+
+    /// Go to the last page
+    pub fn last(&self) {
+        self.setter.set(self.last_page());
+    }
+
+    fn last_page(&self) -> usize {
+        self.total_pages() - 1
+    }
+}
+
+/// Number of pages needed for `total` items
+fn total_pages(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size).max(1)
+}
+
+/// Hook that paginates `total` items into pages of `page_size`
+///
+/// A `page_size` of zero is treated as one. When `total` shrinks below the
+/// current page, the current page moves to the new last page.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::pagination::use_pagination;
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// # let rows: Vec<String> = Vec::new();
+/// // In a component context:
+/// let pages = use_pagination(rows.len(), 20);
+///
+/// if let Some(key) = use_key_event() {
+///     match key.code {
+///         KeyCode::PageDown => pages.next_page(),
+///         KeyCode::PageUp => pages.prev_page(),
+///         _ => {}
+///     }
+/// }
+///
+/// let (start, end) = pages.range();
+/// let visible = &rows[start..end];
+/// let footer = format!("Page {}/{}", pages.current_page() + 1, pages.total_pages());
+/// ```
+pub fn use_pagination(total: usize, page_size: usize) -> PaginationHandle {
+    let page_size = page_size.max(1);
+    let (page, setter) = use_state(|| 0usize);
+
+    // Recompute the clamp when the item count changed
+    let last = total_pages(total, page_size) - 1;
+    if page.get() > last {
+        setter.set(last);
+    }
+
+    PaginationHandle {
+        page,
+        setter,
+        total,
+        page_size,
+    }
+}
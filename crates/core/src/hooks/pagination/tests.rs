@@ -0,0 +1,90 @@
+//! Tests for the use_pagination hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_hook_context, with_test_isolate};
+
+fn render(total: usize, page_size: usize) -> PaginationHandle {
+    with_component_id("PaginatedList", |_| use_pagination(total, page_size))
+}
+
+#[test]
+fn test_page_math() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let pages = use_pagination(95, 10);
+            assert_eq!(pages.total_pages(), 10);
+            assert_eq!(pages.current_page(), 0);
+            assert_eq!(pages.range(), (0, 10));
+            assert!(pages.has_next());
+            assert!(!pages.has_prev());
+
+            pages.goto(4);
+            assert_eq!(pages.range(), (40, 50));
+
+            pages.last();
+            assert_eq!(pages.current_page(), 9);
+            assert_eq!(pages.range(), (90, 95));
+        });
+    });
+}
+
+#[test]
+fn test_exact_and_empty_totals() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let exact = use_pagination(100, 25);
+            assert_eq!(exact.total_pages(), 4);
+
+            let empty = use_pagination(0, 25);
+            assert_eq!(empty.total_pages(), 1);
+            assert_eq!(empty.range(), (0, 0));
+            assert!(!empty.has_next());
+
+            let zero_size = use_pagination(3, 0);
+            assert_eq!(zero_size.page_size(), 1);
+            assert_eq!(zero_size.total_pages(), 3);
+        });
+    });
+}
+
+#[test]
+fn test_clamping_at_bounds() {
+    with_test_isolate(|| {
+        let pages = render(25, 10);
+        pages.prev_page();
+        assert_eq!(render(25, 10).current_page(), 0);
+
+        for _ in 0..10 {
+            render(25, 10).next_page();
+        }
+        let pages = render(25, 10);
+        assert_eq!(pages.current_page(), 2);
+        assert_eq!(pages.range(), (20, 25));
+        assert!(!pages.has_next());
+
+        pages.goto(99);
+        assert_eq!(render(25, 10).current_page(), 2);
+
+        render(25, 10).prev_page();
+        assert_eq!(render(25, 10).current_page(), 1);
+    });
+}
+
+#[test]
+fn test_recomputes_when_total_changes() {
+    with_test_isolate(|| {
+        render(100, 10).goto(9);
+        assert_eq!(render(100, 10).range(), (90, 100));
+
+        // The list shrinks: the current page moves to the new last page
+        let pages = render(42, 10);
+        assert_eq!(pages.current_page(), 4);
+        assert_eq!(pages.range(), (40, 42));
+
+        // Growing again keeps the clamped page
+        let pages = render(100, 10);
+        assert_eq!(pages.current_page(), 4);
+        pages.prev_page();
+        assert_eq!(render(100, 10).current_page(), 3);
+    });
+}
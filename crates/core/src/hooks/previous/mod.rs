@@ -0,0 +1,93 @@
+//! Previous Value Hooks
+//!
+//! This module provides hooks that compare a value with earlier renders:
+//!
+//! - `use_previous_distinct` remembers the last value that was *different*
+//!   from the current one. Re-renders with an equal value, e.g. after a no-op
+//!   set, don't overwrite it, which makes it suitable for detecting genuine
+//!   transitions such as the direction of an animated change.
+//! - `use_changed` reports whether a value differs from the previous render,
+//!   for one-shot reactions without an effect and its dependencies.
+
+use crate::hooks::with_hook_context;
+
+#[cfg(test)]
+mod tests;
+
+/// Per-hook bookkeeping of the tracked values
+struct PreviousState<T> {
+    current: T,
+    previous: Option<T>,
+}
+
+/// Hook that returns the last value that differed from `value`
+///
+/// Returns `None` until `value` changes for the first time. Renders with a
+/// value equal to the current one leave the tracked previous value as is.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::previous::use_previous_distinct;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (balance, _set_balance) = use_state(|| 100i64);
+/// let previous = use_previous_distinct(balance.get());
+///
+/// // Flash green or red depending on the direction of the last change
+/// let rising = previous.is_some_and(|previous| balance.get() > previous);
+/// ```
+pub fn use_previous_distinct<T>(value: T) -> Option<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let state = ctx.get_or_init_state(index, || PreviousState {
+            current: value.clone(),
+            previous: None,
+        });
+        let mut state = state.borrow_mut();
+
+        if state.current != value {
+            let previous = std::mem::replace(&mut state.current, value);
+            state.previous = Some(previous);
+        }
+        state.previous.clone()
+    })
+}
+
+/// Hook that returns true on renders where `value` differs from the last one
+///
+/// Returns false on the first render, as there is nothing to compare with.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::bell::ring_bell;
+/// # use pulse_core::hooks::previous::use_changed;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (alerts, _set_alerts) = use_state(|| 0usize);
+///
+/// if use_changed(alerts.get()) && alerts.get() > 0 {
+///     ring_bell();
+/// }
+/// ```
+pub fn use_changed<T>(value: T) -> bool
+where
+    T: Clone + PartialEq + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let last = ctx.get_or_init_state(index, || value.clone());
+        let mut last = last.borrow_mut();
+
+        if *last == value {
+            false
+        } else {
+            *last = value;
+            true
+        }
+    })
+}
@@ -0,0 +1,49 @@
+//! Tests for the previous value hooks
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render(value: &'static str) -> Option<&'static str> {
+    with_component_id("PreviousComponent", |_| use_previous_distinct(value))
+}
+
+#[test]
+fn test_no_previous_before_first_change() {
+    with_test_isolate(|| {
+        assert_eq!(render("idle"), None);
+        assert_eq!(render("idle"), None);
+    });
+}
+
+#[test]
+fn test_equal_values_keep_previous() {
+    with_test_isolate(|| {
+        render("idle");
+        assert_eq!(render("loading"), Some("idle"));
+
+        // Repeated equal values don't overwrite the tracked previous value
+        assert_eq!(render("loading"), Some("idle"));
+        assert_eq!(render("loading"), Some("idle"));
+
+        assert_eq!(render("done"), Some("loading"));
+        assert_eq!(render("done"), Some("loading"));
+    });
+}
+
+fn render_changed(value: u32) -> bool {
+    with_component_id("ChangedComponent", |_| use_changed(value))
+}
+
+#[test]
+fn test_changed_only_on_render_with_new_value() {
+    with_test_isolate(|| {
+        assert!(!render_changed(1));
+        assert!(!render_changed(1));
+
+        assert!(render_changed(2));
+        assert!(!render_changed(2));
+
+        assert!(render_changed(1));
+        assert!(!render_changed(1));
+    });
+}
@@ -4,12 +4,20 @@
 //! React's API patterns for complex state management scenarios.
 
 use crate::hooks::with_hook_context;
+use crate::tasks::spawn_limited;
 use parking_lot::{Mutex, RwLock};
-use std::sync::Arc;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
 
 #[cfg(test)]
 mod tests;
 
+/// Error reported by [`ActionQueue::error`] for effects dispatched outside of a tokio runtime
+const NO_RUNTIME_ERROR: &str = "async reducer effects require a tokio runtime";
+
 /// A handle to the current state managed by useReducer
 ///
 /// This provides read-only access to the current state value with efficient
@@ -20,6 +28,13 @@ pub struct ReducerStateHandle<S> {
     version: Arc<Mutex<u64>>,
 }
 
+impl<S> ReducerStateHandle<S> {
+    /// Create a handle reading `state`, for reducers kept outside this module
+    pub(crate) fn from_parts(state: Arc<RwLock<S>>, version: Arc<Mutex<u64>>) -> Self {
+        Self { state, version }
+    }
+}
+
 impl<S> ReducerStateHandle<S>
 where
     S: Clone,
@@ -51,6 +66,14 @@ where
     pub fn version(&self) -> u64 {
         *self.version.lock()
     }
+
+    /// Replace the state without going through the reducer
+    #[cfg(feature = "state-snapshot")]
+    pub(crate) fn replace(&self, state: S) {
+        *self.state.write() = state;
+        *self.version.lock() += 1;
+        crate::hooks::batch::request_render();
+    }
 }
 
 /// A dispatch function for sending actions to the reducer
@@ -64,7 +87,7 @@ pub struct DispatchFn<A> {
 
 impl<A> DispatchFn<A> {
     /// Create a new dispatch function
-    fn new<F>(dispatcher: F) -> Self
+    pub(crate) fn new<F>(dispatcher: F) -> Self
     where
         F: Fn(A) + Send + Sync + 'static,
     {
@@ -92,6 +115,8 @@ struct ReducerContainer<S, A> {
     state: Arc<RwLock<S>>,
     version: Arc<Mutex<u64>>,
     reducer: Arc<dyn Fn(S, A) -> S + Send + Sync>,
+    /// Change counter of the hook context that owns this reducer
+    owner: Arc<AtomicU64>,
 }
 
 impl<S, A> ReducerContainer<S, A>
@@ -100,7 +125,7 @@ where
     A: 'static,
 {
     /// Create a new reducer container
-    fn new<R>(initial_state: S, reducer: R) -> Self
+    fn new<R>(initial_state: S, reducer: R, owner: Arc<AtomicU64>) -> Self
     where
         R: Fn(S, A) -> S + Send + Sync + 'static,
     {
@@ -108,6 +133,7 @@ where
             state: Arc::new(RwLock::new(initial_state)),
             version: Arc::new(Mutex::new(0)),
             reducer: Arc::new(reducer),
+            owner,
         }
     }
 
@@ -123,6 +149,7 @@ where
             let mut version = self.version.lock();
             *version += 1;
         }
+        self.owner.fetch_add(1, Ordering::SeqCst);
 
         // TODO: Trigger re-render notification
         // This would integrate with the component re-render system
@@ -141,6 +168,7 @@ where
         let container_state = self.state.clone();
         let container_version = self.version.clone();
         let container_reducer = self.reducer.clone();
+        let container_owner = self.owner.clone();
 
         DispatchFn::new(move |action| {
             let current_state = container_state.read().clone();
@@ -152,6 +180,7 @@ where
                 let mut version = container_version.lock();
                 *version += 1;
             }
+            container_owner.fetch_add(1, Ordering::SeqCst);
 
             // TODO: Trigger re-render notification
         })
@@ -305,7 +334,11 @@ where
 
         // Get or initialize the reducer container for this hook
         let container_ref = ctx.get_or_init_state(index, || {
-            Arc::new(ReducerContainer::new(initial_state, reducer))
+            Arc::new(ReducerContainer::new(
+                initial_state,
+                reducer,
+                ctx.state_changes(),
+            ))
         });
 
         // Extract the Arc<ReducerContainer<S, A>> from Rc<RefCell<Arc<ReducerContainer<S, A>>>>
@@ -318,3 +351,498 @@ where
         (state_handle, dispatch_fn)
     })
 }
+
+/// A slice of reducer state selected by [`use_reducer_selector`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selection<T> {
+    value: T,
+    version: u64,
+    changed: bool,
+}
+
+impl<T> Selection<T> {
+    /// Get the selected slice
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.value.clone()
+    }
+
+    /// Version of the slice, incremented only when the slice changes
+    ///
+    /// Pass it as the dependencies of [`memo`](crate::memo::memo) to skip
+    /// rendering a child while its slice stays the same.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns true if the slice changed since the previous render
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+}
+
+/// Per-hook bookkeeping of a reducer selector
+struct SelectorState<T> {
+    /// Reducer version the slice was selected at
+    source_version: u64,
+    value: T,
+    version: u64,
+}
+
+/// Hook that selects a slice of reducer state
+///
+/// The slice is only selected again when the reducer state changed, and its
+/// version only moves when the selected value differs from the previous
+/// one. Changes to other parts of the state therefore leave the selection
+/// untouched, so a child memoized on it doesn't re-render.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::Component;
+/// # use pulse_core::hooks::reducer::{ReducerStateHandle, use_reducer_selector};
+/// # use pulse_core::memo::memo;
+/// # use ratatui::{Frame, layout::Rect};
+/// # #[derive(Clone)] struct BalanceView { balance: i64 }
+/// # impl Component for BalanceView { fn render(&self, _: Rect, _: &mut Frame) {} }
+/// #[derive(Clone)]
+/// struct Ledger {
+///     balance: i64,
+///     filter: String,
+/// }
+///
+/// # fn render(ledger: &ReducerStateHandle<Ledger>, area: Rect, frame: &mut Frame) {
+/// // In a component context, with the handle returned by `use_reducer`:
+/// let balance = use_reducer_selector(ledger, |ledger| ledger.balance);
+///
+/// // Typing into the filter doesn't re-render the balance
+/// memo(BalanceView { balance: balance.get() }, balance.version()).render(area, frame);
+/// # }
+/// ```
+pub fn use_reducer_selector<S, T, F>(handle: &ReducerStateHandle<S>, selector: F) -> Selection<T>
+where
+    S: Clone,
+    T: Clone + PartialEq + 'static,
+    F: Fn(&S) -> T,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let source_version = handle.version();
+        let state = ctx.get_or_init_state(index, || SelectorState {
+            source_version,
+            value: handle.field(&selector),
+            version: 0,
+        });
+        let mut state = state.borrow_mut();
+
+        let mut changed = false;
+        if state.source_version != source_version {
+            state.source_version = source_version;
+            let value = handle.field(&selector);
+            if value != state.value {
+                state.value = value;
+                state.version += 1;
+                changed = true;
+            }
+        }
+
+        Selection {
+            value: state.value.clone(),
+            version: state.version,
+            changed,
+        }
+    })
+}
+
+/// A side effect returned by a reducer of [`use_reducer_with_effects`]
+///
+/// Effects run after the new state was committed and get the dispatch
+/// function, so they can dispatch follow-up actions or perform I/O.
+pub type Effect<A> = Box<dyn FnOnce(&DispatchFn<A>) + Send>;
+
+/// Reducer returning the next state and the effects to run
+type EffectReducer<S, A> = dyn Fn(S, A) -> (S, Vec<Effect<A>>) + Send + Sync;
+
+/// Internal container for a reducer with effects
+struct EffectReducerContainer<S, A> {
+    state: Arc<RwLock<S>>,
+    version: Arc<Mutex<u64>>,
+    reducer: Arc<EffectReducer<S, A>>,
+    /// Change counter of the hook context that owns this reducer
+    owner: Arc<AtomicU64>,
+}
+
+impl<S, A> EffectReducerContainer<S, A>
+where
+    S: Clone + Send + Sync + 'static,
+    A: Send + Sync + 'static,
+{
+    /// Reduce `action`, commit the new state, then run the returned effects
+    fn dispatch(self: &Arc<Self>, action: A) {
+        // Held across the reduce so concurrent dispatches cannot lose updates
+        let effects = {
+            let mut state = self.state.write();
+            let (new_state, effects) = (self.reducer)(state.clone(), action);
+            *state = new_state;
+            *self.version.lock() += 1;
+            effects
+        };
+        self.owner.fetch_add(1, Ordering::SeqCst);
+        crate::hooks::batch::request_render();
+
+        if effects.is_empty() {
+            return;
+        }
+
+        // No lock is held here, so effects can read the state and dispatch
+        let dispatch = self.dispatch_fn();
+        for effect in effects {
+            effect(&dispatch);
+        }
+    }
+
+    fn state_handle(&self) -> ReducerStateHandle<S> {
+        ReducerStateHandle {
+            state: self.state.clone(),
+            version: self.version.clone(),
+        }
+    }
+
+    fn dispatch_fn(self: &Arc<Self>) -> DispatchFn<A> {
+        let container = self.clone();
+        DispatchFn::new(move |action| container.dispatch(action))
+    }
+}
+
+/// Reducer hook in the style of the Elm architecture
+///
+/// Like [`use_reducer`], but the reducer returns the effects to run along
+/// with the next state. Each dispatch commits the new state first and then
+/// runs the effects returned for it, in order and exactly once. Actions
+/// dispatched by an effect are handled right away, before the remaining
+/// effects run. Keeping effects out of the reducer keeps it a pure function
+/// that is easy to test.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::reducer::{DispatchFn, Effect, use_reducer_with_effects};
+/// #[derive(Clone)]
+/// struct Search {
+///     query: String,
+///     results: Vec<String>,
+/// }
+///
+/// enum Action {
+///     SetQuery(String),
+///     ResultsLoaded(Vec<String>),
+/// }
+///
+/// fn reducer(state: Search, action: Action) -> (Search, Vec<Effect<Action>>) {
+///     match action {
+///         Action::SetQuery(query) => {
+///             let lookup = query.clone();
+///             let fetch: Effect<Action> = Box::new(move |dispatch: &DispatchFn<Action>| {
+///                 let results = vec![format!("{lookup} result")];
+///                 dispatch.dispatch(Action::ResultsLoaded(results));
+///             });
+///             (Search { query, ..state }, vec![fetch])
+///         }
+///         Action::ResultsLoaded(results) => (Search { results, ..state }, vec![]),
+///     }
+/// }
+///
+/// // In a component context:
+/// let initial = Search { query: String::new(), results: vec![] };
+/// let (search, dispatch) = use_reducer_with_effects(reducer, initial);
+/// dispatch.dispatch(Action::SetQuery("rust".to_string()));
+/// ```
+pub fn use_reducer_with_effects<S, A, R>(
+    reducer: R,
+    initial_state: S,
+) -> (ReducerStateHandle<S>, DispatchFn<A>)
+where
+    S: Clone + Send + Sync + 'static,
+    A: Send + Sync + 'static,
+    R: Fn(S, A) -> (S, Vec<Effect<A>>) + Send + Sync + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let container_ref = ctx.get_or_init_state(index, || {
+            Arc::new(EffectReducerContainer {
+                state: Arc::new(RwLock::new(initial_state)),
+                version: Arc::new(Mutex::new(0)),
+                reducer: Arc::new(reducer) as Arc<EffectReducer<S, A>>,
+                owner: ctx.state_changes(),
+            })
+        });
+
+        let container = container_ref.borrow().clone();
+        (container.state_handle(), container.dispatch_fn())
+    })
+}
+
+/// An async side effect returned by a reducer of [`use_async_reducer`]
+///
+/// Resolves to the actions to dispatch once the effect completed.
+pub type AsyncEffect<A> = Pin<Box<dyn Future<Output = Vec<A>> + Send>>;
+
+/// Reducer returning the next state and the async effects to queue
+type AsyncEffectReducer<S, A> = dyn Fn(S, A) -> (S, Vec<AsyncEffect<A>>) + Send + Sync;
+
+/// The async effects of a [`use_async_reducer`] waiting to complete
+///
+/// Effects run one at a time in the order they were queued, so this also
+/// tells how long until the latest dispatched action has been fully handled.
+#[derive(Debug, Clone, Default)]
+pub struct ActionQueue {
+    depth: Arc<AtomicUsize>,
+    error: Arc<Mutex<Option<String>>>,
+}
+
+impl ActionQueue {
+    /// Number of effects running or waiting to run
+    pub fn depth(&self) -> usize {
+        self.depth.load(Ordering::SeqCst)
+    }
+
+    /// Returns true if no effect is running or waiting to run
+    pub fn is_idle(&self) -> bool {
+        self.depth() == 0
+    }
+
+    /// Why the effects of the latest action returning any were not queued
+    pub fn error(&self) -> Option<String> {
+        self.error.lock().clone()
+    }
+}
+
+/// Takes one effect off an [`ActionQueue`] when dropped, even on panic
+struct QueueSlot<'a>(&'a ActionQueue);
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.0.depth.fetch_sub(1, Ordering::SeqCst);
+        crate::hooks::batch::request_render();
+    }
+}
+
+/// Queued effects of an async reducer
+struct PendingEffects<A> {
+    effects: VecDeque<AsyncEffect<A>>,
+    /// Whether an effect is running, which starts the next one when done
+    running: bool,
+}
+
+/// Starts the next queued effect when dropped, even if the running one panicked
+struct StartNext<S, A>(Weak<AsyncReducerContainer<S, A>>)
+where
+    S: Clone + Send + Sync + 'static,
+    A: Send + Sync + 'static;
+
+impl<S, A> Drop for StartNext<S, A>
+where
+    S: Clone + Send + Sync + 'static,
+    A: Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        if let Some(container) = self.0.upgrade() {
+            container.start_next();
+        }
+    }
+}
+
+/// Internal container for a reducer with async effects
+struct AsyncReducerContainer<S, A> {
+    state: Arc<RwLock<S>>,
+    version: Arc<Mutex<u64>>,
+    reducer: Arc<AsyncEffectReducer<S, A>>,
+    /// Change counter of the hook context that owns this reducer
+    owner: Arc<AtomicU64>,
+    queue: ActionQueue,
+    pending: Mutex<PendingEffects<A>>,
+}
+
+impl<S, A> AsyncReducerContainer<S, A>
+where
+    S: Clone + Send + Sync + 'static,
+    A: Send + Sync + 'static,
+{
+    fn new(
+        initial_state: S,
+        reducer: Arc<AsyncEffectReducer<S, A>>,
+        owner: Arc<AtomicU64>,
+    ) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(initial_state)),
+            version: Arc::new(Mutex::new(0)),
+            reducer,
+            owner,
+            queue: ActionQueue::default(),
+            pending: Mutex::new(PendingEffects {
+                effects: VecDeque::new(),
+                running: false,
+            }),
+        }
+    }
+
+    /// Reduce `action`, commit the new state, then queue the returned effects
+    fn dispatch(self: &Arc<Self>, action: A) {
+        // Held across the reduce so concurrent dispatches cannot lose updates
+        let effects = {
+            let mut state = self.state.write();
+            let (new_state, effects) = (self.reducer)(state.clone(), action);
+            *state = new_state;
+            *self.version.lock() += 1;
+            effects
+        };
+        self.owner.fetch_add(1, Ordering::SeqCst);
+        crate::hooks::batch::request_render();
+
+        if effects.is_empty() {
+            return;
+        }
+
+        // The effects could never run, so they are dropped and reported
+        if tokio::runtime::Handle::try_current().is_err() {
+            *self.queue.error.lock() = Some(NO_RUNTIME_ERROR.to_string());
+            return;
+        }
+        *self.queue.error.lock() = None;
+
+        let start = {
+            let mut pending = self.pending.lock();
+            // Counted before queueing so a running effect never finishes them uncounted
+            self.queue.depth.fetch_add(effects.len(), Ordering::SeqCst);
+            pending.effects.extend(effects);
+            !std::mem::replace(&mut pending.running, true)
+        };
+        if start {
+            self.start_next();
+        }
+    }
+
+    /// Run the oldest queued effect on the task pool, then dispatch its actions
+    ///
+    /// Each effect is a task of its own that starts the next one once done,
+    /// so effects run one at a time and nothing holds a permit while the
+    /// queue is empty. A panicking effect only loses its actions.
+    fn start_next(self: &Arc<Self>) {
+        let effect = {
+            let mut pending = self.pending.lock();
+            let effect = pending.effects.pop_front();
+            pending.running = effect.is_some();
+            effect
+        };
+        let Some(effect) = effect else {
+            return;
+        };
+
+        let container = Arc::downgrade(self);
+        let queue = self.queue.clone();
+        spawn_limited(async move {
+            let _next = StartNext(container.clone());
+            let _slot = QueueSlot(&queue);
+
+            let actions = effect.await;
+            if let Some(container) = container.upgrade() {
+                for action in actions {
+                    container.dispatch(action);
+                }
+            }
+        });
+    }
+
+    fn state_handle(&self) -> ReducerStateHandle<S> {
+        ReducerStateHandle {
+            state: self.state.clone(),
+            version: self.version.clone(),
+        }
+    }
+
+    fn dispatch_fn(self: &Arc<Self>) -> DispatchFn<A> {
+        let container = self.clone();
+        DispatchFn::new(move |action| container.dispatch(action))
+    }
+}
+
+/// Reducer hook whose async effects run one at a time, in dispatch order
+///
+/// Like [`use_reducer_with_effects`], but the effects are futures resolving
+/// to follow-up actions. Instead of running concurrently, where a slow save
+/// could finish after a later, faster one and overwrite its result, effects
+/// are queued and each starts only once the previous one completed and its
+/// actions were dispatched. The returned [`ActionQueue`] reports how many
+/// effects are still outstanding, e.g. to show a "saving…" indicator.
+///
+/// Effects run on the Tokio runtime, sharing the hook
+/// [task pool](crate::tasks). Actions returning effects dispatched outside of
+/// a runtime still update the state, but their effects are dropped and
+/// [`ActionQueue::error`] reports the missing runtime.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::reducer::{AsyncEffect, use_async_reducer};
+/// #[derive(Clone)]
+/// struct Document {
+///     text: String,
+///     saved_revision: u32,
+///     revision: u32,
+/// }
+///
+/// enum Action {
+///     Edit(String),
+///     Saved(u32),
+/// }
+///
+/// fn reducer(doc: Document, action: Action) -> (Document, Vec<AsyncEffect<Action>>) {
+///     match action {
+///         Action::Edit(text) => {
+///             let revision = doc.revision + 1;
+///             let contents = text.clone();
+///             let save: AsyncEffect<Action> = Box::pin(async move {
+///                 tokio::fs::write("doc.txt", contents).await.ok();
+///                 vec![Action::Saved(revision)]
+///             });
+///             (Document { text, revision, ..doc }, vec![save])
+///         }
+///         Action::Saved(saved_revision) => (Document { saved_revision, ..doc }, vec![]),
+///     }
+/// }
+///
+/// // In a component context:
+/// let initial = Document { text: String::new(), saved_revision: 0, revision: 0 };
+/// let (doc, dispatch, saves) = use_async_reducer(reducer, initial);
+/// dispatch.dispatch(Action::Edit("hello".to_string()));
+/// let status = if saves.is_idle() { "saved" } else { "saving…" };
+/// ```
+pub fn use_async_reducer<S, A, R>(
+    reducer: R,
+    initial_state: S,
+) -> (ReducerStateHandle<S>, DispatchFn<A>, ActionQueue)
+where
+    S: Clone + Send + Sync + 'static,
+    A: Send + Sync + 'static,
+    R: Fn(S, A) -> (S, Vec<AsyncEffect<A>>) + Send + Sync + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let container_ref = ctx.get_or_init_state(index, || {
+            Arc::new(AsyncReducerContainer::new(
+                initial_state,
+                Arc::new(reducer) as Arc<AsyncEffectReducer<S, A>>,
+                ctx.state_changes(),
+            ))
+        });
+
+        let container = container_ref.borrow().clone();
+        (
+            container.state_handle(),
+            container.dispatch_fn(),
+            container.queue.clone(),
+        )
+    })
+}
@@ -313,3 +313,290 @@ fn test_use_reducer_version_tracking() {
         });
     });
 }
+
+#[derive(Debug)]
+enum CartAction {
+    Add(u32),
+    Saved(u32),
+}
+
+/// Test that effects run once per dispatch, after the state was committed
+#[test]
+fn test_use_reducer_with_effects_runs_effects_after_commit() {
+    use std::sync::{Arc, Mutex, OnceLock};
+
+    with_test_isolate(|| {
+        with_component_id("EffectReducerComponent", |_context| {
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let handle = Arc::new(OnceLock::<ReducerStateHandle<(u32, u32)>>::new());
+
+            let reducer = {
+                let log = log.clone();
+                let handle = handle.clone();
+                move |(total, saved): (u32, u32),
+                      action: CartAction|
+                      -> ((u32, u32), Vec<Effect<CartAction>>) {
+                    match action {
+                        CartAction::Add(amount) => {
+                            let log = log.clone();
+                            let handle = handle.clone();
+                            let save: Effect<CartAction> = Box::new(move |dispatch| {
+                                // The effect sees the committed state
+                                let (total, _) = handle.get().unwrap().get();
+                                log.lock().unwrap().push(format!("save {total}"));
+                                dispatch.dispatch(CartAction::Saved(total));
+                            });
+                            ((total + amount, saved), vec![save])
+                        }
+                        CartAction::Saved(total) => ((total, total), vec![]),
+                    }
+                }
+            };
+
+            let (state, dispatch) = use_reducer_with_effects(reducer, (0, 0));
+            handle.set(state.clone()).ok();
+
+            dispatch.dispatch(CartAction::Add(5));
+            assert_eq!(*log.lock().unwrap(), vec!["save 5"]);
+            assert_eq!(state.get(), (5, 5));
+
+            dispatch.dispatch(CartAction::Add(2));
+            assert_eq!(*log.lock().unwrap(), vec!["save 5", "save 7"]);
+            assert_eq!(state.get(), (7, 7));
+            assert_eq!(state.version(), 4);
+        });
+    });
+}
+
+/// Test that concurrent dispatches from several threads never lose an update
+#[test]
+fn test_use_reducer_with_effects_concurrent_dispatch() {
+    with_test_isolate(|| {
+        let (state, dispatch) = with_component_id("ConcurrentEffectReducer", |_context| {
+            use_reducer_with_effects(|count: u32, amount: u32| (count + amount, Vec::new()), 0)
+        });
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                let dispatch = dispatch.clone();
+                scope.spawn(move || {
+                    for _ in 0..1000 {
+                        dispatch.dispatch(1);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(state.get(), 8000);
+        assert_eq!(state.version(), 8000);
+    });
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Ledger {
+    balance: i64,
+    filter: String,
+}
+
+enum LedgerAction {
+    Deposit(i64),
+    Filter(&'static str),
+}
+
+fn ledger_reducer(state: Ledger, action: LedgerAction) -> Ledger {
+    match action {
+        LedgerAction::Deposit(amount) => Ledger {
+            balance: state.balance + amount,
+            ..state
+        },
+        LedgerAction::Filter(filter) => Ledger {
+            filter: filter.to_string(),
+            ..state
+        },
+    }
+}
+
+/// Test that a selector on one field ignores changes to another field
+#[test]
+fn test_use_reducer_selector_ignores_other_fields() {
+    with_test_isolate(|| {
+        let render = || {
+            with_component_id("SelectorComponent", |_context| {
+                let (ledger, dispatch) = use_reducer(
+                    ledger_reducer,
+                    Ledger {
+                        balance: 10,
+                        filter: String::new(),
+                    },
+                );
+                let balance = use_reducer_selector(&ledger, |ledger| ledger.balance);
+                (balance, dispatch)
+            })
+        };
+
+        let (balance, dispatch) = render();
+        assert_eq!(balance.get(), 10);
+        assert_eq!(balance.version(), 0);
+
+        dispatch.call(LedgerAction::Filter("rent"));
+        let (balance, dispatch) = render();
+        assert_eq!(balance.version(), 0);
+        assert!(!balance.changed());
+
+        dispatch.call(LedgerAction::Deposit(5));
+        let (balance, _) = render();
+        assert_eq!(balance.get(), 15);
+        assert_eq!(balance.version(), 1);
+        assert!(balance.changed());
+
+        // Nothing changed since the last render
+        let (balance, _) = render();
+        assert!(!balance.changed());
+    });
+}
+
+enum SaveAction {
+    /// Save `id`, taking `delay` to complete
+    Save(u32, u64),
+    Saved(u32),
+}
+
+/// Test that async effects apply in dispatch order even if later ones are faster
+#[tokio::test]
+async fn test_use_async_reducer_applies_effects_in_dispatch_order() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    let running = Arc::new(AtomicUsize::new(0));
+    let max_running = Arc::new(AtomicUsize::new(0));
+    let reducer = {
+        let running = running.clone();
+        let max_running = max_running.clone();
+        move |mut saved: Vec<u32>, action: SaveAction| match action {
+            SaveAction::Save(id, delay) => {
+                let running = running.clone();
+                let max_running = max_running.clone();
+                let save: AsyncEffect<SaveAction> = Box::pin(async move {
+                    let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(now_running, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    vec![SaveAction::Saved(id)]
+                });
+                (saved, vec![save])
+            }
+            SaveAction::Saved(id) => {
+                saved.push(id);
+                (saved, vec![])
+            }
+        }
+    };
+
+    let (saved, dispatch, queue) = with_test_isolate(|| {
+        with_component_id("AsyncReducerComponent", |_context| {
+            use_async_reducer(reducer, Vec::new())
+        })
+    });
+    assert!(queue.is_idle());
+
+    // The slowest save is dispatched first
+    dispatch.dispatch(SaveAction::Save(1, 40));
+    dispatch.dispatch(SaveAction::Save(2, 5));
+    dispatch.dispatch(SaveAction::Save(3, 20));
+    assert_eq!(queue.depth(), 3);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !queue.is_idle() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("queued effects should complete");
+
+    assert_eq!(saved.get(), vec![1, 2, 3]);
+    assert_eq!(max_running.load(Ordering::SeqCst), 1);
+}
+
+/// Test that a panicking effect leaves the queue usable and its depth correct
+#[tokio::test]
+async fn test_use_async_reducer_survives_a_panicking_effect() {
+    use std::time::Duration;
+
+    let reducer = |mut saved: Vec<u32>, action: SaveAction| match action {
+        SaveAction::Save(id, _) => {
+            let save: AsyncEffect<SaveAction> = Box::pin(async move {
+                if id == 0 {
+                    panic!("save failed");
+                }
+                vec![SaveAction::Saved(id)]
+            });
+            (saved, vec![save])
+        }
+        SaveAction::Saved(id) => {
+            saved.push(id);
+            (saved, vec![])
+        }
+    };
+
+    let (saved, dispatch, queue) = with_test_isolate(|| {
+        with_component_id("PanickingAsyncReducerComponent", |_context| {
+            use_async_reducer(reducer, Vec::new())
+        })
+    });
+
+    dispatch.dispatch(SaveAction::Save(0, 0));
+    dispatch.dispatch(SaveAction::Save(1, 0));
+    assert_eq!(queue.depth(), 2);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !queue.is_idle() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("the queue should drain past the panicking effect");
+
+    // Effects queued after the panic still run
+    assert_eq!(saved.get(), vec![1]);
+    dispatch.dispatch(SaveAction::Save(2, 0));
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !queue.is_idle() {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("the queue should keep running");
+    assert_eq!(saved.get(), vec![1, 2]);
+}
+
+/// Test that effects dispatched outside of a runtime are reported, not run
+#[test]
+fn test_use_async_reducer_without_runtime_reports_an_error() {
+    let reducer = |mut saved: Vec<u32>, action: SaveAction| match action {
+        SaveAction::Save(id, _) => {
+            let save: AsyncEffect<SaveAction> =
+                Box::pin(async move { vec![SaveAction::Saved(id)] });
+            (saved, vec![save])
+        }
+        SaveAction::Saved(id) => {
+            saved.push(id);
+            (saved, vec![])
+        }
+    };
+
+    let (saved, dispatch, queue) = with_test_isolate(|| {
+        with_component_id("RuntimelessAsyncReducerComponent", |_context| {
+            use_async_reducer(reducer, Vec::new())
+        })
+    });
+
+    // Actions without effects don't need a runtime
+    dispatch.dispatch(SaveAction::Saved(1));
+    assert_eq!(saved.get(), vec![1]);
+    assert_eq!(queue.error(), None);
+
+    dispatch.dispatch(SaveAction::Save(2, 0));
+    assert_eq!(saved.get(), vec![1]);
+    assert!(queue.is_idle());
+    assert_eq!(queue.error().as_deref(), Some(NO_RUNTIME_ERROR));
+}
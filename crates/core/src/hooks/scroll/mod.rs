@@ -0,0 +1,160 @@
+//! Persistent Scroll Hook
+//!
+//! This module provides a `use_persistent_scroll` hook that tracks the scroll
+//! offset of a scrollable view and remembers it per screen. Offsets are kept
+//! in an in-memory registry keyed by a caller-chosen key, so when the user
+//! navigates away and the component later remounts, the view reopens where
+//! it was left. Offsets are always clamped to the scrollable range, including
+//! after the content length or viewport changes.
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Remembered scroll offsets, keyed by screen
+static SCROLL_POSITIONS: Lazy<Mutex<HashMap<String, usize>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The remembered offset for `key`, if any
+pub fn saved_scroll_position(key: &str) -> Option<usize> {
+    SCROLL_POSITIONS.lock().get(key).copied()
+}
+
+/// Forget the remembered offset for `key`
+pub fn forget_scroll_position(key: &str) {
+    SCROLL_POSITIONS.lock().remove(key);
+}
+
+fn save_scroll_position(key: &str, offset: usize) {
+    SCROLL_POSITIONS.lock().insert(key.to_string(), offset);
+}
+
+/// Handle returned by [`use_persistent_scroll`]
+#[derive(Clone)]
+pub struct ScrollHandle {
+    key: String,
+    offset: StateHandle<usize>,
+    setter: StateSetter<usize>,
+    content_len: usize,
+    viewport: usize,
+}
+
+impl ScrollHandle {
+    /// The current offset of the first visible line
+    pub fn offset(&self) -> usize {
+        self.offset.get().min(self.max_offset())
+    }
+
+    /// The largest offset that still fills the viewport
+    pub fn max_offset(&self) -> usize {
+        self.content_len.saturating_sub(self.viewport)
+    }
+
+    /// The `(start, end)` range of visible lines, `end` exclusive
+    pub fn visible_range(&self) -> (usize, usize) {
+        let start = self.offset();
+        (start, (start + self.viewport).min(self.content_len))
+    }
+
+    /// Returns true if the view can scroll further down
+    pub fn can_scroll_down(&self) -> bool {
+        self.offset() < self.max_offset()
+    }
+
+    /// Returns true if the view can scroll further up
+    pub fn can_scroll_up(&self) -> bool {
+        self.offset() > 0
+    }
+
+    /// Scroll to `offset`, clamped to the scrollable range
+    pub fn scroll_to(&self, offset: usize) {
+        let offset = offset.min(self.max_offset());
+        save_scroll_position(&self.key, offset);
+        self.setter.set(offset);
+    }
+
+    /// Scroll down by `lines`
+    pub fn scroll_down(&self, lines: usize) {
+        self.scroll_to(self.offset().saturating_add(lines));
+    }
+
+    /// Scroll up by `lines`
+    pub fn scroll_up(&self, lines: usize) {
+        self.scroll_to(self.offset().saturating_sub(lines));
+    }
+
+    /// Scroll down by one viewport
+    pub fn page_down(&self) {
+        self.scroll_down(self.viewport.max(1));
+    }
+
+    /// Scroll up by one viewport
+    pub fn page_up(&self) {
+        self.scroll_up(self.viewport.max(1));
+    }
+
+    /// Scroll to the first line
+    pub fn to_top(&self) {
+        self.scroll_to(0);
+    }
+
+    /// Scroll to the last page
+    pub fn to_bottom(&self) {
+        self.scroll_to(self.max_offset());
+    }
+}
+
+/// Hook that tracks a scroll offset and remembers it under `key`
+///
+/// `content_len` is the number of lines of content and `viewport` the number
+/// of visible lines. On mount the offset is restored from the last offset
+/// saved under `key`; if `key` changes while mounted, the offset switches to
+/// the one saved for the new key.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::scroll::use_persistent_scroll;
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// # let lines: Vec<String> = Vec::new();
+/// # let height = 20;
+/// // In a component context:
+/// let scroll = use_persistent_scroll("logs", lines.len(), height);
+///
+/// if let Some(key) = use_key_event() {
+///     match key.code {
+///         KeyCode::Down => scroll.scroll_down(1),
+///         KeyCode::Up => scroll.scroll_up(1),
+///         KeyCode::PageDown => scroll.page_down(),
+///         KeyCode::PageUp => scroll.page_up(),
+///         _ => {}
+///     }
+/// }
+///
+/// let (start, end) = scroll.visible_range();
+/// let visible = &lines[start..end];
+/// ```
+pub fn use_persistent_scroll(key: &str, content_len: usize, viewport: usize) -> ScrollHandle {
+    let (current_key, set_current_key) = use_state(|| key.to_string());
+    let (offset, setter) = use_state(|| saved_scroll_position(key).unwrap_or(0));
+
+    if current_key.get() != key {
+        set_current_key.set(key.to_string());
+        setter.set(saved_scroll_position(key).unwrap_or(0));
+    }
+
+    ScrollHandle {
+        key: key.to_string(),
+        offset,
+        setter,
+        content_len,
+        viewport,
+    }
+}
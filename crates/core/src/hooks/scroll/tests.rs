@@ -0,0 +1,91 @@
+//! Tests for the use_persistent_scroll hook
+
+use super::*;
+use crate::hooks::test_utils::{with_hook_context, with_test_isolate};
+
+#[test]
+fn test_scrolling_is_clamped() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let scroll = use_persistent_scroll("scroll_clamped", 50, 10);
+            assert_eq!(scroll.offset(), 0);
+            assert!(!scroll.can_scroll_up());
+
+            scroll.scroll_up(3);
+            assert_eq!(scroll.offset(), 0);
+
+            scroll.page_down();
+            assert_eq!(scroll.offset(), 10);
+            assert_eq!(scroll.visible_range(), (10, 20));
+
+            scroll.scroll_down(100);
+            assert_eq!(scroll.offset(), 40);
+            assert!(!scroll.can_scroll_down());
+
+            scroll.to_top();
+            assert_eq!(scroll.offset(), 0);
+        });
+    });
+}
+
+#[test]
+fn test_navigating_back_restores_offset() {
+    with_test_isolate(|| {
+        with_hook_context(|_| {
+            let scroll = use_persistent_scroll("scroll_restore_list", 100, 20);
+            scroll.scroll_down(37);
+        });
+
+        // Another screen is shown in between
+        with_hook_context(|_| {
+            let other = use_persistent_scroll("scroll_restore_details", 100, 20);
+            assert_eq!(other.offset(), 0);
+            other.to_bottom();
+        });
+
+        // The list remounts with its previous offset
+        with_hook_context(|_| {
+            let scroll = use_persistent_scroll("scroll_restore_list", 100, 20);
+            assert_eq!(scroll.offset(), 37);
+        });
+        assert_eq!(saved_scroll_position("scroll_restore_details"), Some(80));
+    });
+}
+
+#[test]
+fn test_key_change_switches_offset() {
+    with_test_isolate(|| {
+        with_hook_context(|ctx| {
+            let first = use_persistent_scroll("scroll_switch_a", 30, 10);
+            first.scroll_to(5);
+
+            ctx.reset_hook_index();
+            let second = use_persistent_scroll("scroll_switch_b", 30, 10);
+            assert_eq!(second.offset(), 0);
+            second.scroll_to(12);
+
+            ctx.reset_hook_index();
+            let back = use_persistent_scroll("scroll_switch_a", 30, 10);
+            assert_eq!(back.offset(), 5);
+        });
+    });
+}
+
+#[test]
+fn test_shrinking_content_clamps_offset() {
+    with_test_isolate(|| {
+        with_hook_context(|ctx| {
+            let scroll = use_persistent_scroll("scroll_shrink", 100, 10);
+            scroll.to_bottom();
+            assert_eq!(scroll.offset(), 90);
+
+            ctx.reset_hook_index();
+            let scroll = use_persistent_scroll("scroll_shrink", 15, 10);
+            assert_eq!(scroll.offset(), 5);
+            assert_eq!(scroll.visible_range(), (5, 15));
+
+            forget_scroll_position("scroll_shrink");
+            assert_eq!(saved_scroll_position("scroll_shrink"), None);
+        });
+    });
+}
@@ -0,0 +1,337 @@
+//! Search Hook
+//!
+//! This module provides a `use_search` hook, the core of picker-style UIs: it
+//! manages the query text, debounces it so filtering doesn't run on every
+//! keystroke, fuzzy-filters and ranks items, and reports which characters
+//! matched so they can be highlighted. Items are only matched again when the
+//! applied query or the search keys of the items change.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use crate::clock;
+use crate::hooks::batch::{Priority, schedule_render};
+use crate::hooks::state::{StateSetter, use_state};
+use crate::hooks::with_hook_context;
+
+#[cfg(test)]
+mod tests;
+
+/// Default debounce applied to query changes before filtering
+pub const DEFAULT_SEARCH_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Result of matching a query against a candidate string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Relevance score; higher is better
+    pub score: i64,
+    /// Character indices (not byte offsets) of the matched characters
+    pub indices: Vec<usize>,
+}
+
+/// Fuzzy-match `query` against `candidate`
+///
+/// Every query character must appear in the candidate in order
+/// (case-insensitively). Consecutive matches and matches at the start of a
+/// word score higher, gaps and late first matches score lower. Whitespace in
+/// the query is ignored. An empty query matches everything with a score of 0.
+///
+/// # Example
+/// ```
+/// use pulse_core::hooks::search::fuzzy_match;
+///
+/// let m = fuzzy_match("cfg", "config.toml").unwrap();
+/// assert_eq!(m.indices, vec![0, 3, 5]);
+/// assert!(fuzzy_match("xyz", "config.toml").is_none());
+/// ```
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let needle: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if needle.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            indices: Vec::new(),
+        });
+    }
+
+    let haystack: Vec<char> = candidate.chars().collect();
+    let mut indices: Vec<usize> = Vec::with_capacity(needle.len());
+    let mut score = 0i64;
+    let mut next = 0;
+
+    for (position, c) in haystack.iter().enumerate() {
+        if next == needle.len() {
+            break;
+        }
+        if !c.to_lowercase().eq(std::iter::once(needle[next])) {
+            continue;
+        }
+
+        score += 10;
+        let word_start = position == 0
+            || matches!(haystack[position - 1], ' ' | '_' | '-' | '.' | '/' | '\\')
+            || (haystack[position - 1].is_lowercase() && c.is_uppercase());
+        if word_start {
+            score += 8;
+        }
+        match indices.last() {
+            Some(&previous) if previous + 1 == position => score += 15,
+            Some(&previous) => score -= (position - previous - 1).min(10) as i64,
+            None => score -= position.min(15) as i64,
+        }
+
+        indices.push(position);
+        next += 1;
+    }
+
+    if next < needle.len() {
+        return None;
+    }
+
+    // Prefer shorter candidates among otherwise equal matches
+    score -= (haystack.len() / 8) as i64;
+    Some(FuzzyMatch { score, indices })
+}
+
+/// An item that matched the search query
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult<T> {
+    /// The matched item
+    pub item: T,
+    /// Index of the item in the searched list
+    pub index: usize,
+    /// Relevance score; results are sorted by descending score
+    pub score: i64,
+    /// Character indices of the matched characters in the item's key
+    pub matched_indices: Vec<usize>,
+}
+
+/// Query text and its debounced counterpart
+#[derive(Debug, Clone)]
+struct SearchQuery {
+    text: String,
+    applied: String,
+    changed_at: Instant,
+}
+
+/// An item that matched, by its position in the searched list
+struct CachedMatch {
+    index: usize,
+    score: i64,
+    matched_indices: Vec<usize>,
+}
+
+/// Matches cached for a single hook
+struct SearchCache {
+    query: String,
+    /// Number of items and hash of their keys when they were matched
+    fingerprint: (usize, u64),
+    matches: Vec<CachedMatch>,
+}
+
+/// Fingerprint of the search keys, telling when the items must be matched again
+fn fingerprint(keys: &[String]) -> (usize, u64) {
+    let mut hasher = DefaultHasher::new();
+    keys.hash(&mut hasher);
+    (keys.len(), hasher.finish())
+}
+
+/// Handle returned by [`use_search`]
+#[derive(Clone)]
+pub struct SearchHandle<T> {
+    query: String,
+    applied_query: String,
+    results: Rc<[SearchResult<T>]>,
+    setter: StateSetter<SearchQuery>,
+}
+
+impl<T> SearchHandle<T> {
+    /// The query text as typed
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// The query the current results were filtered with (after debouncing)
+    pub fn applied_query(&self) -> &str {
+        &self.applied_query
+    }
+
+    /// Returns true while a typed query has not been applied yet
+    pub fn is_pending(&self) -> bool {
+        self.query != self.applied_query
+    }
+
+    /// The filtered items, best match first
+    pub fn results(&self) -> &[SearchResult<T>] {
+        &self.results
+    }
+
+    /// Replace the query text
+    pub fn set_query(&self, query: impl Into<String>) {
+        let query = query.into();
+        self.edit_query(|text| *text = query);
+    }
+
+    /// Append a typed character to the query
+    pub fn push_char(&self, c: char) {
+        self.edit_query(|text| text.push(c));
+    }
+
+    /// Remove the last character of the query
+    pub fn pop_char(&self) {
+        self.edit_query(|text| {
+            text.pop();
+        });
+    }
+
+    /// Edit the latest query text in place and restart the debounce
+    fn edit_query(&self, edit: impl FnOnce(&mut String)) {
+        self.setter.update(move |current| {
+            let mut text = current.text.clone();
+            edit(&mut text);
+            SearchQuery {
+                text,
+                applied: current.applied.clone(),
+                changed_at: clock::now(),
+            }
+        });
+    }
+
+    /// Clear the query, restoring the full list immediately
+    pub fn clear(&self) {
+        self.setter.set(SearchQuery {
+            text: String::new(),
+            applied: String::new(),
+            changed_at: clock::now(),
+        });
+    }
+}
+
+/// Hook that fuzzy-searches `items` by the string returned from `key_fn`
+///
+/// `key_fn` runs for every item on each render; the items are only matched
+/// again when a key or the applied query changes. Results always hold the
+/// current items. Uses [`DEFAULT_SEARCH_DEBOUNCE`]; see
+/// [`use_search_with_debounce`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::search::use_search;
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// # use pulse_core::hooks::state::use_state;
+/// // In a component context:
+/// let (files, _) = use_state(|| vec!["src/main.rs".to_string(), "Cargo.toml".to_string()]);
+/// let search = use_search(&files.get(), |file| file.clone());
+///
+/// if let Some(key) = use_key_event() {
+///     match key.code {
+///         KeyCode::Char(c) => search.push_char(c),
+///         KeyCode::Backspace => search.pop_char(),
+///         KeyCode::Esc => search.clear(),
+///         _ => {}
+///     }
+/// }
+///
+/// for result in search.results() {
+///     // Render result.item, highlighting result.matched_indices
+/// }
+/// ```
+pub fn use_search<T, F>(items: &[T], key_fn: F) -> SearchHandle<T>
+where
+    T: Clone + 'static,
+    F: Fn(&T) -> String,
+{
+    use_search_with_debounce(items, key_fn, DEFAULT_SEARCH_DEBOUNCE)
+}
+
+/// Hook that fuzzy-searches `items`, applying query changes after `debounce`
+///
+/// Typed characters update [`SearchHandle::query`] immediately, but results
+/// are only re-filtered once the query has been stable for `debounce`. While
+/// a query is pending, every render requests a low-priority render, so it is
+/// applied on the first frame after the debounce period without further
+/// input.
+pub fn use_search_with_debounce<T, F>(items: &[T], key_fn: F, debounce: Duration) -> SearchHandle<T>
+where
+    T: Clone + 'static,
+    F: Fn(&T) -> String,
+{
+    let (query, setter) = use_state(|| SearchQuery {
+        text: String::new(),
+        applied: String::new(),
+        changed_at: clock::now(),
+    });
+
+    let mut current = query.get();
+    if current.text != current.applied {
+        if clock::now().duration_since(current.changed_at) >= debounce {
+            current.applied = current.text.clone();
+            let applied = current.applied.clone();
+            setter.update(move |latest| SearchQuery {
+                applied,
+                ..latest.clone()
+            });
+        } else {
+            schedule_render(Priority::Low);
+        }
+    }
+
+    let keys: Vec<String> = items.iter().map(key_fn).collect();
+    let fingerprint = fingerprint(&keys);
+
+    let results = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let cache = ctx.get_or_init_state(index, || None::<SearchCache>);
+        let mut cache = cache.borrow_mut();
+
+        let stale = cache.as_ref().is_none_or(|cached| {
+            cached.fingerprint != fingerprint || cached.query != current.applied
+        });
+        if stale {
+            let mut matches: Vec<CachedMatch> = keys
+                .iter()
+                .enumerate()
+                .filter_map(|(index, key)| {
+                    fuzzy_match(&current.applied, key).map(|m| CachedMatch {
+                        index,
+                        score: m.score,
+                        matched_indices: m.indices,
+                    })
+                })
+                .collect();
+            // Stable sort keeps the original order among equal scores
+            matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+
+            *cache = Some(SearchCache {
+                query: current.applied.clone(),
+                fingerprint,
+                matches,
+            });
+        }
+
+        let matches = cache.as_ref().map_or(&[][..], |cached| &cached.matches[..]);
+        matches
+            .iter()
+            .map(|m| SearchResult {
+                item: items[m.index].clone(),
+                index: m.index,
+                score: m.score,
+                matched_indices: m.matched_indices.clone(),
+            })
+            .collect::<Rc<[_]>>()
+    });
+
+    SearchHandle {
+        query: current.text,
+        applied_query: current.applied,
+        results,
+        setter,
+    }
+}
@@ -0,0 +1,162 @@
+//! Tests for the use_search hook and fuzzy matching
+
+use super::*;
+use crate::clock::ManualClock;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn files() -> Vec<String> {
+    [
+        "src/main.rs",
+        "src/lib.rs",
+        "Cargo.toml",
+        "README.md",
+        "src/hooks/state.rs",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+fn search(debounce: Duration) -> SearchHandle<String> {
+    with_component_id("SearchComponent", |_| {
+        use_search_with_debounce(&files(), |file| file.clone(), debounce)
+    })
+}
+
+fn names(handle: &SearchHandle<String>) -> Vec<&str> {
+    handle.results().iter().map(|r| r.item.as_str()).collect()
+}
+
+#[test]
+fn test_fuzzy_match_requires_ordered_subsequence() {
+    assert!(fuzzy_match("mrs", "src/main.rs").is_some());
+    assert!(fuzzy_match("srm", "src/main.rs").is_some());
+    assert!(fuzzy_match("zz", "src/main.rs").is_none());
+    assert!(fuzzy_match("CARGO", "Cargo.toml").is_some());
+    assert_eq!(fuzzy_match("", "anything").unwrap().score, 0);
+}
+
+#[test]
+fn test_fuzzy_match_ranks_consecutive_and_word_starts() {
+    let prefix = fuzzy_match("lib", "lib.rs").unwrap();
+    let scattered = fuzzy_match("lib", "long_list_bits").unwrap();
+    assert!(prefix.score > scattered.score);
+
+    let word_start = fuzzy_match("ms", "src/main.rs").unwrap();
+    assert_eq!(word_start.indices, vec![4, 10]);
+    let exact_word = fuzzy_match("main", "src/main.rs").unwrap();
+    let inner = fuzzy_match("ain", "src/main.rs").unwrap();
+    assert!(exact_word.score > inner.score);
+
+    let camel = fuzzy_match("us", "useState").unwrap();
+    assert_eq!(camel.indices, vec![0, 1]);
+}
+
+#[test]
+fn test_typing_narrows_results_and_clearing_restores() {
+    with_test_isolate(|| {
+        let handle = search(Duration::ZERO);
+        assert_eq!(handle.results().len(), 5);
+
+        handle.push_char('s');
+        handle.push_char('r');
+        let handle = search(Duration::ZERO);
+        assert_eq!(handle.query(), "sr");
+        assert!(handle.results().len() < 5);
+        assert!(names(&handle).contains(&"src/main.rs"));
+        assert!(!names(&handle).contains(&"Cargo.toml"));
+
+        handle.push_char('c');
+        handle.push_char('m');
+        let handle = search(Duration::ZERO);
+        assert_eq!(names(&handle), vec!["src/main.rs"]);
+        assert_eq!(handle.results()[0].matched_indices, vec![0, 1, 2, 4]);
+        assert_eq!(handle.results()[0].index, 0);
+
+        handle.clear();
+        let handle = search(Duration::ZERO);
+        assert_eq!(handle.query(), "");
+        assert_eq!(names(&handle), names(&search(Duration::ZERO)));
+        assert_eq!(handle.results().len(), 5);
+        assert_eq!(handle.results()[0].item, "src/main.rs");
+    });
+}
+
+#[test]
+fn test_query_is_debounced() {
+    with_test_isolate(|| {
+        let clock = ManualClock::start();
+        let debounce = Duration::from_millis(50);
+        let handle = search(debounce);
+
+        handle.set_query("toml");
+        let handle = search(debounce);
+        assert!(handle.is_pending());
+        assert_eq!(handle.applied_query(), "");
+        assert_eq!(handle.results().len(), 5);
+
+        clock.advance(Duration::from_millis(49));
+        assert!(search(debounce).is_pending());
+
+        clock.advance(Duration::from_millis(1));
+        let handle = search(debounce);
+        assert!(!handle.is_pending());
+        assert_eq!(names(&handle), vec!["Cargo.toml"]);
+
+        // Clearing bypasses the debounce
+        handle.clear();
+        assert_eq!(search(debounce).results().len(), 5);
+    });
+}
+
+#[test]
+fn test_pop_char_widens_results() {
+    with_test_isolate(|| {
+        let handle = search(Duration::ZERO);
+        handle.set_query("readmex");
+        let handle = search(Duration::ZERO);
+        assert!(handle.results().is_empty());
+
+        handle.pop_char();
+        let handle = search(Duration::ZERO);
+        assert_eq!(names(&handle), vec!["README.md"]);
+    });
+}
+
+/// A file with a size that isn't part of its search key
+#[derive(Debug, Clone, PartialEq)]
+struct File {
+    path: &'static str,
+    size: u64,
+}
+
+#[test]
+fn test_results_follow_item_changes() {
+    with_test_isolate(|| {
+        let search = |items: &[File]| {
+            with_component_id("ChangingItems", |_| {
+                use_search_with_debounce(items, |file| file.path.to_string(), Duration::ZERO)
+            })
+        };
+        let file = |path, size| File { path, size };
+        let mut items = vec![file("src/main.rs", 1), file("Cargo.toml", 2)];
+
+        let handle = search(&items);
+        handle.set_query("rs");
+        assert_eq!(search(&items).results().len(), 1);
+
+        // New, renamed and removed items are matched again
+        items.push(file("src/lib.rs", 3));
+        assert_eq!(search(&items).results().len(), 2);
+        items[1] = file("build.rs", 2);
+        assert_eq!(search(&items).results().len(), 3);
+        items.remove(0);
+        let handle = search(&items);
+        assert_eq!(handle.results()[0].item, file("build.rs", 2));
+        assert_eq!(handle.results()[1].index, 1);
+
+        // Results carry the current items even when their keys are unchanged
+        items[1].size = 30;
+        assert_eq!(search(&items).results()[1].item, file("src/lib.rs", 30));
+    });
+}
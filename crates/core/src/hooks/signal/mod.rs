@@ -45,6 +45,16 @@ impl<T> GlobalSignalContainer<T> {
         self.state.set(new_value);
     }
 
+    /// Set a new value only if it differs from the current one
+    ///
+    /// Setting an equal value is a no-op, so subscribers don't re-render.
+    pub fn set_if_changed(&self, new_value: T)
+    where
+        T: PartialEq,
+    {
+        self.state.set_if_changed(new_value);
+    }
+
     /// Update the value using a function and increment the version
     pub fn update<F>(&self, updater: F)
     where
@@ -92,6 +102,14 @@ impl<T> SignalHandle<T> {
         self.container.set(new_value);
     }
 
+    /// Set a new value; setting an equal value is a no-op
+    pub fn set_if_changed(&self, new_value: T)
+    where
+        T: PartialEq,
+    {
+        self.container.set_if_changed(new_value);
+    }
+
     /// Update the value using a function
     pub fn update<F>(&self, updater: F)
     where
@@ -284,6 +302,14 @@ where
         self.handle().set(new_value);
     }
 
+    /// Set a new value; setting an equal value is a no-op
+    pub fn set_if_changed(&self, new_value: T)
+    where
+        T: PartialEq,
+    {
+        self.handle().set_if_changed(new_value);
+    }
+
     /// Update the value using a function
     pub fn update<F>(&self, updater: F)
     where
@@ -399,6 +425,39 @@ impl Signal {
     }
 }
 
+/// Declare statically typed global signals
+///
+/// Expands to a `static` [`GlobalSignal`] per declaration. Each signal is
+/// keyed by its static rather than by a string, so it can be used anywhere
+/// without lookups, and its type is fixed at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use pulse_core::global_signal;
+///
+/// global_signal! {
+///     /// Number of unread messages
+///     pub UNREAD: u32 = 0;
+///     USER_NAME: String = "guest".to_string();
+/// }
+///
+/// UNREAD.set(3);
+/// UNREAD.update(|count| count + 1);
+/// assert_eq!(UNREAD.get(), 4);
+/// assert_eq!(USER_NAME.get(), "guest");
+/// ```
+#[macro_export]
+macro_rules! global_signal {
+    ($($(#[$attr:meta])* $vis:vis $name:ident : $ty:ty = $init:expr);+ $(;)?) => {
+        $(
+            $(#[$attr])*
+            $vis static $name: $crate::hooks::signal::GlobalSignal<$ty> =
+                $crate::hooks::signal::GlobalSignal::new(|| $init);
+        )+
+    };
+}
+
 /// Hook to use a global signal within a component context
 ///
 /// This function provides a way to use global signals within components while
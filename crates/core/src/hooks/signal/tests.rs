@@ -669,3 +669,50 @@ fn test_reset_with_complex_state() {
     assert_eq!(signal.get().counter, 0);
     assert_eq!(signal.get().message, "Initial");
 }
+
+crate::global_signal! {
+    /// Shared by the components of the macro test
+    MACRO_COUNTER: i32 = 0;
+    MACRO_LABEL: String = "items".to_string();
+}
+
+#[test]
+fn test_global_signal_macro_shares_typed_value() {
+    let _guard = TEST_MUTEX.lock();
+    MACRO_COUNTER.reset();
+
+    // One component writes, another one reads the same signal
+    with_component_id("MacroWriter", |_| {
+        use_global_signal(&MACRO_COUNTER).update(|count| count + 5);
+    });
+    let (count, label) = with_component_id("MacroReader", |_| {
+        let count: i32 = use_global_signal(&MACRO_COUNTER).get();
+        (count, MACRO_LABEL.get())
+    });
+
+    assert_eq!(count, 5);
+    assert_eq!(label, "items");
+    assert_eq!(MACRO_COUNTER.get(), 5);
+    assert_ne!(MACRO_COUNTER.id(), MACRO_LABEL.id());
+    MACRO_COUNTER.reset();
+}
+
+#[test]
+fn test_setting_equal_value_does_not_notify() {
+    static NAME: GlobalSignal<String> = Signal::global(|| "guest".to_string());
+    let handle = NAME.handle();
+
+    // An equal value leaves the version alone, so nothing re-renders
+    let version = handle.version();
+    handle.set_if_changed("guest".to_string());
+    NAME.set_if_changed("guest".to_string());
+    assert_eq!(handle.version(), version);
+
+    handle.set_if_changed("admin".to_string());
+    assert_eq!(handle.version(), version + 1);
+    assert_eq!(NAME.get(), "admin");
+
+    // Plain set notifies even without a change
+    NAME.set("admin".to_string());
+    assert_eq!(handle.version(), version + 2);
+}
@@ -0,0 +1,213 @@
+//! Sort Hook
+//!
+//! This module provides a `use_sort` hook that keeps the sort state of a list
+//! view: which of the available sort keys is active and in which direction.
+//! The items are re-sorted on every render, so the view always reflects the
+//! latest items. Sorting is stable in both directions, keeping items that
+//! compare equal in their original order.
+
+use std::cmp::Ordering;
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// A named way of ordering items, passed to [`use_sort`]
+#[derive(Debug)]
+pub struct SortKey<T> {
+    name: &'static str,
+    compare: fn(&T, &T) -> Ordering,
+}
+
+impl<T> SortKey<T> {
+    /// Create a sort key ordering items ascending by `compare`
+    pub fn new(name: &'static str, compare: fn(&T, &T) -> Ordering) -> Self {
+        Self { name, compare }
+    }
+
+    /// The name shown for this key, e.g. in a column header
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T> Clone for SortKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SortKey<T> {}
+
+/// Direction of a sort
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Smallest first
+    Ascending,
+    /// Largest first
+    Descending,
+}
+
+impl SortDirection {
+    /// The other direction
+    pub fn reversed(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+
+    /// Arrow indicating the direction, for column headers
+    pub fn arrow(self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "▲",
+            SortDirection::Descending => "▼",
+        }
+    }
+}
+
+/// Active sort key index and direction; `None` keeps the original order
+type SortState = Option<(usize, SortDirection)>;
+
+/// Handle returned by [`use_sort`]
+#[derive(Clone)]
+pub struct SortHandle {
+    state: StateHandle<SortState>,
+    setter: StateSetter<SortState>,
+    names: Vec<&'static str>,
+}
+
+impl SortHandle {
+    /// Index of the active sort key, `None` while unsorted
+    pub fn key_index(&self) -> Option<usize> {
+        self.current().map(|(index, _)| index)
+    }
+
+    /// Name of the active sort key, `None` while unsorted
+    pub fn key(&self) -> Option<&'static str> {
+        self.key_index().map(|index| self.names[index])
+    }
+
+    /// Direction of the active sort, `None` while unsorted
+    pub fn direction(&self) -> Option<SortDirection> {
+        self.current().map(|(_, direction)| direction)
+    }
+
+    /// Short description such as `"Date ▼"`, or `"unsorted"`
+    pub fn label(&self) -> String {
+        match self.current() {
+            Some((index, direction)) => format!("{} {}", self.names[index], direction.arrow()),
+            None => "unsorted".to_string(),
+        }
+    }
+
+    /// Step to the next sort
+    ///
+    /// Goes through every key ascending and then descending, in the order
+    /// the keys were given, and back to the original order after the last.
+    pub fn cycle(&self) {
+        let keys = self.names.len();
+        let next = match self.current() {
+            None => (keys > 0).then_some((0, SortDirection::Ascending)),
+            Some((index, SortDirection::Ascending)) => Some((index, SortDirection::Descending)),
+            Some((index, SortDirection::Descending)) => {
+                (index + 1 < keys).then_some((index + 1, SortDirection::Ascending))
+            }
+        };
+        self.setter.set(next);
+    }
+
+    /// Sort by the key at `index`
+    ///
+    /// Like clicking a column header: picking the active key again reverses
+    /// the direction, while another key starts ascending. Out of range
+    /// indices are ignored.
+    pub fn sort_by(&self, index: usize) {
+        if index >= self.names.len() {
+            return;
+        }
+        let next = match self.current() {
+            Some((active, direction)) if active == index => (index, direction.reversed()),
+            _ => (index, SortDirection::Ascending),
+        };
+        self.setter.set(Some(next));
+    }
+
+    /// Reverse the direction of the active sort
+    pub fn toggle_direction(&self) {
+        if let Some((index, direction)) = self.current() {
+            self.setter.set(Some((index, direction.reversed())));
+        }
+    }
+
+    /// Go back to the original order
+    pub fn clear(&self) {
+        self.setter.set(None);
+    }
+
+    /// The stored sort, ignoring keys that no longer exist
+    fn current(&self) -> SortState {
+        self.state
+            .get()
+            .filter(|(index, _)| *index < self.names.len())
+    }
+}
+
+/// Hook that sorts `items` by one of `keys`, keeping the choice across renders
+///
+/// Returns the sorted items and a [`SortHandle`] to change the sort. Items
+/// start in their original order. Items comparing equal keep their original
+/// order, also when sorting descending.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::sort::{SortKey, use_sort};
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// #[derive(Clone)]
+/// struct Expense {
+///     description: String,
+///     amount: f64,
+/// }
+///
+/// # let expenses: Vec<Expense> = Vec::new();
+/// // In a component context:
+/// let (sorted, sort) = use_sort(
+///     &expenses,
+///     &[
+///         SortKey::new("Amount", |a: &Expense, b: &Expense| a.amount.total_cmp(&b.amount)),
+///         SortKey::new("Description", |a: &Expense, b: &Expense| {
+///             a.description.cmp(&b.description)
+///         }),
+///     ],
+/// );
+///
+/// if let Some(key) = use_key_event()
+///     && key.code == KeyCode::Char('o')
+/// {
+///     sort.cycle();
+/// }
+///
+/// let title = format!("Expenses ({})", sort.label());
+/// ```
+pub fn use_sort<T: Clone>(items: &[T], keys: &[SortKey<T>]) -> (Vec<T>, SortHandle) {
+    let (state, setter) = use_state(|| None::<(usize, SortDirection)>);
+    let handle = SortHandle {
+        state,
+        setter,
+        names: keys.iter().map(SortKey::name).collect(),
+    };
+
+    let mut sorted = items.to_vec();
+    if let Some((index, direction)) = handle.current() {
+        let compare = keys[index].compare;
+        match direction {
+            SortDirection::Ascending => sorted.sort_by(compare),
+            SortDirection::Descending => sorted.sort_by(|a, b| compare(b, a)),
+        }
+    }
+
+    (sorted, handle)
+}
@@ -0,0 +1,117 @@
+//! Tests for the use_sort hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Row {
+    name: &'static str,
+    amount: i32,
+}
+
+fn rows() -> Vec<Row> {
+    [("rent", 900), ("coffee", 4), ("books", 30), ("lunch", 12)]
+        .into_iter()
+        .map(|(name, amount)| Row { name, amount })
+        .collect()
+}
+
+fn keys() -> [SortKey<Row>; 2] {
+    [
+        SortKey::new("Amount", |a: &Row, b: &Row| a.amount.cmp(&b.amount)),
+        SortKey::new("Name", |a: &Row, b: &Row| a.name.cmp(b.name)),
+    ]
+}
+
+fn render(items: &[Row]) -> (Vec<&'static str>, SortHandle) {
+    with_component_id("SortedList", |_| {
+        let (sorted, sort) = use_sort(items, &keys());
+        (sorted.iter().map(|row| row.name).collect(), sort)
+    })
+}
+
+#[test]
+fn test_cycle_through_keys_and_directions() {
+    with_test_isolate(|| {
+        let items = rows();
+        let (names, sort) = render(&items);
+        assert_eq!(names, ["rent", "coffee", "books", "lunch"]);
+        assert_eq!(sort.label(), "unsorted");
+
+        sort.cycle();
+        let (names, sort) = render(&items);
+        assert_eq!(names, ["coffee", "lunch", "books", "rent"]);
+        assert_eq!(sort.key(), Some("Amount"));
+        assert_eq!(sort.direction(), Some(SortDirection::Ascending));
+
+        sort.cycle();
+        let (names, sort) = render(&items);
+        assert_eq!(names, ["rent", "books", "lunch", "coffee"]);
+        assert_eq!(sort.label(), "Amount ▼");
+
+        sort.cycle();
+        let (names, sort) = render(&items);
+        assert_eq!(names, ["books", "coffee", "lunch", "rent"]);
+        assert_eq!(sort.key(), Some("Name"));
+
+        sort.cycle();
+        sort.cycle();
+        let (names, sort) = render(&items);
+        assert_eq!(names, ["rent", "coffee", "books", "lunch"]);
+        assert_eq!(sort.key(), None);
+    });
+}
+
+#[test]
+fn test_sort_by_toggles_direction_of_active_key() {
+    with_test_isolate(|| {
+        let items = rows();
+        let (_, sort) = render(&items);
+
+        sort.sort_by(0);
+        assert_eq!(render(&items).1.direction(), Some(SortDirection::Ascending));
+
+        sort.sort_by(0);
+        let (names, sort) = render(&items);
+        assert_eq!(names, ["rent", "books", "lunch", "coffee"]);
+
+        sort.toggle_direction();
+        assert_eq!(render(&items).0, ["coffee", "lunch", "books", "rent"]);
+
+        // Another key starts ascending
+        sort.sort_by(0);
+        sort.sort_by(1);
+        assert_eq!(render(&items).1.label(), "Name ▲");
+
+        sort.sort_by(7);
+        assert_eq!(render(&items).1.label(), "Name ▲");
+
+        sort.clear();
+        assert_eq!(render(&items).1.key(), None);
+    });
+}
+
+#[test]
+fn test_equal_items_keep_their_order_in_both_directions() {
+    with_test_isolate(|| {
+        let items: Vec<Row> = [("a", 2), ("b", 1), ("c", 2), ("d", 1), ("e", 2)]
+            .into_iter()
+            .map(|(name, amount)| Row { name, amount })
+            .collect();
+        let (_, sort) = render(&items);
+
+        sort.sort_by(0);
+        assert_eq!(render(&items).0, ["b", "d", "a", "c", "e"]);
+
+        sort.sort_by(0);
+        assert_eq!(render(&items).0, ["a", "c", "e", "b", "d"]);
+
+        // New items are sorted by the kept key
+        let mut more = items.clone();
+        more.push(Row {
+            name: "f",
+            amount: 3,
+        });
+        assert_eq!(render(&more).0, ["f", "a", "c", "e", "b", "d"]);
+    });
+}
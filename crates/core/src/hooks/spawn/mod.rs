@@ -0,0 +1,196 @@
+//! Spawn Hook
+//!
+//! This module provides a `use_spawn` hook that runs a tokio task for as long
+//! as the component is mounted. The task is aborted when the component
+//! unmounts or when the dependencies change (in which case a new task is
+//! started), so background work can't outlive the component that started it.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::task::AbortHandle;
+
+use crate::hooks::effect::{EffectDependencies, spawn_effect_task, use_effect};
+use crate::hooks::with_hook_context;
+
+#[cfg(test)]
+mod tests;
+
+/// Status of a task started by [`use_spawn`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// The task hasn't been started yet
+    Idle,
+    /// The task is running
+    Running,
+    /// The task ran to completion
+    Finished,
+    /// The task was aborted, by unmount, a dependency change or `abort()`
+    Aborted,
+    /// The task panicked
+    Panicked,
+}
+
+#[derive(Debug)]
+struct TaskState {
+    /// Incremented for every started task, so a stopped task can't
+    /// overwrite the status of its replacement
+    run: u64,
+    status: TaskStatus,
+    abort_handle: Option<AbortHandle>,
+}
+
+/// Handle returned by [`use_spawn`]
+#[derive(Debug, Clone)]
+pub struct SpawnHandle {
+    state: Arc<Mutex<TaskState>>,
+}
+
+impl SpawnHandle {
+    fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(TaskState {
+                run: 0,
+                status: TaskStatus::Idle,
+                abort_handle: None,
+            })),
+        }
+    }
+
+    /// Current status of the task
+    pub fn status(&self) -> TaskStatus {
+        self.state.lock().status
+    }
+
+    /// Returns true while the task is running
+    pub fn is_running(&self) -> bool {
+        self.status() == TaskStatus::Running
+    }
+
+    /// Returns true once the task finished, was aborted or panicked
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status(),
+            TaskStatus::Finished | TaskStatus::Aborted | TaskStatus::Panicked
+        )
+    }
+
+    /// Abort the task
+    ///
+    /// It is started again only when the dependencies change.
+    pub fn abort(&self) {
+        if let Some(abort_handle) = self.state.lock().abort_handle.take() {
+            abort_handle.abort();
+        }
+    }
+
+    /// Spawn `future` as the current run, tracked by the running effect
+    fn start<Fut>(&self, future: Fut)
+    where
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let run = {
+            let mut state = self.state.lock();
+            state.run += 1;
+            state.status = TaskStatus::Running;
+            state.run
+        };
+
+        let guard = RunGuard {
+            state: self.state.clone(),
+            run,
+            completed: false,
+        };
+        let abort_handle = spawn_effect_task(async move {
+            let mut guard = guard;
+            future.await;
+            guard.completed = true;
+        });
+
+        let mut state = self.state.lock();
+        if state.run == run && state.status == TaskStatus::Running {
+            state.abort_handle = Some(abort_handle);
+        }
+    }
+}
+
+/// Records how a run ended when its future is dropped
+struct RunGuard {
+    state: Arc<Mutex<TaskState>>,
+    run: u64,
+    completed: bool,
+}
+
+impl Drop for RunGuard {
+    fn drop(&mut self) {
+        let mut state = self.state.lock();
+        if state.run != self.run {
+            return;
+        }
+        state.status = if self.completed {
+            TaskStatus::Finished
+        } else if std::thread::panicking() {
+            TaskStatus::Panicked
+        } else {
+            TaskStatus::Aborted
+        };
+        state.abort_handle = None;
+    }
+}
+
+/// Hook that runs a task for the lifetime of the component
+///
+/// `future_factory` is called on the first render and whenever `deps`
+/// change; the previous task is aborted first. The task is also aborted when
+/// the component unmounts. Since it may run that long, the task doesn't take
+/// a permit of the shared [`TaskPool`](crate::tasks::TaskPool), which would
+/// starve other hooks' work; see [`spawn_effect_task`]. Must be used within a
+/// tokio runtime.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::spawn::use_spawn;
+/// use pulse_core::hooks::state::use_state;
+/// use std::time::Duration;
+///
+/// let (ticks, set_ticks) = use_state(|| 0);
+/// let ticker = use_spawn(
+///     move || async move {
+///         let mut interval = tokio::time::interval(Duration::from_secs(1));
+///         loop {
+///             interval.tick().await;
+///             set_ticks.update(|ticks| ticks + 1);
+///         }
+///     },
+///     (),
+/// );
+///
+/// // e.g. on a "stop" key:
+/// ticker.abort();
+/// ```
+pub fn use_spawn<Deps, F, Fut>(future_factory: F, deps: impl Into<Option<Deps>>) -> SpawnHandle
+where
+    Deps: EffectDependencies + Clone + PartialEq + 'static,
+    F: FnOnce() -> Fut + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let handle = with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        ctx.get_or_init_state(index, SpawnHandle::new)
+            .borrow()
+            .clone()
+    });
+
+    let task = handle.clone();
+    use_effect(
+        move || {
+            task.start(future_factory());
+            None::<fn()>
+        },
+        deps,
+    );
+
+    handle
+}
@@ -0,0 +1,125 @@
+//! Tests for the use_spawn hook
+
+use super::*;
+use crate::component::{Component, cleanup_unmounted};
+use crate::hooks::test_utils::{with_async_test_isolate, with_component_id};
+use crate::hooks::{HookContext, clear_hook_context, set_hook_context};
+use ratatui::{Frame, Terminal, backend::TestBackend, layout::Rect};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[tokio::test]
+async fn test_task_runs_to_completion() {
+    with_async_test_isolate(|| async {
+        let handle = with_component_id("Finisher", |_| {
+            use_spawn(|| async { sleep(Duration::from_millis(5)).await }, ())
+        });
+        assert!(handle.is_running());
+
+        sleep(Duration::from_millis(30)).await;
+        assert_eq!(handle.status(), TaskStatus::Finished);
+        assert!(handle.is_finished());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_deps_change_restarts_task() {
+    with_async_test_isolate(|| async {
+        let starts = Arc::new(AtomicUsize::new(0));
+        let render = |query: &'static str| {
+            let starts = starts.clone();
+            with_component_id("Searcher", move |_| {
+                use_spawn(
+                    move || async move {
+                        starts.fetch_add(1, Ordering::SeqCst);
+                        std::future::pending::<()>().await
+                    },
+                    query,
+                )
+            })
+        };
+
+        let first = render("a");
+        sleep(Duration::from_millis(10)).await;
+        let same = render("a");
+        sleep(Duration::from_millis(10)).await;
+        assert_eq!(starts.load(Ordering::SeqCst), 1);
+        assert!(same.is_running());
+
+        let second = render("ab");
+        sleep(Duration::from_millis(10)).await;
+        assert_eq!(starts.load(Ordering::SeqCst), 2);
+        // The old task's abort doesn't clobber the new task's status
+        assert!(first.is_running());
+        assert!(second.is_running());
+
+        second.abort();
+        sleep(Duration::from_millis(10)).await;
+        assert_eq!(second.status(), TaskStatus::Aborted);
+    })
+    .await;
+}
+
+#[derive(Clone)]
+struct Poller {
+    handle: Arc<Mutex<Option<SpawnHandle>>>,
+}
+
+impl Component for Poller {
+    fn render(&self, _area: Rect, _frame: &mut Frame) {
+        let handle = use_spawn(std::future::pending::<()>, ());
+        *self.handle.lock() = Some(handle);
+    }
+}
+
+#[tokio::test]
+async fn test_unmount_aborts_task() {
+    let poller = Poller {
+        handle: Arc::new(Mutex::new(None)),
+    };
+    let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+    set_hook_context(Rc::new(HookContext::new()));
+
+    terminal
+        .draw(|frame| poller.render_with_mount(frame.area(), frame))
+        .unwrap();
+    cleanup_unmounted();
+    sleep(Duration::from_millis(10)).await;
+    let handle = poller.handle.lock().clone().unwrap();
+    assert!(handle.is_running());
+
+    // A frame without the poller unmounts it
+    cleanup_unmounted();
+    sleep(Duration::from_millis(10)).await;
+    assert_eq!(handle.status(), TaskStatus::Aborted);
+
+    clear_hook_context();
+}
+
+#[tokio::test]
+async fn test_long_lived_tasks_leave_the_pool_to_futures() {
+    use crate::hooks::future::use_future;
+    use crate::tasks::task_pool;
+
+    with_async_test_isolate(|| async {
+        let (tickers, data) = with_component_id("Dashboard", |_| {
+            // Enough endless tasks to take every permit if they held one
+            let tickers: Vec<_> = (0..task_pool().max_concurrency())
+                .map(|_| use_spawn(std::future::pending::<()>, ()))
+                .collect();
+            (tickers, use_future(|| async { Ok::<_, String>(42) }, ()))
+        });
+
+        sleep(Duration::from_millis(30)).await;
+        assert!(tickers.iter().all(SpawnHandle::is_running));
+        assert_eq!(data.value(), Some(42));
+
+        for ticker in tickers {
+            ticker.abort();
+        }
+    })
+    .await;
+}
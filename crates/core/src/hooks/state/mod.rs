@@ -1,5 +1,7 @@
-use parking_lot::{Mutex, RwLock};
+use parking_lot::{Mutex, RwLock, RwLockReadGuard};
+use std::ops::Deref;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 #[cfg(test)]
 mod tests;
@@ -12,6 +14,12 @@ pub struct StateContainer<T> {
     value: RwLock<T>,
     /// Version counter to track state changes (useful for debugging and optimization)
     version: Mutex<u64>,
+    /// Latest value passed to `set_coalesced`, applied on the next read
+    coalesced: Mutex<Option<T>>,
+    /// Fast path for reads: whether `coalesced` holds a value
+    has_coalesced: AtomicBool,
+    /// Change counter of the hook context that owns this state, if any
+    owner: Option<Arc<AtomicU64>>,
 }
 
 impl<T> StateContainer<T> {
@@ -23,6 +31,22 @@ impl<T> StateContainer<T> {
         Self {
             value: RwLock::new(initializer()),
             version: Mutex::new(0),
+            coalesced: Mutex::new(None),
+            has_coalesced: AtomicBool::new(false),
+            owner: None,
+        }
+    }
+
+    /// Also count changes in `changes`, the counter of the owning hook context
+    pub(crate) fn owned_by(mut self, changes: Arc<AtomicU64>) -> Self {
+        self.owner = Some(changes);
+        self
+    }
+
+    /// Tell the owning hook context that this state changed
+    fn notify_owner(&self) {
+        if let Some(owner) = &self.owner {
+            owner.fetch_add(1, Ordering::SeqCst);
         }
     }
 
@@ -31,9 +55,50 @@ impl<T> StateContainer<T> {
     where
         T: Clone,
     {
+        self.apply_coalesced();
         self.value.read().clone()
     }
 
+    /// Read the current value by reference, without cloning it
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.apply_coalesced();
+        f(&self.value.read())
+    }
+
+    /// Borrow the current value, holding a read lock until the guard drops
+    pub fn borrow(&self) -> StateRef<'_, T> {
+        self.apply_coalesced();
+        StateRef {
+            guard: self.value.read(),
+        }
+    }
+
+    /// Apply the pending coalesced value, if any
+    ///
+    /// The render was already requested by `set_coalesced`, so this only
+    /// bumps the version.
+    fn apply_coalesced(&self) {
+        if !self.has_coalesced.load(Ordering::Acquire) {
+            return;
+        }
+
+        let mut coalesced = self.coalesced.lock();
+        if let Some(new_value) = coalesced.take() {
+            *self.value.write() = new_value;
+            *self.version.lock() += 1;
+        }
+        self.has_coalesced.store(false, Ordering::Release);
+    }
+
+    /// Discard the pending coalesced value, superseded by a direct update
+    fn discard_coalesced(&self) {
+        if self.has_coalesced.load(Ordering::Acquire) {
+            let mut coalesced = self.coalesced.lock();
+            coalesced.take();
+            self.has_coalesced.store(false, Ordering::Release);
+        }
+    }
+
     /// Private method to increment version and trigger re-render
     /// This eliminates code duplication between set() and update()
     fn increment_version_and_notify(&self) {
@@ -42,13 +107,32 @@ impl<T> StateContainer<T> {
             let mut version = self.version.lock();
             *version += 1;
         }
+        self.notify_owner();
+
+        // Notify the runtime (deferred while batching updates)
+        crate::hooks::batch::request_render();
+    }
+
+    /// Set a new value unless it equals the current one
+    ///
+    /// Returns true if the value changed. An equal value neither bumps the
+    /// version nor requests a render.
+    pub fn set_if_changed(&self, new_value: T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.apply_coalesced();
+        if *self.value.read() == new_value {
+            return false;
+        }
 
-        // TODO: Trigger re-render notification
-        // This would integrate with the component re-render system
+        self.set(new_value);
+        true
     }
 
     /// Set a new value (thread-safe write)
     pub fn set(&self, new_value: T) {
+        self.discard_coalesced();
         {
             let mut value = self.value.write();
             *value = new_value;
@@ -63,6 +147,9 @@ impl<T> StateContainer<T> {
         F: FnOnce(&T) -> T,
         T: Clone,
     {
+        // Updates build on the latest coalesced value
+        self.apply_coalesced();
+
         // Perform atomic read-modify-write operation
         {
             let mut value = self.value.write();
@@ -73,12 +160,49 @@ impl<T> StateContainer<T> {
         self.increment_version_and_notify();
     }
 
+    /// Set a new value, keeping only the latest one until the next read
+    ///
+    /// Values set in quick succession (e.g. by a background task producing
+    /// updates faster than the UI renders) overwrite each other without
+    /// touching the state; the next read, typically in the next frame,
+    /// applies only the most recent one. Only the first coalesced set between
+    /// two reads requests a render.
+    pub fn set_coalesced(&self, new_value: T) {
+        let mut coalesced = self.coalesced.lock();
+        let first = coalesced.replace(new_value).is_none();
+        self.has_coalesced.store(true, Ordering::Release);
+        drop(coalesced);
+
+        if first {
+            self.notify_owner();
+            crate::hooks::batch::request_render();
+        }
+    }
+
     /// Get the current version (useful for change detection)
     pub fn version(&self) -> u64 {
+        self.apply_coalesced();
         *self.version.lock()
     }
 }
 
+/// Read guard returned by [`StateHandle::borrow`]
+///
+/// The state stays read-locked while the guard is alive, so setting the same
+/// state before dropping it deadlocks. Keep the guard for a single expression
+/// or block and prefer [`StateHandle::with`] when in doubt.
+pub struct StateRef<'a, T> {
+    guard: RwLockReadGuard<'a, T>,
+}
+
+impl<T> Deref for StateRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
 /// A handle to a piece of state that mirrors React's useState return value
 /// This is what gets returned to the component
 #[derive(Debug)]
@@ -112,6 +236,31 @@ impl<T> StateHandle<T> {
         self.container.get()
     }
 
+    /// Read the current value by reference, without cloning it
+    ///
+    /// Prefer this over [`get`](Self::get) for large state such as long lists,
+    /// where cloning on every render is expensive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use pulse_core::hooks::state::use_state;
+    /// // In a component context:
+    /// let (tasks, _) = use_state(|| vec!["write docs".to_string(); 1000]);
+    /// let pending = tasks.with(|tasks| tasks.len());
+    /// ```
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.container.with(f)
+    }
+
+    /// Borrow the current value without cloning it
+    ///
+    /// The returned guard holds a read lock; see [`StateRef`] for when it is
+    /// safe to keep around.
+    pub fn borrow(&self) -> StateRef<'_, T> {
+        self.container.borrow()
+    }
+
     /// Get the current version of the state (useful for change detection)
     pub fn version(&self) -> u64 {
         self.container.version()
@@ -153,6 +302,31 @@ impl<T> StateSetter<T> {
         self.container.set(new_value);
     }
 
+    /// Set the state, dropping intermediate values until the next frame
+    ///
+    /// Use this for updates from background tasks that may fire faster than
+    /// the UI renders: only the latest value is applied, and only one render
+    /// is requested per frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use pulse_core::hooks::state::use_state;
+    /// // In a component context:
+    /// let (cpu, set_cpu) = use_state(|| 0.0f32);
+    ///
+    /// tokio::spawn(async move {
+    ///     loop {
+    ///         // Sampled far more often than the UI renders
+    ///         set_cpu.set_coalesced(42.0);
+    ///         tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+    ///     }
+    /// });
+    /// ```
+    pub fn set_coalesced(&self, new_value: T) {
+        self.container.set_coalesced(new_value);
+    }
+
     /// Update the state using a function (functional update)
     /// This mirrors React's setState(prevState => newState) pattern
     pub fn update<F>(&self, updater: F)
@@ -248,8 +422,7 @@ where
     where
         F: FnOnce(&T) -> R,
     {
-        let value = self.get();
-        getter(&value)
+        self.with(getter)
     }
 
     /// Map the state value to a different type
@@ -403,8 +576,9 @@ where
         let index = ctx.next_hook_index();
 
         // Get or initialize the state container for this hook
-        let container_ref =
-            ctx.get_or_init_state(index, || Arc::new(StateContainer::new(initializer)));
+        let container_ref = ctx.get_or_init_state(index, || {
+            Arc::new(StateContainer::new(initializer).owned_by(ctx.state_changes()))
+        });
 
         // Extract the Arc<StateContainer<T>> from Rc<RefCell<Arc<StateContainer<T>>>>
         let container = container_ref.borrow().clone();
@@ -281,3 +281,82 @@ fn test_use_state_field_access() {
         });
     });
 }
+
+/// Rapid coalesced sets are collapsed into one update per frame
+#[test]
+fn test_set_coalesced_keeps_latest_per_frame() {
+    use crate::hooks::batch::render_request_count;
+
+    with_test_isolate(|| {
+        with_hook_context(|_context| {
+            let (state_handle, setter) = use_state(|| 0u32);
+            let requests = render_request_count();
+            let version = state_handle.version();
+
+            // Frame 1: a burst of updates from a fast producer
+            for value in 1..=1000 {
+                setter.set_coalesced(value);
+            }
+            assert_eq!(render_request_count(), requests + 1);
+            assert_eq!(state_handle.get(), 1000);
+            assert_eq!(state_handle.version(), version + 1);
+
+            // Reading again doesn't apply anything new
+            assert_eq!(state_handle.get(), 1000);
+            assert_eq!(state_handle.version(), version + 1);
+
+            // Frame 2: another burst requests one more render
+            for value in 2000..2100 {
+                setter.set_coalesced(value);
+            }
+            assert_eq!(render_request_count(), requests + 2);
+            assert_eq!(state_handle.get(), 2099);
+            assert_eq!(state_handle.version(), version + 2);
+
+            // A direct set supersedes a pending coalesced value
+            setter.set_coalesced(1);
+            setter.set(7);
+            assert_eq!(state_handle.get(), 7);
+
+            // Functional updates build on the latest coalesced value
+            setter.set_coalesced(10);
+            setter.update(|value| value + 1);
+            assert_eq!(state_handle.get(), 11);
+        });
+    });
+}
+
+/// `with` and `borrow` read the state without cloning it, unlike `get`
+#[test]
+fn test_with_and_borrow_do_not_clone() {
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    struct Tracked(Vec<u64>);
+
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Tracked(self.0.clone())
+        }
+    }
+
+    with_test_isolate(|| {
+        with_hook_context(|_context| {
+            let (state, set_state) = use_state(|| Tracked((0..1000).collect()));
+            CLONES.store(0, Ordering::SeqCst);
+
+            assert_eq!(state.get().0.len(), 1000);
+            assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+
+            assert_eq!(state.with(|tracked| tracked.0.len()), 1000);
+            assert_eq!(state.field(|tracked| tracked.0[999]), 999);
+            assert_eq!(state.borrow().0[0], 0);
+            assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+
+            // Reads see coalesced values without a clone either
+            set_state.set_coalesced(Tracked(vec![7]));
+            assert_eq!(state.with(|tracked| tracked.0.clone()), vec![7]);
+            assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+        });
+    });
+}
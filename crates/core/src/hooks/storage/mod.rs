@@ -12,9 +12,21 @@
 //! - Support for both primitive and complex serializable types
 //! - Thread-safe operations for concurrent access
 
-use std::{any::Any, collections::HashMap, fs, path::PathBuf, sync::Arc};
-
-use crate::hooks::state::StateHandle;
+use std::{
+    any::Any,
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use crate::error::{ContextError, ResultExt};
+use crate::hooks::reducer::{DispatchFn, ReducerStateHandle};
+use crate::hooks::state::{StateHandle, StateRef};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::sync::OnceLock;
@@ -43,6 +55,17 @@ pub enum LocalStorageError {
     DirectoryCreationError(String),
     /// Storage is not available (e.g., in SSR context)
     StorageUnavailable,
+    /// The backend does not implement the requested operation
+    Unsupported(String),
+    /// A compare-and-swap found a different version than expected
+    Conflict {
+        /// Key whose version did not match
+        key: String,
+        /// Version the caller expected, `None` meaning the key should be absent
+        expected: Option<u64>,
+        /// Version actually stored, `None` if the key is absent
+        actual: Option<u64>,
+    },
 }
 
 impl std::fmt::Display for LocalStorageError {
@@ -62,6 +85,18 @@ impl std::fmt::Display for LocalStorageError {
             LocalStorageError::StorageUnavailable => {
                 write!(f, "Storage is not available in this context")
             }
+            LocalStorageError::Unsupported(operation) => {
+                write!(f, "Storage backend does not support {}", operation)
+            }
+            LocalStorageError::Conflict {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Conflict on '{}': expected version {:?}, found {:?}",
+                key, expected, actual
+            ),
         }
     }
 }
@@ -142,6 +177,22 @@ pub trait StorageBackend: Send + Sync {
     /// Remove a value from storage
     fn remove(&self, key: &str) -> LocalStorageResult<()>;
 
+    /// List every key currently held in storage
+    ///
+    /// Backends that cannot enumerate their keys keep the default, which
+    /// returns [`LocalStorageError::Unsupported`].
+    fn keys(&self) -> LocalStorageResult<Vec<String>> {
+        Err(LocalStorageError::Unsupported("listing keys".to_string()))
+    }
+
+    /// Remove every value from storage
+    fn clear_all(&self) -> LocalStorageResult<()> {
+        for key in self.keys()? {
+            self.remove(&key)?;
+        }
+        Ok(())
+    }
+
     /// Check if storage is available
     fn is_available(&self) -> bool;
 }
@@ -159,6 +210,34 @@ pub trait AsyncStorageBackend: Send + Sync {
     /// Remove a value from storage asynchronously
     async fn remove_async(&self, key: &str) -> LocalStorageResult<()>;
 
+    /// Read a value together with its version asynchronously
+    ///
+    /// The version starts at 1 and increases on every write to the key.
+    /// Backends without versioning keep the default, which returns
+    /// [`LocalStorageError::Unsupported`].
+    async fn read_versioned_async(&self, _key: &str) -> LocalStorageResult<Option<(String, u64)>> {
+        Err(LocalStorageError::Unsupported(
+            "versioned reads".to_string(),
+        ))
+    }
+
+    /// Write a value only if the stored version still matches `expected_version`
+    ///
+    /// Pass `None` to require that the key does not exist yet. Returns the new
+    /// version on success and [`LocalStorageError::Conflict`] on mismatch.
+    /// Backends without versioning keep the default, which returns
+    /// [`LocalStorageError::Unsupported`].
+    async fn compare_and_swap(
+        &self,
+        _key: &str,
+        _expected_version: Option<u64>,
+        _value: &str,
+    ) -> LocalStorageResult<u64> {
+        Err(LocalStorageError::Unsupported(
+            "compare-and-swap".to_string(),
+        ))
+    }
+
     /// Check if storage is available
     fn is_available(&self) -> bool;
 
@@ -245,6 +324,32 @@ impl StorageBackend for FileStorageBackend {
         Ok(())
     }
 
+    fn keys(&self) -> LocalStorageResult<Vec<String>> {
+        if !self.config.storage_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&self.config.storage_dir).map_err(|e| {
+            LocalStorageError::ReadError(format!(
+                "Failed to list storage directory '{}': {}",
+                self.config.storage_dir.display(),
+                e
+            ))
+        })?;
+
+        let suffix = format!(".{}", self.config.file_extension);
+        let mut keys: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                name.strip_suffix(&suffix).map(str::to_string)
+            })
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+
     fn is_available(&self) -> bool {
         // Check if we can create the storage directory or if it already exists
         if self.config.storage_dir.exists() {
@@ -307,11 +412,157 @@ impl StorageBackend for MemoryStorageBackend {
         Ok(())
     }
 
+    fn keys(&self) -> LocalStorageResult<Vec<String>> {
+        let mut keys: Vec<String> = self.storage.read().keys().cloned().collect();
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn clear_all(&self) -> LocalStorageResult<()> {
+        self.clear();
+        Ok(())
+    }
+
     fn is_available(&self) -> bool {
         true
     }
 }
 
+/// Prefix marking values written by [`CompressingStorageBackend`] in compressed form
+#[cfg(feature = "compression")]
+const COMPRESSED_MARKER: &str = "gz:";
+
+/// Storage backend wrapper that gzip-compresses large values
+///
+/// Values longer than the threshold are gzipped, base64-encoded and stored
+/// with a `gz:` marker; smaller values are stored as-is. Reads check for the
+/// marker, so a store holding both forms (for example one that predates the
+/// wrapper) decodes correctly.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::storage::{
+///     CompressingStorageBackend, FileStorageBackend, LocalStorageConfig, set_storage_backend,
+/// };
+/// use std::sync::Arc;
+///
+/// let files = FileStorageBackend::new(LocalStorageConfig::default());
+/// set_storage_backend(Arc::new(CompressingStorageBackend::new(files, 4096)));
+/// ```
+#[cfg(feature = "compression")]
+#[derive(Debug)]
+pub struct CompressingStorageBackend<B> {
+    inner: B,
+    threshold: usize,
+}
+
+#[cfg(feature = "compression")]
+impl<B: StorageBackend> CompressingStorageBackend<B> {
+    /// Wrap a backend, compressing values longer than `threshold` bytes
+    pub fn new(inner: B, threshold: usize) -> Self {
+        Self { inner, threshold }
+    }
+
+    /// Get the wrapped backend
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Get the size in bytes above which values are compressed
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Gzip and base64-encode a value, prefixed with the compression marker
+    fn compress(value: &str) -> LocalStorageResult<String> {
+        use base64::Engine;
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(value.as_bytes())
+            .and_then(|()| encoder.finish())
+            .map(|bytes| {
+                format!(
+                    "{}{}",
+                    COMPRESSED_MARKER,
+                    base64::engine::general_purpose::STANDARD.encode(bytes)
+                )
+            })
+            .map_err(|e| {
+                LocalStorageError::SerializationError(format!("Failed to compress value: {}", e))
+            })
+    }
+
+    /// Reverse [`Self::compress`] for a value with the marker already stripped
+    fn decompress(encoded: &str) -> LocalStorageResult<String> {
+        use base64::Engine;
+        use flate2::read::GzDecoder;
+        use std::io::Read;
+
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|e| {
+                LocalStorageError::DeserializationError(format!(
+                    "Failed to decode compressed value: {}",
+                    e
+                ))
+            })?;
+
+        let mut value = String::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_string(&mut value)
+            .map_err(|e| {
+                LocalStorageError::DeserializationError(format!(
+                    "Failed to decompress value: {}",
+                    e
+                ))
+            })?;
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "compression")]
+impl<B: StorageBackend> StorageBackend for CompressingStorageBackend<B> {
+    fn read(&self, key: &str) -> LocalStorageResult<Option<String>> {
+        match self.inner.read(key)? {
+            Some(stored) => match stored.strip_prefix(COMPRESSED_MARKER) {
+                Some(encoded) => Self::decompress(encoded).map(Some),
+                None => Ok(Some(stored)),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn write(&self, key: &str, value: &str) -> LocalStorageResult<()> {
+        // Values that happen to start with the marker are compressed too, so
+        // reads never mistake them for compressed data
+        if value.len() > self.threshold || value.starts_with(COMPRESSED_MARKER) {
+            self.inner.write(key, &Self::compress(value)?)
+        } else {
+            self.inner.write(key, value)
+        }
+    }
+
+    fn remove(&self, key: &str) -> LocalStorageResult<()> {
+        self.inner.remove(key)
+    }
+
+    fn keys(&self) -> LocalStorageResult<Vec<String>> {
+        self.inner.keys()
+    }
+
+    fn clear_all(&self) -> LocalStorageResult<()> {
+        self.inner.clear_all()
+    }
+
+    fn is_available(&self) -> bool {
+        self.inner.is_available()
+    }
+}
+
 /// SQLite-based storage backend for persistent, database-backed storage
 #[cfg(feature = "sqlite")]
 #[derive(Debug)]
@@ -393,7 +644,9 @@ impl AsyncStorageBackend for SqliteStorageBackend {
 
     async fn write_async(&self, key: &str, value: &str) -> LocalStorageResult<()> {
         let query = format!(
-            "INSERT OR REPLACE INTO {} (key, value, updated_at) VALUES (?, ?, datetime('now'))",
+            "INSERT INTO {} (key, value, version, updated_at) VALUES (?, ?, 1, datetime('now')) \
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value, \
+             version = version + 1, updated_at = excluded.updated_at",
             self.table_name
         );
 
@@ -423,6 +676,91 @@ impl AsyncStorageBackend for SqliteStorageBackend {
         Ok(())
     }
 
+    async fn read_versioned_async(&self, key: &str) -> LocalStorageResult<Option<(String, u64)>> {
+        let query = format!(
+            "SELECT value, version FROM {} WHERE key = ?",
+            self.table_name
+        );
+
+        let result = sqlx::query(&query)
+            .bind(key)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                LocalStorageError::ReadError(format!("Failed to read from SQLite: {}", e))
+            })?;
+
+        match result {
+            Some(row) => {
+                let value: String = row.try_get("value").map_err(|e| {
+                    LocalStorageError::ReadError(format!(
+                        "Failed to extract value from SQLite row: {}",
+                        e
+                    ))
+                })?;
+                let version: i64 = row.try_get("version").map_err(|e| {
+                    LocalStorageError::ReadError(format!(
+                        "Failed to extract version from SQLite row: {}",
+                        e
+                    ))
+                })?;
+                Ok(Some((value, version as u64)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn compare_and_swap(
+        &self,
+        key: &str,
+        expected_version: Option<u64>,
+        value: &str,
+    ) -> LocalStorageResult<u64> {
+        // A single conditional statement keeps the check and write atomic
+        let result = match expected_version {
+            Some(version) => {
+                let query = format!(
+                    "UPDATE {} SET value = ?, version = version + 1, updated_at = datetime('now') \
+                     WHERE key = ? AND version = ?",
+                    self.table_name
+                );
+                sqlx::query(&query)
+                    .bind(value)
+                    .bind(key)
+                    .bind(version as i64)
+                    .execute(&self.pool)
+                    .await
+            }
+            None => {
+                let query = format!(
+                    "INSERT INTO {} (key, value, version, updated_at) \
+                     VALUES (?, ?, 1, datetime('now')) ON CONFLICT(key) DO NOTHING",
+                    self.table_name
+                );
+                sqlx::query(&query)
+                    .bind(key)
+                    .bind(value)
+                    .execute(&self.pool)
+                    .await
+            }
+        }
+        .map_err(|e| LocalStorageError::WriteError(format!("Failed to write to SQLite: {}", e)))?;
+
+        if result.rows_affected() == 1 {
+            return Ok(expected_version.map_or(1, |version| version + 1));
+        }
+
+        let actual = self
+            .read_versioned_async(key)
+            .await?
+            .map(|(_, version)| version);
+        Err(LocalStorageError::Conflict {
+            key: key.to_string(),
+            expected: expected_version,
+            actual,
+        })
+    }
+
     fn is_available(&self) -> bool {
         !self.pool.is_closed()
     }
@@ -433,6 +771,7 @@ impl AsyncStorageBackend for SqliteStorageBackend {
             CREATE TABLE IF NOT EXISTS {} (
                 key TEXT PRIMARY KEY,
                 value TEXT NOT NULL,
+                version INTEGER NOT NULL DEFAULT 1,
                 created_at DATETIME DEFAULT (datetime('now')),
                 updated_at DATETIME DEFAULT (datetime('now'))
             )
@@ -450,6 +789,35 @@ impl AsyncStorageBackend for SqliteStorageBackend {
                 ))
             })?;
 
+        // Tables created before versioning lack the column; add it in place
+        let columns_query = format!("PRAGMA table_info({})", self.table_name);
+        let columns = sqlx::query(&columns_query)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                LocalStorageError::ReadError(format!("Failed to inspect SQLite table: {}", e))
+            })?;
+        let has_version = columns.iter().any(|row| {
+            row.try_get::<String, _>("name")
+                .is_ok_and(|name| name == "version")
+        });
+
+        if !has_version {
+            let alter_query = format!(
+                "ALTER TABLE {} ADD COLUMN version INTEGER NOT NULL DEFAULT 1",
+                self.table_name
+            );
+            sqlx::query(&alter_query)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    LocalStorageError::DirectoryCreationError(format!(
+                        "Failed to add version column to SQLite table: {}",
+                        e
+                    ))
+                })?;
+        }
+
         // Create index for better performance
         let create_index_query = format!(
             "CREATE INDEX IF NOT EXISTS idx_{}_updated_at ON {} (updated_at)",
@@ -470,6 +838,45 @@ impl AsyncStorageBackend for SqliteStorageBackend {
     }
 }
 
+/// Copy every key from a synchronous backend into an async backend
+///
+/// Values are copied verbatim, so existing keys in `to` are overwritten while
+/// keys only present in `to` are left alone. Returns the number of keys copied.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::storage::{
+///     FileStorageBackend, LocalStorageConfig, SqliteStorageBackend, migrate_storage,
+/// };
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let files = FileStorageBackend::new(LocalStorageConfig::default());
+/// let sqlite = SqliteStorageBackend::new("sqlite:app.db").await?;
+/// let copied = migrate_storage(&files, &sqlite).await?;
+/// println!("Migrated {} keys", copied);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "sqlite")]
+pub async fn migrate_storage(
+    from: &dyn StorageBackend,
+    to: &dyn AsyncStorageBackend,
+) -> LocalStorageResult<usize> {
+    if !from.is_available() || !to.is_available() {
+        return Err(LocalStorageError::StorageUnavailable);
+    }
+
+    let mut copied = 0;
+    for key in from.keys()? {
+        if let Some(value) = from.read(&key)? {
+            to.write_async(&key, &value).await?;
+            copied += 1;
+        }
+    }
+    Ok(copied)
+}
+
 /// Local storage handle that provides access to stored values
 ///
 /// This handle provides a reactive interface to local storage, automatically
@@ -509,6 +916,19 @@ where
         self.state.get()
     }
 
+    /// Read the current value by reference, without cloning it
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.state.with(f)
+    }
+
+    /// Borrow the current value without cloning it
+    ///
+    /// The returned guard holds a read lock; see [`StateRef`] for when it is
+    /// safe to keep around.
+    pub fn borrow(&self) -> StateRef<'_, T> {
+        self.state.borrow()
+    }
+
     /// Get the storage key
     pub fn key(&self) -> &str {
         &self.key
@@ -600,8 +1020,8 @@ where
         // Update the reactive state first
         self.state_setter.set(new_value);
 
-        // Then persist to storage (ignore errors to maintain reactivity)
-        let _ = self.handle.save_to_storage();
+        // Then persist to storage (log errors to maintain reactivity)
+        self.persist();
     }
 
     /// Update the value using a function and persist to storage
@@ -612,8 +1032,20 @@ where
         // Update the reactive state first
         self.state_setter.update(updater);
 
-        // Then persist to storage (ignore errors to maintain reactivity)
-        let _ = self.handle.save_to_storage();
+        // Then persist to storage (log errors to maintain reactivity)
+        self.persist();
+    }
+
+    /// Save the current value, logging failures instead of returning them
+    fn persist(&self) {
+        let key = self.handle.key();
+        if let Err(error) = self
+            .handle
+            .save_to_storage()
+            .with_context(|| format!("while saving '{}' to local storage", key))
+        {
+            tracing::warn!(target: "hooks::storage", "{}", error);
+        }
     }
 
     /// Set a new value without persisting to storage
@@ -704,6 +1136,128 @@ fn get_storage_backend() -> Arc<dyn StorageBackend> {
     backend_lock.read().clone()
 }
 
+/// How [`import_all`] treats keys already present in storage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportMode {
+    /// Keep existing keys, replacing only those present in the bundle
+    #[default]
+    Merge,
+    /// Remove every existing key before loading the bundle
+    Overwrite,
+}
+
+/// Key of the object wrapping a value that [`export_all`] embeds verbatim
+const RAW_VALUE_TAG: &str = "$raw";
+
+/// The stored text of a value tagged by [`raw_value`], if `value` is one
+fn as_raw_value(value: &serde_json::Value) -> Option<&str> {
+    match value.as_object() {
+        Some(object) if object.len() == 1 => object.get(RAW_VALUE_TAG)?.as_str(),
+        _ => None,
+    }
+}
+
+/// Tag stored text to be written back verbatim by [`import_all`]
+fn raw_value(raw: String) -> serde_json::Value {
+    serde_json::json!({ RAW_VALUE_TAG: raw })
+}
+
+/// Export every key/value in the current storage backend as one JSON object
+///
+/// Values are embedded as parsed JSON, so the bundle can be inspected or
+/// edited by hand. Values that are not valid JSON are embedded as
+/// `{"$raw": "<stored text>"}`, which [`import_all`] writes back verbatim, so
+/// every value survives a round trip.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::storage::export_all;
+///
+/// let bundle = export_all().expect("storage should be readable");
+/// std::fs::write("backup.json", bundle.to_string()).unwrap();
+/// ```
+pub fn export_all() -> LocalStorageResult<serde_json::Value> {
+    let backend = get_storage_backend();
+    if !backend.is_available() {
+        return Err(LocalStorageError::StorageUnavailable);
+    }
+
+    let mut bundle = serde_json::Map::new();
+    for key in backend.keys()? {
+        if let Some(raw) = backend.read(&key)? {
+            let value = match serde_json::from_str(&raw) {
+                // JSON that looks like a tag is tagged too, to read back as itself
+                Ok(value) if as_raw_value(&value).is_none() => value,
+                _ => raw_value(raw),
+            };
+            bundle.insert(key, value);
+        }
+    }
+    Ok(serde_json::Value::Object(bundle))
+}
+
+/// Load a bundle produced by [`export_all`] into the current storage backend
+///
+/// Hooks mounted after the import read the imported values; handles that are
+/// already mounted keep their in-memory state until they are saved again.
+/// Values tagged as `{"$raw": "<text>"}` are stored as that text.
+///
+/// Bundles are often loaded from untrusted files, so keys containing a path
+/// separator or `..` are rejected before anything is written.
+pub fn import_all(bundle: &serde_json::Value, mode: ImportMode) -> LocalStorageResult<()> {
+    let entries = bundle.as_object().ok_or_else(|| {
+        LocalStorageError::DeserializationError("storage bundle must be a JSON object".to_string())
+    })?;
+    if let Some(key) = entries.keys().find(|key| !is_safe_key(key)) {
+        return Err(LocalStorageError::DeserializationError(format!(
+            "storage bundle contains an invalid key '{}'",
+            key
+        )));
+    }
+
+    let backend = get_storage_backend();
+    if !backend.is_available() {
+        return Err(LocalStorageError::StorageUnavailable);
+    }
+
+    if mode == ImportMode::Overwrite {
+        backend.clear_all()?;
+    }
+
+    let config = get_storage_config();
+    for (key, value) in entries {
+        if let Some(raw) = as_raw_value(value) {
+            backend.write(key, raw)?;
+            continue;
+        }
+
+        let json_str = if config.pretty_json {
+            serde_json::to_string_pretty(value)
+        } else {
+            serde_json::to_string(value)
+        }
+        .map_err(|e| LocalStorageError::SerializationError(e.to_string()))?;
+        backend.write(key, &json_str)?;
+    }
+
+    // Drop cached containers so the next mount reloads from storage
+    if let Some(states_registry) = STORAGE_STATES.get() {
+        let mut states = states_registry.write();
+        match mode {
+            ImportMode::Overwrite => states.clear(),
+            ImportMode::Merge => states.retain(|key, _| !entries.contains_key(key)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `key` can be used as a file name without escaping the storage directory
+fn is_safe_key(key: &str) -> bool {
+    !key.is_empty() && !key.contains(['/', '\\', '\0']) && !key.contains("..")
+}
+
 /// Clear all global storage state (for testing)
 #[cfg(test)]
 pub fn clear_storage_state() {
@@ -907,8 +1461,17 @@ where
                     }
                 }
             }
-            _ => {
-                // Storage read failed or no value found, use default
+            Ok(None) => {
+                // No value stored yet, use default
+                default_value
+            }
+            Err(error) => {
+                // Storage read failed, use default
+                tracing::warn!(
+                    target: "hooks::storage",
+                    "{}",
+                    ContextError::new(format!("while loading '{}' from local storage", key), error)
+                );
                 default_value
             }
         }
@@ -929,12 +1492,13 @@ where
 }
 
 pub fn use_local_storage<T>(
-    key: String,
+    key: impl Into<String>,
     default_value: T,
 ) -> (LocalStorageHandle<T>, LocalStorageSetter<T>)
 where
     T: Clone + Serialize + for<'de> Deserialize<'de> + Send + Sync + 'static,
 {
+    let key = key.into();
     // Get or create the global state container for this key
     let container = get_or_create_storage_state(&key, default_value);
 
@@ -952,3 +1516,356 @@ where
 
     (storage_handle, storage_setter)
 }
+
+/// A collection of storage entries sharing a key prefix
+///
+/// Each entry is persisted under `prefix.key`, so several maps can share one
+/// backend without seeing each other's entries. Dots and percent signs in
+/// entry keys are escaped, so a map doesn't list the entries of a map nested
+/// under its prefix, such as `prefix.sub`. Writes bump an internal revision
+/// so the owning component re-renders.
+pub struct LocalStorageMap<V> {
+    prefix: String,
+    backend: Arc<dyn StorageBackend>,
+    config: LocalStorageConfig,
+    revision: crate::hooks::state::StateSetter<u64>,
+    _marker: std::marker::PhantomData<fn() -> V>,
+}
+
+impl<V> LocalStorageMap<V>
+where
+    V: Serialize + for<'de> Deserialize<'de>,
+{
+    /// Get the key prefix shared by every entry
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Build the full storage key for an entry
+    fn storage_key(&self, key: &str) -> String {
+        format!("{}.{}", self.prefix, escape_entry_key(key))
+    }
+
+    /// Read an entry, returning None if it is missing or cannot be decoded
+    pub fn get(&self, key: &str) -> Option<V> {
+        if !self.backend.is_available() {
+            return None;
+        }
+
+        match self.backend.read(&self.storage_key(key)) {
+            Ok(Some(json_str)) => serde_json::from_str::<V>(&json_str).ok(),
+            _ => None,
+        }
+    }
+
+    /// Write an entry and persist it to storage
+    pub fn set(&self, key: &str, value: V) -> LocalStorageResult<()> {
+        if !self.backend.is_available() {
+            return Err(LocalStorageError::StorageUnavailable);
+        }
+
+        let json_str = if self.config.pretty_json {
+            serde_json::to_string_pretty(&value)
+        } else {
+            serde_json::to_string(&value)
+        }
+        .map_err(|e| LocalStorageError::SerializationError(e.to_string()))?;
+
+        self.backend.write(&self.storage_key(key), &json_str)?;
+        self.revision.update(|revision| revision.wrapping_add(1));
+        Ok(())
+    }
+
+    /// Remove an entry from storage
+    pub fn remove(&self, key: &str) -> LocalStorageResult<()> {
+        if !self.backend.is_available() {
+            return Err(LocalStorageError::StorageUnavailable);
+        }
+
+        self.backend.remove(&self.storage_key(key))?;
+        self.revision.update(|revision| revision.wrapping_add(1));
+        Ok(())
+    }
+
+    /// List the keys of every entry under this prefix, without the prefix
+    pub fn keys(&self) -> Vec<String> {
+        if !self.backend.is_available() {
+            return Vec::new();
+        }
+
+        let namespace = format!("{}.", self.prefix);
+        self.backend
+            .keys()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|key| {
+                // Keys with another dot belong to a nested map
+                key.strip_prefix(&namespace)
+                    .filter(|entry| !entry.contains('.'))
+                    .map(unescape_entry_key)
+            })
+            .collect()
+    }
+}
+
+/// Escape the separator in a map entry key, so the key never spans namespaces
+fn escape_entry_key(key: &str) -> String {
+    key.replace('%', "%25").replace('.', "%2E")
+}
+
+/// Undo [`escape_entry_key`]
+fn unescape_entry_key(key: &str) -> String {
+    key.replace("%2E", ".").replace("%25", "%")
+}
+
+impl<V> Clone for LocalStorageMap<V> {
+    fn clone(&self) -> Self {
+        Self {
+            prefix: self.prefix.clone(),
+            backend: self.backend.clone(),
+            config: self.config.clone(),
+            revision: self.revision.clone(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Manage a namespaced collection of persisted entries
+///
+/// Unlike [`use_local_storage`], which binds one key to one value, this hook
+/// exposes any number of entries stored as `prefix.key` in the current
+/// storage backend. Entries are read on demand, so `get` always reflects
+/// what is persisted.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::storage::use_local_storage_map;
+///
+/// // In a component context:
+/// let projects = use_local_storage_map::<String>("projects");
+///
+/// projects.set("alpha", "dark".to_string()).ok();
+/// for key in projects.keys() {
+///     println!("{} => {:?}", key, projects.get(&key));
+/// }
+/// ```
+pub fn use_local_storage_map<V>(prefix: impl Into<String>) -> LocalStorageMap<V>
+where
+    V: Serialize + for<'de> Deserialize<'de>,
+{
+    let (_revision, set_revision) = crate::hooks::state::use_state(|| 0u64);
+
+    LocalStorageMap {
+        prefix: prefix.into(),
+        backend: get_storage_backend(),
+        config: get_storage_config(),
+        revision: set_revision,
+        _marker: std::marker::PhantomData,
+    }
+}
+
+/// Reducer whose state is saved to storage after dispatches
+struct PersistentReducer<S: Serialize, A> {
+    state: Arc<RwLock<S>>,
+    version: Arc<parking_lot::Mutex<u64>>,
+    reducer: Arc<dyn Fn(S, A) -> S + Send + Sync>,
+    /// Change counter of the hook context that owns this reducer
+    owner: Arc<AtomicU64>,
+    key: String,
+    backend: Arc<dyn StorageBackend>,
+    config: LocalStorageConfig,
+    debounce: Duration,
+    /// When the newest unsaved state was committed
+    unsaved_since: parking_lot::Mutex<Option<Instant>>,
+}
+
+impl<S, A> PersistentReducer<S, A>
+where
+    S: Clone + Serialize + Send + Sync + 'static,
+    A: 'static,
+{
+    /// Reduce `action`, then save the new state or schedule saving it
+    fn dispatch(&self, action: A) {
+        let current_state = self.state.read().clone();
+        let new_state = (self.reducer)(current_state, action);
+        *self.state.write() = new_state;
+        *self.version.lock() += 1;
+        self.owner.fetch_add(1, Ordering::SeqCst);
+        crate::hooks::batch::request_render();
+
+        if self.debounce.is_zero() {
+            self.save();
+        } else {
+            *self.unsaved_since.lock() = Some(crate::clock::now());
+        }
+    }
+
+    /// Save the state once no action was dispatched for the debounce period
+    fn save_if_settled(&self) {
+        let settled = matches!(
+            *self.unsaved_since.lock(),
+            Some(since) if crate::clock::now().saturating_duration_since(since) >= self.debounce
+        );
+        if settled {
+            self.save();
+        } else if self.unsaved_since.lock().is_some() {
+            crate::hooks::batch::schedule_render(crate::hooks::batch::Priority::Low);
+        }
+    }
+}
+
+impl<S: Serialize, A> PersistentReducer<S, A> {
+    /// Save the current state, logging failures instead of returning them
+    fn save(&self) {
+        *self.unsaved_since.lock() = None;
+        if let Err(error) = self
+            .write_state()
+            .with_context(|| format!("while saving '{}' to local storage", self.key))
+        {
+            tracing::warn!(target: "hooks::storage", "{}", error);
+        }
+    }
+
+    fn write_state(&self) -> LocalStorageResult<()> {
+        if !self.backend.is_available() {
+            return Err(LocalStorageError::StorageUnavailable);
+        }
+
+        let state = self.state.read();
+        let json_str = if self.config.pretty_json {
+            serde_json::to_string_pretty(&*state)
+        } else {
+            serde_json::to_string(&*state)
+        }
+        .map_err(|e| LocalStorageError::SerializationError(e.to_string()))?;
+
+        self.backend.write(&self.key, &json_str)
+    }
+}
+
+impl<S: Serialize, A> Drop for PersistentReducer<S, A> {
+    fn drop(&mut self) {
+        // Don't lose the last changes when the component goes away mid-debounce
+        if self.unsaved_since.get_mut().is_some() {
+            self.save();
+        }
+    }
+}
+
+/// Read the state stored under `key`, if there is a readable one
+fn load_stored_state<S>(backend: &dyn StorageBackend, key: &str) -> Option<S>
+where
+    S: for<'de> Deserialize<'de>,
+{
+    if !backend.is_available() {
+        return None;
+    }
+
+    match backend.read(key) {
+        Ok(Some(json_str)) => match serde_json::from_str::<S>(&json_str) {
+            Ok(state) => Some(state),
+            Err(error) => {
+                tracing::warn!(
+                    target: "hooks::storage",
+                    "Ignoring unreadable state stored under '{}': {}",
+                    key,
+                    error
+                );
+                None
+            }
+        },
+        Ok(None) => None,
+        Err(error) => {
+            tracing::warn!(
+                target: "hooks::storage",
+                "{}",
+                ContextError::new(format!("while loading '{}' from local storage", key), error)
+            );
+            None
+        }
+    }
+}
+
+/// Reducer hook whose state survives restarts
+///
+/// Like [`use_reducer`](crate::hooks::reducer::use_reducer), but the initial
+/// state is read from storage under `key`, falling back to `default_state`
+/// when nothing readable is stored, and the state is saved again after every
+/// dispatch. See [`use_persistent_reducer_with_debounce`] to save less often.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::hooks::storage::use_persistent_reducer;
+///
+/// fn counter_reducer(count: i32, step: i32) -> i32 {
+///     count + step
+/// }
+///
+/// // In a component context:
+/// let (count, dispatch) = use_persistent_reducer(counter_reducer, "counter", 0);
+///
+/// // Saved right away, and read back on the next start
+/// dispatch.dispatch(1);
+/// ```
+pub fn use_persistent_reducer<S, A, R>(
+    reducer: R,
+    key: impl Into<String>,
+    default_state: S,
+) -> (ReducerStateHandle<S>, DispatchFn<A>)
+where
+    S: Clone + Serialize + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    A: Send + Sync + 'static,
+    R: Fn(S, A) -> S + Send + Sync + 'static,
+{
+    use_persistent_reducer_with_debounce(reducer, key, default_state, Duration::ZERO)
+}
+
+/// Reducer hook whose state is saved once dispatches pause for `debounce`
+///
+/// Works like [`use_persistent_reducer`], but a burst of actions, such as
+/// typing into a field kept in the state, is saved once instead of after
+/// every action. While changes are unsaved, every render requests a
+/// low-priority render, so the state is saved on the first frame after
+/// `debounce` passed without a dispatch. Changes still
+/// waiting for the debounce are saved when the component unmounts.
+pub fn use_persistent_reducer_with_debounce<S, A, R>(
+    reducer: R,
+    key: impl Into<String>,
+    default_state: S,
+    debounce: Duration,
+) -> (ReducerStateHandle<S>, DispatchFn<A>)
+where
+    S: Clone + Serialize + for<'de> Deserialize<'de> + Send + Sync + 'static,
+    A: Send + Sync + 'static,
+    R: Fn(S, A) -> S + Send + Sync + 'static,
+{
+    let container = crate::hooks::with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let container = ctx.get_or_init_state(index, || {
+            let key = key.into();
+            let backend = get_storage_backend();
+            let state = load_stored_state(backend.as_ref(), &key).unwrap_or(default_state);
+            Arc::new(PersistentReducer {
+                state: Arc::new(RwLock::new(state)),
+                version: Arc::new(parking_lot::Mutex::new(0)),
+                reducer: Arc::new(reducer) as Arc<dyn Fn(S, A) -> S + Send + Sync>,
+                owner: ctx.state_changes(),
+                key,
+                backend,
+                config: get_storage_config(),
+                debounce,
+                unsaved_since: parking_lot::Mutex::new(None),
+            })
+        });
+        container.borrow().clone()
+    });
+
+    container.save_if_settled();
+
+    let state_handle =
+        ReducerStateHandle::from_parts(container.state.clone(), container.version.clone());
+    let dispatch_fn = DispatchFn::new(move |action| container.dispatch(action));
+    (state_handle, dispatch_fn)
+}
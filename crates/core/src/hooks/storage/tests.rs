@@ -95,6 +95,8 @@ fn test_use_local_storage_basic_primitives() {
 
             str_setter.set("world".to_string());
             assert_eq!(str_handle.get(), "world");
+            assert_eq!(str_handle.with(|value| value.len()), 5);
+            assert_eq!(str_handle.borrow().as_str(), "world");
 
             // Test with boolean
             let (bool_handle, bool_setter) = use_local_storage("test_bool".to_string(), false);
@@ -329,6 +331,12 @@ fn test_storage_unavailable() {
             }
         }
 
+        // Backends that do not list their keys report it instead of failing to compile
+        assert!(matches!(
+            UnavailableBackend.keys(),
+            Err(LocalStorageError::Unsupported(_))
+        ));
+
         let unavailable_backend = Arc::new(UnavailableBackend);
         set_storage_backend(unavailable_backend);
 
@@ -426,6 +434,257 @@ fn test_storage_key_uniqueness() {
     });
 }
 
+/// Test that two storage maps with different prefixes stay isolated
+#[test]
+fn test_local_storage_map_prefix_isolation() {
+    with_storage_test(|| {
+        let backend = create_temp_storage_backend();
+        set_storage_backend(backend.clone());
+
+        with_hook_context(|_ctx| {
+            let alpha = use_local_storage_map::<i32>("alpha");
+            let beta = use_local_storage_map::<i32>("beta");
+
+            alpha.set("width", 80).unwrap();
+            alpha.set("height", 24).unwrap();
+            beta.set("width", 120).unwrap();
+
+            // Entries are persisted under `prefix.key`
+            assert_eq!(backend.read("alpha.width").unwrap().as_deref(), Some("80"));
+            assert_eq!(backend.read("beta.width").unwrap().as_deref(), Some("120"));
+
+            assert_eq!(alpha.get("width"), Some(80));
+            assert_eq!(beta.get("width"), Some(120));
+            assert_eq!(beta.get("height"), None);
+
+            assert_eq!(
+                alpha.keys(),
+                vec!["height".to_string(), "width".to_string()]
+            );
+            assert_eq!(beta.keys(), vec!["width".to_string()]);
+
+            // Removing from one map leaves the other untouched
+            alpha.remove("width").unwrap();
+            assert_eq!(alpha.get("width"), None);
+            assert_eq!(beta.get("width"), Some(120));
+            assert_eq!(alpha.keys(), vec!["height".to_string()]);
+        });
+    });
+}
+
+/// Test that a map doesn't list the entries of a map nested under its prefix
+#[test]
+fn test_local_storage_map_ignores_nested_prefixes() {
+    with_storage_test(|| {
+        let backend = create_temp_storage_backend();
+        set_storage_backend(backend.clone());
+
+        with_hook_context(|_ctx| {
+            let outer = use_local_storage_map::<i32>("a");
+            let nested = use_local_storage_map::<i32>("a.b");
+
+            outer.set("notes.txt", 1).unwrap();
+            outer.set("100%", 2).unwrap();
+            nested.set("x", 3).unwrap();
+
+            // Separators in entry keys are escaped
+            assert_eq!(backend.read("a.notes%2Etxt").unwrap().as_deref(), Some("1"));
+            assert_eq!(outer.get("notes.txt"), Some(1));
+            assert_eq!(outer.get("b.x"), None);
+
+            assert_eq!(
+                outer.keys(),
+                vec!["100%".to_string(), "notes.txt".to_string()]
+            );
+            assert_eq!(nested.keys(), vec!["x".to_string()]);
+        });
+    });
+}
+
+/// Test listing and clearing keys on the file backend
+#[test]
+fn test_file_storage_backend_keys_and_clear_all() {
+    let dir = tempfile::tempdir().unwrap();
+    let backend = FileStorageBackend::new(LocalStorageConfig {
+        storage_dir: dir.path().join("store"),
+        ..LocalStorageConfig::default()
+    });
+
+    assert!(backend.keys().unwrap().is_empty());
+
+    backend.write("b.one", "1").unwrap();
+    backend.write("a", "2").unwrap();
+    fs::write(dir.path().join("store").join("notes.txt"), "ignored").unwrap();
+
+    assert_eq!(
+        backend.keys().unwrap(),
+        vec!["a".to_string(), "b.one".to_string()]
+    );
+
+    backend.clear_all().unwrap();
+    assert!(backend.keys().unwrap().is_empty());
+    assert!(dir.path().join("store").join("notes.txt").exists());
+}
+
+/// Test round-tripping a multi-key store through export and import
+#[test]
+fn test_export_import_round_trip() {
+    with_storage_test(|| {
+        let backend = create_temp_storage_backend();
+        set_storage_backend(backend.clone());
+
+        backend.write("count", "42").unwrap();
+        backend
+            .write(
+                "data",
+                &serde_json::to_string(&TestData::default()).unwrap(),
+            )
+            .unwrap();
+        backend.write("raw", "not json").unwrap();
+
+        let bundle = export_all().unwrap();
+        assert_eq!(bundle["count"], serde_json::json!(42));
+        assert_eq!(bundle["raw"], serde_json::json!({ "$raw": "not json" }));
+
+        // Merge keeps keys that are not part of the bundle
+        backend.clear();
+        backend.write("extra", "true").unwrap();
+        import_all(&bundle, ImportMode::Merge).unwrap();
+        assert_eq!(backend.len(), 4);
+        assert_eq!(backend.read("extra").unwrap().as_deref(), Some("true"));
+
+        // Overwrite leaves exactly the bundle behind
+        import_all(&bundle, ImportMode::Overwrite).unwrap();
+        assert_eq!(backend.keys().unwrap(), vec!["count", "data", "raw"]);
+        assert_eq!(export_all().unwrap(), bundle);
+
+        with_hook_context(|_ctx| {
+            let (handle, _setter) = use_local_storage(
+                "data".to_string(),
+                TestData {
+                    id: 0,
+                    ..TestData::default()
+                },
+            );
+            assert_eq!(handle.get(), TestData::default());
+        });
+
+        assert!(import_all(&serde_json::json!([1, 2]), ImportMode::Merge).is_err());
+    });
+}
+
+/// Test that stored text which isn't JSON, or looks like a tag, is restored verbatim
+#[test]
+fn test_export_import_restores_raw_values() {
+    with_storage_test(|| {
+        let backend = create_temp_storage_backend();
+        set_storage_backend(backend.clone());
+
+        let stored = [
+            ("raw", "not json"),
+            ("quoted", "\"not json\""),
+            ("lookalike", r#"{"$raw":"x"}"#),
+            ("tagged", r#"{"$raw":"x","other":1}"#),
+        ];
+        for (key, value) in stored {
+            backend.write(key, value).unwrap();
+        }
+
+        let bundle = export_all().unwrap();
+        assert_eq!(bundle["quoted"], serde_json::json!("not json"));
+        let text = bundle.to_string();
+
+        backend.clear();
+        import_all(&serde_json::from_str(&text).unwrap(), ImportMode::Merge).unwrap();
+        for (key, value) in stored {
+            assert_eq!(
+                backend.read(key).unwrap().as_deref(),
+                Some(value),
+                "key {}",
+                key
+            );
+        }
+    });
+}
+
+/// Test that bundle keys cannot escape the storage directory
+#[test]
+fn test_import_rejects_path_traversal_keys() {
+    with_storage_test(|| {
+        let dir = tempfile::tempdir().unwrap();
+        let store = dir.path().join("store");
+        let backend = Arc::new(FileStorageBackend::new(LocalStorageConfig {
+            storage_dir: store.clone(),
+            ..LocalStorageConfig::default()
+        }));
+        set_storage_backend(backend.clone());
+        backend.write("kept", "1").unwrap();
+
+        for key in ["../escaped", "nested/key", "..\\escaped", "..", ""] {
+            let bundle = serde_json::json!({ "safe": 1, key: 2 });
+            assert!(
+                import_all(&bundle, ImportMode::Overwrite).is_err(),
+                "key {:?} was accepted",
+                key
+            );
+        }
+
+        // Nothing was written or cleared by the rejected imports
+        assert!(!dir.path().join("escaped.json").exists());
+        assert_eq!(backend.keys().unwrap(), vec!["kept"]);
+
+        // Dots inside an ordinary key are fine
+        import_all(
+            &serde_json::json!({ "tasks.alpha": [1] }),
+            ImportMode::Merge,
+        )
+        .unwrap();
+        assert_eq!(backend.keys().unwrap(), vec!["kept", "tasks.alpha"]);
+    });
+}
+
+/// Test compressed round trips above and below the threshold
+#[cfg(feature = "compression")]
+#[test]
+fn test_compressing_backend_round_trip() {
+    let backend = CompressingStorageBackend::new(MemoryStorageBackend::new(), 64);
+
+    // Small values are stored verbatim
+    backend.write("small", "[1,2,3]").unwrap();
+    assert_eq!(
+        backend.inner().read("small").unwrap().as_deref(),
+        Some("[1,2,3]")
+    );
+    assert_eq!(backend.read("small").unwrap().as_deref(), Some("[1,2,3]"));
+
+    // Large values are compressed on disk and restored on read
+    let large = serde_json::to_string(&vec![TestData::default(); 50]).unwrap();
+    backend.write("large", &large).unwrap();
+    let stored = backend.inner().read("large").unwrap().unwrap();
+    assert!(stored.starts_with("gz:"));
+    assert!(stored.len() < large.len());
+    assert_eq!(backend.read("large").unwrap(), Some(large));
+
+    // Entries written before the wrapper existed still read back
+    backend.inner().write("legacy", "\"plain\"").unwrap();
+    assert_eq!(
+        backend.read("legacy").unwrap().as_deref(),
+        Some("\"plain\"")
+    );
+
+    // A short value that looks like the marker is not misread
+    backend.write("tricky", "gz:not-really").unwrap();
+    assert_eq!(
+        backend.read("tricky").unwrap().as_deref(),
+        Some("gz:not-really")
+    );
+
+    assert_eq!(
+        backend.keys().unwrap(),
+        vec!["large", "legacy", "small", "tricky"]
+    );
+}
+
 // SQLite Backend Tests
 #[cfg(feature = "sqlite")]
 mod sqlite_tests {
@@ -657,4 +916,235 @@ mod sqlite_tests {
             assert_eq!(result, Some("persistent_value".to_string()));
         }
     }
+
+    #[tokio::test]
+    async fn test_sqlite_compare_and_swap_detects_conflict() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let db_path = temp_file.path().to_str().unwrap();
+        let database_url = format!("sqlite:{}", db_path);
+
+        // Two instances sharing one database, as two running apps would
+        let first = SqliteStorageBackend::new(&database_url).await.unwrap();
+        let second = SqliteStorageBackend::new(&database_url).await.unwrap();
+
+        // Creating a key requires that it is absent
+        let version = first.compare_and_swap("doc", None, "v1").await.unwrap();
+        assert_eq!(version, 1);
+        assert!(matches!(
+            second.compare_and_swap("doc", None, "other").await,
+            Err(LocalStorageError::Conflict {
+                expected: None,
+                actual: Some(1),
+                ..
+            })
+        ));
+
+        // Both instances read the same version
+        let (_, seen_by_first) = first.read_versioned_async("doc").await.unwrap().unwrap();
+        let (_, seen_by_second) = second.read_versioned_async("doc").await.unwrap().unwrap();
+        assert_eq!(seen_by_first, seen_by_second);
+
+        // The second instance wins the race
+        let version = second
+            .compare_and_swap("doc", Some(seen_by_second), "from second")
+            .await
+            .unwrap();
+        assert_eq!(version, 2);
+
+        // The first instance's stale write is rejected rather than lost
+        let result = first
+            .compare_and_swap("doc", Some(seen_by_first), "from first")
+            .await;
+        match result {
+            Err(LocalStorageError::Conflict {
+                key,
+                expected,
+                actual,
+            }) => {
+                assert_eq!(key, "doc");
+                assert_eq!(expected, Some(1));
+                assert_eq!(actual, Some(2));
+            }
+            other => panic!("Expected Conflict, got {:?}", other),
+        }
+        assert_eq!(
+            first.read_async("doc").await.unwrap(),
+            Some("from second".to_string())
+        );
+
+        // Plain writes keep bumping the version
+        first.write_async("doc", "forced").await.unwrap();
+        let (value, version) = second.read_versioned_async("doc").await.unwrap().unwrap();
+        assert_eq!(value, "forced");
+        assert_eq!(version, 3);
+    }
+
+    /// In-memory async backend for exercising backend-agnostic helpers
+    #[derive(Default)]
+    struct MemoryAsyncBackend {
+        storage: RwLock<HashMap<String, String>>,
+    }
+
+    #[async_trait]
+    impl AsyncStorageBackend for MemoryAsyncBackend {
+        async fn read_async(&self, key: &str) -> LocalStorageResult<Option<String>> {
+            Ok(self.storage.read().get(key).cloned())
+        }
+
+        async fn write_async(&self, key: &str, value: &str) -> LocalStorageResult<()> {
+            self.storage
+                .write()
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        async fn remove_async(&self, key: &str) -> LocalStorageResult<()> {
+            self.storage.write().remove(key);
+            Ok(())
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        async fn initialize(&self) -> LocalStorageResult<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_versioning_is_unsupported_by_default() {
+        let backend = MemoryAsyncBackend::default();
+        backend.write_async("doc", "v1").await.unwrap();
+
+        assert!(matches!(
+            backend.read_versioned_async("doc").await,
+            Err(LocalStorageError::Unsupported(_))
+        ));
+        assert!(matches!(
+            backend.compare_and_swap("doc", Some(1), "v2").await,
+            Err(LocalStorageError::Unsupported(_))
+        ));
+        assert_eq!(
+            backend.read_async("doc").await.unwrap().as_deref(),
+            Some("v1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_migrate_storage_copies_every_key() {
+        let from = MemoryStorageBackend::new();
+        from.write("count", "42").unwrap();
+        from.write("theme", "\"dark\"").unwrap();
+        from.write("tasks.alpha", "[1,2,3]").unwrap();
+
+        let to = MemoryAsyncBackend::default();
+        to.write_async("theme", "\"light\"").await.unwrap();
+        to.write_async("untouched", "true").await.unwrap();
+
+        let copied = migrate_storage(&from, &to).await.unwrap();
+        assert_eq!(copied, 3);
+
+        // Every source key reads back identically from the destination
+        for key in from.keys().unwrap() {
+            assert_eq!(
+                to.read_async(&key).await.unwrap(),
+                from.read(&key).unwrap(),
+                "mismatch for key '{}'",
+                key
+            );
+        }
+
+        // Keys only present in the destination survive the migration
+        assert_eq!(
+            to.read_async("untouched").await.unwrap(),
+            Some("true".to_string())
+        );
+    }
+}
+
+fn shopping_reducer(mut items: Vec<String>, item: &'static str) -> Vec<String> {
+    items.push(item.to_string());
+    items
+}
+
+/// Test that dispatched changes are read back after a restart
+#[test]
+fn test_persistent_reducer_survives_restart() {
+    with_storage_test(|| {
+        let memory_backend = create_temp_storage_backend();
+        set_storage_backend(memory_backend.clone());
+
+        with_hook_context(|_ctx| {
+            let (items, dispatch) =
+                use_persistent_reducer(shopping_reducer, "shopping", Vec::new());
+            assert!(items.get().is_empty());
+
+            dispatch.dispatch("milk");
+            dispatch.dispatch("eggs");
+            assert_eq!(
+                memory_backend.read("shopping").unwrap(),
+                Some(r#"["milk","eggs"]"#.to_string())
+            );
+        });
+
+        // A fresh hook context stands in for the next start of the app
+        with_hook_context(|_ctx| {
+            let (items, dispatch) =
+                use_persistent_reducer(shopping_reducer, "shopping", Vec::new());
+            assert_eq!(items.get(), vec!["milk", "eggs"]);
+            assert_eq!(items.version(), 0);
+
+            dispatch.dispatch("bread");
+            assert_eq!(items.get(), vec!["milk", "eggs", "bread"]);
+        });
+    });
+}
+
+/// Test that debounced persistence saves once dispatches pause
+#[test]
+fn test_persistent_reducer_debounces_saves() {
+    with_storage_test(|| {
+        let memory_backend = create_temp_storage_backend();
+        set_storage_backend(memory_backend.clone());
+        let clock = crate::clock::ManualClock::start();
+        let debounce = Duration::from_millis(300);
+
+        with_hook_context(|ctx| {
+            let render = || {
+                ctx.reset_hook_index();
+                use_persistent_reducer_with_debounce(
+                    shopping_reducer,
+                    "debounced",
+                    Vec::new(),
+                    debounce,
+                )
+            };
+            let stored = || memory_backend.read("debounced").unwrap();
+
+            let (_, dispatch) = render();
+            dispatch.dispatch("milk");
+            clock.advance(Duration::from_millis(200));
+            render();
+            assert_eq!(stored(), None);
+
+            // Another dispatch restarts the debounce
+            dispatch.dispatch("eggs");
+            clock.advance(Duration::from_millis(250));
+            render();
+            assert_eq!(stored(), None);
+
+            clock.advance(Duration::from_millis(50));
+            render();
+            assert_eq!(stored(), Some(r#"["milk","eggs"]"#.to_string()));
+
+            // Changes still waiting for the debounce are saved on unmount
+            dispatch.dispatch("bread");
+        });
+
+        assert_eq!(
+            memory_backend.read("debounced").unwrap(),
+            Some(r#"["milk","eggs","bread"]"#.to_string())
+        );
+    });
 }
@@ -3,6 +3,16 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+/// Serializes tests that set the global current event
+static CURRENT_EVENT_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Acquire exclusive use of the global current event for the duration of a test
+pub(crate) fn lock_current_event() -> std::sync::MutexGuard<'static, ()> {
+    CURRENT_EVENT_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
 // Thread-local registry to track component contexts by ID for testing
 thread_local! {
     static COMPONENT_CONTEXTS: RefCell<HashMap<&'static str, Rc<HookContext>>> =
@@ -0,0 +1,63 @@
+//! Theme Hooks
+//!
+//! This module provides a `use_high_contrast` hook that lets users switch an
+//! application theme to its high-contrast variant at runtime.
+
+use crate::color::Theme;
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Handle for turning high-contrast mode on and off
+#[derive(Clone)]
+pub struct HighContrastToggle {
+    enabled: StateHandle<bool>,
+    setter: StateSetter<bool>,
+}
+
+impl HighContrastToggle {
+    /// Returns true if high-contrast mode is currently enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.get()
+    }
+
+    /// Enable or disable high-contrast mode
+    pub fn set(&self, enabled: bool) {
+        self.setter.set(enabled);
+    }
+
+    /// Flip high-contrast mode
+    pub fn toggle(&self) {
+        self.setter.update(|enabled| !enabled);
+    }
+}
+
+/// Hook that switches the provided theme to its high-contrast variant on demand
+///
+/// Returns the theme to render with — `theme` itself, or
+/// [`Theme::high_contrast`] when enabled — and a toggle handle. High-contrast
+/// mode starts disabled.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::theme::use_high_contrast;
+/// # use pulse_core::color::Theme;
+/// // In a component context:
+/// let (theme, high_contrast) = use_high_contrast(Theme::default());
+///
+/// // Later, e.g. in an event handler for a "toggle contrast" key:
+/// high_contrast.toggle();
+/// ```
+pub fn use_high_contrast(theme: Theme) -> (Theme, HighContrastToggle) {
+    let (enabled, setter) = use_state(|| false);
+
+    let active = if enabled.get() {
+        theme.high_contrast()
+    } else {
+        theme
+    };
+
+    (active, HighContrastToggle { enabled, setter })
+}
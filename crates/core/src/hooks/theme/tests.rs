@@ -0,0 +1,91 @@
+//! Tests for the theme hooks and high-contrast generation
+
+use super::*;
+use crate::color::{HIGH_CONTRAST_MIN_RATIO, contrast_ratio, relative_luminance};
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use ratatui::style::Color;
+
+fn light_theme() -> Theme {
+    Theme {
+        primary: Color::Rgb(129, 140, 248),
+        secondary: Color::Rgb(156, 163, 175),
+        accent: Color::Yellow,
+        success: Color::Rgb(134, 239, 172),
+        warning: Color::Rgb(253, 224, 71),
+        danger: Color::Rgb(252, 165, 165),
+        background: Color::Rgb(249, 250, 251),
+        text: Color::Rgb(75, 85, 99),
+    }
+}
+
+#[test]
+fn test_relative_luminance_extremes() {
+    assert_eq!(relative_luminance(Color::Rgb(0, 0, 0)), 0.0);
+    assert!((relative_luminance(Color::Rgb(255, 255, 255)) - 1.0).abs() < 1e-9);
+    assert!((contrast_ratio(Color::Black, Color::White) - 21.0).abs() < 1e-9);
+    assert_eq!(contrast_ratio(Color::Red, Color::Red), 1.0);
+}
+
+#[test]
+fn test_high_contrast_pairs_exceed_threshold() {
+    for theme in [Theme::default(), light_theme()] {
+        let high = theme.high_contrast();
+        for foreground in high.foregrounds() {
+            let ratio = contrast_ratio(foreground, high.background);
+            assert!(
+                ratio >= HIGH_CONTRAST_MIN_RATIO,
+                "{:?} on {:?} has contrast {:.2}",
+                foreground,
+                high.background,
+                ratio
+            );
+        }
+    }
+}
+
+#[test]
+fn test_high_contrast_keeps_background_polarity() {
+    assert_eq!(
+        Theme::default().high_contrast().background,
+        Color::Rgb(0, 0, 0)
+    );
+    assert_eq!(
+        light_theme().high_contrast().background,
+        Color::Rgb(255, 255, 255)
+    );
+}
+
+#[test]
+fn test_high_contrast_does_not_reduce_contrast() {
+    let theme = Theme::default();
+    let high = theme.high_contrast();
+    for (original, boosted) in theme.foregrounds().into_iter().zip(high.foregrounds()) {
+        assert!(
+            contrast_ratio(boosted, high.background) >= contrast_ratio(original, theme.background)
+        );
+    }
+}
+
+#[test]
+fn test_use_high_contrast_toggle() {
+    with_test_isolate(|| {
+        let render = || {
+            with_component_id("HighContrastComponent", |_| {
+                use_high_contrast(Theme::default())
+            })
+        };
+
+        let (theme, toggle) = render();
+        assert_eq!(theme, Theme::default());
+        assert!(!toggle.is_enabled());
+
+        toggle.toggle();
+        let (theme, toggle) = render();
+        assert!(toggle.is_enabled());
+        assert_eq!(theme, Theme::default().high_contrast());
+
+        toggle.set(false);
+        let (theme, _) = render();
+        assert_eq!(theme, Theme::default());
+    });
+}
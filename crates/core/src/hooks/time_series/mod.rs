@@ -0,0 +1,122 @@
+//! Time Series Hook
+//!
+//! This module provides a `use_time_series` hook keeping the most recent
+//! samples of a metric in a fixed-size ring buffer, as needed for sparklines
+//! of CPU usage, network activity and the like. Once the buffer is full,
+//! each new sample evicts the oldest one. The handle is `Send`, so samples
+//! can be pushed from background tasks.
+
+use std::collections::VecDeque;
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Handle returned by [`use_time_series`]
+#[derive(Clone)]
+pub struct TimeSeriesHandle {
+    samples: StateHandle<VecDeque<u64>>,
+    setter: StateSetter<VecDeque<u64>>,
+    capacity: usize,
+}
+
+impl TimeSeriesHandle {
+    /// Append a sample, evicting the oldest one when the buffer is full
+    pub fn push(&self, sample: u64) {
+        let capacity = self.capacity;
+        self.setter.update(move |samples| {
+            let mut samples = samples.clone();
+            // A smaller capacity than in earlier renders drops several
+            while samples.len() >= capacity {
+                samples.pop_front();
+            }
+            samples.push_back(sample);
+            samples
+        });
+    }
+
+    /// Remove all samples
+    pub fn clear(&self) {
+        self.setter.set(VecDeque::new());
+    }
+
+    /// The samples, oldest first
+    pub fn values(&self) -> Vec<u64> {
+        self.samples
+            .with(|samples| samples.iter().copied().collect())
+    }
+
+    /// The most recent sample
+    pub fn current(&self) -> Option<u64> {
+        self.samples.with(|samples| samples.back().copied())
+    }
+
+    /// The smallest sample in the buffer
+    pub fn min(&self) -> Option<u64> {
+        self.samples.with(|samples| samples.iter().copied().min())
+    }
+
+    /// The largest sample in the buffer
+    pub fn max(&self) -> Option<u64> {
+        self.samples.with(|samples| samples.iter().copied().max())
+    }
+
+    /// Number of samples in the buffer
+    pub fn len(&self) -> usize {
+        self.samples.with(|samples| samples.len())
+    }
+
+    /// Returns true if no samples were pushed since the last clear
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Maximum number of samples kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+/// Hook keeping the last `capacity` samples of a metric
+///
+/// Draw the samples with [`AnnotatedSparkline`](crate::widgets::AnnotatedSparkline)
+/// or a plain ratatui `Sparkline`.
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::time_series::use_time_series;
+/// # use pulse_core::hooks::interval::use_interval;
+/// # use pulse_core::widgets::AnnotatedSparkline;
+/// # use ratatui::{Frame, layout::Rect};
+/// # use std::time::Duration;
+/// # fn read_cpu_usage() -> u64 { 42 }
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// let cpu = use_time_series(60);
+///
+/// use_interval(
+///     {
+///         let cpu = cpu.clone();
+///         move || cpu.push(read_cpu_usage())
+///     },
+///     Duration::from_secs(1),
+/// );
+///
+/// frame.render_widget(AnnotatedSparkline::new(cpu.values()).unit("%"), area);
+/// # }
+/// ```
+pub fn use_time_series(capacity: usize) -> TimeSeriesHandle {
+    assert!(capacity > 0, "time series capacity must be at least 1");
+    let (samples, setter) = use_state(|| VecDeque::with_capacity(capacity));
+
+    TimeSeriesHandle {
+        samples,
+        setter,
+        capacity,
+    }
+}
@@ -0,0 +1,75 @@
+//! Tests for the use_time_series hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render(capacity: usize) -> TimeSeriesHandle {
+    with_component_id("CpuChart", |_| use_time_series(capacity))
+}
+
+#[test]
+fn test_oldest_samples_are_evicted() {
+    with_test_isolate(|| {
+        let series = render(3);
+        assert!(series.is_empty());
+        assert_eq!(series.current(), None);
+        assert_eq!(series.min(), None);
+
+        for sample in [5, 9, 2, 7] {
+            series.push(sample);
+        }
+        assert_eq!(series.values(), [9, 2, 7]);
+        assert_eq!(series.len(), 3);
+        assert_eq!(series.current(), Some(7));
+        assert_eq!(series.min(), Some(2));
+        assert_eq!(series.max(), Some(9));
+
+        // Re-rendering keeps the samples
+        let series = render(3);
+        series.push(1);
+        assert_eq!(series.values(), [2, 7, 1]);
+
+        series.clear();
+        assert!(series.is_empty());
+    });
+}
+
+#[test]
+fn test_shrinking_capacity_drops_the_oldest_samples() {
+    with_test_isolate(|| {
+        let series = render(5);
+        for sample in 1..=5 {
+            series.push(sample);
+        }
+
+        let series = render(2);
+        series.push(6);
+        assert_eq!(series.values(), [5, 6]);
+        assert_eq!(series.len(), series.capacity());
+    });
+}
+
+#[test]
+fn test_samples_can_be_pushed_from_other_threads() {
+    with_test_isolate(|| {
+        let series = render(10);
+        let producer = series.clone();
+        std::thread::spawn(move || {
+            for sample in 0..4 {
+                producer.push(sample * 10);
+            }
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(series.values(), [0, 10, 20, 30]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "capacity must be at least 1")]
+fn test_zero_capacity_panics() {
+    with_test_isolate(|| {
+        render(0);
+    });
+}
@@ -0,0 +1,156 @@
+//! Toggle List Hook
+//!
+//! This module provides a `use_toggle_list` hook for multi-select lists such
+//! as checklists, where any number of items can be selected at once. Items
+//! are identified by an id of the caller's choice, so the selection survives
+//! reordering and filtering of the list.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Selected ids, in the order they were selected
+#[derive(Clone)]
+struct ToggledIds<Id> {
+    order: Vec<Id>,
+    ids: HashSet<Id>,
+}
+
+impl<Id: Hash + Eq + Clone> ToggledIds<Id> {
+    fn new() -> Self {
+        Self {
+            order: Vec::new(),
+            ids: HashSet::new(),
+        }
+    }
+
+    fn insert(&mut self, id: Id) {
+        if self.ids.insert(id.clone()) {
+            self.order.push(id);
+        }
+    }
+
+    fn remove(&mut self, id: &Id) {
+        if self.ids.remove(id) {
+            self.order.retain(|selected| selected != id);
+        }
+    }
+}
+
+/// Handle returned by [`use_toggle_list`]
+#[derive(Clone)]
+pub struct ToggleListHandle<Id> {
+    selection: StateHandle<ToggledIds<Id>>,
+    setter: StateSetter<ToggledIds<Id>>,
+}
+
+impl<Id> ToggleListHandle<Id>
+where
+    Id: Hash + Eq + Clone + Send + Sync + 'static,
+{
+    /// Select `id` if it isn't selected, deselect it otherwise
+    pub fn toggle(&self, id: Id) {
+        self.setter.update(move |selection| {
+            let mut selection = selection.clone();
+            if selection.ids.contains(&id) {
+                selection.remove(&id);
+            } else {
+                selection.insert(id);
+            }
+            selection
+        });
+    }
+
+    /// Select or deselect `id`
+    pub fn set_selected(&self, id: Id, selected: bool) {
+        self.setter.update(move |selection| {
+            let mut selection = selection.clone();
+            if selected {
+                selection.insert(id);
+            } else {
+                selection.remove(&id);
+            }
+            selection
+        });
+    }
+
+    /// Returns true if `id` is selected
+    pub fn is_selected(&self, id: &Id) -> bool {
+        self.selection.with(|selection| selection.ids.contains(id))
+    }
+
+    /// The selected ids, in the order they were selected
+    pub fn selected(&self) -> Vec<Id> {
+        self.selection.with(|selection| selection.order.clone())
+    }
+
+    /// Number of selected ids
+    pub fn count(&self) -> usize {
+        self.selection.with(|selection| selection.order.len())
+    }
+
+    /// Returns true if nothing is selected
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    /// Select every id in `ids`, keeping ids that are already selected
+    pub fn select_all(&self, ids: impl IntoIterator<Item = Id>) {
+        let ids: Vec<Id> = ids.into_iter().collect();
+        self.setter.update(move |selection| {
+            let mut selection = selection.clone();
+            for id in ids {
+                selection.insert(id);
+            }
+            selection
+        });
+    }
+
+    /// Deselect everything
+    pub fn clear(&self) {
+        self.setter.set(ToggledIds::new());
+    }
+}
+
+/// Hook that tracks which items of a list are selected
+///
+/// Nothing is selected initially. Since the hook doesn't know the items,
+/// [`select_all`](ToggleListHandle::select_all) takes their ids.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::toggle_list::use_toggle_list;
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// # struct Task { id: u32, title: String }
+/// # let tasks: Vec<Task> = Vec::new();
+/// # let cursor = 0;
+/// // In a component context:
+/// let checked = use_toggle_list::<u32>();
+///
+/// if let Some(key) = use_key_event() {
+///     match key.code {
+///         KeyCode::Char(' ') => checked.toggle(tasks[cursor].id),
+///         KeyCode::Char('a') => checked.select_all(tasks.iter().map(|task| task.id)),
+///         KeyCode::Esc => checked.clear(),
+///         _ => {}
+///     }
+/// }
+///
+/// for task in &tasks {
+///     let mark = if checked.is_selected(&task.id) { "[x]" } else { "[ ]" };
+///     let line = format!("{mark} {}", task.title);
+/// }
+/// ```
+pub fn use_toggle_list<Id>() -> ToggleListHandle<Id>
+where
+    Id: Hash + Eq + Clone + Send + Sync + 'static,
+{
+    let (selection, setter) = use_state(ToggledIds::new);
+    ToggleListHandle { selection, setter }
+}
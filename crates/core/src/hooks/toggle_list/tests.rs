@@ -0,0 +1,65 @@
+//! Tests for the use_toggle_list hook
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render() -> ToggleListHandle<&'static str> {
+    with_component_id("Checklist", |_| use_toggle_list())
+}
+
+#[test]
+fn test_toggle_selects_and_deselects() {
+    with_test_isolate(|| {
+        let list = render();
+        assert!(list.is_empty());
+
+        list.toggle("milk");
+        list.toggle("eggs");
+        let list = render();
+        assert!(list.is_selected(&"milk"));
+        assert!(list.is_selected(&"eggs"));
+        assert!(!list.is_selected(&"bread"));
+        assert_eq!(list.selected(), vec!["milk", "eggs"]);
+
+        list.toggle("milk");
+        let list = render();
+        assert!(!list.is_selected(&"milk"));
+        assert_eq!(list.selected(), vec!["eggs"]);
+
+        list.set_selected("eggs", true);
+        list.set_selected("bread", false);
+        assert_eq!(render().selected(), vec!["eggs"]);
+    });
+}
+
+#[test]
+fn test_select_all_keeps_existing_selection() {
+    with_test_isolate(|| {
+        let list = render();
+        list.toggle("eggs");
+        list.select_all(["milk", "eggs", "bread"]);
+
+        let list = render();
+        assert_eq!(list.selected(), vec!["eggs", "milk", "bread"]);
+        assert_eq!(list.count(), 3);
+    });
+}
+
+#[test]
+fn test_clear_deselects_everything() {
+    with_test_isolate(|| {
+        let list = render();
+        list.select_all(["milk", "eggs"]);
+        assert_eq!(render().count(), 2);
+
+        list.clear();
+        let list = render();
+        assert!(list.is_empty());
+        assert!(!list.is_selected(&"milk"));
+
+        // Selecting again after clearing starts a new order
+        list.toggle("eggs");
+        list.toggle("milk");
+        assert_eq!(render().selected(), vec!["eggs", "milk"]);
+    });
+}
@@ -0,0 +1,127 @@
+//! Transition Hook
+//!
+//! This module provides a `use_transition` hook that turns discrete state
+//! changes into smooth transitions. When the tracked value changes, the hook
+//! reports both the previous and the next value together with an interpolation
+//! progress running from `0.0` to `1.0` over the requested duration, which can
+//! be used to cross-fade colors, slide panels or animate gauges.
+
+use std::time::{Duration, Instant};
+
+use crate::clock;
+use crate::hooks::batch::{Priority, schedule_render};
+use crate::hooks::with_hook_context;
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a transition between two values
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition<T> {
+    /// The value being transitioned away from
+    pub previous: T,
+    /// The value being transitioned to (the current value)
+    pub next: T,
+    /// Interpolation progress in `0.0..=1.0`; `1.0` once the transition is over
+    pub progress: f64,
+}
+
+impl<T> Transition<T> {
+    /// Returns true while the transition is still running
+    pub fn is_active(&self) -> bool {
+        self.progress < 1.0
+    }
+
+    /// Linearly interpolate between two numbers using the current progress
+    ///
+    /// # Example
+    /// ```
+    /// use pulse_core::hooks::transition::Transition;
+    ///
+    /// let transition = Transition { previous: 0, next: 10, progress: 0.25 };
+    /// assert_eq!(transition.lerp(0.0, 100.0), 25.0);
+    /// ```
+    pub fn lerp(&self, from: f64, to: f64) -> f64 {
+        from + (to - from) * self.progress
+    }
+}
+
+impl Transition<f64> {
+    /// The interpolated value between `previous` and `next`
+    pub fn value(&self) -> f64 {
+        self.lerp(self.previous, self.next)
+    }
+}
+
+/// Per-hook bookkeeping for an in-flight transition
+struct TransitionState<T> {
+    previous: T,
+    next: T,
+    started: Option<Instant>,
+}
+
+/// Hook that animates changes of `value` over `duration`
+///
+/// On the first render there is nothing to transition from, so `previous` and
+/// `next` are both `value` and progress is `1.0`. Whenever `value` changes, a
+/// new transition starts from the previously rendered value. If `value` changes
+/// again mid-transition, the new transition starts from the last target.
+///
+/// While the transition runs, every render requests a low-priority render,
+/// so progress advances on its own; no timer is needed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::transition::use_transition;
+/// # use pulse_core::hooks::state::use_state;
+/// # use std::time::Duration;
+/// // In a component context:
+/// let (percent, _set_percent) = use_state(|| 0.0f64);
+/// let transition = use_transition(percent.get(), Duration::from_millis(300));
+///
+/// // Animate a gauge from the old to the new value
+/// let shown = transition.value();
+/// ```
+pub fn use_transition<T>(value: T, duration: Duration) -> Transition<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    with_hook_context(|ctx| {
+        let index = ctx.next_hook_index();
+        let state = ctx.get_or_init_state(index, || TransitionState {
+            previous: value.clone(),
+            next: value.clone(),
+            started: None,
+        });
+        let mut state = state.borrow_mut();
+        let now = clock::now();
+
+        if state.next != value {
+            state.previous = std::mem::replace(&mut state.next, value);
+            state.started = Some(now);
+        }
+
+        let progress = match state.started {
+            Some(started) => transition_progress(now.duration_since(started), duration),
+            None => 1.0,
+        };
+        if progress < 1.0 {
+            schedule_render(Priority::Low);
+        }
+
+        Transition {
+            previous: state.previous.clone(),
+            next: state.next.clone(),
+            progress,
+        }
+    })
+}
+
+/// Progress of a transition that has been running for `elapsed`
+fn transition_progress(elapsed: Duration, duration: Duration) -> f64 {
+    if duration.is_zero() {
+        return 1.0;
+    }
+    (elapsed.as_secs_f64() / duration.as_secs_f64()).clamp(0.0, 1.0)
+}
@@ -0,0 +1,89 @@
+//! Tests for the use_transition hook
+
+use super::*;
+use crate::clock::ManualClock;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+fn render(value: i32, duration: Duration) -> Transition<i32> {
+    with_component_id("TransitionComponent", |_| use_transition(value, duration))
+}
+
+#[test]
+fn test_initial_render_has_no_transition() {
+    with_test_isolate(|| {
+        let transition = render(5, Duration::from_millis(100));
+        assert_eq!(transition.previous, 5);
+        assert_eq!(transition.next, 5);
+        assert_eq!(transition.progress, 1.0);
+        assert!(!transition.is_active());
+    });
+}
+
+#[test]
+fn test_progress_sampled_over_transition_window() {
+    with_test_isolate(|| {
+        let clock = ManualClock::start();
+        let duration = Duration::from_millis(200);
+        render(0, duration);
+
+        let start = render(10, duration);
+        assert_eq!(start.previous, 0);
+        assert_eq!(start.next, 10);
+        assert_eq!(start.progress, 0.0);
+        assert!(start.is_active());
+
+        for expected in [0.25, 0.5, 0.75] {
+            clock.advance(Duration::from_millis(50));
+            let sample = render(10, duration);
+            assert!(
+                (sample.progress - expected).abs() < 1e-9,
+                "progress {}",
+                sample.progress
+            );
+            assert_eq!(sample.previous, 0);
+            assert_eq!(sample.next, 10);
+        }
+
+        clock.advance(Duration::from_millis(100));
+        let done = render(10, duration);
+        assert_eq!(done.progress, 1.0);
+        assert!(!done.is_active());
+    });
+}
+
+#[test]
+fn test_retarget_mid_transition_starts_from_last_target() {
+    with_test_isolate(|| {
+        let clock = ManualClock::start();
+        let duration = Duration::from_secs(10);
+        render(1, duration);
+        render(2, duration);
+        clock.advance(Duration::from_secs(4));
+
+        let retargeted = render(3, duration);
+        assert_eq!(retargeted.previous, 2);
+        assert_eq!(retargeted.next, 3);
+        assert_eq!(retargeted.progress, 0.0);
+    });
+}
+
+#[test]
+fn test_zero_duration_completes_immediately() {
+    with_test_isolate(|| {
+        render(1, Duration::ZERO);
+        let transition = render(2, Duration::ZERO);
+        assert_eq!(transition.previous, 1);
+        assert_eq!(transition.progress, 1.0);
+    });
+}
+
+#[test]
+fn test_interpolated_value() {
+    let transition = Transition {
+        previous: 10.0,
+        next: 20.0,
+        progress: 0.5,
+    };
+    assert_eq!(transition.value(), 15.0);
+    assert_eq!(transition.lerp(0.0, 4.0), 2.0);
+}
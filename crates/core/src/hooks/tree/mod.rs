@@ -0,0 +1,322 @@
+//! Tree Hook
+//!
+//! This module provides a `use_tree` hook for collapsible trees such as file
+//! explorers. The hook keeps track of which nodes are expanded and which node
+//! is selected, and flattens the tree into the list of currently visible rows
+//! for rendering (see [`crate::widgets::TreeView`]).
+//!
+//! Nodes are identified by their [`TreePath`], the child indices leading from
+//! the root to the node.
+
+use std::collections::HashSet;
+
+use crate::hooks::state::{StateHandle, StateSetter, use_state};
+
+#[cfg(test)]
+mod tests;
+
+/// Path from the root to a node: the index of each child taken on the way
+///
+/// The root's path is empty.
+pub type TreePath = Vec<usize>;
+
+/// A node of a tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeNode<T> {
+    pub value: T,
+    pub children: Vec<TreeNode<T>>,
+}
+
+impl<T> TreeNode<T> {
+    /// Create a leaf node
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    /// Create a node with children
+    pub fn with_children(value: T, children: Vec<TreeNode<T>>) -> Self {
+        Self { value, children }
+    }
+
+    /// Add a child node (builder style)
+    pub fn child(mut self, child: TreeNode<T>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Get the node at `path`, if it exists
+    pub fn get(&self, path: &[usize]) -> Option<&TreeNode<T>> {
+        path.iter()
+            .try_fold(self, |node, &index| node.children.get(index))
+    }
+}
+
+/// A visible row of the flattened tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisibleNode<T> {
+    /// Path of the node
+    pub path: TreePath,
+    /// Nesting depth; the root has depth 0
+    pub depth: usize,
+    /// The node's value
+    pub value: T,
+    /// Whether the node has children
+    pub has_children: bool,
+    /// Whether the node is expanded
+    pub expanded: bool,
+}
+
+/// Expand and selection state of a tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TreeState {
+    expanded: HashSet<TreePath>,
+    selected: TreePath,
+}
+
+/// Handle returned by [`use_tree`]
+#[derive(Clone)]
+pub struct TreeHandle<T> {
+    root: TreeNode<T>,
+    visible: Vec<VisibleNode<T>>,
+    state: StateHandle<TreeState>,
+    setter: StateSetter<TreeState>,
+}
+
+impl<T: Clone> TreeHandle<T> {
+    /// The tree being displayed
+    pub fn root(&self) -> &TreeNode<T> {
+        &self.root
+    }
+
+    /// The visible rows, in display order
+    pub fn visible(&self) -> &[VisibleNode<T>] {
+        &self.visible
+    }
+
+    /// Path of the selected node
+    pub fn selected(&self) -> TreePath {
+        self.state.get().selected
+    }
+
+    /// Index of the selected node within [`Self::visible`]
+    pub fn selected_index(&self) -> usize {
+        let selected = self.selected();
+        self.visible
+            .iter()
+            .position(|node| node.path == selected)
+            .unwrap_or(0)
+    }
+
+    /// The selected node
+    pub fn selected_node(&self) -> Option<&TreeNode<T>> {
+        self.root.get(&self.selected())
+    }
+
+    /// Returns true if the node at `path` is expanded
+    pub fn is_expanded(&self, path: &[usize]) -> bool {
+        self.state.get().expanded.contains(path)
+    }
+
+    /// Select the node at `path`
+    ///
+    /// Collapsed ancestors are expanded so the node becomes visible.
+    pub fn select(&self, path: TreePath) {
+        if self.root.get(&path).is_none() {
+            return;
+        }
+        self.setter.update(move |state| {
+            let mut state = state.clone();
+            for depth in 0..path.len() {
+                state.expanded.insert(path[..depth].to_vec());
+            }
+            state.selected = path;
+            state
+        });
+    }
+
+    /// Move the selection to the next visible node
+    pub fn select_next(&self) {
+        let index = (self.selected_index() + 1).min(self.visible.len().saturating_sub(1));
+        self.select_visible(index);
+    }
+
+    /// Move the selection to the previous visible node
+    pub fn select_prev(&self) {
+        self.select_visible(self.selected_index().saturating_sub(1));
+    }
+
+    /// Expand the node at `path`
+    pub fn expand(&self, path: &[usize]) {
+        if self
+            .root
+            .get(path)
+            .is_some_and(|node| !node.children.is_empty())
+        {
+            let path = path.to_vec();
+            self.setter.update(move |state| {
+                let mut state = state.clone();
+                state.expanded.insert(path);
+                state
+            });
+        }
+    }
+
+    /// Collapse the node at `path`
+    ///
+    /// If the selection is inside the collapsed subtree, it moves to the
+    /// collapsed node so it stays visible.
+    pub fn collapse(&self, path: &[usize]) {
+        let path = path.to_vec();
+        self.setter.update(move |state| {
+            let mut state = state.clone();
+            state.expanded.remove(&path);
+            if state.selected.len() > path.len() && state.selected.starts_with(&path) {
+                state.selected = path;
+            }
+            state
+        });
+    }
+
+    /// Expand or collapse the node at `path`
+    pub fn toggle(&self, path: &[usize]) {
+        if self.is_expanded(path) {
+            self.collapse(path);
+        } else {
+            self.expand(path);
+        }
+    }
+
+    /// Expand the selected node, or move to its first child if already expanded
+    ///
+    /// This is the conventional behaviour of the right arrow key.
+    pub fn expand_selected(&self) {
+        let selected = self.selected();
+        let has_children = self
+            .root
+            .get(&selected)
+            .is_some_and(|node| !node.children.is_empty());
+        if !has_children {
+            return;
+        }
+        if self.is_expanded(&selected) {
+            let mut child = selected;
+            child.push(0);
+            self.select(child);
+        } else {
+            self.expand(&selected);
+        }
+    }
+
+    /// Collapse the selected node, or move to its parent if already collapsed
+    ///
+    /// This is the conventional behaviour of the left arrow key.
+    pub fn collapse_selected(&self) {
+        let selected = self.selected();
+        if self.is_expanded(&selected) {
+            self.collapse(&selected);
+        } else if let Some((_, parent)) = selected.split_last() {
+            self.select(parent.to_vec());
+        }
+    }
+
+    fn select_visible(&self, index: usize) {
+        if let Some(node) = self.visible.get(index) {
+            self.select(node.path.clone());
+        }
+    }
+}
+
+/// Flatten the visible part of a tree into display rows
+pub fn flatten_tree<T: Clone>(
+    root: &TreeNode<T>,
+    expanded: &HashSet<TreePath>,
+) -> Vec<VisibleNode<T>> {
+    fn visit<T: Clone>(
+        node: &TreeNode<T>,
+        path: &mut TreePath,
+        expanded: &HashSet<TreePath>,
+        out: &mut Vec<VisibleNode<T>>,
+    ) {
+        let is_expanded = expanded.contains(path.as_slice());
+        out.push(VisibleNode {
+            path: path.clone(),
+            depth: path.len(),
+            value: node.value.clone(),
+            has_children: !node.children.is_empty(),
+            expanded: is_expanded,
+        });
+        if is_expanded {
+            for (index, child) in node.children.iter().enumerate() {
+                path.push(index);
+                visit(child, path, expanded, out);
+                path.pop();
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    visit(root, &mut Vec::new(), expanded, &mut out);
+    out
+}
+
+/// Hook that manages expand/collapse and selection state for a tree
+///
+/// The root starts expanded and selected. `root` can change between renders
+/// (e.g. when a directory listing is refreshed); if the selected node no
+/// longer exists, the selection moves to its closest existing ancestor.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::tree::{TreeNode, use_tree};
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use crossterm::event::KeyCode;
+/// // In a component context:
+/// let tree = use_tree(
+///     TreeNode::new("project")
+///         .child(TreeNode::new("src").child(TreeNode::new("main.rs")))
+///         .child(TreeNode::new("Cargo.toml")),
+/// );
+///
+/// if let Some(key) = use_key_event() {
+///     match key.code {
+///         KeyCode::Down => tree.select_next(),
+///         KeyCode::Up => tree.select_prev(),
+///         KeyCode::Right => tree.expand_selected(),
+///         KeyCode::Left => tree.collapse_selected(),
+///         _ => {}
+///     }
+/// }
+/// ```
+pub fn use_tree<T>(root: TreeNode<T>) -> TreeHandle<T>
+where
+    T: Clone + 'static,
+{
+    let (state, setter) = use_state(|| TreeState {
+        expanded: HashSet::from([TreePath::new()]),
+        selected: TreePath::new(),
+    });
+
+    let selected = state.get().selected;
+    if root.get(&selected).is_none() {
+        let mut ancestor = selected;
+        while root.get(&ancestor).is_none() {
+            ancestor.pop();
+        }
+        setter.update(move |state| TreeState {
+            expanded: state.expanded.clone(),
+            selected: ancestor,
+        });
+    }
+
+    let visible = flatten_tree(&root, &state.get().expanded);
+    TreeHandle {
+        root,
+        visible,
+        state,
+        setter,
+    }
+}
@@ -0,0 +1,185 @@
+//! Tests for the use_tree hook and the tree view widget
+
+use super::*;
+use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+use crate::widgets::TreeView;
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+/// project
+/// ├── src
+/// │   ├── main.rs
+/// │   └── hooks
+/// │       └── state.rs
+/// ├── docs
+/// │   └── guide.md
+/// └── Cargo.toml
+fn project() -> TreeNode<&'static str> {
+    TreeNode::new("project")
+        .child(
+            TreeNode::new("src")
+                .child(TreeNode::new("main.rs"))
+                .child(TreeNode::new("hooks").child(TreeNode::new("state.rs"))),
+        )
+        .child(TreeNode::new("docs").child(TreeNode::new("guide.md")))
+        .child(TreeNode::new("Cargo.toml"))
+}
+
+fn render() -> TreeHandle<&'static str> {
+    with_component_id("TreeComponent", |_| use_tree(project()))
+}
+
+fn labels(tree: &TreeHandle<&'static str>) -> Vec<(usize, &'static str)> {
+    tree.visible().iter().map(|n| (n.depth, n.value)).collect()
+}
+
+#[test]
+fn test_flatten_mixed_expanded_and_collapsed() {
+    let root = project();
+    let expanded = HashSet::from([vec![], vec![0], vec![0, 1]]);
+    let rows: Vec<_> = flatten_tree(&root, &expanded)
+        .into_iter()
+        .map(|n| (n.path, n.value, n.has_children, n.expanded))
+        .collect();
+
+    assert_eq!(
+        rows,
+        vec![
+            (vec![], "project", true, true),
+            (vec![0], "src", true, true),
+            (vec![0, 0], "main.rs", false, false),
+            (vec![0, 1], "hooks", true, true),
+            (vec![0, 1, 0], "state.rs", false, false),
+            (vec![1], "docs", true, false),
+            (vec![2], "Cargo.toml", false, false),
+        ]
+    );
+
+    // A collapsed parent hides expanded descendants
+    let expanded = HashSet::from([vec![], vec![0, 1]]);
+    let values: Vec<_> = flatten_tree(&root, &expanded)
+        .into_iter()
+        .map(|n| n.value)
+        .collect();
+    assert_eq!(values, vec!["project", "src", "docs", "Cargo.toml"]);
+}
+
+#[test]
+fn test_navigation_skips_collapsed_children() {
+    with_test_isolate(|| {
+        let tree = render();
+        assert_eq!(
+            labels(&tree),
+            vec![(0, "project"), (1, "src"), (1, "docs"), (1, "Cargo.toml")]
+        );
+
+        tree.select_next();
+        render().select_next();
+        assert_eq!(render().selected(), vec![1]);
+
+        // Expand "docs" and walk into it
+        render().expand_selected();
+        render().select_next();
+        let tree = render();
+        assert_eq!(tree.selected_node().unwrap().value, "guide.md");
+
+        tree.select_next();
+        assert_eq!(render().selected(), vec![2]);
+
+        // Moving past the end stays on the last row
+        render().select_next();
+        assert_eq!(render().selected(), vec![2]);
+
+        render().select_prev();
+        render().select_prev();
+        render().select_prev();
+        assert_eq!(render().selected(), vec![0]);
+    });
+}
+
+#[test]
+fn test_collapse_moves_selection_to_collapsed_node() {
+    with_test_isolate(|| {
+        render().select(vec![0, 1, 0]);
+        let tree = render();
+        assert_eq!(tree.selected_node().unwrap().value, "state.rs");
+        assert!(tree.is_expanded(&[0]));
+        assert!(tree.is_expanded(&[0, 1]));
+
+        tree.collapse(&[0]);
+        let tree = render();
+        assert_eq!(tree.selected(), vec![0]);
+        assert!(!labels(&tree).contains(&(2, "hooks")));
+
+        // Re-expanding restores the previously expanded descendants
+        tree.expand(&[0]);
+        assert!(labels(&render()).contains(&(3, "state.rs")));
+    });
+}
+
+#[test]
+fn test_arrow_key_semantics() {
+    with_test_isolate(|| {
+        render().select(vec![1]);
+        render().expand_selected();
+        assert!(render().is_expanded(&[1]));
+
+        render().expand_selected();
+        assert_eq!(render().selected(), vec![1, 0]);
+
+        render().collapse_selected();
+        assert_eq!(render().selected(), vec![1]);
+
+        render().collapse_selected();
+        assert!(!render().is_expanded(&[1]));
+        assert_eq!(render().selected(), vec![1]);
+
+        // Leaves cannot be expanded
+        render().select(vec![2]);
+        render().expand_selected();
+        assert!(!render().is_expanded(&[2]));
+    });
+}
+
+#[test]
+fn test_selection_survives_shrinking_tree() {
+    with_test_isolate(|| {
+        render().select(vec![0, 1, 0]);
+
+        let smaller =
+            || TreeNode::new("project").child(TreeNode::new("src").child(TreeNode::new("main.rs")));
+        with_component_id("TreeComponent", |_| use_tree(smaller()));
+        let tree = with_component_id("TreeComponent", |_| use_tree(smaller()));
+        assert_eq!(tree.selected(), vec![0]);
+    });
+}
+
+#[test]
+fn test_tree_view_renders_visible_rows() {
+    with_test_isolate(|| {
+        render().select(vec![0]);
+        let tree = render();
+
+        let area = Rect::new(0, 0, 16, 4);
+        let mut buffer = Buffer::empty(area);
+        TreeView::new(&tree, |name| (*name).into()).render(area, &mut buffer);
+
+        let rows: Vec<String> = (0..4)
+            .map(|y| {
+                (0..16)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec!["▾ project", "  ▸ src", "  ▸ docs", "    Cargo.toml"]
+        );
+        assert!(
+            buffer[(2, 1)]
+                .modifier
+                .contains(ratatui::style::Modifier::REVERSED)
+        );
+    });
+}
@@ -0,0 +1,252 @@
+//! Vim-style Modal Navigation
+//!
+//! This module provides an opt-in `use_vim_nav` hook that adds vim-like modal
+//! keybindings to list-heavy components:
+//!
+//! - **Normal** mode: `j`/`k` move the selection, `h`/`l` scroll horizontally,
+//!   `w`/`b` jump forward/backward by [`VimNavState::jump`] items, `gg` goes to
+//!   the first item and `G` to the last. Motions accept a count (`5j`, `3G`).
+//! - **Insert** mode (`i`): keys are left to the component, e.g. for editing
+//!   the selected item; `Esc` returns to normal mode.
+//! - **Visual** mode (`v`): motions extend a selection range anchored where
+//!   visual mode was entered; `Esc` or `v` returns to normal mode.
+//!
+//! The navigation state machine is available separately as [`VimNavState`] for
+//! components that want to feed it keys themselves.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::hooks::{
+    event::{consume_event, filtered::use_key_event},
+    mode::use_mode,
+    state::use_state,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Default number of items moved by the `w` and `b` motions
+pub const DEFAULT_JUMP: usize = 5;
+
+/// The active vim mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum VimMode {
+    #[default]
+    Normal,
+    Insert,
+    Visual,
+}
+
+/// Vim navigation state for a list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VimNavState {
+    /// The active mode
+    pub mode: VimMode,
+    /// Index of the selected item
+    pub cursor: usize,
+    /// Horizontal scroll offset, moved by `h` and `l`
+    pub column: usize,
+    /// Number of items moved by `w` and `b`
+    pub jump: usize,
+    /// Where visual mode was entered
+    visual_anchor: usize,
+    /// Pending count prefix, e.g. the `5` of `5j`
+    count: Option<usize>,
+    /// Whether the first `g` of `gg` was typed
+    pending_g: bool,
+}
+
+impl Default for VimNavState {
+    fn default() -> Self {
+        Self {
+            mode: VimMode::Normal,
+            cursor: 0,
+            column: 0,
+            jump: DEFAULT_JUMP,
+            visual_anchor: 0,
+            count: None,
+            pending_g: false,
+        }
+    }
+}
+
+impl VimNavState {
+    /// Create a new navigation state in normal mode at the first item
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The selected range in visual mode, as inclusive `(start, end)` indices
+    pub fn visual_range(&self) -> Option<(usize, usize)> {
+        (self.mode == VimMode::Visual).then(|| {
+            (
+                self.visual_anchor.min(self.cursor),
+                self.visual_anchor.max(self.cursor),
+            )
+        })
+    }
+
+    /// Returns true if `index` is selected (the cursor, or inside the visual range)
+    pub fn is_selected(&self, index: usize) -> bool {
+        match self.visual_range() {
+            Some((start, end)) => (start..=end).contains(&index),
+            None => index == self.cursor,
+        }
+    }
+
+    /// Apply a key to the state for a list of `list_len` items
+    ///
+    /// Returns true if the key was handled. In insert mode only `Esc` is
+    /// handled, all other keys are left to the caller.
+    pub fn handle_key(&mut self, key: &KeyEvent, list_len: usize) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+
+        let handled = match self.mode {
+            VimMode::Insert => {
+                if key.code == KeyCode::Esc {
+                    self.mode = VimMode::Normal;
+                    true
+                } else {
+                    false
+                }
+            }
+            VimMode::Normal | VimMode::Visual => self.handle_command(key, list_len),
+        };
+
+        self.clamp(list_len);
+        handled
+    }
+
+    /// Handle a key in normal or visual mode
+    fn handle_command(&mut self, key: &KeyEvent, list_len: usize) -> bool {
+        let KeyCode::Char(c) = key.code else {
+            self.reset_pending();
+            if key.code == KeyCode::Esc && self.mode == VimMode::Visual {
+                self.mode = VimMode::Normal;
+                return true;
+            }
+            return false;
+        };
+
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            self.reset_pending();
+            return false;
+        }
+
+        // Count prefix; a leading 0 is not a count
+        if let Some(digit) = c.to_digit(10)
+            && (digit != 0 || self.count.is_some())
+        {
+            self.count = Some(
+                self.count
+                    .unwrap_or(0)
+                    .saturating_mul(10)
+                    .saturating_add(digit as usize),
+            );
+            return true;
+        }
+
+        let count = self.count.take();
+        let steps = count.unwrap_or(1);
+        let last = list_len.saturating_sub(1);
+
+        if c == 'g' {
+            if self.pending_g {
+                self.pending_g = false;
+                self.cursor = count.map_or(0, |line| line.saturating_sub(1));
+            } else {
+                self.pending_g = true;
+                self.count = count;
+            }
+            return true;
+        }
+        self.pending_g = false;
+
+        match c {
+            'j' => self.cursor = self.cursor.saturating_add(steps),
+            'k' => self.cursor = self.cursor.saturating_sub(steps),
+            'l' => self.column = self.column.saturating_add(steps),
+            'h' => self.column = self.column.saturating_sub(steps),
+            'w' => self.cursor = self.cursor.saturating_add(steps.saturating_mul(self.jump)),
+            'b' => self.cursor = self.cursor.saturating_sub(steps.saturating_mul(self.jump)),
+            'G' => self.cursor = count.map_or(last, |line| line.saturating_sub(1)),
+            'i' if self.mode == VimMode::Normal => self.mode = VimMode::Insert,
+            'v' if self.mode == VimMode::Normal => {
+                self.mode = VimMode::Visual;
+                self.visual_anchor = self.cursor;
+            }
+            'v' => self.mode = VimMode::Normal,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Forget a partially typed count or `g` prefix
+    fn reset_pending(&mut self) {
+        self.count = None;
+        self.pending_g = false;
+    }
+
+    /// Keep the cursor and visual anchor within the list
+    fn clamp(&mut self, list_len: usize) {
+        let last = list_len.saturating_sub(1);
+        self.cursor = self.cursor.min(last);
+        self.visual_anchor = self.visual_anchor.min(last);
+    }
+}
+
+/// Hook that adds vim-style modal navigation to a list of `list_len` items
+///
+/// The active mode is held with [`use_mode`], like components that declare
+/// their own keymaps. The hook reads the current key event, applies it to the
+/// navigation state and consumes keys it handled, so later `use_event` calls
+/// in the same frame do not see them. In insert mode keys other than `Esc`
+/// pass through.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::vim::{VimMode, use_vim_nav};
+/// # let items = vec!["alpha", "beta", "gamma"];
+/// // In a component context:
+/// let nav = use_vim_nav(items.len());
+///
+/// for (index, item) in items.iter().enumerate() {
+///     let marker = if nav.is_selected(index) { ">" } else { " " };
+///     // Render `marker` and `item`...
+/// }
+///
+/// if nav.mode == VimMode::Insert {
+///     // Edit items[nav.cursor]
+/// }
+/// ```
+pub fn use_vim_nav(list_len: usize) -> VimNavState {
+    let mode = use_mode(VimMode::Normal);
+    let (state, set_state) = use_state(VimNavState::new);
+
+    let mut next = state.get();
+    next.mode = mode.get();
+
+    if let Some(key) = use_key_event() {
+        if next.handle_key(&key, list_len) {
+            consume_event();
+        }
+    } else {
+        // The list may have shrunk since the last key press
+        next.clamp(list_len);
+    }
+
+    if !mode.is(&next.mode) {
+        mode.set(next.mode);
+    }
+    if next != state.get() {
+        set_state.set(next.clone());
+    }
+
+    next
+}
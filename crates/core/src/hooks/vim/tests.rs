@@ -0,0 +1,149 @@
+//! Tests for vim-style modal navigation
+
+use super::*;
+use crate::hooks::event::{set_current_event, use_event};
+use crate::hooks::test_utils::{lock_current_event, with_component_id, with_test_isolate};
+use crossterm::event::Event;
+use std::sync::Arc;
+
+fn keys(state: &mut VimNavState, input: &str, list_len: usize) {
+    for c in input.chars() {
+        let key = match c {
+            '\x1b' => KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+            c => KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE),
+        };
+        state.handle_key(&key, list_len);
+    }
+}
+
+#[test]
+fn test_basic_motions() {
+    let mut state = VimNavState::new();
+
+    keys(&mut state, "jjj", 10);
+    assert_eq!(state.cursor, 3);
+    keys(&mut state, "k", 10);
+    assert_eq!(state.cursor, 2);
+
+    keys(&mut state, "G", 10);
+    assert_eq!(state.cursor, 9);
+    keys(&mut state, "j", 10);
+    assert_eq!(state.cursor, 9, "cursor stays on the last item");
+
+    keys(&mut state, "gg", 10);
+    assert_eq!(state.cursor, 0);
+    keys(&mut state, "k", 10);
+    assert_eq!(state.cursor, 0);
+
+    keys(&mut state, "llh", 10);
+    assert_eq!(state.column, 1);
+}
+
+#[test]
+fn test_word_motions_and_counts() {
+    let mut state = VimNavState::new();
+
+    keys(&mut state, "w", 20);
+    assert_eq!(state.cursor, DEFAULT_JUMP);
+    keys(&mut state, "b", 20);
+    assert_eq!(state.cursor, 0);
+
+    keys(&mut state, "3j", 20);
+    assert_eq!(state.cursor, 3);
+    keys(&mut state, "2w", 20);
+    assert_eq!(state.cursor, 13);
+    keys(&mut state, "12k", 20);
+    assert_eq!(state.cursor, 1);
+
+    // {count}G and {count}gg go to a line number
+    keys(&mut state, "7G", 20);
+    assert_eq!(state.cursor, 6);
+    keys(&mut state, "4gg", 20);
+    assert_eq!(state.cursor, 3);
+}
+
+#[test]
+fn test_huge_counts_saturate() {
+    let mut state = VimNavState::new();
+
+    // A count beyond usize::MAX times the jump stops at the list edges
+    let huge = "9".repeat(30);
+    keys(&mut state, &format!("{huge}w"), 20);
+    assert_eq!(state.cursor, 19);
+    keys(&mut state, &format!("{huge}b"), 20);
+    assert_eq!(state.cursor, 0);
+}
+
+#[test]
+fn test_mode_transitions() {
+    let mut state = VimNavState::new();
+    assert_eq!(state.mode, VimMode::Normal);
+
+    keys(&mut state, "i", 5);
+    assert_eq!(state.mode, VimMode::Insert);
+
+    // Motions are plain text in insert mode
+    let j = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE);
+    assert!(!state.handle_key(&j, 5));
+    assert_eq!(state.cursor, 0);
+
+    keys(&mut state, "\x1b", 5);
+    assert_eq!(state.mode, VimMode::Normal);
+
+    keys(&mut state, "jv", 5);
+    assert_eq!(state.mode, VimMode::Visual);
+    keys(&mut state, "jj", 5);
+    assert_eq!(state.visual_range(), Some((1, 3)));
+    assert!(state.is_selected(2));
+    assert!(!state.is_selected(4));
+
+    keys(&mut state, "gg", 5);
+    assert_eq!(state.visual_range(), Some((0, 1)));
+
+    // 'i' does not enter insert mode from visual mode
+    keys(&mut state, "i", 5);
+    assert_eq!(state.mode, VimMode::Visual);
+
+    keys(&mut state, "\x1b", 5);
+    assert_eq!(state.mode, VimMode::Normal);
+    assert_eq!(state.visual_range(), None);
+    assert!(state.is_selected(0));
+}
+
+#[test]
+fn test_empty_list() {
+    let mut state = VimNavState::new();
+    keys(&mut state, "jjGw", 0);
+    assert_eq!(state.cursor, 0);
+}
+
+#[test]
+fn test_use_vim_nav_consumes_handled_keys() {
+    let _lock = lock_current_event();
+    with_test_isolate(|| {
+        let render = |key: KeyCode| {
+            set_current_event(Some(Arc::new(Event::Key(KeyEvent::new(
+                key,
+                KeyModifiers::NONE,
+            )))));
+            let result = with_component_id("VimNavComponent", |_| {
+                let nav = use_vim_nav(4);
+                (nav, use_event())
+            });
+            set_current_event(None);
+            result
+        };
+
+        let (nav, passed_through) = render(KeyCode::Char('j'));
+        assert_eq!(nav.cursor, 1);
+        assert_eq!(passed_through, None);
+
+        let (nav, _) = render(KeyCode::Char('i'));
+        assert_eq!(nav.mode, VimMode::Insert);
+
+        // Insert mode lets typed keys through to the component
+        let (nav, passed_through) = render(KeyCode::Char('j'));
+        assert_eq!(nav.cursor, 1);
+        assert!(passed_through.is_some());
+    });
+}
@@ -0,0 +1,441 @@
+//! Localized strings from a message catalog
+//!
+//! A [`Catalog`] maps locales to messages, loaded from JSON or from simple
+//! Fluent (`.ftl`) resources. Components make a catalog available with
+//! [`use_translations`] and look messages up with the [`t!`](crate::t) macro,
+//! which fills `{name}` placeholders from its arguments:
+//!
+//! ```rust,no_run
+//! use pulse_core::{i18n::{Catalog, use_translations}, t};
+//!
+//! fn greeting(catalog: &Catalog, name: &str) -> String {
+//!     // In a component context:
+//!     use_translations(catalog);
+//!     t!("greeting", name = name)
+//! }
+//! ```
+//!
+//! The active locale is changed with [`set_locale`], which re-renders the
+//! app so every message is looked up again. Like the [clock](crate::clock),
+//! it is per thread, matching the render loop, which runs all components on
+//! a single thread. Messages missing from the active locale come from the
+//! catalog's fallback locale, and a message missing there too is shown as
+//! its key.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+
+use crate::hooks::batch::request_render;
+use crate::hooks::context::{
+    Context, create_context_with_default, use_context_provider, use_context_with_default,
+};
+
+thread_local! {
+    static ACTIVE_LOCALE: RefCell<String> = RefCell::new("en".to_string());
+}
+
+static TRANSLATIONS: Lazy<Context<Translations>> =
+    Lazy::new(|| create_context_with_default(Translations::default()));
+
+/// The active locale on this thread, `"en"` until [`set_locale`] is called
+pub fn locale() -> String {
+    ACTIVE_LOCALE.with(|active| active.borrow().clone())
+}
+
+/// Change the active locale on this thread, re-rendering the app if it
+/// changed
+pub fn set_locale(locale: impl Into<String>) {
+    let locale = locale.into();
+    let changed = ACTIVE_LOCALE.with(|active| {
+        let mut active = active.borrow_mut();
+        *active != locale && {
+            *active = locale;
+            true
+        }
+    });
+    if changed {
+        request_render();
+    }
+}
+
+/// Messages for any number of locales
+///
+/// Cloning a catalog is cheap; clones share their messages until one of them
+/// is modified.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    messages: Arc<HashMap<String, HashMap<String, String>>>,
+    fallback: Option<String>,
+}
+
+impl Catalog {
+    /// Create an empty catalog
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a catalog from JSON mapping locales to messages
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pulse_core::i18n::Catalog;
+    ///
+    /// let catalog = Catalog::from_json(r#"{
+    ///     "en": { "greeting": "Hello, {name}!" },
+    ///     "fr": { "greeting": "Bonjour, {name} !" }
+    /// }"#).unwrap();
+    /// assert_eq!(catalog.message("fr", "greeting"), Some("Bonjour, {name} !"));
+    /// ```
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        Ok(Self {
+            messages: Arc::new(serde_json::from_str(json)?),
+            fallback: None,
+        })
+    }
+
+    /// Set the locale used for messages missing from the active locale
+    pub fn with_fallback(mut self, locale: impl Into<String>) -> Self {
+        self.fallback = Some(locale.into());
+        self
+    }
+
+    /// Add or replace one message
+    pub fn insert(
+        &mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        Arc::make_mut(&mut self.messages)
+            .entry(locale.into())
+            .or_default()
+            .insert(key.into(), message.into());
+    }
+
+    /// Add the messages of a Fluent resource for `locale`
+    ///
+    /// Only plain messages are supported: `key = value` lines, indented
+    /// continuation lines, `#` comments and `{ $name }` variables. Selectors,
+    /// terms and attributes are not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pulse_core::i18n::Catalog;
+    ///
+    /// let mut catalog = Catalog::new();
+    /// catalog.add_fluent("de", "# Greetings\ngreeting = Hallo, { $name }!");
+    /// assert_eq!(catalog.message("de", "greeting"), Some("Hallo, {name}!"));
+    /// ```
+    pub fn add_fluent(&mut self, locale: impl Into<String>, source: &str) {
+        let locale = locale.into();
+        let mut current: Option<(String, String)> = None;
+
+        for line in source.lines() {
+            if line.starts_with([' ', '\t']) && !line.trim().is_empty() {
+                if let Some((_, message)) = current.as_mut() {
+                    if !message.is_empty() {
+                        message.push('\n');
+                    }
+                    message.push_str(line.trim());
+                }
+                continue;
+            }
+            if let Some((key, message)) = current.take() {
+                self.insert(locale.clone(), key, fluent_placeables(&message));
+            }
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                current = Some((key.trim().to_string(), value.trim().to_string()));
+            }
+        }
+        if let Some((key, message)) = current {
+            self.insert(locale, key, fluent_placeables(&message));
+        }
+    }
+
+    /// The message for `key` in exactly `locale`, without any fallback
+    pub fn message(&self, locale: &str, key: &str) -> Option<&str> {
+        self.messages
+            .get(locale)
+            .and_then(|messages| messages.get(key))
+            .map(String::as_str)
+    }
+
+    /// The message for `key` in `locale`, falling back to its language and
+    /// then to the catalog's fallback locale
+    ///
+    /// A region-specific locale such as `"fr-CA"` falls back to `"fr"`.
+    pub fn lookup(&self, locale: &str, key: &str) -> Option<&str> {
+        let language = locale.split(['-', '_']).next().unwrap_or(locale);
+        self.message(locale, key)
+            .or_else(|| self.message(language, key))
+            .or_else(|| {
+                self.fallback
+                    .as_deref()
+                    .and_then(|fallback| self.message(fallback, key))
+            })
+    }
+
+    /// The locales with at least one message, sorted
+    pub fn locales(&self) -> Vec<&str> {
+        let mut locales: Vec<_> = self.messages.keys().map(String::as_str).collect();
+        locales.sort_unstable();
+        locales
+    }
+}
+
+/// Turn Fluent `{ $name }` variables into `{name}` placeholders
+fn fluent_placeables(message: &str) -> String {
+    let mut result = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let inner = rest[start + 1..start + end].trim();
+        result.push('{');
+        result.push_str(inner.strip_prefix('$').unwrap_or(inner));
+        result.push('}');
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Replace `{name}` placeholders in `template` with matching arguments
+///
+/// Placeholders without a matching argument are left as they are, and `{{`
+/// and `}}` produce literal braces.
+///
+/// # Examples
+///
+/// ```rust
+/// use pulse_core::i18n::interpolate;
+///
+/// assert_eq!(interpolate("{count} new", &[("count", &3)]), "3 new");
+/// ```
+pub fn interpolate(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().is_some_and(|&(_, next)| next == '{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek().is_some_and(|&(_, next)| next == '}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let Some(end) = template[index..].find('}').map(|end| index + end) else {
+                    result.push_str(&template[index..]);
+                    break;
+                };
+                let name = template[index + 1..end].trim();
+                match args.iter().find(|(arg, _)| *arg == name) {
+                    Some((_, value)) => result.push_str(&value.to_string()),
+                    None => result.push_str(&template[index..=end]),
+                }
+                while chars.next_if(|&(next, _)| next <= end).is_some() {}
+            }
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// A catalog together with the locale it is read in
+#[derive(Debug, Clone)]
+pub struct Translations {
+    catalog: Catalog,
+    locale: String,
+}
+
+impl Default for Translations {
+    fn default() -> Self {
+        Self {
+            catalog: Catalog::default(),
+            locale: locale(),
+        }
+    }
+}
+
+impl Translations {
+    /// The locale messages are looked up in
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// The catalog messages are looked up in
+    pub fn catalog(&self) -> &Catalog {
+        &self.catalog
+    }
+
+    /// The localized message for `key` with its placeholders filled from
+    /// `args`, or `key` itself if the catalog has no such message
+    pub fn t(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        match self.catalog.lookup(&self.locale, key) {
+            Some(template) => interpolate(template, args),
+            None => key.to_string(),
+        }
+    }
+}
+
+/// Hook that makes `catalog` available to this component and its children
+///
+/// Returns the translations in the active locale. Children read them with
+/// [`use_translations_context`] or the [`t!`](crate::t) macro.
+pub fn use_translations(catalog: &Catalog) -> Translations {
+    use_context_provider(|| Translations {
+        catalog: catalog.clone(),
+        locale: locale(),
+    })
+}
+
+/// Hook that returns the translations provided by a parent component
+///
+/// Without a provider the catalog is empty, so every key is shown as is.
+pub fn use_translations_context() -> Translations {
+    let mut translations = use_context_with_default(&TRANSLATIONS);
+    translations.locale = locale();
+    translations
+}
+
+/// Look up a localized message from the translations in context
+///
+/// Takes the message key and optional `name = value` arguments filling the
+/// message's `{name}` placeholders. Missing messages are shown as their key.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::t;
+///
+/// // In a component below `use_translations(&catalog)`:
+/// let title = t!("inbox.title");
+/// let unread = t!("inbox.unread", count = 3, user = "ada");
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($key:expr $(,)?) => {
+        $crate::i18n::use_translations_context().t($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::use_translations_context().t(
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+
+    fn catalog() -> Catalog {
+        Catalog::from_json(
+            r#"{
+                "en": { "greeting": "Hello, {name}!", "farewell": "Bye" },
+                "fr": { "greeting": "Bonjour, {name} !" }
+            }"#,
+        )
+        .unwrap()
+        .with_fallback("en")
+    }
+
+    #[test]
+    fn test_lookup_by_locale_and_language() {
+        let catalog = catalog();
+        assert_eq!(catalog.lookup("fr", "greeting"), Some("Bonjour, {name} !"));
+        assert_eq!(
+            catalog.lookup("fr-CA", "greeting"),
+            Some("Bonjour, {name} !")
+        );
+        assert_eq!(catalog.lookup("en", "greeting"), Some("Hello, {name}!"));
+        assert_eq!(catalog.locales(), vec!["en", "fr"]);
+    }
+
+    #[test]
+    fn test_interpolation() {
+        assert_eq!(
+            interpolate(
+                "{user} has {count} messages",
+                &[("count", &3), ("user", &"ada")]
+            ),
+            "ada has 3 messages"
+        );
+        assert_eq!(interpolate("{missing} stays", &[]), "{missing} stays");
+        assert_eq!(interpolate("{{literal}} {x}", &[("x", &1)]), "{literal} 1");
+        assert_eq!(interpolate("unclosed {x", &[("x", &1)]), "unclosed {x");
+    }
+
+    #[test]
+    fn test_fallback_locale_then_key() {
+        let translations = Translations {
+            catalog: catalog(),
+            locale: "fr".to_string(),
+        };
+        assert_eq!(translations.t("farewell", &[]), "Bye");
+        assert_eq!(translations.t("menu.quit", &[]), "menu.quit");
+
+        let without_fallback = Translations {
+            catalog: Catalog::from_json(r#"{"en": {"farewell": "Bye"}}"#).unwrap(),
+            locale: "fr".to_string(),
+        };
+        assert_eq!(without_fallback.t("farewell", &[]), "farewell");
+    }
+
+    #[test]
+    fn test_fluent_resource() {
+        let mut catalog = Catalog::new();
+        catalog.add_fluent(
+            "en",
+            "# Inbox\nunread = You have { $count } unread\n    messages\n\ntitle = Inbox",
+        );
+        assert_eq!(
+            catalog.message("en", "unread"),
+            Some("You have {count} unread\nmessages")
+        );
+        assert_eq!(catalog.message("en", "title"), Some("Inbox"));
+    }
+
+    #[test]
+    fn test_locale_is_per_thread() {
+        std::thread::spawn(|| {
+            set_locale("de");
+            assert_eq!(locale(), "de");
+        })
+        .join()
+        .unwrap();
+        assert_eq!(locale(), "en");
+    }
+
+    #[test]
+    fn test_t_macro_follows_active_locale() {
+        with_test_isolate(|| {
+            let catalog = catalog();
+            with_component_id("TranslationsProvider", |_| {
+                use_translations(&catalog);
+                assert_eq!(t!("greeting", name = "Ada"), "Hello, Ada!");
+                assert_eq!(t!("unknown.key"), "unknown.key");
+
+                set_locale("fr");
+                assert_eq!(t!("greeting", name = "Ada"), "Bonjour, Ada !");
+                set_locale("en");
+            });
+        });
+    }
+}
@@ -0,0 +1,151 @@
+//! Layout helpers that stay inside their parent area
+//!
+//! Popups and dialogs are usually placed by computing a rectangle inside the
+//! frame by hand. On a very small terminal such a rectangle easily ends up
+//! larger than the frame or partly outside of it, and rendering into it
+//! panics. The helpers here always return a rectangle within the given area,
+//! shrinking it down to an empty one if nothing fits, and [`safe_area`]
+//! applies the same guarantee to any computed rectangle.
+
+use ratatui::layout::Rect;
+
+/// Clamp `rect` to the bounds of `parent`
+///
+/// Returns the part of `rect` inside `parent`. A rectangle entirely outside
+/// of it becomes an empty one at the nearest position within `parent`, so
+/// the result can always be rendered into.
+///
+/// # Examples
+///
+/// ```rust
+/// use pulse_core::layout::safe_area;
+/// use ratatui::layout::Rect;
+///
+/// let frame = Rect::new(0, 0, 20, 5);
+/// assert_eq!(safe_area(Rect::new(10, 2, 60, 12), frame), Rect::new(10, 2, 10, 3));
+/// assert_eq!(safe_area(Rect::new(30, 9, 4, 4), frame), Rect::new(20, 5, 0, 0));
+/// ```
+pub fn safe_area(rect: Rect, parent: Rect) -> Rect {
+    let x = rect.x.clamp(parent.left(), parent.right());
+    let y = rect.y.clamp(parent.top(), parent.bottom());
+    let right = rect.right().clamp(x, parent.right());
+    let bottom = rect.bottom().clamp(y, parent.bottom());
+    Rect::new(x, y, right - x, bottom - y)
+}
+
+/// Rectangle of `percent_x` by `percent_y` percent of `area`, centered in it
+///
+/// Percentages above 100 are treated as 100.
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let scale =
+        |length: u16, percent: u16| (u32::from(length) * u32::from(percent.min(100)) / 100) as u16;
+    centered_size(
+        scale(area.width, percent_x),
+        scale(area.height, percent_y),
+        area,
+    )
+}
+
+/// Rectangle of `width` by `height` cells centered in `area`
+///
+/// The size is reduced to that of `area` when it doesn't fit.
+///
+/// # Examples
+///
+/// ```rust
+/// use pulse_core::layout::centered_size;
+/// use ratatui::layout::Rect;
+///
+/// assert_eq!(centered_size(6, 2, Rect::new(0, 0, 10, 4)), Rect::new(2, 1, 6, 2));
+/// assert_eq!(centered_size(60, 12, Rect::new(0, 0, 1, 1)), Rect::new(0, 0, 1, 1));
+/// ```
+pub fn centered_size(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{
+        Terminal,
+        backend::TestBackend,
+        layout::{Constraint, Layout},
+        widgets::{Block, Borders, Clear, Paragraph},
+    };
+
+    fn assert_within(rect: Rect, parent: Rect) {
+        assert_eq!(
+            rect.intersection(parent),
+            rect,
+            "{rect:?} not in {parent:?}"
+        );
+    }
+
+    #[test]
+    fn test_tiny_terminal_renders_without_panicking() {
+        let mut terminal = Terminal::new(TestBackend::new(1, 1)).unwrap();
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                let rects = [
+                    centered_rect(60, 40, area),
+                    centered_rect(250, 250, area),
+                    centered_size(60, 12, area),
+                    safe_area(Rect::new(0, 0, 60, 12), area),
+                    safe_area(Rect::new(5, 5, 3, 3), area),
+                ];
+                for rect in rects {
+                    assert_within(rect, area);
+                    frame.render_widget(Clear, rect);
+                    let dialog = Paragraph::new("Add todo")
+                        .block(Block::default().borders(Borders::ALL).title("Dialog"));
+                    frame.render_widget(dialog, rect);
+
+                    // Nested splits of a clamped area stay within it too
+                    for part in Layout::vertical([Constraint::Length(3); 3])
+                        .split(rect)
+                        .iter()
+                    {
+                        assert_within(*part, area);
+                    }
+                }
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_safe_area_clamps_to_parent() {
+        let parent = Rect::new(2, 3, 10, 4);
+        assert_eq!(
+            safe_area(Rect::new(4, 4, 2, 2), parent),
+            Rect::new(4, 4, 2, 2)
+        );
+        assert_eq!(
+            safe_area(Rect::new(0, 0, 5, 5), parent),
+            Rect::new(2, 3, 3, 2)
+        );
+        assert_eq!(
+            safe_area(Rect::new(0, 0, 1, 1), parent),
+            Rect::new(2, 3, 0, 0)
+        );
+        assert_eq!(
+            safe_area(Rect::new(20, 1, 4, 4), parent),
+            Rect::new(12, 3, 0, 2)
+        );
+    }
+
+    #[test]
+    fn test_centered_rect_in_offset_area() {
+        let area = Rect::new(10, 5, 20, 10);
+        assert_eq!(centered_rect(50, 50, area), Rect::new(15, 7, 10, 5));
+        assert_eq!(centered_rect(100, 100, area), area);
+        assert_eq!(centered_rect(0, 0, area), Rect::new(20, 10, 0, 0));
+    }
+}
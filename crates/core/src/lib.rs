@@ -1,13 +1,33 @@
+#[cfg(feature = "bidi")]
+pub mod bidi;
+pub mod clock;
+pub mod color;
 pub mod component;
+pub mod conditional;
+pub mod devtools;
 pub use component::Component;
 
+pub mod error;
 pub mod exit;
+pub mod format;
 pub mod hooks;
+pub mod i18n;
+pub mod layout;
+pub mod memo;
 
-mod vdom;
-pub use vdom::{Element, IntoElement};
+pub mod vdom;
+pub use vdom::{Children, Element, IntoElement, ParentComponent};
 
 pub mod panic_handler;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod redraw;
+pub mod router;
+pub mod slots;
+#[cfg(feature = "state-snapshot")]
+pub mod snapshot;
+pub mod tasks;
+pub mod widgets;
 
 // Re-export commonly used items
 pub use exit::{exit_guard, request_exit, reset_exit, should_exit};
@@ -0,0 +1,353 @@
+//! Memoized components
+//!
+//! [`memo`] wraps a child component so that it is only rendered again when
+//! its dependencies change, like `React.memo`. In frames where the
+//! dependencies and the area are unchanged, the cells the child drew last
+//! time are copied back into the frame instead of calling its `render`.
+//!
+//! The child runs its hooks in a hook context of its own, so skipping its
+//! render doesn't shift the hook order of the parent.
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use ratatui::{Frame, buffer::Buffer, layout::Rect};
+
+use crate::Component;
+use crate::component::{collect_rendered, keep_mounted};
+use crate::hooks::{HookContext, get_hook_context, set_hook_context, with_hook_context};
+
+/// Copy the cells of `area` out of `buffer`
+pub(crate) fn capture_region(buffer: &Buffer, area: Rect) -> Buffer {
+    let area = area.intersection(buffer.area);
+    let mut region = Buffer::empty(area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            region[(x, y)] = buffer[(x, y)].clone();
+        }
+    }
+    region
+}
+
+/// Copy the cells of `region` back into `buffer` at the same position
+pub(crate) fn blit_region(buffer: &mut Buffer, region: &Buffer) {
+    let area = region.area.intersection(buffer.area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            buffer[(x, y)] = region[(x, y)].clone();
+        }
+    }
+}
+
+/// Render in `context` instead of the current hook context
+///
+/// Returns the components rendered with mount tracking, to keep them mounted
+/// in frames that reuse the output instead of rendering again.
+pub(crate) fn render_isolated(context: &Rc<HookContext>, render: impl FnOnce()) -> Vec<usize> {
+    let parent = get_hook_context();
+    context.reset_hook_index();
+    set_hook_context(context.clone());
+    let ((), rendered) = collect_rendered(render);
+    if let Some(parent) = parent {
+        set_hook_context(parent);
+    }
+    rendered
+}
+
+/// What a memoized child drew in its last render
+struct MemoCache<D> {
+    deps: D,
+    /// State version of the child's hook context when it was drawn
+    state_version: u64,
+    area: Rect,
+    cells: Buffer,
+    rendered: Vec<usize>,
+}
+
+/// Hook state of a [`Memo`]
+struct MemoState<D> {
+    context: Rc<HookContext>,
+    cache: Option<MemoCache<D>>,
+}
+
+/// A component that re-renders its child only when `deps` change
+///
+/// Created by [`memo`].
+#[derive(Clone)]
+pub struct Memo<C, D> {
+    component: C,
+    deps: D,
+}
+
+/// Skip re-rendering `component` while `deps` and its area are unchanged
+///
+/// The child is also rendered again when a state, reducer or future of its
+/// own (or of its descendants) changes, or when a context slice it reads with
+/// [`use_context_selector`](crate::hooks::context::use_context_selector)
+/// does. Everything else its output depends on, such as props, whole context
+/// values or global signals, must be reflected in `deps`; otherwise the
+/// cached cells go stale.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::Component;
+/// # use pulse_core::memo::memo;
+/// # use pulse_core::hooks::state::use_state;
+/// # use ratatui::{Frame, layout::Rect};
+/// #[derive(Clone)]
+/// struct Chart {
+///     points: Vec<u64>,
+/// }
+///
+/// impl Component for Chart {
+///     fn render(&self, area: Rect, frame: &mut Frame) {
+///         // Expensive drawing
+///     }
+/// }
+///
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// // In a component context:
+/// let (points, _set_points) = use_state(Vec::<u64>::new);
+/// let version = points.version();
+/// memo(Chart { points: points.get() }, version).render(area, frame);
+/// # }
+/// ```
+pub fn memo<C, D>(component: C, deps: D) -> Memo<C, D>
+where
+    C: Component,
+    D: PartialEq + Clone + 'static,
+{
+    Memo { component, deps }
+}
+
+impl<C, D> Component for Memo<C, D>
+where
+    C: Component,
+    D: PartialEq + Clone + 'static,
+{
+    fn component_id(&self) -> Cow<'static, str> {
+        format!("Memo({})", self.component.component_id()).into()
+    }
+
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let state = with_hook_context(|ctx| {
+            let index = ctx.next_hook_index();
+            ctx.get_or_init_state(index, || MemoState::<D> {
+                context: Rc::new(HookContext::new()),
+                cache: None,
+            })
+        });
+        let mut state = state.borrow_mut();
+
+        // Counted before rendering, so state the child sets while rendering
+        // draws it again next frame
+        let state_version = state.context.state_version();
+        if let Some(cache) = &state.cache
+            && cache.area == area
+            && cache.deps == self.deps
+            && cache.state_version == state_version
+            && !state.context.selections_changed()
+        {
+            blit_region(frame.buffer_mut(), &cache.cells);
+            keep_mounted(&cache.rendered);
+            return;
+        }
+
+        // Render the child in its own hook context
+        let rendered = render_isolated(&state.context, || {
+            self.component.render_with_mount(area, frame)
+        });
+
+        state.cache = Some(MemoCache {
+            deps: self.deps.clone(),
+            state_version,
+            area,
+            cells: capture_region(frame.buffer_mut(), area),
+            rendered,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::reducer::{DispatchFn, use_reducer};
+    use crate::hooks::state::{StateSetter, use_state};
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+    use ratatui::{Terminal, backend::TestBackend, widgets::Paragraph};
+    use std::cell::Cell;
+
+    #[derive(Clone)]
+    struct Counted {
+        label: String,
+        renders: Rc<Cell<usize>>,
+    }
+
+    impl Component for Counted {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            self.renders.set(self.renders.get() + 1);
+            let (count, _) = use_state(|| 0);
+            let text = format!("{} {}", self.label, count.get());
+            frame.render_widget(Paragraph::new(text), area);
+        }
+    }
+
+    fn draw(
+        terminal: &mut Terminal<TestBackend>,
+        renders: &Rc<Cell<usize>>,
+        label: &str,
+        deps: u32,
+    ) -> String {
+        with_component_id("MemoParent", |_| {
+            let child = Counted {
+                label: label.to_string(),
+                renders: renders.clone(),
+            };
+            terminal
+                .draw(|frame| {
+                    memo(child, deps).render(frame.area(), frame);
+                    // Hooks after the memoized child keep their order
+                    let (after, _) = use_state(|| "after");
+                    assert_eq!(after.get(), "after");
+                })
+                .unwrap();
+        });
+
+        terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn test_unchanged_deps_skip_render() {
+        with_test_isolate(|| {
+            let renders = Rc::new(Cell::new(0));
+            let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+
+            assert_eq!(draw(&mut terminal, &renders, "one", 1), "one 0");
+            assert_eq!(renders.get(), 1);
+
+            // The label changed but the deps didn't, the cached cells are reused
+            assert_eq!(draw(&mut terminal, &renders, "two", 1), "one 0");
+            assert_eq!(draw(&mut terminal, &renders, "two", 1), "one 0");
+            assert_eq!(renders.get(), 1);
+
+            assert_eq!(draw(&mut terminal, &renders, "two", 2), "two 0");
+            assert_eq!(renders.get(), 2);
+        });
+    }
+
+    /// Child whose counter is changed from outside through its setter
+    #[derive(Clone)]
+    struct Clicker {
+        setter: Rc<std::cell::RefCell<Option<StateSetter<i32>>>>,
+    }
+
+    impl Component for Clicker {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let (clicks, set_clicks) = use_state(|| 0);
+            *self.setter.borrow_mut() = Some(set_clicks);
+            frame.render_widget(Paragraph::new(format!("clicks {}", clicks.get())), area);
+        }
+    }
+
+    #[test]
+    fn test_child_state_change_renders_again() {
+        with_test_isolate(|| {
+            let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+            let child = Clicker {
+                setter: Rc::default(),
+            };
+            let mut draw = || {
+                with_component_id("MemoClickerParent", |_| {
+                    terminal
+                        .draw(|frame| memo(child.clone(), ()).render(frame.area(), frame))
+                        .unwrap();
+                });
+                terminal.backend().buffer()[(7, 0)].symbol().to_string()
+            };
+
+            assert_eq!(draw(), "0");
+            assert_eq!(draw(), "0");
+
+            // The deps are unchanged, but the child's own state is not
+            child.setter.borrow().as_ref().unwrap().set(3);
+            assert_eq!(draw(), "3");
+            assert_eq!(draw(), "3");
+        });
+    }
+
+    /// Child whose counter lives in a reducer dispatched from outside
+    #[derive(Clone)]
+    struct Tally {
+        dispatch: Rc<std::cell::RefCell<Option<DispatchFn<i32>>>>,
+    }
+
+    impl Component for Tally {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let (total, dispatch) = use_reducer(|total: i32, amount: i32| total + amount, 0);
+            *self.dispatch.borrow_mut() = Some(dispatch);
+            frame.render_widget(Paragraph::new(format!("total {}", total.get())), area);
+        }
+    }
+
+    #[test]
+    fn test_child_reducer_change_renders_again() {
+        with_test_isolate(|| {
+            let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+            let child = Tally {
+                dispatch: Rc::default(),
+            };
+            let mut draw = || {
+                with_component_id("MemoTallyParent", |_| {
+                    terminal
+                        .draw(|frame| memo(child.clone(), ()).render(frame.area(), frame))
+                        .unwrap();
+                });
+                terminal.backend().buffer()[(6, 0)].symbol().to_string()
+            };
+
+            assert_eq!(draw(), "0");
+            child.dispatch.borrow().as_ref().unwrap().dispatch(5);
+            assert_eq!(draw(), "5");
+            assert_eq!(draw(), "5");
+        });
+    }
+
+    #[test]
+    fn test_resize_renders_again() {
+        with_test_isolate(|| {
+            let renders = Rc::new(Cell::new(0));
+            let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+            draw(&mut terminal, &renders, "one", 1);
+
+            terminal.backend_mut().resize(12, 1);
+            terminal.autoresize().unwrap();
+            draw(&mut terminal, &renders, "one", 1);
+            assert_eq!(renders.get(), 2);
+        });
+    }
+
+    #[test]
+    fn test_capture_and_blit_region() {
+        let mut source = Buffer::empty(Rect::new(0, 0, 4, 2));
+        source.set_string(0, 0, "abcd", ratatui::style::Style::default());
+        source.set_string(0, 1, "efgh", ratatui::style::Style::default());
+
+        let region = capture_region(&source, Rect::new(1, 0, 2, 2));
+        assert_eq!(region.area, Rect::new(1, 0, 2, 2));
+
+        let mut target = Buffer::empty(Rect::new(0, 0, 4, 2));
+        blit_region(&mut target, &region);
+        assert_eq!(target[(1, 0)].symbol(), "b");
+        assert_eq!(target[(2, 1)].symbol(), "g");
+        assert_eq!(target[(0, 0)].symbol(), " ");
+    }
+}
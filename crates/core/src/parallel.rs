@@ -0,0 +1,253 @@
+//! Parallel rendering of independent panels
+//!
+//! [`ParallelPanels`] renders sibling panels that don't share state into
+//! separate buffers on the rayon thread pool, then copies each buffer into
+//! the frame. It only pays off when panels are expensive to draw, such as
+//! dense charts on a large dashboard; for ordinary widgets the per-panel
+//! buffer setup costs more than it saves.
+//!
+//! Panels run on worker threads without access to the component's hook
+//! state. Read everything a panel needs in the parent component and move it
+//! into the panel; calling a hook inside a panel panics.
+
+use ratatui::{
+    Frame, Terminal, TerminalOptions, Viewport, backend::TestBackend, buffer::Buffer, layout::Rect,
+};
+use rayon::prelude::*;
+
+use crate::Component;
+use crate::hooks::without_hooks;
+use crate::memo::blit_region;
+
+/// Render function of a single panel
+type PanelFn<'a> = Box<dyn Fn(Rect, &mut Frame) + Send + Sync + 'a>;
+
+/// A set of sibling panels rendered in parallel
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::parallel::ParallelPanels;
+/// # use ratatui::{Frame, layout::{Constraint, Layout}, widgets::Paragraph};
+/// # fn render(frame: &mut Frame, cpu: Vec<u64>, memory: Vec<u64>) {
+/// let [left, right] = Layout::horizontal([Constraint::Fill(1); 2]).areas(frame.area());
+///
+/// ParallelPanels::new()
+///     .panel(left, move |area, frame| {
+///         frame.render_widget(Paragraph::new(format!("{:?}", cpu)), area);
+///     })
+///     .panel(right, move |area, frame| {
+///         frame.render_widget(Paragraph::new(format!("{:?}", memory)), area);
+///     })
+///     .render(frame);
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ParallelPanels<'a> {
+    panels: Vec<(Rect, PanelFn<'a>)>,
+}
+
+impl<'a> ParallelPanels<'a> {
+    /// Create an empty set of panels
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a panel drawn by `render` into `area`
+    pub fn panel(
+        mut self,
+        area: Rect,
+        render: impl Fn(Rect, &mut Frame) + Send + Sync + 'a,
+    ) -> Self {
+        self.panels.push((area, Box::new(render)));
+        self
+    }
+
+    /// Add a component that doesn't use hooks as a panel
+    ///
+    /// The component's `render` is called directly, without mount tracking,
+    /// so `on_mount` and `on_unmount` don't run.
+    pub fn component<C>(self, area: Rect, component: C) -> Self
+    where
+        C: Component + Send + Sync + 'a,
+    {
+        self.panel(area, move |area, frame| component.render(area, frame))
+    }
+
+    /// Number of panels added so far
+    pub fn len(&self) -> usize {
+        self.panels.len()
+    }
+
+    /// Whether no panel has been added
+    pub fn is_empty(&self) -> bool {
+        self.panels.is_empty()
+    }
+
+    /// Render every panel in parallel and composite them into `frame`
+    ///
+    /// Panels are copied into the frame in the order they were added, so a
+    /// later panel wins where areas overlap.
+    pub fn render(self, frame: &mut Frame) {
+        let frame_area = frame.area();
+        let buffers: Vec<Buffer> = self
+            .panels
+            .par_iter()
+            .map(|(area, render)| render_panel(area.intersection(frame_area), render))
+            .collect();
+
+        let target = frame.buffer_mut();
+        for buffer in &buffers {
+            blit_region(target, buffer);
+        }
+    }
+}
+
+/// Render one panel into a buffer covering exactly `area`
+fn render_panel(area: Rect, render: &PanelFn<'_>) -> Buffer {
+    if area.is_empty() {
+        return Buffer::empty(area);
+    }
+
+    // A fixed viewport gives the panel a frame whose buffer spans only its
+    // own area, at the same coordinates it has in the real frame
+    let backend = TestBackend::new(area.right(), area.bottom());
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Fixed(area),
+        },
+    )
+    .expect("a test backend never fails to initialize");
+
+    without_hooks(|| {
+        let mut frame = terminal.get_frame();
+        render(area, &mut frame);
+    });
+    std::mem::take(terminal.current_buffer_mut())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::state::use_state;
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+    use ratatui::{
+        layout::{Constraint, Layout},
+        widgets::{Block, Borders, Paragraph},
+    };
+
+    #[derive(Clone)]
+    struct Panel {
+        title: String,
+        lines: Vec<String>,
+    }
+
+    impl Component for Panel {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .title(self.title.as_str());
+            frame.render_widget(Paragraph::new(self.lines.join("\n")).block(block), area);
+        }
+    }
+
+    fn panels() -> Vec<Panel> {
+        (0..6)
+            .map(|index| Panel {
+                title: format!("Panel {}", index),
+                lines: (0..8)
+                    .map(|line| format!("row {} of {}", line, index))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn grid(area: Rect) -> Vec<Rect> {
+        let rows = Layout::vertical([Constraint::Fill(1); 2]).split(area);
+        rows.iter()
+            .flat_map(|row| {
+                Layout::horizontal([Constraint::Fill(1); 3])
+                    .split(*row)
+                    .to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parallel_render_matches_sequential() {
+        let mut sequential = Terminal::new(TestBackend::new(90, 24)).unwrap();
+        sequential
+            .draw(|frame| {
+                for (panel, area) in panels().into_iter().zip(grid(frame.area())) {
+                    panel.render(area, frame);
+                }
+            })
+            .unwrap();
+
+        let mut parallel = Terminal::new(TestBackend::new(90, 24)).unwrap();
+        parallel
+            .draw(|frame| {
+                let mut set = ParallelPanels::new();
+                for (panel, area) in panels().into_iter().zip(grid(frame.area())) {
+                    set = set.component(area, panel);
+                }
+                assert_eq!(set.len(), 6);
+                set.render(frame);
+            })
+            .unwrap();
+
+        assert_eq!(sequential.backend().buffer(), parallel.backend().buffer());
+    }
+
+    #[test]
+    fn test_panels_are_clipped_to_the_frame() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        terminal
+            .draw(|frame| {
+                ParallelPanels::new()
+                    .panel(Rect::new(5, 0, 20, 5), |area, frame| {
+                        frame.render_widget(Paragraph::new("abcdefghij"), area);
+                    })
+                    .render(frame);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(4, 0)].symbol(), " ");
+        assert_eq!(buffer[(5, 0)].symbol(), "a");
+        assert_eq!(buffer[(9, 0)].symbol(), "e");
+    }
+
+    #[test]
+    fn test_hooks_in_a_panel_panic_clearly() {
+        with_test_isolate(|| {
+            with_component_id("ParallelParent", |_| {
+                let mut terminal = Terminal::new(TestBackend::new(20, 4)).unwrap();
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    terminal
+                        .draw(|frame| {
+                            ParallelPanels::new()
+                                .panel(frame.area(), |_, _| {
+                                    let _ = use_state(|| 0);
+                                })
+                                .render(frame);
+                        })
+                        .unwrap();
+                }));
+
+                let payload = result.expect_err("hooks in a panel should panic");
+                let message = payload
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+                    .unwrap_or_default();
+                assert!(message.contains("ParallelPanels"), "{}", message);
+
+                // Hooks keep working on the calling thread afterwards
+                let (value, _) = use_state(|| 5);
+                assert_eq!(value.get(), 5);
+            });
+        });
+    }
+}
@@ -0,0 +1,278 @@
+//! Full redraw requests and per-frame rendering metrics
+//!
+//! Ratatui only writes cells that changed since the previous frame. When the
+//! terminal gets into a bad state (another process wrote to it, a resize went
+//! wrong) that diff no longer matches the screen; [`request_full_redraw`] asks
+//! the runtime to clear the terminal and write every cell on the next frame.
+//!
+//! The runtime also records [`FrameMetrics`] for each frame, which helps
+//! debugging rendering artifacts and excessive redraws. When a render budget
+//! is configured, frames that take longer are flagged, and components can
+//! check [`skip_non_essential`] to leave out expensive decorations until
+//! rendering catches up.
+//!
+//! Alongside the metrics, the runtime records the [`damage_regions`] of each
+//! frame: the rectangles covering every cell that changed. Transports that
+//! mirror the terminal elsewhere, such as over a network, can send just those
+//! regions instead of the whole frame.
+//!
+//! The runtime also ticks an [`FpsMeter`] after every frame; components read
+//! the smoothed frame rate with
+//! [`use_fps`](crate::hooks::frame::use_fps).
+
+use parking_lot::Mutex;
+use ratatui::layout::Rect;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static FULL_REDRAW: AtomicBool = AtomicBool::new(false);
+static LAST_FRAME_METRICS: Mutex<Option<FrameMetrics>> = Mutex::new(None);
+static SKIP_WHEN_OVER_BUDGET: AtomicBool = AtomicBool::new(false);
+static LAST_DAMAGE_REGIONS: Mutex<Vec<Rect>> = Mutex::new(Vec::new());
+static FPS: Mutex<FpsMeter> = Mutex::new(FpsMeter::new());
+
+/// Rendering statistics for a single frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameMetrics {
+    /// Sequence number of the frame, starting at 1
+    pub frame: u64,
+    /// Number of cells written to the terminal
+    pub changed_cells: usize,
+    /// Total number of cells in the frame
+    pub total_cells: usize,
+    /// Whether the frame was a forced full redraw
+    pub full_redraw: bool,
+    /// Time spent rendering the component tree
+    pub render_time: Duration,
+    /// Whether rendering took longer than the configured render budget
+    pub over_budget: bool,
+}
+
+impl FrameMetrics {
+    /// Fraction of the frame that was written, in `0.0..=1.0`
+    pub fn changed_ratio(&self) -> f64 {
+        if self.total_cells == 0 {
+            0.0
+        } else {
+            self.changed_cells as f64 / self.total_cells as f64
+        }
+    }
+}
+
+/// Exponentially smoothed frame rate computed from frame timestamps
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FpsMeter {
+    last_frame: Option<Instant>,
+    fps: f64,
+}
+
+impl FpsMeter {
+    /// Weight of the newest frame in the smoothed rate
+    const SMOOTHING: f64 = 0.1;
+
+    /// Create a meter that has not seen any frame
+    pub const fn new() -> Self {
+        Self {
+            last_frame: None,
+            fps: 0.0,
+        }
+    }
+
+    /// Record a frame drawn at `at`, returning the updated frame rate
+    ///
+    /// The first interval sets the rate directly; later intervals move it
+    /// a tenth of the way towards their own rate, which smooths out jitter
+    /// while still following lasting changes within a few dozen frames.
+    pub fn tick(&mut self, at: Instant) -> f64 {
+        if let Some(last_frame) = self.last_frame {
+            let interval = at.saturating_duration_since(last_frame).as_secs_f64();
+            if interval > 0.0 {
+                let rate = 1.0 / interval;
+                self.fps = if self.fps == 0.0 {
+                    rate
+                } else {
+                    self.fps + Self::SMOOTHING * (rate - self.fps)
+                };
+            }
+        }
+        self.last_frame = Some(at);
+        self.fps
+    }
+
+    /// Smoothed frames per second, `0.0` until two frames were recorded
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+}
+
+/// Tick the app's frame rate with a frame drawn at `at`
+pub fn record_frame_tick(at: Instant) {
+    FPS.lock().tick(at);
+}
+
+/// The app's smoothed frames per second, `0.0` before the second frame
+pub fn current_fps() -> f64 {
+    FPS.lock().fps()
+}
+
+/// Request a complete redraw of the terminal on the next frame
+pub fn request_full_redraw() {
+    FULL_REDRAW.store(true, Ordering::Release);
+    crate::hooks::batch::request_render();
+}
+
+/// Take the pending full redraw request, returning true if one was pending
+pub fn take_full_redraw_request() -> bool {
+    FULL_REDRAW.swap(false, Ordering::AcqRel)
+}
+
+/// Record the metrics of the frame that was just drawn
+pub fn record_frame_metrics(metrics: FrameMetrics) {
+    *LAST_FRAME_METRICS.lock() = Some(metrics);
+}
+
+/// Get the metrics of the most recently drawn frame
+pub fn last_frame_metrics() -> Option<FrameMetrics> {
+    *LAST_FRAME_METRICS.lock()
+}
+
+/// Record the damage regions of the frame that was just drawn
+pub fn record_damage_regions(regions: Vec<Rect>) {
+    *LAST_DAMAGE_REGIONS.lock() = regions;
+}
+
+/// Rectangles covering every cell that changed in the most recently drawn frame
+///
+/// Empty before the first frame and after a frame that changed nothing. A
+/// full redraw reports the whole frame as a single region.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::redraw::damage_regions;
+///
+/// for region in damage_regions() {
+///     // Send the cells inside `region` to the remote terminal
+/// }
+/// ```
+pub fn damage_regions() -> Vec<Rect> {
+    LAST_DAMAGE_REGIONS.lock().clone()
+}
+
+/// Merge changed cell positions into damage regions
+///
+/// Adjacent cells on a row are joined into spans, and spans covering the
+/// same columns on consecutive rows are joined into rectangles. Positions
+/// must be in row-major order, as produced by ratatui's buffer diff.
+pub fn compute_damage_regions(cells: impl IntoIterator<Item = (u16, u16)>) -> Vec<Rect> {
+    let mut spans: Vec<Rect> = Vec::new();
+    for (x, y) in cells {
+        match spans.last_mut() {
+            Some(span) if span.y == y && span.right() == x => span.width += 1,
+            _ => spans.push(Rect::new(x, y, 1, 1)),
+        }
+    }
+
+    let mut regions: Vec<Rect> = Vec::new();
+    for span in spans {
+        let below = regions.iter_mut().rev().find(|region| {
+            region.x == span.x && region.width == span.width && region.bottom() == span.y
+        });
+        match below {
+            Some(region) => region.height += 1,
+            None => regions.push(span),
+        }
+    }
+    regions
+}
+
+/// Time the most recently drawn frame spent rendering
+///
+/// Zero before the first frame.
+pub fn last_frame_duration() -> Duration {
+    last_frame_metrics()
+        .map(|metrics| metrics.render_time)
+        .unwrap_or_default()
+}
+
+/// Enable or disable [`skip_non_essential`] for frames over budget
+pub fn set_skip_non_essential_when_over_budget(enabled: bool) {
+    SKIP_WHEN_OVER_BUDGET.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns true if components should skip non-essential rendering work
+///
+/// This is the case when the previous frame exceeded the render budget and
+/// the runtime is configured to shed work in that case.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::redraw::skip_non_essential;
+///
+/// if !skip_non_essential() {
+///     // Render the animated background
+/// }
+/// ```
+pub fn skip_non_essential() -> bool {
+    SKIP_WHEN_OVER_BUDGET.load(Ordering::Relaxed)
+        && last_frame_metrics().is_some_and(|metrics| metrics.over_budget)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_redraw_request_is_taken_once() {
+        request_full_redraw();
+        assert!(take_full_redraw_request());
+        assert!(!take_full_redraw_request());
+    }
+
+    #[test]
+    fn test_frame_metrics_ratio() {
+        let metrics = FrameMetrics {
+            frame: 1,
+            changed_cells: 20,
+            total_cells: 80,
+            full_redraw: false,
+            ..FrameMetrics::default()
+        };
+        assert_eq!(metrics.changed_ratio(), 0.25);
+        assert_eq!(FrameMetrics::default().changed_ratio(), 0.0);
+
+        record_frame_metrics(metrics);
+        assert_eq!(last_frame_metrics(), Some(metrics));
+    }
+
+    #[test]
+    fn test_damage_regions_merge_spans_and_rows() {
+        assert!(compute_damage_regions([]).is_empty());
+
+        // A 3x2 block, a separate cell on the first row, and a span that
+        // doesn't line up with the block
+        let cells = [
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (8, 1),
+            (2, 2),
+            (3, 2),
+            (4, 2),
+            (0, 5),
+            (1, 5),
+        ];
+        assert_eq!(
+            compute_damage_regions(cells),
+            vec![
+                Rect::new(2, 1, 3, 2),
+                Rect::new(8, 1, 1, 1),
+                Rect::new(0, 5, 2, 1),
+            ]
+        );
+
+        record_damage_regions(vec![Rect::new(0, 0, 4, 1)]);
+        assert_eq!(damage_regions(), vec![Rect::new(0, 0, 4, 1)]);
+    }
+}
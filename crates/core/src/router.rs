@@ -0,0 +1,231 @@
+//! Route-based rendering
+//!
+//! [`Router`] renders the component that belongs to the current route of a
+//! [`NavHandle`], so multi-screen apps can map routes to screens in one place
+//! instead of matching on the route in every render. Route changes can
+//! optionally slide the new screen in.
+
+use std::rc::Rc;
+use std::time::Duration;
+
+use ratatui::{Frame, layout::Rect};
+
+use crate::Component;
+use crate::hooks::navigation::NavHandle;
+use crate::hooks::transition::use_transition;
+
+/// Renders a view into an area
+type RenderFn = Rc<dyn Fn(Rect, &mut Frame)>;
+
+/// A type-erased component rendered by a [`Router`]
+///
+/// Any component converts into a view with `into()`; the component is
+/// rendered with mount tracking, so leaving a route unmounts its screen.
+#[derive(Clone)]
+pub struct RouteView {
+    render: RenderFn,
+}
+
+impl RouteView {
+    /// Wrap `component` into a view
+    pub fn new<C: Component>(component: C) -> Self {
+        Self {
+            render: Rc::new(move |area, frame| component.render_with_mount(area, frame)),
+        }
+    }
+
+    /// A view that renders nothing
+    pub fn empty() -> Self {
+        Self {
+            render: Rc::new(|_, _| {}),
+        }
+    }
+
+    /// Render the view into `area`
+    pub fn render(&self, area: Rect, frame: &mut Frame) {
+        (self.render)(area, frame);
+    }
+}
+
+impl<C: Component> From<C> for RouteView {
+    fn from(component: C) -> Self {
+        Self::new(component)
+    }
+}
+
+/// Component that renders the screen of the current route
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::Component;
+/// # use pulse_core::hooks::navigation::use_navigation_with;
+/// # use pulse_core::router::Router;
+/// # use ratatui::{Frame, layout::Rect};
+/// # use std::time::Duration;
+/// # #[derive(Clone)] struct Inbox;
+/// # impl Component for Inbox { fn render(&self, _: Rect, _: &mut Frame) {} }
+/// # #[derive(Clone)] struct Message(usize);
+/// # impl Component for Message { fn render(&self, _: Rect, _: &mut Frame) {} }
+/// #[derive(Clone, PartialEq)]
+/// enum Route {
+///     Inbox,
+///     Message(usize),
+/// }
+///
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// // In a component context:
+/// let nav = use_navigation_with(|| Route::Inbox);
+///
+/// Router::new(nav, |route| match route {
+///     Route::Inbox => Inbox.into(),
+///     Route::Message(id) => Message(*id).into(),
+/// })
+/// .with_transition(Duration::from_millis(150))
+/// .render(area, frame);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Router<Route: 'static> {
+    nav: NavHandle<Route>,
+    routes: Rc<dyn Fn(&Route) -> RouteView>,
+    fallback: RouteView,
+    transition: Duration,
+}
+
+impl<Route: Clone + PartialEq + 'static> Router<Route> {
+    /// Create a router that renders `routes(current_route)`
+    pub fn new<F>(nav: NavHandle<Route>, routes: F) -> Self
+    where
+        F: Fn(&Route) -> RouteView + 'static,
+    {
+        Self {
+            nav,
+            routes: Rc::new(routes),
+            fallback: RouteView::empty(),
+            transition: Duration::ZERO,
+        }
+    }
+
+    /// Render `fallback` while the navigation stack is empty
+    pub fn with_fallback(mut self, fallback: impl Into<RouteView>) -> Self {
+        self.fallback = fallback.into();
+        self
+    }
+
+    /// Slide the new screen in from the right over `duration` on route changes
+    pub fn with_transition(mut self, duration: Duration) -> Self {
+        self.transition = duration;
+        self
+    }
+}
+
+impl<Route: Clone + PartialEq + 'static> Component for Router<Route> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let route = self.nav.current();
+        let transition = use_transition(route.clone(), self.transition);
+
+        let view = match &route {
+            Some(route) => (self.routes)(route),
+            None => self.fallback.clone(),
+        };
+        view.render(slide_in(area, transition.progress), frame);
+    }
+}
+
+/// The part of `area` covered by a screen sliding in from the right
+fn slide_in(area: Rect, progress: f64) -> Rect {
+    let hidden = (f64::from(area.width) * (1.0 - progress)).round() as u16;
+    let width = area.width.saturating_sub(hidden);
+    Rect {
+        x: area.right() - width,
+        width,
+        ..area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::navigation::use_navigation_with;
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+    use ratatui::{Terminal, backend::TestBackend, widgets::Paragraph};
+    use std::borrow::Cow;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Route {
+        Home,
+        Detail(u32),
+    }
+
+    #[derive(Clone)]
+    struct Screen(String);
+
+    impl Component for Screen {
+        fn component_id(&self) -> Cow<'static, str> {
+            format!("RouterScreen{}", self.0).into()
+        }
+
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            frame.render_widget(Paragraph::new(self.0.as_str()), area);
+        }
+    }
+
+    fn render_app(
+        terminal: &mut Terminal<TestBackend>,
+        navigate: impl FnOnce(&NavHandle<Route>),
+    ) -> String {
+        with_component_id("RouterApp", |_| {
+            let nav = use_navigation_with(|| Route::Home);
+            navigate(&nav);
+
+            let router = Router::new(nav, |route| match route {
+                Route::Home => Screen("home".to_string()).into(),
+                Route::Detail(id) => Screen(format!("detail {id}")).into(),
+            });
+            terminal
+                .draw(|frame| router.render(frame.area(), frame))
+                .unwrap();
+        });
+
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn test_route_change_renders_matching_component() {
+        with_test_isolate(|| {
+            let mut terminal = Terminal::new(TestBackend::new(12, 1)).unwrap();
+
+            assert_eq!(render_app(&mut terminal, |_| {}), "home");
+            assert_eq!(
+                render_app(&mut terminal, |nav| nav.push(Route::Detail(4))),
+                "detail 4"
+            );
+            assert_eq!(
+                render_app(&mut terminal, |nav| {
+                    nav.pop();
+                }),
+                "home"
+            );
+        });
+    }
+
+    #[test]
+    fn test_slide_in_area() {
+        let area = Rect::new(2, 0, 10, 3);
+        assert_eq!(slide_in(area, 0.0), Rect::new(12, 0, 0, 3));
+        assert_eq!(slide_in(area, 0.5), Rect::new(7, 0, 5, 3));
+        assert_eq!(slide_in(area, 1.0), area);
+
+        // Progress outside 0..=1 stays within the area
+        assert_eq!(slide_in(area, -0.5), Rect::new(12, 0, 0, 3));
+        assert_eq!(slide_in(area, 1.5), area);
+    }
+}
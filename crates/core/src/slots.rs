@@ -0,0 +1,207 @@
+//! Named render slots
+//!
+//! A component can declare a named slot for a region it owns with
+//! [`use_slot`], e.g. a footer hint bar owned by the root component. Any
+//! component in the tree can then route content into that region with
+//! [`render_into_slot`], without the two knowing about each other. Slot
+//! content is rendered by [`render_slots`] after the main render pass, which
+//! the runtime calls once per frame.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use ratatui::{Frame, layout::Rect};
+
+use crate::vdom::Children;
+use crate::{Component, Element};
+
+thread_local! {
+    static SLOTS: RefCell<SlotRegistry> = RefCell::new(SlotRegistry::default());
+}
+
+/// Slots declared and content routed during the current frame
+#[derive(Default)]
+struct SlotRegistry {
+    areas: HashMap<String, Rect>,
+    /// Routed content per slot, in the order the slots first got content
+    contents: Vec<(String, Vec<Element>)>,
+}
+
+/// Declare the slot `name` covering `area` for the current frame
+///
+/// Declaring the same slot twice in a frame keeps the last area.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::slots::use_slot;
+/// # use ratatui::{Frame, layout::{Constraint, Layout, Rect}};
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// // In the root component:
+/// let [body, footer] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+/// use_slot("footer", footer);
+/// # }
+/// ```
+pub fn use_slot(name: &str, area: Rect) {
+    SLOTS.with(|slots| {
+        slots.borrow_mut().areas.insert(name.to_string(), area);
+    });
+}
+
+/// Render `component` into the slot `name` once the main pass is done
+///
+/// Several components routed into the same slot share its area, stacked in
+/// the order they were routed. Slots are rendered in the order they first
+/// got content, so overlapping slots draw predictably. Content for a slot
+/// that isn't declared in the frame is dropped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::{Component, slots::render_into_slot};
+/// # use ratatui::{Frame, layout::Rect};
+/// # #[derive(Clone)] struct KeyHints;
+/// # impl Component for KeyHints { fn render(&self, _: Rect, _: &mut Frame) {} }
+/// // Anywhere in the tree:
+/// render_into_slot("footer", KeyHints);
+/// ```
+pub fn render_into_slot<C: Component>(name: &str, component: C) {
+    SLOTS.with(|slots| {
+        let contents = &mut slots.borrow_mut().contents;
+        let element = Element::component(component);
+        match contents.iter_mut().find(|(slot, _)| slot == name) {
+            Some((_, elements)) => elements.push(element),
+            None => contents.push((name.to_string(), vec![element])),
+        }
+    });
+}
+
+/// Render the content routed into each slot and reset the slots
+///
+/// Called by the runtime after the component tree was rendered.
+pub fn render_slots(frame: &mut Frame) {
+    let SlotRegistry { areas, contents } = SLOTS.with(|slots| slots.take());
+
+    for (name, elements) in contents {
+        match areas.get(&name) {
+            Some(area) => Children::new(elements).render_children(*area, frame),
+            None => tracing::debug!("Dropping content for undeclared slot '{}'", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::test_utils::with_test_isolate;
+    use ratatui::{
+        Terminal,
+        backend::TestBackend,
+        layout::{Constraint, Layout},
+        widgets::Paragraph,
+    };
+
+    #[derive(Clone)]
+    struct Root;
+
+    impl Component for Root {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let [body, footer] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+            use_slot("footer", footer);
+            Page.render(body, frame);
+        }
+    }
+
+    #[derive(Clone)]
+    struct Page;
+
+    impl Component for Page {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            frame.render_widget(Paragraph::new("page"), area);
+            render_into_slot("footer", Hint("q quit"));
+            render_into_slot("sidebar", Hint("dropped"));
+        }
+    }
+
+    #[derive(Clone)]
+    struct Hint(&'static str);
+
+    impl Component for Hint {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            frame.render_widget(Paragraph::new(self.0), area);
+        }
+    }
+
+    fn rows(terminal: &Terminal<TestBackend>) -> Vec<String> {
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_content_appears_in_slot_region() {
+        with_test_isolate(|| {
+            let mut terminal = Terminal::new(TestBackend::new(8, 3)).unwrap();
+            terminal
+                .draw(|frame| {
+                    Root.render(frame.area(), frame);
+                    render_slots(frame);
+                })
+                .unwrap();
+
+            assert_eq!(rows(&terminal), vec!["page    ", "        ", "q quit  "]);
+        });
+    }
+
+    #[test]
+    fn test_slots_reset_after_frame() {
+        with_test_isolate(|| {
+            let mut terminal = Terminal::new(TestBackend::new(8, 1)).unwrap();
+            terminal
+                .draw(|frame| {
+                    use_slot("status", frame.area());
+                    render_into_slot("status", Hint("first"));
+                    render_slots(frame);
+                })
+                .unwrap();
+
+            // Nothing is routed in the next frame, so the slot stays empty
+            terminal
+                .draw(|frame| {
+                    use_slot("status", frame.area());
+                    render_slots(frame);
+                })
+                .unwrap();
+            assert_eq!(rows(&terminal), vec!["        "]);
+        });
+    }
+
+    #[test]
+    fn test_overlapping_slots_render_in_routing_order() {
+        with_test_isolate(|| {
+            let mut terminal = Terminal::new(TestBackend::new(8, 1)).unwrap();
+            let names = ["toast", "status", "help", "modal", "footer"];
+            for _ in 0..10 {
+                terminal
+                    .draw(|frame| {
+                        for name in names {
+                            use_slot(name, frame.area());
+                        }
+                        render_into_slot("modal", Hint("modal"));
+                        render_into_slot("status", Hint("status"));
+                        render_into_slot("help", Hint("help"));
+                        render_into_slot("toast", Hint("toast"));
+                        render_slots(frame);
+                    })
+                    .unwrap();
+
+                // The slot routed last is drawn over the others
+                assert!(rows(&terminal)[0].starts_with("toast"));
+            }
+        });
+    }
+}
@@ -0,0 +1,429 @@
+//! App state snapshots for crash recovery
+//!
+//! [`serialize_app_state`] captures the values of registered `use_state` and
+//! `use_reducer` hooks into a blob, and [`restore_app_state`] feeds such a
+//! blob back in on the next launch, so an app resumes where it left off
+//! without wiring each hook to storage.
+//!
+//! Hook state is type-erased, so only states registered under a stable key
+//! with [`register_state`] or [`register_reducer`] take part. Like the hook
+//! state itself, the registry belongs to the render thread. Values restored
+//! before their hook registers are kept until it does, which lets the blob be
+//! restored before the first render.
+//!
+//! In debug builds, [`HotReloadSnapshot`] keeps a snapshot file up to date
+//! while the app runs and restores it on startup, so restarting the binary
+//! after a code change (e.g. via cargo-watch) preserves the UI state.
+//!
+//! Requires the `state-snapshot` feature.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+#[cfg(debug_assertions)]
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
+
+use crate::hooks::reducer::ReducerStateHandle;
+use crate::hooks::state::StateHandle;
+
+thread_local! {
+    static REGISTRY: RefCell<SnapshotRegistry> = RefCell::new(SnapshotRegistry::default());
+}
+
+/// Errors that can occur while taking or restoring a snapshot
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The value registered under `key` could not be serialized
+    Serialize {
+        key: String,
+        source: serde_json::Error,
+    },
+    /// The snapshot value for `key` doesn't fit the registered state
+    Deserialize {
+        key: String,
+        source: serde_json::Error,
+    },
+    /// The blob is not a snapshot
+    InvalidSnapshot(serde_json::Error),
+    /// Reading or writing a snapshot file failed
+    Io(std::io::Error),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Serialize { key, source } => {
+                write!(f, "Failed to serialize state '{}': {}", key, source)
+            }
+            SnapshotError::Deserialize { key, source } => {
+                write!(f, "Failed to restore state '{}': {}", key, source)
+            }
+            SnapshotError::InvalidSnapshot(source) => write!(f, "Invalid snapshot: {}", source),
+            SnapshotError::Io(source) => write!(f, "Snapshot file error: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SnapshotError::Serialize { source, .. }
+            | SnapshotError::Deserialize { source, .. }
+            | SnapshotError::InvalidSnapshot(source) => Some(source),
+            SnapshotError::Io(source) => Some(source),
+        }
+    }
+}
+
+/// A registered state that can be written to and read from a snapshot
+trait SnapshotEntry {
+    fn save(&self) -> serde_json::Result<Value>;
+    fn load(&self, value: Value) -> serde_json::Result<()>;
+}
+
+impl<T> SnapshotEntry for StateHandle<T>
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+{
+    fn save(&self) -> serde_json::Result<Value> {
+        serde_json::to_value(self.get())
+    }
+
+    fn load(&self, value: Value) -> serde_json::Result<()> {
+        self.container().set(serde_json::from_value(value)?);
+        Ok(())
+    }
+}
+
+impl<S> SnapshotEntry for ReducerStateHandle<S>
+where
+    S: Serialize + DeserializeOwned + Clone + 'static,
+{
+    fn save(&self) -> serde_json::Result<Value> {
+        serde_json::to_value(self.get())
+    }
+
+    fn load(&self, value: Value) -> serde_json::Result<()> {
+        self.replace(serde_json::from_value(value)?);
+        Ok(())
+    }
+}
+
+/// Registered states, and restored values waiting for their state
+#[derive(Default)]
+struct SnapshotRegistry {
+    entries: HashMap<String, Rc<dyn SnapshotEntry>>,
+    pending: HashMap<String, Value>,
+}
+
+fn register(key: &str, entry: Rc<dyn SnapshotEntry>) {
+    let pending = REGISTRY.with(|registry| {
+        let mut registry = registry.borrow_mut();
+        registry.entries.insert(key.to_string(), entry.clone());
+        registry.pending.remove(key)
+    });
+
+    if let Some(value) = pending
+        && let Err(error) = entry.load(value)
+    {
+        tracing::warn!("Ignoring snapshot of state '{}': {}", key, error);
+    }
+}
+
+/// Include the state behind `state` in snapshots under `key`
+///
+/// Call it on every render, right after `use_state`; registering a key again
+/// replaces the previous registration. If a restored snapshot holds a value
+/// for `key`, the state is set to it on the first registration.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use pulse_core::hooks::state::use_state;
+/// # use pulse_core::snapshot::register_state;
+/// // In a component context:
+/// let (filter, set_filter) = use_state(String::new);
+/// register_state("transactions.filter", &filter);
+/// ```
+pub fn register_state<T>(key: &str, state: &StateHandle<T>)
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+{
+    register(key, Rc::new(state.clone()));
+}
+
+/// Include the state of a `use_reducer` hook in snapshots under `key`
+///
+/// See [`register_state`].
+pub fn register_reducer<S>(key: &str, state: &ReducerStateHandle<S>)
+where
+    S: Serialize + DeserializeOwned + Clone + 'static,
+{
+    register(key, Rc::new(state.clone()));
+}
+
+/// Stop including the state registered under `key` in snapshots
+pub fn unregister_state(key: &str) {
+    REGISTRY.with(|registry| {
+        registry.borrow_mut().entries.remove(key);
+    });
+}
+
+/// Snapshot the values of all registered states into a blob
+///
+/// Restored values whose state never registered are carried over, so a
+/// screen that wasn't visited doesn't lose its snapshot.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::snapshot::serialize_app_state;
+///
+/// let blob = serialize_app_state().unwrap();
+/// std::fs::write("app.snapshot", blob).unwrap();
+/// ```
+pub fn serialize_app_state() -> Result<Vec<u8>, SnapshotError> {
+    let (entries, mut values) = REGISTRY.with(|registry| {
+        let registry = registry.borrow();
+        (
+            registry.entries.clone(),
+            registry.pending.clone().into_iter().collect::<Map<_, _>>(),
+        )
+    });
+
+    for (key, entry) in entries {
+        let value = entry.save().map_err(|source| SnapshotError::Serialize {
+            key: key.clone(),
+            source,
+        })?;
+        values.insert(key, value);
+    }
+
+    serde_json::to_vec(&values).map_err(SnapshotError::InvalidSnapshot)
+}
+
+/// Restore the values of a blob taken by [`serialize_app_state`]
+///
+/// Registered states are set right away; the other values are applied when
+/// their state registers. Call it before the first render to resume where
+/// the previous run left off.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::snapshot::restore_app_state;
+///
+/// if let Ok(blob) = std::fs::read("app.snapshot") {
+///     restore_app_state(&blob).unwrap();
+/// }
+/// ```
+pub fn restore_app_state(blob: &[u8]) -> Result<(), SnapshotError> {
+    let values: Map<String, Value> =
+        serde_json::from_slice(blob).map_err(SnapshotError::InvalidSnapshot)?;
+
+    let mut ready = Vec::new();
+    REGISTRY.with(|registry| {
+        let mut registry = registry.borrow_mut();
+        for (key, value) in values {
+            match registry.entries.get(&key) {
+                Some(entry) => ready.push((key, entry.clone(), value)),
+                None => {
+                    registry.pending.insert(key, value);
+                }
+            }
+        }
+    });
+
+    // Set outside the registry borrow, in case setting a state re-enters it
+    for (key, entry, value) in ready {
+        entry
+            .load(value)
+            .map_err(|source| SnapshotError::Deserialize { key, source })?;
+    }
+    Ok(())
+}
+
+/// A snapshot file that survives restarts during development
+///
+/// [`load`](Self::load) restores the file written by the previous run, and
+/// [`save_if_changed`](Self::save_if_changed), called after every frame,
+/// rewrites it whenever a registered state changed. The runtime does both
+/// when `RuntimeConfig::hot_reload_snapshot` is set. Only available in debug
+/// builds.
+#[cfg(debug_assertions)]
+pub struct HotReloadSnapshot {
+    path: PathBuf,
+    /// The blob last written to or read from the file
+    written: Option<Vec<u8>>,
+}
+
+#[cfg(debug_assertions)]
+impl HotReloadSnapshot {
+    /// Restore the snapshot at `path`, if there is one
+    ///
+    /// A missing or unreadable snapshot starts from the initial state; stale
+    /// snapshots are expected after code changes, so they are only logged.
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let written = match std::fs::read(&path) {
+            Ok(blob) => match restore_app_state(&blob) {
+                Ok(()) => Some(blob),
+                Err(error) => {
+                    tracing::warn!("Ignoring snapshot {}: {}", path.display(), error);
+                    None
+                }
+            },
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => None,
+            Err(error) => {
+                tracing::warn!("Failed to read snapshot {}: {}", path.display(), error);
+                None
+            }
+        };
+
+        Self { path, written }
+    }
+
+    /// Write the snapshot file if the registered states changed
+    ///
+    /// Returns true if the file was written.
+    pub fn save_if_changed(&mut self) -> Result<bool, SnapshotError> {
+        let blob = serialize_app_state()?;
+        if self.written.as_ref() == Some(&blob) {
+            return Ok(false);
+        }
+
+        std::fs::write(&self.path, &blob).map_err(SnapshotError::Io)?;
+        self.written = Some(blob);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::reducer::use_reducer;
+    use crate::hooks::state::use_state;
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Filter {
+        query: String,
+        page: u32,
+    }
+
+    fn render_app() -> (Filter, i64) {
+        with_component_id("SnapshotApp", |_| {
+            let (filter, _) = use_state(|| Filter {
+                query: String::new(),
+                page: 0,
+            });
+            register_state("snapshot_test.filter", &filter);
+
+            let (total, _) = use_reducer(|total: i64, amount: i64| total + amount, 0);
+            register_reducer("snapshot_test.total", &total);
+
+            (filter.get(), total.get())
+        })
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let blob = with_test_isolate(|| {
+            with_component_id("SnapshotApp", |_| {
+                let (_, set_filter) = use_state(|| Filter {
+                    query: String::new(),
+                    page: 0,
+                });
+                set_filter.set(Filter {
+                    query: "rent".to_string(),
+                    page: 3,
+                });
+                let (_, dispatch) = use_reducer(|total: i64, amount: i64| total + amount, 0);
+                dispatch.dispatch(-1250);
+            });
+            render_app();
+            serialize_app_state().unwrap()
+        });
+        unregister_state("snapshot_test.filter");
+        unregister_state("snapshot_test.total");
+
+        // Next launch: restore before the first render
+        restore_app_state(&blob).unwrap();
+        with_test_isolate(|| {
+            let (filter, total) = render_app();
+            assert_eq!(
+                filter,
+                Filter {
+                    query: "rent".to_string(),
+                    page: 3,
+                }
+            );
+            assert_eq!(total, -1250);
+        });
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn test_hot_reload_snapshot_survives_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.snapshot");
+        let render = || {
+            with_component_id("HotReloadApp", |_| {
+                let (count, set_count) = use_state(|| 0u32);
+                register_state("hot_reload_test.count", &count);
+                (count.get(), set_count)
+            })
+        };
+
+        // First run: nothing to restore, every change rewrites the file
+        with_test_isolate(|| {
+            let mut snapshot = HotReloadSnapshot::load(&path);
+            let (count, set_count) = render();
+            assert_eq!(count, 0);
+            assert!(snapshot.save_if_changed().unwrap());
+            assert!(!snapshot.save_if_changed().unwrap());
+
+            set_count.set(7);
+            assert!(snapshot.save_if_changed().unwrap());
+        });
+        unregister_state("hot_reload_test.count");
+
+        // Second run: the state picks up where the first run stopped
+        with_test_isolate(|| {
+            let mut snapshot = HotReloadSnapshot::load(&path);
+            let (count, _) = render();
+            assert_eq!(count, 7);
+            assert!(!snapshot.save_if_changed().unwrap());
+        });
+        unregister_state("hot_reload_test.count");
+    }
+
+    #[test]
+    fn test_restore_rejects_invalid_snapshot() {
+        assert!(matches!(
+            restore_app_state(b"not json"),
+            Err(SnapshotError::InvalidSnapshot(_))
+        ));
+    }
+
+    #[test]
+    fn test_restore_rejects_mismatched_value() {
+        with_test_isolate(|| {
+            with_component_id("SnapshotMismatch", |_| {
+                let (count, _) = use_state(|| 1u32);
+                register_state("snapshot_test.count", &count);
+            });
+
+            let error = restore_app_state(br#"{"snapshot_test.count": "many"}"#).unwrap_err();
+            assert!(
+                matches!(error, SnapshotError::Deserialize { ref key, .. } if key == "snapshot_test.count")
+            );
+            unregister_state("snapshot_test.count");
+        });
+    }
+}
@@ -0,0 +1,267 @@
+//! Concurrency limit for tasks spawned by hooks
+//!
+//! Hooks such as `use_future`, `use_async_effect` and `use_async_interval`
+//! spawn tokio tasks. With many components on screen this could start an
+//! unbounded amount of work at once, so hook work goes through a shared
+//! [`TaskPool`]: tasks are spawned right away but wait for a permit before
+//! doing any work, so at most [`TaskPool::max_concurrency`] run at a time and
+//! the rest are queued in order.
+//!
+//! Tasks running for a component's whole lifetime, such as those of
+//! `use_spawn` and `spawn_effect_task`, would hold a permit until unmount and
+//! starve short-lived work like `use_future`. They are started with
+//! [`spawn_long_lived`] outside the limit instead, and can acquire a permit
+//! around each unit of work, as `use_async_interval` does for every tick.
+//!
+//! The global pool is configured through the runtime's `RuntimeConfig`, or
+//! directly with [`set_max_concurrent_tasks`].
+
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinHandle;
+
+/// Default number of hook tasks allowed to run at the same time
+pub const DEFAULT_MAX_CONCURRENT_TASKS: usize = 64;
+
+static GLOBAL_POOL: Lazy<TaskPool> = Lazy::new(|| TaskPool::new(DEFAULT_MAX_CONCURRENT_TASKS));
+
+/// Permission to run one task in a [`TaskPool`], released on drop
+#[derive(Debug)]
+pub struct TaskPermit {
+    permit: Option<OwnedSemaphorePermit>,
+    running: Arc<AtomicUsize>,
+    excess: Arc<AtomicUsize>,
+}
+
+impl Drop for TaskPermit {
+    fn drop(&mut self) {
+        self.running.fetch_sub(1, Ordering::SeqCst);
+
+        // A pool that shrank below its running tasks retires their permits
+        let retire = self
+            .excess
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |excess| {
+                excess.checked_sub(1)
+            })
+            .is_ok();
+        if retire && let Some(permit) = self.permit.take() {
+            permit.forget();
+        }
+    }
+}
+
+/// A pool that limits how many tasks run concurrently
+#[derive(Debug)]
+pub struct TaskPool {
+    semaphore: Arc<Semaphore>,
+    max_concurrency: Mutex<usize>,
+    running: Arc<AtomicUsize>,
+    /// Permits held by running tasks that are forgotten when released
+    excess: Arc<AtomicUsize>,
+}
+
+impl TaskPool {
+    /// Create a pool running at most `max_concurrency` tasks (at least one)
+    pub fn new(max_concurrency: usize) -> Self {
+        let max_concurrency = max_concurrency.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_concurrency)),
+            max_concurrency: Mutex::new(max_concurrency),
+            running: Arc::new(AtomicUsize::new(0)),
+            excess: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Maximum number of tasks running at the same time
+    pub fn max_concurrency(&self) -> usize {
+        *self.max_concurrency.lock()
+    }
+
+    /// Change the concurrency limit (at least one)
+    ///
+    /// Raising the limit lets queued tasks start right away. Lowering it
+    /// doesn't interrupt running tasks: their permits are retired as they
+    /// finish, until no more than the new limit run at a time.
+    pub fn set_max_concurrency(&self, max_concurrency: usize) {
+        let max_concurrency = max_concurrency.max(1);
+        let mut current = self.max_concurrency.lock();
+        if max_concurrency > *current {
+            // Keep permits that were about to be retired before adding new ones
+            let mut added = max_concurrency - *current;
+            let kept = self
+                .excess
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |excess| {
+                    Some(excess.saturating_sub(added))
+                })
+                .expect("the update always succeeds")
+                .min(added);
+            added -= kept;
+            self.semaphore.add_permits(added);
+        } else {
+            let removed = *current - max_concurrency;
+            let forgotten = self.semaphore.forget_permits(removed);
+            self.excess.fetch_add(removed - forgotten, Ordering::SeqCst);
+        }
+        *current = max_concurrency;
+    }
+
+    /// Number of tasks currently holding a permit
+    pub fn running(&self) -> usize {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    /// Wait until a task may run
+    pub async fn acquire(&self) -> TaskPermit {
+        let permit = self
+            .semaphore
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("task pool semaphore is never closed");
+        self.running.fetch_add(1, Ordering::SeqCst);
+        TaskPermit {
+            permit: Some(permit),
+            running: self.running.clone(),
+            excess: self.excess.clone(),
+        }
+    }
+
+    /// Spawn `future` on the current tokio runtime once a permit is available
+    ///
+    /// The future isn't polled until it holds a permit. Aborting the returned
+    /// handle also removes a queued task from the queue.
+    pub fn spawn<F>(&'static self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        tokio::spawn(async move {
+            let _permit = self.acquire().await;
+            future.await
+        })
+    }
+}
+
+/// The pool used by all hooks
+pub fn task_pool() -> &'static TaskPool {
+    &GLOBAL_POOL
+}
+
+/// Set how many hook tasks may run at the same time
+pub fn set_max_concurrent_tasks(max_concurrency: usize) {
+    task_pool().set_max_concurrency(max_concurrency);
+}
+
+/// Spawn hook work on the global [`TaskPool`]
+pub fn spawn_limited<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    task_pool().spawn(future)
+}
+
+/// Spawn a task that runs for a component's lifetime, outside the limit
+///
+/// Such a task would hold a permit of the global [`TaskPool`] until it is
+/// aborted; acquire one from [`task_pool`] around each unit of its work
+/// instead.
+pub fn spawn_long_lived<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_pool_limits_concurrency_under_load() {
+        static POOL: Lazy<TaskPool> = Lazy::new(|| TaskPool::new(3));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let active = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..20)
+            .map(|_| {
+                let peak = peak.clone();
+                let active = active.clone();
+                POOL.spawn(async move {
+                    let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    active.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        assert!(POOL.running() <= 3);
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+        assert_eq!(POOL.running(), 0);
+    }
+
+    /// Whether a permit is available without waiting
+    async fn can_acquire(pool: &TaskPool) -> bool {
+        tokio::time::timeout(Duration::from_millis(10), pool.acquire())
+            .await
+            .is_ok()
+    }
+
+    #[tokio::test]
+    async fn test_set_max_concurrency() {
+        let pool = TaskPool::new(0);
+        assert_eq!(pool.max_concurrency(), 1);
+
+        let first = pool.acquire().await;
+        pool.set_max_concurrency(2);
+        assert_eq!(pool.max_concurrency(), 2);
+        let second = pool.acquire().await;
+        assert_eq!(pool.running(), 2);
+        assert!(!can_acquire(&pool).await);
+
+        // Running tasks finish, but only one may run afterwards
+        pool.set_max_concurrency(1);
+        drop(first);
+        assert_eq!(pool.running(), 1);
+        assert!(!can_acquire(&pool).await);
+        drop(second);
+        assert!(can_acquire(&pool).await);
+
+        pool.set_max_concurrency(3);
+        let _permits = [
+            pool.acquire().await,
+            pool.acquire().await,
+            pool.acquire().await,
+        ];
+        assert!(!can_acquire(&pool).await);
+    }
+
+    #[tokio::test]
+    async fn test_growing_keeps_permits_about_to_be_retired() {
+        let pool = TaskPool::new(2);
+        let first = pool.acquire().await;
+        let second = pool.acquire().await;
+
+        // Shrinking and growing back before any task finished is a no-op
+        pool.set_max_concurrency(1);
+        pool.set_max_concurrency(2);
+        drop(first);
+        drop(second);
+        let _permits = [pool.acquire().await, pool.acquire().await];
+        assert!(!can_acquire(&pool).await);
+    }
+}
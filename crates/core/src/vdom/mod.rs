@@ -1,32 +1,531 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::Paragraph,
+};
+
 pub trait IntoElement {
     type Element: crate::Component;
     fn into_element(self) -> Self::Element;
 }
 
+/// Components that render child elements passed by their parent
+///
+/// Implemented by container components, typically by storing the children
+/// in a field and calling [`Children::render_children`] in `render`. See
+/// [`Element::parent`].
+pub trait ParentComponent: crate::Component {
+    /// Return the component with `children` as its children
+    fn with_children(self, children: Children) -> Self;
+}
+
+/// A node of the element tree
+///
+/// Elements are components themselves, so an element tree can be handed to
+/// the runtime like any other component.
 pub type Element = VNode;
+
+#[derive(Clone)]
 pub enum VNode {
+    /// Plain text
     Text(String),
-    // Element(Element),
-    // Component(Component),
-    // Fragment(Vec<VNode>),
-}
-
-// pub enum Component {
-//     Function(ComponentFn),
-//     Struct(ComponentStruct),
-// }
-
-// pub struct ComponentFn {
-//     pub fn create(props: Props) -> Element,
-//     pub fn update(prev_props: Props, props: Props) -> Element,
-// }
-
-// pub struct ComponentStruct {
-//     pub props: Props,
-//     pub children: Vec<Element>,
-// }
-
-// pub struct Props {
-//     pub id: u64,
-//     pub props: HashMap<String, String>,
-// }
+    /// A component, along with the children passed to it
+    Component(ComponentNode),
+    /// Several elements rendered next to each other
+    Fragment(Children),
+}
+
+impl VNode {
+    /// Create a text node
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
+    /// Create a node rendering `component`
+    pub fn component<C: crate::Component>(component: C) -> Self {
+        Self::Component(ComponentNode::new(component, Children::default()))
+    }
+
+    /// Create a node rendering a container `component` with `children`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use pulse_core::{Children, Component, Element, vdom::ParentComponent};
+    /// # use ratatui::{Frame, layout::Rect};
+    /// # #[derive(Clone, Default)] struct Panel { children: Children }
+    /// # impl Component for Panel {
+    /// #     fn render(&self, area: Rect, frame: &mut Frame) {
+    /// #         self.children.render_children(area, frame)
+    /// #     }
+    /// # }
+    /// # impl ParentComponent for Panel {
+    /// #     fn with_children(self, children: Children) -> Self { Self { children } }
+    /// # }
+    /// let tree = Element::parent(
+    ///     Panel::default(),
+    ///     vec![Element::text("Header"), Element::text("Body")],
+    /// );
+    /// assert_eq!(tree.children().len(), 2);
+    /// ```
+    pub fn parent<C: ParentComponent>(component: C, children: impl Into<Children>) -> Self {
+        let children = children.into();
+        Self::Component(ComponentNode::new(
+            component.with_children(children.clone()),
+            children,
+        ))
+    }
+
+    /// Create a node rendering `elements` stacked vertically
+    pub fn fragment(elements: impl IntoIterator<Item = Element>) -> Self {
+        Self::Fragment(Children::new(elements.into_iter().collect()))
+    }
+
+    /// The direct children of this node
+    pub fn children(&self) -> &[Element] {
+        match self {
+            Self::Text(_) => &[],
+            Self::Component(node) => node.children.elements(),
+            Self::Fragment(children) => children.elements(),
+        }
+    }
+
+    /// Render the node into `area`
+    ///
+    /// Components are rendered with mount tracking, so a child that is no
+    /// longer passed to its parent gets unmounted.
+    pub fn render(&self, area: Rect, frame: &mut Frame) {
+        match self {
+            Self::Text(text) => frame.render_widget(Paragraph::new(text.as_str()), area),
+            Self::Component(node) => node.render(area, frame),
+            Self::Fragment(children) => children.render_children(area, frame),
+        }
+    }
+}
+
+impl crate::Component for VNode {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        VNode::render(self, area, frame);
+    }
+
+    fn component_id(&self) -> Cow<'static, str> {
+        match self {
+            Self::Component(node) => node.element_id.clone(),
+            _ => "Element".into(),
+        }
+    }
+}
+
+impl fmt::Debug for VNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            Self::Component(node) => f.debug_tuple("Component").field(node).finish(),
+            Self::Fragment(children) => f
+                .debug_tuple("Fragment")
+                .field(&children.elements())
+                .finish(),
+        }
+    }
+}
+
+impl From<&str> for VNode {
+    fn from(text: &str) -> Self {
+        Self::text(text)
+    }
+}
+
+impl From<String> for VNode {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+/// Converts the value into an element node, keeping elements as they are
+fn element_of<T: IntoElement>(value: T) -> Element {
+    let element = value.into_element();
+    match (&element as &dyn std::any::Any).downcast_ref::<Element>() {
+        Some(node) => node.clone(),
+        None => Element::component(element),
+    }
+}
+
+macro_rules! impl_into_element_for_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: IntoElement),+> IntoElement for ($($name,)+) {
+            type Element = Element;
+
+            #[allow(non_snake_case)]
+            fn into_element(self) -> Element {
+                let ($($name,)+) = self;
+                Element::fragment([$(element_of($name)),+])
+            }
+        }
+    };
+}
+
+impl_into_element_for_tuple!(A, B);
+impl_into_element_for_tuple!(A, B, C);
+impl_into_element_for_tuple!(A, B, C, D);
+impl_into_element_for_tuple!(A, B, C, D, E);
+impl_into_element_for_tuple!(A, B, C, D, E, F);
+impl_into_element_for_tuple!(A, B, C, D, E, F, G);
+impl_into_element_for_tuple!(A, B, C, D, E, F, G, H);
+
+/// Renders a type-erased component into an area
+type RenderFn = Rc<dyn Fn(Rect, &mut Frame)>;
+
+/// A type-erased component inside an [`Element`]
+#[derive(Clone)]
+pub struct ComponentNode {
+    id: Cow<'static, str>,
+    element_id: Cow<'static, str>,
+    render: RenderFn,
+    children: Children,
+}
+
+impl ComponentNode {
+    fn new<C: crate::Component>(component: C, children: Children) -> Self {
+        let id = component.component_id();
+        // Owned ids are per instance, so interning them would leak
+        let element_id = match &id {
+            Cow::Borrowed(type_name) => Cow::Borrowed(interned_element_id(type_name)),
+            Cow::Owned(id) => Cow::Owned(format!("Element({id})")),
+        };
+        Self {
+            element_id,
+            id,
+            render: Rc::new(move |area, frame| component.render_with_mount(area, frame)),
+            children,
+        }
+    }
+
+    /// The id of the wrapped component
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        (self.render)(area, frame);
+    }
+}
+
+/// The id of an element wrapping the component type named `type_name`
+///
+/// Elements are rebuilt on every render, so these ids are interned to keep
+/// rendering them allocation-free. There is one per component type.
+fn interned_element_id(type_name: &'static str) -> &'static str {
+    thread_local! {
+        static ELEMENT_IDS: RefCell<HashMap<&'static str, &'static str>> =
+            RefCell::new(HashMap::new());
+    }
+
+    ELEMENT_IDS.with(|ids| {
+        *ids.borrow_mut()
+            .entry(type_name)
+            .or_insert_with(|| String::leak(format!("Element({type_name})")))
+    })
+}
+
+impl fmt::Debug for ComponentNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComponentNode")
+            .field("id", &self.id)
+            .field("children", &self.children.elements())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Child elements passed to a container component by its parent
+///
+/// Containers store the children they were given and call
+/// [`render_children`](Self::render_children) in their own `render`, which
+/// lets them lay out arbitrary content without knowing its type.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use pulse_core::{Component, Element};
+/// use pulse_core::vdom::Children;
+/// use ratatui::{Frame, layout::Rect, widgets::{Block, Borders}};
+///
+/// #[derive(Clone)]
+/// struct Panel {
+///     title: String,
+///     children: Children,
+/// }
+///
+/// impl Component for Panel {
+///     fn render(&self, area: Rect, frame: &mut Frame) {
+///         let block = Block::default().borders(Borders::ALL).title(self.title.as_str());
+///         let inner = block.inner(area);
+///         frame.render_widget(block, area);
+///         self.children.render_children(inner, frame);
+///     }
+/// }
+///
+/// # #[derive(Clone)] struct Chart;
+/// # impl Component for Chart { fn render(&self, _: Rect, _: &mut Frame) {} }
+/// let panel = Panel {
+///     title: "Overview".to_string(),
+///     children: Children::new(vec![Element::text("Revenue"), Element::component(Chart)]),
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct Children {
+    elements: Vec<Element>,
+    direction: Direction,
+}
+
+impl Children {
+    /// Children stacked vertically
+    pub fn new(elements: Vec<Element>) -> Self {
+        Self {
+            elements,
+            direction: Direction::Vertical,
+        }
+    }
+
+    /// Lay the children out along `direction` instead
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Add a child after the existing ones
+    pub fn push(&mut self, element: impl Into<Element>) {
+        self.elements.push(element.into());
+    }
+
+    /// The child elements
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+
+    /// Number of children
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true if there are no children
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Render the children into equal slices of `area`
+    pub fn render_children(&self, area: Rect, frame: &mut Frame) {
+        if self.elements.is_empty() {
+            return;
+        }
+
+        let areas = Layout::default()
+            .direction(self.direction)
+            .constraints(self.elements.iter().map(|_| Constraint::Fill(1)))
+            .split(area);
+        for (element, area) in self.elements.iter().zip(areas.iter()) {
+            element.render(*area, frame);
+        }
+    }
+}
+
+impl Default for Children {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl From<Vec<Element>> for Children {
+    fn from(elements: Vec<Element>) -> Self {
+        Self::new(elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Component;
+    use crate::hooks::test_utils::with_test_isolate;
+    use ratatui::{
+        Terminal,
+        backend::TestBackend,
+        widgets::{Block, Borders},
+    };
+
+    #[derive(Clone)]
+    struct Panel {
+        children: Children,
+    }
+
+    impl Component for Panel {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let block = Block::default().borders(Borders::ALL);
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            self.children.render_children(inner, frame);
+        }
+    }
+
+    impl ParentComponent for Panel {
+        fn with_children(self, children: Children) -> Self {
+            Self { children }
+        }
+    }
+
+    #[derive(Clone)]
+    struct Label(&'static str);
+
+    impl Component for Label {
+        fn component_id(&self) -> Cow<'static, str> {
+            format!("Label{}", self.0).into()
+        }
+
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            frame.render_widget(Paragraph::new(self.0), area);
+        }
+    }
+
+    fn rows(terminal: &Terminal<TestBackend>) -> Vec<String> {
+        let buffer = terminal.backend().buffer();
+        let width = buffer.area.width as usize;
+        buffer
+            .content
+            .chunks(width)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_container_renders_passed_children() {
+        with_test_isolate(|| {
+            let panel = Panel {
+                children: Children::new(vec![
+                    Element::text("first"),
+                    Element::component(Label("second")),
+                ]),
+            };
+
+            let mut terminal = Terminal::new(TestBackend::new(10, 4)).unwrap();
+            terminal
+                .draw(|frame| panel.render(frame.area(), frame))
+                .unwrap();
+
+            assert_eq!(
+                rows(&terminal),
+                vec!["┌────────┐", "│first   │", "│second  │", "└────────┘"]
+            );
+        });
+    }
+
+    #[test]
+    fn test_horizontal_children() {
+        with_test_isolate(|| {
+            let mut children = Children::default().with_direction(Direction::Horizontal);
+            children.push("ab");
+            children.push(Element::component(Label("cd")));
+            assert_eq!(children.len(), 2);
+
+            let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+            terminal
+                .draw(|frame| children.render_children(frame.area(), frame))
+                .unwrap();
+
+            assert_eq!(rows(&terminal), vec!["abcd"]);
+        });
+    }
+
+    #[test]
+    fn test_element_tree_construction() {
+        let tree = Element::parent(
+            Panel {
+                children: Children::default(),
+            },
+            vec![
+                Element::text("title"),
+                (Label("left"), Label("right")).into_element(),
+            ],
+        );
+
+        assert!(matches!(&tree, Element::Component(node) if node.id().ends_with("Panel")));
+        assert_eq!(tree.children().len(), 2);
+        assert!(matches!(&tree.children()[0], Element::Text(text) if text == "title"));
+
+        let fragment = &tree.children()[1];
+        assert!(matches!(fragment, Element::Fragment(_)));
+        let ids: Vec<_> = fragment
+            .children()
+            .iter()
+            .map(|child| match child {
+                Element::Component(node) => node.id().to_string(),
+                other => panic!("expected a component, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(ids, vec!["Labelleft", "Labelright"]);
+    }
+
+    #[test]
+    fn test_element_tree_renders() {
+        with_test_isolate(|| {
+            let tree = Element::parent(
+                Panel {
+                    children: Children::default(),
+                },
+                vec![
+                    Element::text("title"),
+                    (Label("one"), Element::text("two")).into_element(),
+                ],
+            );
+
+            let mut terminal = Terminal::new(TestBackend::new(10, 6)).unwrap();
+            terminal
+                .draw(|frame| tree.render_with_mount(frame.area(), frame))
+                .unwrap();
+
+            assert_eq!(
+                rows(&terminal),
+                vec![
+                    "┌────────┐",
+                    "│title   │",
+                    "│        │",
+                    "│one     │",
+                    "│two     │",
+                    "└────────┘"
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_element_ids_of_type_named_components_are_borrowed() {
+        #[derive(Clone)]
+        struct Divider;
+
+        impl Component for Divider {
+            fn render(&self, _area: Rect, _frame: &mut Frame) {}
+        }
+
+        let first = Element::component(Divider).component_id();
+        let second = Element::component(Divider).component_id();
+        assert!(matches!(first, Cow::Borrowed(_)));
+        assert!(first.starts_with("Element(") && first.ends_with("Divider)"));
+        assert!(std::ptr::eq(first.as_ref(), second.as_ref()));
+
+        let label = Element::component(Label("a")).component_id();
+        assert_eq!(label, "Element(Labela)");
+    }
+
+    #[test]
+    fn test_nested_tuples_flatten_elements() {
+        let element = (Element::text("a"), (Label("b"), Element::text("c"))).into_element();
+
+        // Elements are kept as they are instead of being wrapped again
+        assert!(matches!(&element.children()[0], Element::Text(text) if text == "a"));
+        assert!(matches!(&element.children()[1], Element::Fragment(_)));
+        assert_eq!(element.children()[1].children().len(), 2);
+    }
+}
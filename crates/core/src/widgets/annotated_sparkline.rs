@@ -0,0 +1,226 @@
+//! Sparkline with current, min and max labels
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Sparkline, Widget},
+};
+
+/// Sparkline annotated with the current, minimum and maximum values
+///
+/// The bottom row shows `now 42%  min 20%  max 79%` and the rows above it the
+/// sparkline. Threshold lines are dotted across the empty part of the chart
+/// at their value, with the chart scaled so every threshold is in view. Feed
+/// it from [`use_time_series`].
+///
+/// [`use_time_series`]: crate::hooks::time_series::use_time_series
+#[derive(Debug, Clone)]
+pub struct AnnotatedSparkline<'a> {
+    data: Vec<u64>,
+    thresholds: Vec<(u64, Color)>,
+    unit: String,
+    style: Style,
+    label_style: Style,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> AnnotatedSparkline<'a> {
+    /// Create a sparkline of `data`, oldest sample first
+    pub fn new(data: impl IntoIterator<Item = u64>) -> Self {
+        Self {
+            data: data.into_iter().collect(),
+            thresholds: Vec::new(),
+            unit: String::new(),
+            style: Style::default(),
+            label_style: Style::default().fg(Color::Gray),
+            block: None,
+        }
+    }
+
+    /// Draw a line of `color` at `value`
+    pub fn threshold(mut self, value: u64, color: Color) -> Self {
+        self.thresholds.push((value, color));
+        self
+    }
+
+    /// Append `unit` to the labels, e.g. `"%"` or `" KB/s"`
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Set the style of the sparkline
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the labels
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Surround the sparkline with `block`
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// The most recent sample
+    pub fn current(&self) -> Option<u64> {
+        self.data.last().copied()
+    }
+
+    /// The smallest sample
+    pub fn min(&self) -> Option<u64> {
+        self.data.iter().copied().min()
+    }
+
+    /// The largest sample
+    pub fn max(&self) -> Option<u64> {
+        self.data.iter().copied().max()
+    }
+
+    /// The annotation row
+    fn annotations(&self) -> Line<'static> {
+        let value = |value: Option<u64>| match value {
+            Some(value) => format!("{}{}", value, self.unit),
+            None => "–".to_string(),
+        };
+        Line::from(vec![
+            Span::styled("now ", self.label_style),
+            Span::styled(
+                value(self.current()),
+                self.label_style.add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  min {}", value(self.min())), self.label_style),
+            Span::styled(format!("  max {}", value(self.max())), self.label_style),
+        ])
+    }
+
+    /// Dot the thresholds across the blank cells of the chart
+    fn render_thresholds(&self, chart: Rect, scale: u64, buf: &mut Buffer) {
+        if chart.is_empty() || scale == 0 {
+            return;
+        }
+        for &(value, color) in &self.thresholds {
+            // Widened so large values cannot overflow; the level is at most
+            // the chart height since no threshold exceeds the scale
+            let level = (u128::from(value) * u128::from(chart.height) / u128::from(scale))
+                .min(u128::from(chart.height - 1));
+            let y = chart.bottom() - 1 - level as u16;
+            for x in chart.left()..chart.right() {
+                let cell = &mut buf[(x, y)];
+                if cell.symbol() == " " {
+                    cell.set_symbol("┄").set_fg(color);
+                }
+            }
+        }
+    }
+}
+
+impl Widget for AnnotatedSparkline<'_> {
+    fn render(mut self, area: Rect, buf: &mut Buffer) {
+        let area = match self.block.take() {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                inner
+            }
+            None => area,
+        };
+        if area.is_empty() {
+            return;
+        }
+
+        let [chart, labels] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+        let scale = self
+            .thresholds
+            .iter()
+            .map(|&(value, _)| value)
+            .chain(self.max())
+            .max()
+            .unwrap_or(0);
+
+        Sparkline::default()
+            .data(&self.data)
+            .max(scale)
+            .style(self.style)
+            .render(chart, buf);
+        self.render_thresholds(chart, scale, buf);
+        self.annotations().render(labels, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+    use crate::hooks::time_series::use_time_series;
+
+    fn rows(buf: &Buffer) -> Vec<String> {
+        buf.content
+            .chunks(buf.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_annotations_reflect_the_data() {
+        with_test_isolate(|| {
+            let series = with_component_id("CpuChart", |_| use_time_series(4));
+            for sample in [90, 35, 60, 12, 48] {
+                series.push(sample);
+            }
+
+            let sparkline = AnnotatedSparkline::new(series.values()).unit("%");
+            assert_eq!(sparkline.current(), Some(48));
+            assert_eq!(sparkline.min(), Some(12));
+            assert_eq!(sparkline.max(), Some(60));
+
+            let area = Rect::new(0, 0, 26, 3);
+            let mut buf = Buffer::empty(area);
+            sparkline.render(area, &mut buf);
+            assert_eq!(rows(&buf)[2], "now 48%  min 12%  max 60% ");
+            assert!(buf[(4, 2)].modifier.contains(Modifier::BOLD));
+
+            let mut buf = Buffer::empty(area);
+            AnnotatedSparkline::new([]).render(area, &mut buf);
+            assert_eq!(rows(&buf)[2], "now –  min –  max –       ");
+        });
+    }
+
+    #[test]
+    fn test_thresholds_are_drawn_in_the_blank_cells() {
+        let area = Rect::new(0, 0, 4, 5);
+        let mut buf = Buffer::empty(area);
+        AnnotatedSparkline::new([10, 40, 20, 5])
+            .threshold(80, Color::Red)
+            .threshold(40, Color::Yellow)
+            .render(area, &mut buf);
+
+        // The chart scales to the highest threshold, so 40 fills the bottom
+        // half, right under its line
+        assert_eq!(&rows(&buf)[..4], ["┄┄┄┄", "┄┄┄┄", " █  ", "▄██▂"]);
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+        assert_eq!(buf[(0, 1)].fg, Color::Yellow);
+    }
+
+    #[test]
+    fn test_thresholds_near_u64_max() {
+        let area = Rect::new(0, 0, 4, 5);
+        let mut buf = Buffer::empty(area);
+        AnnotatedSparkline::new([])
+            .threshold(u64::MAX, Color::Red)
+            .threshold(u64::MAX / 2, Color::Yellow)
+            .render(area, &mut buf);
+
+        assert_eq!(&rows(&buf)[..4], ["┄┄┄┄", "    ", "┄┄┄┄", "    "]);
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+        assert_eq!(buf[(0, 2)].fg, Color::Yellow);
+    }
+}
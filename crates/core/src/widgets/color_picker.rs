@@ -0,0 +1,153 @@
+//! RGB sliders with a preview swatch
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::hooks::color_picker::{ColorPickerHandle, RgbChannel};
+
+/// Width of the preview swatch, including the gap before it
+const SWATCH_WIDTH: u16 = 8;
+
+/// Sliders of a [`use_color_picker`] with a swatch of the selected color
+///
+/// Draws one row per slider, `R ━━━━━━──── 128`, with the focused slider's
+/// label highlighted, and fills the right of the area with the selected
+/// color. It needs 3 rows; the sliders stretch to the available width.
+///
+/// [`use_color_picker`]: crate::hooks::color_picker::use_color_picker
+#[derive(Clone)]
+pub struct ColorPicker<'a> {
+    picker: &'a ColorPickerHandle,
+    label_style: Style,
+    focused_style: Style,
+    track_style: Style,
+}
+
+impl<'a> ColorPicker<'a> {
+    /// Create sliders showing `picker`
+    pub fn new(picker: &'a ColorPickerHandle) -> Self {
+        Self {
+            picker,
+            label_style: Style::default().fg(Color::Gray),
+            focused_style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            track_style: Style::default().fg(Color::DarkGray),
+        }
+    }
+
+    /// Set the style of the slider labels and values
+    pub fn label_style(mut self, style: Style) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Set the style of the focused slider's label
+    pub fn focused_style(mut self, style: Style) -> Self {
+        self.focused_style = style;
+        self
+    }
+
+    /// Set the style of the unfilled part of the sliders
+    pub fn track_style(mut self, style: Style) -> Self {
+        self.track_style = style;
+        self
+    }
+
+    /// The row drawn for `channel`'s slider in `width` cells
+    fn slider(&self, channel: RgbChannel, width: u16) -> Line<'static> {
+        let value = self.picker.value(channel);
+        let track = width.saturating_sub(6) as usize;
+        let filled = (value as usize * track + 127) / 255;
+        let fill_color = match channel {
+            RgbChannel::Red => Color::Rgb(value, 0, 0),
+            RgbChannel::Green => Color::Rgb(0, value, 0),
+            RgbChannel::Blue => Color::Rgb(0, 0, value),
+        };
+        let label_style = if channel == self.picker.focused() {
+            self.focused_style
+        } else {
+            self.label_style
+        };
+
+        Line::from(vec![
+            Span::styled(channel.label(), label_style),
+            Span::raw(" "),
+            Span::styled("━".repeat(filled), Style::default().fg(fill_color)),
+            Span::styled("─".repeat(track - filled), self.track_style),
+            Span::styled(format!("{:>4}", value), self.label_style),
+        ])
+    }
+}
+
+impl Widget for ColorPicker<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [sliders, _, swatch] = Layout::horizontal([
+            Constraint::Min(0),
+            Constraint::Length(2),
+            Constraint::Length(SWATCH_WIDTH - 2),
+        ])
+        .areas(area);
+
+        let lines: Vec<_> = RgbChannel::ALL
+            .into_iter()
+            .map(|channel| self.slider(channel, sliders.width))
+            .collect();
+        Paragraph::new(lines).render(sliders, buf);
+        Block::default()
+            .style(Style::default().bg(self.picker.color()))
+            .render(swatch, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::color_picker::use_color_picker;
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[test]
+    fn test_sliders_and_swatch_match_the_color() {
+        with_test_isolate(|| {
+            let mut terminal = Terminal::new(TestBackend::new(24, 3)).unwrap();
+            terminal
+                .draw(|frame| {
+                    with_component_id("ThemeEditor", |_| {
+                        let picker = use_color_picker(Color::Rgb(255, 128, 0));
+                        picker.focus(RgbChannel::Green);
+                        frame.render_widget(ColorPicker::new(&picker), frame.area());
+                    });
+                })
+                .unwrap();
+
+            let buffer = terminal.backend().buffer();
+            let rows: Vec<String> = buffer
+                .content
+                .chunks(24)
+                .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+                .collect();
+            assert_eq!(
+                rows,
+                [
+                    "R ━━━━━━━━━━ 255        ",
+                    "G ━━━━━───── 128        ",
+                    "B ──────────   0        ",
+                ]
+            );
+
+            assert_eq!(buffer[(0, 1)].modifier, Modifier::BOLD | Modifier::REVERSED);
+            assert_eq!(buffer[(0, 0)].fg, Color::Gray);
+            assert_eq!(buffer[(2, 0)].fg, Color::Rgb(255, 0, 0));
+            for x in 18..24 {
+                assert_eq!(buffer[(x, 2)].bg, Color::Rgb(255, 128, 0));
+            }
+            assert_eq!(buffer[(17, 2)].bg, Color::Reset);
+        });
+    }
+}
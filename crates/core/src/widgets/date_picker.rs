@@ -0,0 +1,177 @@
+//! Month calendar with the selected day highlighted
+
+use chrono::{Datelike, Months, NaiveDate};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+/// Weekday headers, weeks starting on Monday
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Number of days in the month starting on `first`
+fn days_in_month(first: NaiveDate) -> u32 {
+    match first.checked_add_months(Months::new(1)) {
+        Some(next) => next.signed_duration_since(first).num_days() as u32,
+        // Only the last month chrono supports has no next month
+        None => 31,
+    }
+}
+
+/// Month grid for [`use_date_picker`] with the selected day highlighted
+///
+/// Draws the month and year as a title, the weekday names and one row per
+/// week, 20 cells wide and up to 8 rows high.
+///
+/// [`use_date_picker`]: crate::hooks::date_picker::use_date_picker
+#[derive(Debug, Clone)]
+pub struct DatePicker {
+    selected: NaiveDate,
+    title_style: Style,
+    weekday_style: Style,
+    selected_style: Style,
+}
+
+impl DatePicker {
+    /// Create a calendar of the month of `selected`
+    pub fn new(selected: NaiveDate) -> Self {
+        Self {
+            selected,
+            title_style: Style::default().add_modifier(Modifier::BOLD),
+            weekday_style: Style::default().fg(Color::Gray),
+            selected_style: Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Set the style of the month and year title
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.title_style = style;
+        self
+    }
+
+    /// Set the style of the weekday names
+    pub fn weekday_style(mut self, style: Style) -> Self {
+        self.weekday_style = style;
+        self
+    }
+
+    /// Set the style of the selected day
+    pub fn selected_style(mut self, style: Style) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// The lines drawn by the calendar
+    fn lines(&self) -> Vec<Line<'static>> {
+        let title = Line::from(Span::styled(
+            self.selected.format("%B %Y").to_string(),
+            self.title_style,
+        ))
+        .alignment(Alignment::Center);
+        let weekdays = Line::from(Span::styled(WEEKDAYS.join(" "), self.weekday_style));
+
+        let mut lines = vec![title, weekdays];
+        let first = self.selected.with_day(1).expect("every month has a day 1");
+        let mut spans = vec![Span::raw(
+            "   ".repeat(first.weekday().num_days_from_monday() as usize),
+        )];
+        for day in 1..=days_in_month(first) {
+            let weekday = (first.weekday().num_days_from_monday() + day - 1) % 7;
+            if weekday > 0 && day > 1 {
+                spans.push(Span::raw(" "));
+            }
+            let style = if day == self.selected.day() {
+                self.selected_style
+            } else {
+                Style::default()
+            };
+            spans.push(Span::styled(format!("{:>2}", day), style));
+            if weekday == 6 {
+                lines.push(Line::from(std::mem::take(&mut spans)));
+            }
+        }
+        if !spans.is_empty() {
+            lines.push(Line::from(spans));
+        }
+        lines
+    }
+}
+
+impl Widget for DatePicker {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Paragraph::new(self.lines()).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn render_rows(date: NaiveDate) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(20, 8)).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget(DatePicker::new(date), frame.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content
+            .chunks(20)
+            .map(|row| {
+                let row: String = row.iter().map(|cell| cell.symbol()).collect();
+                row.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        let first = |year, month| NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+        assert_eq!(days_in_month(first(2024, 2)), 29);
+        assert_eq!(days_in_month(first(2023, 2)), 28);
+        assert_eq!(days_in_month(first(1900, 2)), 28);
+        assert_eq!(days_in_month(first(2000, 2)), 29);
+        assert_eq!(days_in_month(first(2025, 12)), 31);
+        assert_eq!(days_in_month(first(2025, 4)), 30);
+        assert_eq!(days_in_month(NaiveDate::MAX.with_day(1).unwrap()), 31);
+    }
+
+    #[test]
+    fn test_grid_of_a_leap_february() {
+        let rows = render_rows(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+        assert_eq!(
+            rows,
+            [
+                "    February 2024",
+                "Mo Tu We Th Fr Sa Su",
+                "          1  2  3  4",
+                " 5  6  7  8  9 10 11",
+                "12 13 14 15 16 17 18",
+                "19 20 21 22 23 24 25",
+                "26 27 28 29",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_selected_day_is_highlighted() {
+        let lines = DatePicker::new(NaiveDate::from_ymd_opt(2025, 6, 30).unwrap())
+            .selected_style(Style::default().fg(Color::Cyan))
+            .lines();
+
+        // June 2025 starts on a Sunday and ends on a Monday in a row of its own
+        assert_eq!(lines.len(), 8);
+        let last = &lines[7];
+        assert_eq!(last.to_string(), "30");
+        assert_eq!(last.spans[0].style.fg, Some(Color::Cyan));
+        assert_eq!(lines[2].spans[1].style, Style::default());
+    }
+}
@@ -0,0 +1,133 @@
+//! Grid of values drawn as color intensities
+
+use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+
+/// Default palette, from empty to most active, in the style of contribution
+/// graphs
+const GREENS: [Color; 5] = [
+    Color::Rgb(40, 44, 52),
+    Color::Rgb(14, 68, 41),
+    Color::Rgb(0, 109, 50),
+    Color::Rgb(38, 166, 65),
+    Color::Rgb(57, 211, 83),
+];
+
+/// Grid of values where higher values get more intense colors
+///
+/// Each value is drawn as a `■` two cells wide, colored with a palette entry
+/// picked by its share of the largest value: zero uses the first color and
+/// the largest value the last. When the area is too small, the leftmost
+/// columns are dropped, so with [`use_heatmap_data`] the most recent weeks
+/// stay visible.
+///
+/// [`use_heatmap_data`]: crate::hooks::heatmap::use_heatmap_data
+#[derive(Debug, Clone)]
+pub struct Heatmap {
+    grid: Vec<Vec<u64>>,
+    palette: Vec<Color>,
+    symbol: &'static str,
+}
+
+impl Heatmap {
+    /// Create a heatmap of `grid`, given as rows of values
+    pub fn new(grid: Vec<Vec<u64>>) -> Self {
+        Self {
+            grid,
+            palette: GREENS.to_vec(),
+            symbol: "■",
+        }
+    }
+
+    /// Use `palette` from the color of zero to the most intense one
+    ///
+    /// # Panics
+    ///
+    /// Panics if `palette` is empty.
+    pub fn palette(mut self, palette: impl IntoIterator<Item = Color>) -> Self {
+        self.palette = palette.into_iter().collect();
+        assert!(
+            !self.palette.is_empty(),
+            "heatmap palette must not be empty"
+        );
+        self
+    }
+
+    /// Draw each value with `symbol` instead of `■`
+    pub fn symbol(mut self, symbol: &'static str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Index into the palette for `value`
+    ///
+    /// Zero always maps to the first color and any other value to at least
+    /// the second, so a little activity is still visible.
+    pub fn intensity(&self, value: u64) -> usize {
+        let levels = self.palette.len() - 1;
+        let max = self.grid.iter().flatten().copied().max().unwrap_or(0);
+        if value == 0 || max == 0 || levels == 0 {
+            return 0;
+        }
+        let level = (value.min(max) as u128 * levels as u128).div_ceil(max as u128);
+        (level as usize).max(1)
+    }
+
+    /// Color drawn for `value`
+    pub fn color(&self, value: u64) -> Color {
+        self.palette[self.intensity(value)]
+    }
+}
+
+impl Widget for Heatmap {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let visible_columns = (area.width as usize).div_ceil(2);
+        for (row, values) in self.grid.iter().take(area.height as usize).enumerate() {
+            let skipped = values.len().saturating_sub(visible_columns);
+            for (column, &value) in values.iter().skip(skipped).enumerate() {
+                let x = area.x + column as u16 * 2;
+                buf[(x, area.y + row as u16)]
+                    .set_symbol(self.symbol)
+                    .set_fg(self.color(value));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_higher_values_are_more_intense() {
+        let heatmap = Heatmap::new(vec![vec![0, 1, 3], vec![5, 8, 12]]);
+
+        let levels: Vec<_> = [0, 1, 3, 5, 8, 12]
+            .into_iter()
+            .map(|value| heatmap.intensity(value))
+            .collect();
+        assert_eq!(levels, [0, 1, 1, 2, 3, 4]);
+        assert_eq!(heatmap.color(0), GREENS[0]);
+        assert_eq!(heatmap.color(12), GREENS[4]);
+
+        let two_colors = heatmap.clone().palette([Color::Black, Color::Red]);
+        assert_eq!(two_colors.color(0), Color::Black);
+        assert_eq!(two_colors.color(1), Color::Red);
+
+        let empty = Heatmap::new(vec![vec![0, 0]]);
+        assert_eq!(empty.intensity(0), 0);
+    }
+
+    #[test]
+    fn test_most_recent_columns_stay_visible() {
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::empty(area);
+        Heatmap::new(vec![vec![9, 0, 4, 8]])
+            .palette([Color::Black, Color::Yellow, Color::Red])
+            .render(area, &mut buf);
+
+        let row: String = buf.content.iter().map(|cell| cell.symbol()).collect();
+        assert_eq!(row, "■ ■ ■");
+        let colors: Vec<_> = [0, 2, 4].into_iter().map(|x| buf[(x, 0)].fg).collect();
+        assert_eq!(colors, [Color::Black, Color::Yellow, Color::Red]);
+    }
+}
@@ -0,0 +1,182 @@
+//! Footer bar listing the available keys
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use crate::hooks::mode::KeyHint;
+
+/// Footer bar showing key bindings with their descriptions
+///
+/// Build it from [`ModeHandle::hints`] on every render and it always lists
+/// the bindings of the active mode, so footers no longer need to be kept in
+/// sync with the key handling by hand.
+///
+/// [`ModeHandle::hints`]: crate::hooks::mode::ModeHandle::hints
+///
+/// # Example
+/// ```rust,no_run
+/// # use pulse_core::hooks::mode::{Keymap, use_mode, when};
+/// # use pulse_core::hooks::event::filtered::use_key_event;
+/// # use pulse_core::widgets::KeyHints;
+/// # use crossterm::event::KeyCode;
+/// # use ratatui::{Frame, layout::Rect};
+/// # #[derive(Clone, PartialEq)]
+/// # enum Mode { Normal, Editing }
+/// # fn render(footer: Rect, frame: &mut Frame) {
+/// let mode = use_mode(Mode::Normal);
+/// let bindings = [
+///     when(
+///         Mode::Normal,
+///         Keymap::new().bind('e', |_| mode.set(Mode::Editing)).describe("Edit"),
+///     ),
+///     when(
+///         Mode::Editing,
+///         Keymap::new().bind(KeyCode::Enter, |_| mode.set(Mode::Normal)).describe("Save"),
+///     ),
+/// ];
+/// if let Some(key) = use_key_event() {
+///     mode.handle_key(&key, &bindings);
+/// }
+/// frame.render_widget(KeyHints::new(mode.hints(&bindings)), footer);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyHints {
+    hints: Vec<KeyHint>,
+    key_style: Style,
+    description_style: Style,
+}
+
+impl KeyHints {
+    /// Create a footer listing `hints` in order
+    pub fn new(hints: impl IntoIterator<Item = KeyHint>) -> Self {
+        Self {
+            hints: hints.into_iter().collect(),
+            key_style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            description_style: Style::default().fg(Color::Gray),
+        }
+    }
+
+    /// Set the style of the keys
+    pub fn key_style(mut self, style: Style) -> Self {
+        self.key_style = style;
+        self
+    }
+
+    /// Set the style of the descriptions
+    pub fn description_style(mut self, style: Style) -> Self {
+        self.description_style = style;
+        self
+    }
+
+    /// The line drawn by the footer
+    fn line(&self) -> Line<'static> {
+        let mut spans = Vec::with_capacity(self.hints.len() * 4);
+        for (index, hint) in self.hints.iter().enumerate() {
+            if index > 0 {
+                spans.push(Span::raw("  "));
+            }
+            spans.push(Span::styled(hint.binding.to_string(), self.key_style));
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(
+                hint.description.clone(),
+                self.description_style,
+            ));
+        }
+        Line::from(spans)
+    }
+}
+
+impl Widget for KeyHints {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Paragraph::new(self.line()).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::mode::{KeyBinding, Keymap, ModeHandle, use_mode, when};
+    use crate::hooks::test_utils::{with_component_id, with_test_isolate};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::{Terminal, backend::TestBackend};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Mode {
+        Browse,
+        Search,
+    }
+
+    /// Render the footer of a component, pressing `key` first if given
+    fn render_footer(terminal: &mut Terminal<TestBackend>, key: Option<KeyEvent>) -> String {
+        terminal
+            .draw(|frame| {
+                with_component_id("KeyHintsFooter", |_| {
+                    let mode: ModeHandle<Mode> = use_mode(Mode::Browse);
+                    let bindings = [
+                        when(
+                            Mode::Browse,
+                            Keymap::new()
+                                .bind('/', |_| mode.set(Mode::Search))
+                                .describe("Search")
+                                .bind('x', |_| {})
+                                .bind(KeyBinding::ctrl(KeyCode::Char('q')), |_| {})
+                                .describe("Quit"),
+                        ),
+                        when(
+                            Mode::Search,
+                            Keymap::new()
+                                .bind(KeyCode::Esc, |_| mode.set(Mode::Browse))
+                                .describe("Cancel")
+                                .bind(KeyCode::Enter, |_| mode.set(Mode::Browse))
+                                .describe("Go"),
+                        ),
+                    ];
+                    if let Some(key) = key {
+                        mode.handle_key(&key, &bindings);
+                    }
+                    frame.render_widget(KeyHints::new(mode.hints(&bindings)), frame.area());
+                });
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+        row.trim_end().to_string()
+    }
+
+    #[test]
+    fn test_hints_follow_the_active_mode() {
+        with_test_isolate(|| {
+            let mut terminal = Terminal::new(TestBackend::new(40, 1)).unwrap();
+
+            // Undescribed bindings are left out
+            assert_eq!(render_footer(&mut terminal, None), "/ Search  Ctrl+q Quit");
+
+            let slash = KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE);
+            render_footer(&mut terminal, Some(slash));
+            assert_eq!(render_footer(&mut terminal, None), "Esc Cancel  Enter Go");
+        });
+    }
+
+    #[test]
+    fn test_styles_apply_to_keys_and_descriptions() {
+        let line = KeyHints::new([KeyHint {
+            binding: KeyBinding::from('s'),
+            description: "Save".to_string(),
+        }])
+        .key_style(Style::default().fg(Color::Cyan))
+        .line();
+
+        assert_eq!(line.to_string(), "s Save");
+        assert_eq!(line.spans[0].style.fg, Some(Color::Cyan));
+        assert_eq!(line.spans[2].style.fg, Some(Color::Gray));
+    }
+}
@@ -0,0 +1,37 @@
+//! Reusable widgets built on top of the hooks
+//!
+//! These are plain ratatui widgets that render the state managed by the
+//! corresponding hooks, e.g. [`TreeView`] for [`use_tree`], [`KeyHints`] for
+//! [`use_mode`], [`DatePicker`] for [`use_date_picker`], [`ColorPicker`] for
+//! [`use_color_picker`], [`AnnotatedSparkline`] for [`use_time_series`] and
+//! [`Heatmap`] for [`use_heatmap_data`], plus render helpers such as
+//! [`render_error_with_retry`] for failed futures, [`TooSmall`] for
+//! terminals below an app's minimum size and [`ThresholdGauge`] for gauges
+//! colored by value bands.
+//!
+//! [`use_tree`]: crate::hooks::tree::use_tree
+//! [`use_mode`]: crate::hooks::mode::use_mode
+//! [`use_date_picker`]: crate::hooks::date_picker::use_date_picker
+//! [`use_color_picker`]: crate::hooks::color_picker::use_color_picker
+//! [`use_time_series`]: crate::hooks::time_series::use_time_series
+//! [`use_heatmap_data`]: crate::hooks::heatmap::use_heatmap_data
+
+mod annotated_sparkline;
+mod color_picker;
+mod date_picker;
+mod heatmap;
+mod key_hints;
+mod retry;
+mod threshold_gauge;
+mod too_small;
+mod tree;
+
+pub use annotated_sparkline::AnnotatedSparkline;
+pub use color_picker::ColorPicker;
+pub use date_picker::DatePicker;
+pub use heatmap::Heatmap;
+pub use key_hints::KeyHints;
+pub use retry::{RETRY_KEY, render_error_with_retry};
+pub use threshold_gauge::ThresholdGauge;
+pub use too_small::TooSmall;
+pub use tree::TreeView;
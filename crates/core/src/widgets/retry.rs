@@ -0,0 +1,205 @@
+//! Error message with a retry key
+
+use std::fmt::Display;
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Paragraph, Wrap},
+};
+
+use crate::hooks::event::{consume_event, peek_current_event};
+use crate::hooks::future::FutureHandle;
+
+/// Key that retries the failed future in [`render_error_with_retry`]
+pub const RETRY_KEY: char = 'r';
+
+/// Render an error message and retry the future when [`RETRY_KEY`] is pressed
+///
+/// The retry key is consumed, so it doesn't reach other handlers. Unlike
+/// event hooks, this helper may be called conditionally, typically only
+/// while the future is in the error state.
+///
+/// # Example
+/// ```rust,no_run
+/// # use pulse_core::hooks::future::{FutureState, use_future};
+/// # use pulse_core::widgets::render_error_with_retry;
+/// # use ratatui::{Frame, layout::Rect, widgets::Paragraph};
+/// # async fn load() -> Result<String, String> { Ok(String::new()) }
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// let data = use_future(|| load(), ());
+/// match data.state() {
+///     FutureState::Resolved(text) => frame.render_widget(Paragraph::new(text), area),
+///     FutureState::Error(error) => render_error_with_retry(area, frame, &error, &data),
+///     _ => frame.render_widget(Paragraph::new("Loading..."), area),
+/// }
+/// # }
+/// ```
+pub fn render_error_with_retry<T, E>(
+    area: Rect,
+    frame: &mut Frame,
+    error: &impl Display,
+    retry_handle: &FutureHandle<T, E>,
+) where
+    T: Clone,
+    E: Clone,
+{
+    if let Some(event) = peek_current_event()
+        && let Event::Key(key) = event.as_ref()
+        && key.kind != KeyEventKind::Release
+        && key.code == KeyCode::Char(RETRY_KEY)
+        && (key.modifiers - KeyModifiers::SHIFT).is_empty()
+    {
+        consume_event();
+        retry_handle.retry();
+    }
+
+    let text = Text::from(vec![
+        Line::from(vec![
+            Span::styled(
+                "Error: ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(error.to_string(), Style::default().fg(Color::Red)),
+        ]),
+        Line::from(Span::styled(
+            format!("Press '{}' to retry", RETRY_KEY),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ]);
+    frame.render_widget(Paragraph::new(text).wrap(Wrap { trim: true }), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::event::{is_event_consumed, set_current_event};
+    use crate::hooks::future::{FutureState, use_future};
+    use crate::hooks::test_utils::{lock_current_event, with_component_id, with_test_isolate};
+    use crossterm::event::KeyEvent;
+    use ratatui::{Terminal, backend::TestBackend};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    /// Render a component whose future fails on its first attempt
+    fn render_flaky(terminal: &mut Terminal<TestBackend>, attempts: &Arc<AtomicUsize>) {
+        let attempts = attempts.clone();
+        terminal
+            .draw(|frame| {
+                with_component_id("FlakyLoader", |_| {
+                    let data = use_future(
+                        move || async move {
+                            match attempts.fetch_add(1, Ordering::SeqCst) {
+                                0 => Err("connection refused".to_string()),
+                                _ => Ok("loaded".to_string()),
+                            }
+                        },
+                        (),
+                    );
+                    if let FutureState::Error(error) = data.state() {
+                        render_error_with_retry(frame.area(), frame, &error, &data);
+                    }
+                })
+            })
+            .unwrap();
+    }
+
+    fn screen(terminal: &Terminal<TestBackend>) -> String {
+        let buffer = terminal.backend().buffer();
+        buffer.content.iter().map(|cell| cell.symbol()).collect()
+    }
+
+    /// Render the retry message of a failed future with `event` as the current event
+    fn render_failed(terminal: &mut Terminal<TestBackend>, event: Option<Event>) -> bool {
+        let handle = FutureHandle::<String, String>::new(Default::default());
+        set_current_event(event.map(Arc::new));
+        terminal
+            .draw(|frame| {
+                render_error_with_retry(frame.area(), frame, &"disk full", &handle);
+            })
+            .unwrap();
+        let consumed = is_event_consumed();
+        set_current_event(None);
+        consumed
+    }
+
+    #[test]
+    fn test_renders_error_and_retry_hint() {
+        let _lock = lock_current_event();
+        let mut terminal = Terminal::new(TestBackend::new(20, 3)).unwrap();
+
+        assert!(!render_failed(&mut terminal, None));
+        let buffer = terminal.backend().buffer();
+        let line = |y: u16| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert_eq!(line(0), "Error: disk full    ");
+        assert_eq!(line(1), "Press 'r' to retry  ");
+        assert_eq!(buffer[(0, 0)].fg, Color::Red);
+        assert!(buffer[(0, 0)].modifier.contains(Modifier::BOLD));
+        assert_eq!(buffer[(7, 0)].fg, Color::Red);
+        assert_eq!(buffer[(0, 1)].fg, Color::DarkGray);
+    }
+
+    #[test]
+    fn test_only_the_retry_key_is_consumed() {
+        let _lock = lock_current_event();
+        let mut terminal = Terminal::new(TestBackend::new(20, 3)).unwrap();
+        let key = |code, modifiers| Some(Event::Key(KeyEvent::new(code, modifiers)));
+
+        assert!(render_failed(
+            &mut terminal,
+            key(KeyCode::Char(RETRY_KEY), KeyModifiers::NONE)
+        ));
+        assert!(!render_failed(
+            &mut terminal,
+            key(KeyCode::Char('x'), KeyModifiers::NONE)
+        ));
+        assert!(!render_failed(
+            &mut terminal,
+            key(KeyCode::Char(RETRY_KEY), KeyModifiers::CONTROL)
+        ));
+    }
+
+    #[test]
+    fn test_retry_key_reruns_future() {
+        let _lock = lock_current_event();
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let _runtime = runtime.enter();
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let mut terminal = Terminal::new(TestBackend::new(40, 2)).unwrap();
+
+        with_test_isolate(|| {
+            set_current_event(None);
+            render_flaky(&mut terminal, &attempts);
+            thread::sleep(Duration::from_millis(50));
+
+            // Failed: the error and the retry hint are shown
+            render_flaky(&mut terminal, &attempts);
+            assert!(screen(&terminal).contains("connection refused"));
+            assert!(screen(&terminal).contains("Press 'r' to retry"));
+            assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+            // Pressing the retry key consumes it and re-runs the future
+            set_current_event(Some(Arc::new(Event::Key(KeyEvent::from(KeyCode::Char(
+                RETRY_KEY,
+            ))))));
+            render_flaky(&mut terminal, &attempts);
+            assert!(is_event_consumed());
+            set_current_event(None);
+            render_flaky(&mut terminal, &attempts);
+            thread::sleep(Duration::from_millis(50));
+
+            assert_eq!(attempts.load(Ordering::SeqCst), 2);
+            render_flaky(&mut terminal, &attempts);
+            assert!(!screen(&terminal).contains("connection refused"));
+        });
+    }
+}
@@ -0,0 +1,189 @@
+//! Gauge colored by the band its value falls in
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Gauge, Widget},
+};
+
+/// Gauge whose bar and label take the color of the value's band
+///
+/// Each `(threshold, color)` pair starts a band: the color of the highest
+/// threshold the value exceeds is used, and values exceeding none of them
+/// use the lowest band's color. Thresholds are in the same unit as the value,
+/// so a band starting at `max` marks the over-limit case. The bar is full
+/// for values past `max`, while the default label keeps the real percentage,
+/// e.g. `125%`.
+///
+/// # Example
+/// ```rust,no_run
+/// # use pulse_core::widgets::ThresholdGauge;
+/// # use ratatui::{Frame, layout::Rect, style::Color};
+/// # fn render(area: Rect, frame: &mut Frame, spent: f64, limit: f64) {
+/// let gauge = ThresholdGauge::new(
+///     spent,
+///     limit,
+///     [(0.0, Color::Green), (limit * 0.8, Color::Yellow), (limit, Color::Red)],
+/// )
+/// .label(format!("${spent:.2} / ${limit:.2}"));
+/// frame.render_widget(gauge, area);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThresholdGauge<'a> {
+    value: f64,
+    max: f64,
+    thresholds: Vec<(f64, Color)>,
+    label: Option<String>,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> ThresholdGauge<'a> {
+    /// Create a gauge of `value` out of `max`, colored by `thresholds`
+    pub fn new(value: f64, max: f64, thresholds: impl IntoIterator<Item = (f64, Color)>) -> Self {
+        let mut thresholds: Vec<_> = thresholds.into_iter().collect();
+        thresholds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            value,
+            max,
+            thresholds,
+            label: None,
+            block: None,
+        }
+    }
+
+    /// Replace the percentage label with `label`
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Surround the gauge with `block`
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Color of the band the value falls in
+    ///
+    /// [`Color::Reset`] if no thresholds were given.
+    pub fn color(&self) -> Color {
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|(threshold, _)| self.value > *threshold)
+            .or(self.thresholds.first())
+            .map_or(Color::Reset, |(_, color)| *color)
+    }
+
+    /// Filled part of the bar, in `0.0..=1.0`
+    ///
+    /// NaN values count as empty and an infinite value as full.
+    pub fn ratio(&self) -> f64 {
+        let ratio = self.value / self.max;
+        if self.max > 0.0 && !ratio.is_nan() {
+            ratio.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Text drawn in the middle of the bar
+    fn label_text(&self) -> String {
+        match &self.label {
+            Some(label) => label.clone(),
+            None if self.max > 0.0 => format!("{:.0}%", self.value / self.max * 100.0),
+            None => "0%".to_string(),
+        }
+    }
+}
+
+impl Widget for ThresholdGauge<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let color = self.color();
+        // The label keeps the default background so it stays readable over
+        // the filled part of the bar
+        let label = Span::styled(
+            self.label_text(),
+            Style::default()
+                .fg(color)
+                .bg(Color::Reset)
+                .add_modifier(Modifier::BOLD),
+        );
+        let mut gauge = Gauge::default()
+            .gauge_style(Style::default().fg(color))
+            .ratio(self.ratio())
+            .label(label);
+        if let Some(block) = self.block {
+            gauge = gauge.block(block);
+        }
+        gauge.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget(spent: f64) -> ThresholdGauge<'static> {
+        ThresholdGauge::new(
+            spent,
+            200.0,
+            [
+                (200.0, Color::Red),
+                (0.0, Color::Green),
+                (160.0, Color::Yellow),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_color_matches_the_band() {
+        assert_eq!(budget(0.0).color(), Color::Green);
+        assert_eq!(budget(-5.0).color(), Color::Green);
+        assert_eq!(budget(160.0).color(), Color::Green);
+        assert_eq!(budget(160.5).color(), Color::Yellow);
+        assert_eq!(budget(200.0).color(), Color::Yellow);
+
+        // Over the limit
+        assert_eq!(budget(200.01).color(), Color::Red);
+        assert_eq!(budget(500.0).color(), Color::Red);
+        assert_eq!(budget(500.0).ratio(), 1.0);
+        assert_eq!(budget(500.0).label_text(), "250%");
+
+        assert_eq!(ThresholdGauge::new(1.0, 2.0, []).color(), Color::Reset);
+        assert_eq!(ThresholdGauge::new(1.0, 0.0, []).ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_ratio_of_non_finite_values() {
+        assert_eq!(budget(f64::NAN).ratio(), 0.0);
+        assert_eq!(budget(f64::INFINITY).ratio(), 1.0);
+        assert_eq!(budget(f64::NEG_INFINITY).ratio(), 0.0);
+        assert_eq!(ThresholdGauge::new(1.0, f64::NAN, []).ratio(), 0.0);
+        assert_eq!(ThresholdGauge::new(f64::INFINITY, f64::INFINITY, []).ratio(), 0.0);
+
+        // Rendering does not panic on any of them
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let area = Rect::new(0, 0, 10, 1);
+            let mut buf = Buffer::empty(area);
+            budget(value).render(area, &mut buf);
+        }
+    }
+
+    #[test]
+    fn test_bar_and_label_use_the_band_color() {
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        budget(170.0).label("ok").render(area, &mut buf);
+
+        let row: String = buf.content.iter().map(|cell| cell.symbol()).collect();
+        assert_eq!(row, "████ok ██ ");
+        assert_eq!(buf[(0, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(4, 0)].fg, Color::Yellow);
+        assert_eq!(buf[(4, 0)].bg, Color::Reset);
+        assert!(buf[(4, 0)].modifier.contains(Modifier::BOLD));
+    }
+}
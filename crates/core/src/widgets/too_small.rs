@@ -0,0 +1,67 @@
+//! Message shown instead of content that doesn't fit
+
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Paragraph, Wrap},
+};
+
+use crate::Component;
+
+/// Centered notice that the terminal is smaller than the app needs
+///
+/// Shows the required and the current size, so users know how far to
+/// enlarge the window. Used as the fallback of
+/// [`render_or_message`](crate::conditional::render_or_message).
+#[derive(Debug, Clone)]
+pub struct TooSmall {
+    min_width: u16,
+    min_height: u16,
+    style: Style,
+}
+
+impl TooSmall {
+    /// Create a notice for content needing `min_width` x `min_height` cells
+    pub fn new(min_width: u16, min_height: u16) -> Self {
+        Self {
+            min_width,
+            min_height,
+            style: Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Set the style of the notice
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Component for TooSmall {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.is_empty() {
+            return;
+        }
+
+        let lines = vec![
+            Line::from("Terminal too small"),
+            Line::from(format!(
+                "Need {}x{}, have {}x{}",
+                self.min_width, self.min_height, area.width, area.height
+            )),
+        ];
+        let paragraph = Paragraph::new(lines)
+            .style(self.style)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        // Center vertically when the wrapped notice leaves room
+        let height = (paragraph.line_count(area.width) as u16).min(area.height);
+        let top = area.y + (area.height - height) / 2;
+        frame.render_widget(paragraph, Rect::new(area.x, top, area.width, height));
+    }
+}
@@ -0,0 +1,106 @@
+//! Tree view widget
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+use crate::hooks::tree::TreeHandle;
+
+/// Renders the visible rows of a [`TreeHandle`]
+///
+/// Each row is indented by its depth and prefixed with an expand marker.
+/// The view scrolls so that the selected row stays visible.
+///
+/// # Example
+/// ```rust,no_run
+/// # use pulse_core::hooks::tree::{TreeNode, use_tree};
+/// # use pulse_core::widgets::TreeView;
+/// # use ratatui::{Frame, layout::Rect};
+/// # fn render(area: Rect, frame: &mut Frame) {
+/// let tree = use_tree(TreeNode::new("root").child(TreeNode::new("child")));
+/// frame.render_widget(TreeView::new(&tree, |name| (*name).into()), area);
+/// # }
+/// ```
+pub struct TreeView<'a, T, F> {
+    tree: &'a TreeHandle<T>,
+    label: F,
+    style: Style,
+    highlight_style: Style,
+    indent: u16,
+}
+
+impl<'a, T, F> TreeView<'a, T, F>
+where
+    T: Clone,
+    F: Fn(&T) -> Line<'a>,
+{
+    /// Create a tree view rendering each node with `label`
+    pub fn new(tree: &'a TreeHandle<T>, label: F) -> Self {
+        Self {
+            tree,
+            label,
+            style: Style::default(),
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            indent: 2,
+        }
+    }
+
+    /// Set the base style of the view
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the selected row
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Set the number of columns each nesting level is indented by
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+}
+
+impl<'a, T, F> Widget for TreeView<'a, T, F>
+where
+    T: Clone,
+    F: Fn(&T) -> Line<'a>,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+
+        let visible = self.tree.visible();
+        let selected = self.tree.selected_index();
+        let height = area.height as usize;
+        let offset = (selected + 1).saturating_sub(height);
+
+        for (row, node) in visible.iter().skip(offset).take(height).enumerate() {
+            let marker = match (node.has_children, node.expanded) {
+                (true, true) => "▾ ",
+                (true, false) => "▸ ",
+                (false, _) => "  ",
+            };
+            let indent = " ".repeat(node.depth * self.indent as usize);
+
+            let mut spans = vec![Span::raw(indent), Span::raw(marker)];
+            spans.extend((self.label)(&node.value).spans);
+            let line = Line::from(spans);
+
+            let y = area.y + row as u16;
+            buf.set_line(area.x, y, &line, area.width);
+            if offset + row == selected {
+                buf.set_style(Rect::new(area.x, y, area.width, 1), self.highlight_style);
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Full render pass of the todolist example
+//!
+//! Run with `cargo bench -p pulse_examples --bench todolist_render`.
+//!
+//! Each iteration draws the whole `todolist_reducer` app into an in-memory
+//! 120x40 terminal, exercising the reducer, layout and widget code together.
+//!
+//! Baseline (release build, x86_64 Linux, rustc 1.95):
+//!
+//! | benchmark            | time    |
+//! |----------------------|---------|
+//! | todolist/full_render | ~300 µs |
+//!
+//! Treat a sustained slowdown of more than ~20% against this number as a
+//! regression worth investigating before merging.
+
+use std::rc::Rc;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use pulse::prelude::*;
+use pulse_core::hooks::{HookContext, clear_hook_context, set_hook_context};
+use ratatui::{Terminal, backend::TestBackend};
+
+#[allow(dead_code)]
+#[path = "../todolist_reducer/src/main.rs"]
+mod todolist;
+
+fn bench_full_render(c: &mut Criterion) {
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    let context = Rc::new(HookContext::new());
+    set_hook_context(context.clone());
+
+    c.bench_function("todolist/full_render", |b| {
+        b.iter(|| {
+            terminal
+                .draw(|frame| {
+                    context.reset_hook_index();
+                    todolist::App.render_with_mount(frame.area(), frame);
+                })
+                .unwrap();
+            pulse_core::component::cleanup_unmounted();
+        })
+    });
+
+    clear_hook_context();
+}
+
+criterion_group!(benches, bench_full_render);
+criterion_main!(benches);
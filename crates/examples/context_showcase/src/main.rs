@@ -163,9 +163,13 @@ impl Component for ContextApp {
             UserContext::user(),
             UserContext::guest(),
         ];
-        let current_theme = themes[theme_index.get() % themes.len()].clone();
+        let mut current_theme = themes[theme_index.get() % themes.len()].clone();
         let current_user = users[user_index.get() % users.len()].clone();
 
+        // Custom accent color, starting from the theme's own
+        let accent = use_color_picker(current_theme.accent);
+        current_theme.accent = accent.color();
+
         // Provide contexts
         let theme = use_context_provider(|| current_theme);
         let _user = use_context_provider(|| current_user);
@@ -179,11 +183,14 @@ impl Component for ContextApp {
         // Handle input events
         if let Some(Event::Key(key)) = use_event()
             && key.kind == crossterm::event::KeyEventKind::Press
+            && !accent.handle_key(&key)
         {
             match key.code {
                 crossterm::event::KeyCode::Char('q') => request_exit(),
                 crossterm::event::KeyCode::Char('t') => {
-                    set_theme_index.update(|i| (*i + 1) % themes.len());
+                    let next = (theme_index.get() + 1) % themes.len();
+                    set_theme_index.set(next);
+                    accent.set(themes[next].accent);
                 }
                 crossterm::event::KeyCode::Char('u') => {
                     set_user_index.update(|i| (*i + 1) % users.len());
@@ -206,6 +213,7 @@ impl Component for ContextApp {
             .constraints([
                 Constraint::Length(3), // Header
                 Constraint::Min(0),    // Content
+                Constraint::Length(5), // Accent picker
                 Constraint::Length(3), // Footer
             ])
             .split(area);
@@ -227,8 +235,25 @@ impl Component for ContextApp {
         ThemeShowcase.render(content_chunks[1], frame);
         SettingsPanel.render(content_chunks[2], frame);
 
+        // Accent picker, editing the theme all components above receive
+        let picker_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.primary))
+            .title(" 🖌 Custom Accent (↑/↓ slider, ←/→ adjust) ");
+        let picker_area = picker_block.inner(chunks[2]);
+        frame.render_widget(picker_block, chunks[2]);
+        frame.render_widget(
+            ColorPicker::new(&accent).focused_style(
+                Style::default()
+                    .fg(theme.background)
+                    .bg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            picker_area,
+        );
+
         // Footer
-        FooterComponent.render(chunks[2], frame);
+        FooterComponent.render(chunks[3], frame);
     }
 }
 
@@ -459,14 +484,66 @@ impl Component for FooterComponent {
 }
 
 /// Settings panel component that demonstrates multiple context consumption
+///
+/// Only the parts of the three contexts it shows are selected, so switching to
+/// a theme with the same colors or a new login of the same user doesn't
+/// re-render the panel.
 #[derive(Clone)]
 pub struct SettingsPanel;
 
 impl Component for SettingsPanel {
     fn render(&self, area: Rect, frame: &mut Frame) {
-        let theme = use_context::<ThemeContext>();
-        let settings = use_context::<SettingsContext>();
-        let user = use_context::<UserContext>();
+        let view = SettingsView {
+            theme: use_context_selector(|theme: &ThemeContext| PanelColors {
+                primary: theme.primary,
+                secondary: theme.secondary,
+                accent: theme.accent,
+                background: theme.background,
+            }),
+            settings: use_context_selector(|settings: &SettingsContext| settings.clone()),
+            user: use_context_selector(|user: &UserContext| SessionUser {
+                name: user.name.clone(),
+                role: user.role.clone(),
+                session_count: user.session_count,
+            }),
+        };
+
+        memo(view.clone(), view).render(area, frame);
+    }
+}
+
+/// Theme colors used by the settings panel
+#[derive(Debug, Clone, PartialEq)]
+struct PanelColors {
+    primary: Color,
+    secondary: Color,
+    accent: Color,
+    background: Color,
+}
+
+/// User details shown in the settings panel
+#[derive(Debug, Clone, PartialEq)]
+struct SessionUser {
+    name: String,
+    role: String,
+    session_count: u32,
+}
+
+/// The context slices a [`SettingsPanel`] renders
+#[derive(Debug, Clone, PartialEq)]
+struct SettingsView {
+    theme: PanelColors,
+    settings: SettingsContext,
+    user: SessionUser,
+}
+
+impl Component for SettingsView {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let SettingsView {
+            theme,
+            settings,
+            user,
+        } = self;
 
         let settings_text = vec![
             Line::from(vec![Span::styled(
@@ -602,6 +679,8 @@ fn render_help_overlay(area: Rect, frame: &mut Frame, theme: &ThemeContext) {
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from("  t - Cycle through themes"),
+        Line::from("  ↑/↓ - Pick an accent slider"),
+        Line::from("  ←/→ - Adjust it (Shift for bigger steps)"),
         Line::from("  u - Switch between users"),
         Line::from("  h - Toggle this help"),
         Line::from("  q - Quit application"),
@@ -644,27 +723,6 @@ fn render_help_overlay(area: Rect, frame: &mut Frame, theme: &ThemeContext) {
     frame.render_widget(help_popup, popup_area);
 }
 
-/// Utility function to create a centered rectangle for popups
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     pulse::render_async(|| async { ContextApp }).await?;
@@ -1,4 +1,4 @@
-use pulse::prelude::*;
+use pulse::{crossterm::event::KeyCode, prelude::*};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -14,12 +14,12 @@ struct CounterProps {
 }
 
 #[derive(Clone)]
-struct Counter {
+pub(crate) struct Counter {
     props: CounterProps,
 }
 
 impl Counter {
-    fn new(initial_value: i32) -> Self {
+    pub(crate) fn new(initial_value: i32) -> Self {
         Self {
             props: CounterProps { initial_value },
         }
@@ -29,11 +29,15 @@ impl Counter {
 impl Component for Counter {
     fn render(&self, area: Rect, frame: &mut Frame) {
         // Use the state hook to manage the counter value
-        let (count, _set_count) = use_state(|| self.props.initial_value);
+        let (count, set_count) = use_state(|| self.props.initial_value);
 
-        // Handle keyboard events (this is a simplified example - in a real app,
-        // you'd want to handle events in an event loop)
-        // For this example, we'll just show how to use the state
+        // Handle the increment and decrement keys
+        match use_key_event().map(|key| key.code) {
+            Some(KeyCode::Char('+')) => set_count.update(|count| count + 1),
+            Some(KeyCode::Char('-')) => set_count.update(|count| count - 1),
+            Some(KeyCode::Char('q')) => request_exit(),
+            _ => {}
+        }
 
         // Create layout
         let chunks = Layout::default()
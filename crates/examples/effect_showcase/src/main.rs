@@ -7,9 +7,9 @@ use ratatui::{
     style::{Color, Modifier, Style},
     symbols::border,
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Gauge, Paragraph, Sparkline},
+    widgets::{Block, Borders, Gauge, Paragraph},
 };
-use std::{collections::VecDeque, time::Duration};
+use std::time::Duration;
 use tokio::time::interval;
 
 #[tokio::main]
@@ -98,27 +98,16 @@ impl Component for RealTimeClockComponent {
     fn render(&self, area: Rect, frame: &mut Frame) {
         let (current_time, set_time) = use_state(Local::now);
 
-        // Effect that updates time every second
-        use_effect(
-            {
-                move || {
-                    let mut interval = interval(Duration::from_secs(1));
-
-                    tokio::spawn(async move {
-                        loop {
-                            interval.tick().await;
-                            set_time.set(Local::now());
-                        }
-                    });
-
-                    // Cleanup function
-                    Some(|| {
-                        // In a real implementation, we'd cancel the timer here
-                        tracing::info!("🕐 Clock timer cleanup");
-                    })
+        // Task that updates time every second while the clock is mounted
+        use_spawn(
+            move || async move {
+                let mut interval = interval(Duration::from_secs(1));
+                loop {
+                    interval.tick().await;
+                    set_time.set(Local::now());
                 }
             },
-            (), // Run once on mount
+            (), // Start once on mount
         );
 
         let time_str = current_time.get().format("%H:%M:%S").to_string();
@@ -177,7 +166,7 @@ impl Component for AnimatedProgressComponent {
                 move || {
                     let mut interval = interval(Duration::from_millis(100));
 
-                    tokio::spawn(async move {
+                    spawn_effect_task(async move {
                         let mut current_progress = 0u16;
                         let mut current_direction = 1i16;
                         let mut frame_count = 0u8;
@@ -286,61 +275,49 @@ struct SystemMonitorComponent;
 
 impl Component for SystemMonitorComponent {
     fn render(&self, area: Rect, frame: &mut Frame) {
-        let (cpu_data, set_cpu) = use_state(VecDeque::<u64>::new);
+        let cpu = use_time_series(20);
         let (memory_usage, set_memory) = use_state(|| 45u16);
-        let (network_activity, set_network) = use_state(VecDeque::<u64>::new);
+        let network = use_time_series(15);
         let (last_update, set_update_time) = use_state(Local::now);
 
         // Effect for system monitoring with multiple data sources
-        use_effect_once(move || {
-            let set_cpu = set_cpu.clone();
-            let set_memory = set_memory.clone();
-            let set_network = set_network.clone();
-            let set_update_time = set_update_time.clone();
-
-            std::thread::spawn(move || {
-                let rt = tokio::runtime::Runtime::new().unwrap();
-                rt.block_on(async {
-                    let mut interval = interval(Duration::from_millis(500));
-                    let mut rng = rand::rng();
-
-                    loop {
-                        interval.tick().await;
-
-                        // Generate mock CPU data
-                        set_cpu.update(|current| {
-                            let mut new_data = current.clone();
-                            new_data.push_back(rng.random_range(20..80));
-                            if new_data.len() > 20 {
-                                new_data.pop_front();
-                            }
-                            new_data
-                        });
-
-                        // Generate mock memory usage
-                        set_memory.update(|current| {
-                            let change = rng.random_range(-5i16..5);
-                            (*current as i16 + change).clamp(30, 90) as u16
-                        });
-
-                        // Generate mock network activity
-                        set_network.update(|current| {
-                            let mut new_data = current.clone();
-                            new_data.push_back(rng.random_range(0..100));
-                            if new_data.len() > 15 {
-                                new_data.pop_front();
-                            }
-                            new_data
-                        });
+        use_effect_once({
+            let cpu = cpu.clone();
+            let network = network.clone();
+            move || {
+                let set_memory = set_memory.clone();
+                let set_update_time = set_update_time.clone();
+
+                std::thread::spawn(move || {
+                    let rt = tokio::runtime::Runtime::new().unwrap();
+                    rt.block_on(async {
+                        let mut interval = interval(Duration::from_millis(500));
+                        let mut rng = rand::rng();
+
+                        loop {
+                            interval.tick().await;
+
+                            // Generate mock CPU data
+                            cpu.push(rng.random_range(20..80));
+
+                            // Generate mock memory usage
+                            set_memory.update(|current| {
+                                let change = rng.random_range(-5i16..5);
+                                (*current as i16 + change).clamp(30, 90) as u16
+                            });
 
-                        set_update_time.set(Local::now());
-                    }
+                            // Generate mock network activity
+                            network.push(rng.random_range(0..100));
+
+                            set_update_time.set(Local::now());
+                        }
+                    });
                 });
-            });
 
-            // Return cleanup function
-            move || {
-                tracing::info!("🖥️  System monitor cleanup");
+                // Return cleanup function
+                move || {
+                    tracing::info!("🖥️  System monitor cleanup");
+                }
             }
         });
 
@@ -356,19 +333,19 @@ impl Component for SystemMonitorComponent {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3), // CPU sparkline
+                Constraint::Length(4), // CPU sparkline
                 Constraint::Length(2), // Memory gauge
-                Constraint::Length(3), // Network sparkline
+                Constraint::Length(4), // Network sparkline
                 Constraint::Min(1),    // Last update
             ])
             .split(inner);
 
-        // CPU usage sparkline
-        let cpu_values: Vec<u64> = cpu_data.get().iter().cloned().collect();
-        if !cpu_values.is_empty() {
-            let cpu_sparkline = Sparkline::default()
+        // CPU usage sparkline, warning above 70%
+        if !cpu.is_empty() {
+            let cpu_sparkline = AnnotatedSparkline::new(cpu.values())
                 .block(Block::default().title("CPU Usage"))
-                .data(&cpu_values)
+                .unit("%")
+                .threshold(70, Color::Red)
                 .style(Style::default().fg(Color::Yellow));
             frame.render_widget(cpu_sparkline, chunks[0]);
         }
@@ -382,11 +359,10 @@ impl Component for SystemMonitorComponent {
         frame.render_widget(memory_gauge, chunks[1]);
 
         // Network activity sparkline
-        let network_values: Vec<u64> = network_activity.get().iter().cloned().collect();
-        if !network_values.is_empty() {
-            let network_sparkline = Sparkline::default()
+        if !network.is_empty() {
+            let network_sparkline = AnnotatedSparkline::new(network.values())
                 .block(Block::default().title("Network"))
-                .data(&network_values)
+                .unit(" KB/s")
                 .style(Style::default().fg(Color::Green));
             frame.render_widget(network_sparkline, chunks[2]);
         }
@@ -419,7 +395,7 @@ impl Component for DataFetcherComponent {
                 move || {
                     let mut interval = interval(Duration::from_secs(3));
 
-                    tokio::spawn(async move {
+                    spawn_effect_task(async move {
                         let mut count = 0;
                         loop {
                             interval.tick().await;
@@ -129,6 +129,11 @@ impl Component for DataFetcherComponent {
                     let delay = rand::rng().random_range(500..2000);
                     tokio::time::sleep(Duration::from_millis(delay)).await;
 
+                    // Simulate the occasional network failure
+                    if rand::rng().random_range(0..5) == 0 {
+                        return Err("connection timed out".to_string());
+                    }
+
                     let data = [
                         "📊 Sales Report",
                         "📈 Analytics Dashboard",
@@ -164,7 +169,17 @@ impl Component for DataFetcherComponent {
             FutureState::Pending => ("🔄 Fetching data...".to_string(), Color::Yellow),
             FutureState::Progress(_) => ("⚡ Processing...".to_string(), Color::Blue),
             FutureState::Resolved(data) => (format!("✅ {}", data), Color::Green),
-            FutureState::Error(err) => (format!("❌ Error: {}", err), Color::Red),
+            FutureState::Error(err) => {
+                let block = Block::default()
+                    .title("📡 Data Fetcher")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Red))
+                    .border_set(border::ROUNDED);
+                let inner = block.inner(area);
+                frame.render_widget(block, area);
+                render_error_with_retry(inner, frame, &err, &data_future);
+                return;
+            }
         };
 
         let paragraph = Paragraph::new(content)
@@ -10,7 +10,7 @@ use ratatui::{
     text::{Line, Span, Text},
     widgets::{Block, Borders, Gauge, List, ListItem, Paragraph},
 };
-use std::{cell::RefCell, collections::VecDeque};
+use std::{borrow::Cow, cell::RefCell, collections::VecDeque};
 
 // Global log state for tracking mount/unmount events
 thread_local! {
@@ -171,8 +171,8 @@ impl Component for HeaderComponent {
 struct SystemStatsPanel;
 
 impl Component for SystemStatsPanel {
-    fn component_id(&self) -> String {
-        "system_stats_panel".to_string()
+    fn component_id(&self) -> Cow<'static, str> {
+        "system_stats_panel".into()
     }
 
     fn on_mount(&self) {
@@ -239,8 +239,8 @@ impl Component for SystemStatsPanel {
 struct NetworkMonitorPanel;
 
 impl Component for NetworkMonitorPanel {
-    fn component_id(&self) -> String {
-        "network_monitor_panel".to_string()
+    fn component_id(&self) -> Cow<'static, str> {
+        "network_monitor_panel".into()
     }
 
     fn on_mount(&self) {
@@ -293,8 +293,8 @@ impl Component for NetworkMonitorPanel {
 struct TaskManagerPanel;
 
 impl Component for TaskManagerPanel {
-    fn component_id(&self) -> String {
-        "task_manager_panel".to_string()
+    fn component_id(&self) -> Cow<'static, str> {
+        "task_manager_panel".into()
     }
 
     fn on_mount(&self) {
@@ -350,8 +350,8 @@ impl Component for TaskManagerPanel {
 struct WeatherPanel;
 
 impl Component for WeatherPanel {
-    fn component_id(&self) -> String {
-        "weather_panel".to_string()
+    fn component_id(&self) -> Cow<'static, str> {
+        "weather_panel".into()
     }
 
     fn on_mount(&self) {
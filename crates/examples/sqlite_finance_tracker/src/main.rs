@@ -14,13 +14,14 @@ use crossterm::event::{Event, KeyCode, KeyEventKind};
 use pulse::prelude::*;
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Gauge, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Duration;
 use uuid::Uuid;
 
 /// Transaction data structure
@@ -218,6 +219,28 @@ impl FinanceTracker {
     }
 }
 
+/// Smallest terminal the tracker's layout fits into
+const MIN_WIDTH: u16 = 60;
+const MIN_HEIGHT: u16 = 20;
+
+/// Root component showing a notice instead of the tracker on tiny terminals
+#[derive(Clone)]
+struct App {
+    tracker: FinanceTracker,
+}
+
+impl Component for App {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        render_or_message(
+            MIN_WIDTH,
+            MIN_HEIGHT,
+            || self.tracker.clone(),
+            || TooSmall::new(MIN_WIDTH, MIN_HEIGHT),
+        )
+        .render(area, frame);
+    }
+}
+
 impl Component for FinanceTracker {
     fn render(&self, area: Rect, frame: &mut Frame) {
         let theme = Theme::default();
@@ -225,18 +248,27 @@ impl Component for FinanceTracker {
         // Initialize with default data - we'll load from SQLite using effects
         let (data, set_data) = use_state(FinanceData::default);
 
+        // Load and save failures are shown in the footer
+        let (error, set_error) = use_error();
+
         // Load data from SQLite on component mount
         let backend_clone = self.backend.clone();
+        let load_error = set_error.clone();
         let data_loader = use_future(
             move || {
                 let backend = backend_clone.clone();
+                let load_error = load_error.clone();
                 async move {
-                    match backend.read_async("finance_data").await {
+                    let result = match backend.read_async("finance_data").await {
                         Ok(Some(json_data)) => serde_json::from_str::<FinanceData>(&json_data)
                             .map_err(|e| format!("Failed to parse data: {}", e)),
                         Ok(None) => Ok(FinanceData::default()),
                         Err(e) => Err(format!("Failed to load data: {}", e)),
+                    };
+                    if let Err(e) = &result {
+                        load_error.set(e.as_str());
                     }
+                    result
                 }
             },
             (), // Load once on mount
@@ -249,23 +281,47 @@ impl Component for FinanceTracker {
         {
             set_data.set(loaded_data);
         }
+        let (transactions, sort) = use_sort(
+            &data.get().transactions,
+            &[
+                SortKey::new("Date", |a: &Transaction, b: &Transaction| {
+                    a.date.cmp(&b.date)
+                }),
+                SortKey::new("Amount", |a: &Transaction, b: &Transaction| {
+                    a.amount.total_cmp(&b.amount)
+                }),
+                SortKey::new("Description", |a: &Transaction, b: &Transaction| {
+                    a.description.cmp(&b.description)
+                }),
+            ],
+        );
         let (selected_tab, set_selected_tab) = use_state(|| 0);
         let (show_add_transaction, set_show_add_transaction) = use_state(|| false);
+        let amount = use_number_input(0.0, 1.0, 0.0, 1_000_000.0);
+        let date = use_date_picker(Utc::now());
         let (save_trigger, set_save_trigger) = use_state(|| 0);
 
         // Auto-save data to SQLite when save_trigger changes
         let data_clone = data.get();
         let backend_clone = self.backend.clone();
         let save_trigger_value = save_trigger.get();
+        let save_error = set_error.clone();
         let _save_future = use_future(
             move || async move {
                 if save_trigger_value > 0 {
-                    let json_data = serde_json::to_string(&data_clone)
-                        .map_err(|e| format!("Failed to serialize data: {}", e))?;
-                    backend_clone
-                        .write_async("finance_data", &json_data)
-                        .await
-                        .map_err(|e| format!("Failed to save data: {}", e))?;
+                    let saved = async {
+                        let json_data = serde_json::to_string(&data_clone)
+                            .map_err(|e| format!("Failed to serialize data: {}", e))?;
+                        backend_clone
+                            .write_async("finance_data", &json_data)
+                            .await
+                            .map_err(|e| format!("Failed to save data: {}", e))
+                    }
+                    .await;
+                    if let Err(e) = &saved {
+                        save_error.set(e.as_str());
+                    }
+                    saved?;
                 }
                 Ok::<(), String>(())
             },
@@ -275,6 +331,11 @@ impl Component for FinanceTracker {
         // Handle keyboard input
         if let Some(Event::Key(key)) = use_event()
             && key.kind == KeyEventKind::Press
+            && !(show_add_transaction.get()
+                && match key.code {
+                    KeyCode::Left | KeyCode::Right => date.handle_key(&key),
+                    _ => amount.handle_key(&key),
+                })
         {
             match key.code {
                 KeyCode::Char('q') => request_exit(),
@@ -284,14 +345,22 @@ impl Component for FinanceTracker {
                 KeyCode::Char('a') => {
                     set_show_add_transaction.update(|show| !show);
                 }
+                KeyCode::Char('o') if selected_tab.get() == 1 => sort.cycle(),
                 KeyCode::Char('s') => {
                     // Trigger save by updating save_trigger
+                    set_error.clear();
                     set_save_trigger.update(|trigger| *trigger + 1);
                 }
                 _ => {}
             }
         }
 
+        // Alert when a budget is exceeded, at most every 30 seconds
+        let bell = use_bell(Duration::from_secs(30));
+        if data.get().budgets.iter().any(Budget::is_over_budget) {
+            bell.ring();
+        }
+
         // Main layout
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -308,17 +377,25 @@ impl Component for FinanceTracker {
         // Render content based on selected tab
         match selected_tab.get() {
             0 => render_dashboard(chunks[1], frame, &data.get(), &theme),
-            1 => render_transactions(chunks[1], frame, &data.get(), &theme),
+            1 => render_transactions(chunks[1], frame, &transactions, &sort, &theme),
             2 => render_budgets(chunks[1], frame, &data.get(), &theme),
             _ => {}
         }
 
         // Render footer
-        render_footer(chunks[2], frame, &theme);
+        render_footer(chunks[2], frame, &theme, error.as_ref());
 
         // Render add transaction modal if shown
         if show_add_transaction.get() {
-            render_add_transaction_modal(area, frame, &theme, &set_show_add_transaction, &set_data);
+            render_add_transaction_modal(
+                area,
+                frame,
+                &theme,
+                &amount,
+                &date,
+                &set_show_add_transaction,
+                &set_data,
+            );
         }
     }
 }
@@ -350,7 +427,10 @@ fn render_dashboard(area: Rect, frame: &mut Frame, data: &FinanceData, theme: &T
     } else {
         theme.danger
     };
-    let balance_text = format!("💰 Current Balance: ${:.2}", data.balance);
+    let balance_text = format!(
+        "💰 Current Balance: {}",
+        format_currency(data.balance, "USD")
+    );
 
     let balance_card = Paragraph::new(balance_text)
         .style(
@@ -425,13 +505,7 @@ fn render_budget_overview(area: Rect, frame: &mut Frame, data: &FinanceData, the
         .iter()
         .map(|budget| {
             let percentage = budget.percentage_used();
-            let color = if budget.is_over_budget() {
-                theme.danger
-            } else if percentage > 80.0 {
-                theme.warning
-            } else {
-                theme.success
-            };
+            let color = budget_gauge(budget, theme).color();
 
             ListItem::new(vec![
                 Line::from(vec![
@@ -444,8 +518,10 @@ fn render_budget_overview(area: Rect, frame: &mut Frame, data: &FinanceData, the
                 ]),
                 Line::from(vec![Span::styled(
                     format!(
-                        "${:.2} / ${:.2} ({:.1}%)",
-                        budget.spent, budget.limit, percentage
+                        "{} / {} ({}%)",
+                        format_currency(budget.spent, "USD"),
+                        format_currency(budget.limit, "USD"),
+                        format_number(percentage, 1)
                     ),
                     Style::default().fg(color),
                 )]),
@@ -463,9 +539,14 @@ fn render_budget_overview(area: Rect, frame: &mut Frame, data: &FinanceData, the
     frame.render_widget(budgets_list, area);
 }
 
-fn render_transactions(area: Rect, frame: &mut Frame, data: &FinanceData, theme: &Theme) {
-    let transaction_items: Vec<ListItem> = data
-        .transactions
+fn render_transactions(
+    area: Rect,
+    frame: &mut Frame,
+    transactions: &[Transaction],
+    sort: &SortHandle,
+    theme: &Theme,
+) {
+    let transaction_items: Vec<ListItem> = transactions
         .iter()
         .map(|transaction| {
             let amount_color = match transaction.transaction_type {
@@ -496,13 +577,29 @@ fn render_transactions(area: Rect, frame: &mut Frame, data: &FinanceData, theme:
     let transactions_list = List::new(transaction_items).block(
         Block::default()
             .borders(Borders::ALL)
-            .title(" 📊 All Transactions ")
+            .title(format!(
+                " 📊 All Transactions · {} (O to sort) ",
+                sort.label()
+            ))
             .border_style(Style::default().fg(theme.primary)),
     );
 
     frame.render_widget(transactions_list, area);
 }
 
+/// Gauge of a budget, turning yellow past 80% of the limit and red over it
+fn budget_gauge(budget: &Budget, theme: &Theme) -> ThresholdGauge<'static> {
+    ThresholdGauge::new(
+        budget.spent,
+        budget.limit,
+        [
+            (0.0, theme.success),
+            (budget.limit * 0.8, theme.warning),
+            (budget.limit, theme.danger),
+        ],
+    )
+}
+
 fn render_budgets(area: Rect, frame: &mut Frame, data: &FinanceData, theme: &Theme) {
     let budget_chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -516,24 +613,17 @@ fn render_budgets(area: Rect, frame: &mut Frame, data: &FinanceData, theme: &The
 
     for (i, budget) in data.budgets.iter().enumerate() {
         if i < budget_chunks.len() {
-            let percentage = budget.percentage_used() as u16;
-            let color = if budget.is_over_budget() {
-                theme.danger
-            } else if percentage > 80 {
-                theme.warning
-            } else {
-                theme.success
-            };
-
-            let gauge = Gauge::default()
+            let gauge = budget_gauge(budget, theme)
                 .block(Block::default().borders(Borders::ALL).title(format!(
                     " {} {:?} Budget ",
                     budget.category.icon(),
                     budget.category
                 )))
-                .gauge_style(Style::default().fg(color))
-                .percent(percentage)
-                .label(format!("${:.2} / ${:.2}", budget.spent, budget.limit));
+                .label(format!(
+                    "{} / {}",
+                    format_currency(budget.spent, "USD"),
+                    format_currency(budget.limit, "USD")
+                ));
 
             frame.render_widget(gauge, budget_chunks[i]);
         }
@@ -544,6 +634,8 @@ fn render_add_transaction_modal(
     area: Rect,
     frame: &mut Frame,
     theme: &Theme,
+    amount: &NumberInputHandle,
+    date: &DatePickerHandle,
     _set_show_modal: &StateSetter<bool>,
     _set_data: &StateSetter<FinanceData>,
 ) {
@@ -562,27 +654,77 @@ fn render_add_transaction_modal(
         Line::from(""),
         Line::from("Press 'Esc' to close this modal"),
         Line::from(""),
-        Line::from("🚧 Transaction form coming soon!"),
-        Line::from("This would include:"),
-        Line::from("• Amount input"),
+        Line::from(vec![
+            Span::raw("Amount: $"),
+            Span::styled(
+                format!("{}▏", amount.text()),
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from("Type digits, ↑/↓ to adjust"),
+        Line::from(format!("Date: {}", date.date().format("%Y-%m-%d"))),
+        Line::from("←/→ day, Shift+←/→ month"),
+        Line::from(""),
+        Line::from("🚧 More fields coming soon:"),
         Line::from("• Description field"),
         Line::from("• Category selection"),
-        Line::from("• Date picker"),
     ])
     .alignment(Alignment::Center)
-    .wrap(Wrap { trim: true })
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title(" ➕ Add Transaction ")
-            .border_style(Style::default().fg(theme.primary)),
-    );
+    .wrap(Wrap { trim: true });
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" ➕ Add Transaction ")
+        .border_style(Style::default().fg(theme.primary));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let [form, calendar] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(8)]).areas(inner);
+    frame.render_widget(modal, form);
+
+    let [calendar] = Layout::horizontal([Constraint::Length(20)])
+        .flex(Flex::Center)
+        .areas(calendar);
+    let date_picker = DatePicker::new(date.date())
+        .title_style(
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )
+        .selected_style(
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        );
+    frame.render_widget(date_picker, calendar);
+}
+
+fn render_footer(area: Rect, frame: &mut Frame, theme: &Theme, error: Option<&ContextError>) {
+    let footer_text = match error {
+        Some(error) => vec![Line::from(Span::styled(
+            format!("⚠ {}", error),
+            Style::default()
+                .fg(theme.danger)
+                .add_modifier(Modifier::BOLD),
+        ))],
+        None => footer_hints(theme),
+    };
+
+    let footer = Paragraph::new(footer_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.secondary)),
+        );
 
-    frame.render_widget(modal, popup_area);
+    frame.render_widget(footer, area);
 }
 
-fn render_footer(area: Rect, frame: &mut Frame, theme: &Theme) {
-    let footer_text = vec![Line::from(vec![
+fn footer_hints(theme: &Theme) -> Vec<Line<'static>> {
+    vec![Line::from(vec![
         Span::styled(
             "Tab",
             Style::default()
@@ -611,37 +753,7 @@ fn render_footer(area: Rect, frame: &mut Frame, theme: &Theme) {
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(": Quit"),
-    ])];
-
-    let footer = Paragraph::new(footer_text)
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(theme.secondary)),
-        );
-
-    frame.render_widget(footer, area);
-}
-
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
+    ])]
 }
 
 #[tokio::main]
@@ -653,9 +765,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Initialize the finance tracker with SQLite backend first
     let app = match FinanceTracker::new().await {
-        Ok(app) => {
+        Ok(tracker) => {
             println!("✅ SQLite backend initialized successfully");
-            app
+            App { tracker }
         }
         Err(e) => {
             eprintln!("❌ Failed to initialize SQLite backend: {}", e);
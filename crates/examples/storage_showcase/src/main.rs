@@ -132,6 +132,14 @@ impl Component for TaskManager {
             match key.code {
                 KeyCode::Char('q') => request_exit(),
                 KeyCode::Char('h') => set_show_help.update(|h| !h),
+                KeyCode::Char('b') => {
+                    // Dump every stored key into a single backup bundle
+                    if let Ok(bundle) = export_all()
+                        && let Ok(json) = serde_json::to_string_pretty(&bundle)
+                    {
+                        let _ = std::fs::write("./beautiful_tasks_backup.json", json);
+                    }
+                }
                 KeyCode::Char('t') => {
                     set_app_data.update(|data| AppData {
                         theme: match data.theme {
@@ -249,6 +257,8 @@ impl Component for TaskManager {
             Span::styled(" New | ", Style::default().fg(Color::Gray)),
             Span::styled("t", Style::default().fg(theme.primary_color())),
             Span::styled(" Theme | ", Style::default().fg(Color::Gray)),
+            Span::styled("b", Style::default().fg(theme.primary_color())),
+            Span::styled(" Backup | ", Style::default().fg(Color::Gray)),
             Span::styled("h", Style::default().fg(theme.primary_color())),
             Span::styled(" Help | ", Style::default().fg(Color::Gray)),
             Span::styled("q", Style::default().fg(theme.primary_color())),
@@ -419,6 +429,7 @@ fn render_help_overlay(area: Rect, frame: &mut Frame, theme: &Theme) {
         )]),
         Line::from("  n - Create new task"),
         Line::from("  t - Cycle themes"),
+        Line::from("  b - Back up all data to JSON"),
         Line::from("  h - Toggle this help"),
         Line::from("  q - Quit (auto-saves!)"),
         Line::from(""),
@@ -457,26 +468,6 @@ fn count_tasks_by_priority(tasks: &[Task]) -> std::collections::HashMap<Priority
     counts
 }
 
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     pulse::render_async(|| async { TaskManager }).await?;
@@ -0,0 +1,91 @@
+//! Golden-frame tests for the example apps
+//!
+//! Each test replays a few key presses against an example's root component
+//! in an in-memory terminal, renders a fixed number of frames and compares
+//! the final screen with a file committed under `tests/golden`. Only the
+//! text of the screen is compared, not colors or modifiers.
+//!
+//! After an intentional visual change, rewrite the golden files with
+//! `UPDATE_GOLDEN=1 cargo test -p pulse_examples --test golden` and review
+//! the diff before committing.
+
+use pulse::crossterm::event::{Event, KeyCode, KeyEvent};
+use pulse::prelude::*;
+use pulse::session::{SessionEvent, replay_frames};
+use ratatui::{buffer::Buffer, layout::Size};
+use std::path::PathBuf;
+
+#[allow(dead_code)]
+#[path = "../counter_app/src/main.rs"]
+mod counter_app;
+
+/// Size of the terminal the examples are rendered into
+const SCREEN: Size = Size::new(80, 20);
+
+/// Frames rendered per test, enough for the last key press to settle
+const FRAMES: u64 = 10;
+
+/// Key presses delivered one per frame, starting with the first
+fn key_presses(codes: &[KeyCode]) -> Vec<SessionEvent> {
+    codes
+        .iter()
+        .enumerate()
+        .map(|(frame, code)| {
+            SessionEvent::at_frame(frame as u64 + 1, Event::Key(KeyEvent::from(*code)))
+        })
+        .collect()
+}
+
+/// Text of each row of the screen, without trailing spaces
+fn screen_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            let row: String = (area.left()..area.right())
+                .map(|x| buffer[(x, y)].symbol())
+                .collect();
+            format!("{}\n", row.trim_end())
+        })
+        .collect()
+}
+
+/// Compare `buffer` with the golden file `name`, or rewrite it on request
+fn assert_golden(name: &str, buffer: &Buffer) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{}.txt", name));
+    let actual = screen_text(buffer);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|error| {
+        panic!(
+            "missing golden file {} ({}); run with UPDATE_GOLDEN=1 to create it",
+            path.display(),
+            error
+        )
+    });
+    assert!(
+        actual == expected,
+        "{} no longer matches {}\n--- expected\n{}--- actual\n{}",
+        name,
+        path.display(),
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn golden_counter_app() {
+    let events = key_presses(&[
+        KeyCode::Char('+'),
+        KeyCode::Char('+'),
+        KeyCode::Char('+'),
+        KeyCode::Char('-'),
+    ]);
+    let screen = replay_frames(SCREEN, events, FRAMES, || counter_app::Counter::new(0)).unwrap();
+    assert_golden("counter_app", &screen);
+}
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Local};
 use pulse::{
-    crossterm::event::{Event, KeyCode, KeyEventKind},
+    crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind},
     prelude::*,
 };
 use ratatui::{
@@ -11,6 +11,7 @@ use ratatui::{
     text::{Line, Span, Text},
     widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
 };
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 #[tokio::main]
@@ -19,7 +20,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 #[derive(Clone)]
-struct App;
+pub(crate) struct App;
 
 impl Component for App {
     fn on_mount(&self) {
@@ -86,7 +87,7 @@ impl Component for HeaderComponent {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 struct Todo {
     id: Uuid,
     text: String,
@@ -95,7 +96,7 @@ struct Todo {
     priority: Priority,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 enum Priority {
     Low,
     Medium,
@@ -121,13 +122,15 @@ impl Priority {
     }
 }
 
-#[derive(Clone, Debug)]
+/// Todos are saved between runs; the rest is transient UI state
+#[derive(Clone, Debug, Serialize, Deserialize)]
 struct TodoState {
     todos: Vec<Todo>,
-    filter: Filter,
+    #[serde(skip)]
     selected_index: usize,
-    input_mode: bool,
+    #[serde(skip)]
     input_text: String,
+    #[serde(skip)]
     dialog: Option<DialogState>,
 }
 
@@ -147,11 +150,12 @@ enum DialogType {
     Exit,
 }
 
+/// Which keymap handles key presses
 #[derive(Clone, Debug, PartialEq)]
-enum Filter {
-    All,
-    Active,
-    Completed,
+enum Mode {
+    Browsing,
+    Adding,
+    Confirming,
 }
 
 #[derive(Clone, Debug)]
@@ -160,10 +164,12 @@ enum TodoAction {
     ToggleTodo(Uuid),
     #[allow(dead_code)]
     DeleteTodo(Uuid),
-    SetFilter(Filter),
-    SelectNext,
-    SelectPrevious,
-    ToggleInputMode,
+    /// Select the first visible todo, e.g. after the filter changed
+    ResetSelection,
+    /// Move the selection down among the given number of visible todos
+    SelectNext(usize),
+    /// Move the selection up among the given number of visible todos
+    SelectPrevious(usize),
     UpdateInput(String),
     #[allow(dead_code)]
     ClearCompleted,
@@ -193,7 +199,6 @@ fn todo_reducer(state: TodoState, action: TodoAction) -> TodoState {
             TodoState {
                 todos: new_todos,
                 input_text: String::new(),
-                input_mode: false,
                 ..state
             }
         }
@@ -239,29 +244,26 @@ fn todo_reducer(state: TodoState, action: TodoAction) -> TodoState {
                 ..state
             }
         }
-        TodoAction::SetFilter(filter) => TodoState {
-            filter,
+        TodoAction::ResetSelection => TodoState {
             selected_index: 0,
             ..state
         },
-        TodoAction::SelectNext => {
-            let filtered_todos = filter_todos(&state.todos, &state.filter);
-            let new_index = if filtered_todos.is_empty() {
+        TodoAction::SelectNext(visible) => {
+            let new_index = if visible == 0 {
                 0
             } else {
-                (state.selected_index + 1) % filtered_todos.len()
+                (state.selected_index + 1) % visible
             };
             TodoState {
                 selected_index: new_index,
                 ..state
             }
         }
-        TodoAction::SelectPrevious => {
-            let filtered_todos = filter_todos(&state.todos, &state.filter);
-            let new_index = if filtered_todos.is_empty() {
+        TodoAction::SelectPrevious(visible) => {
+            let new_index = if visible == 0 {
                 0
             } else if state.selected_index == 0 {
-                filtered_todos.len() - 1
+                visible - 1
             } else {
                 state.selected_index - 1
             };
@@ -270,10 +272,6 @@ fn todo_reducer(state: TodoState, action: TodoAction) -> TodoState {
                 ..state
             }
         }
-        TodoAction::ToggleInputMode => TodoState {
-            input_mode: !state.input_mode,
-            ..state
-        },
         TodoAction::UpdateInput(text) => TodoState {
             input_text: text,
             ..state
@@ -368,17 +366,6 @@ fn todo_reducer(state: TodoState, action: TodoAction) -> TodoState {
     }
 }
 
-fn filter_todos<'a>(todos: &'a [Todo], filter: &Filter) -> Vec<&'a Todo> {
-    todos
-        .iter()
-        .filter(|todo| match filter {
-            Filter::All => true,
-            Filter::Active => !todo.completed,
-            Filter::Completed => todo.completed,
-        })
-        .collect()
-}
-
 #[derive(Clone)]
 struct TodoListComponent;
 
@@ -408,101 +395,122 @@ impl Component for TodoListComponent {
                     priority: Priority::Low,
                 },
             ],
-            filter: Filter::All,
             selected_index: 0,
-            input_mode: false,
             input_text: String::new(),
             dialog: None,
         };
 
-        let (state, dispatch) = use_reducer(todo_reducer, initial_state);
+        let (state, dispatch) =
+            use_persistent_reducer(todo_reducer, "todolist_reducer", initial_state);
         let current_state = state.get();
+        let mode = use_mode(Mode::Browsing);
+        let (visible_todos, filter) = use_filter(
+            &current_state.todos,
+            &[
+                FilterPredicate::new("Active", |todo: &Todo| !todo.completed),
+                FilterPredicate::new("Completed", |todo: &Todo| todo.completed),
+            ],
+        );
 
-        // Handle keyboard input
+        // Handle keyboard input through per-mode keymaps
         if let Some(event) = use_event()
             && let Event::Key(key) = event
             && key.kind == KeyEventKind::Press
         {
-            match key.code {
-                KeyCode::Char('a') if !current_state.input_mode => {
-                    dispatch.call(TodoAction::ToggleInputMode);
-                }
-                KeyCode::Char('1') if !current_state.input_mode => {
-                    dispatch.call(TodoAction::SetFilter(Filter::All));
-                }
-                KeyCode::Char('2') if !current_state.input_mode => {
-                    dispatch.call(TodoAction::SetFilter(Filter::Active));
-                }
-                KeyCode::Char('3') if !current_state.input_mode => {
-                    dispatch.call(TodoAction::SetFilter(Filter::Completed));
-                }
-                KeyCode::Char('c')
-                    if !current_state.input_mode && current_state.dialog.is_none() =>
-                {
-                    dispatch.call(TodoAction::ShowDialog(
-                        DialogType::ClearCompleted,
-                        "Clear Completed Tasks".to_string(),
-                        "Are you sure you want to clear all completed tasks? This action cannot be undone.".to_string(),
-                        None,
-                    ));
-                }
-                KeyCode::Up if !current_state.input_mode => {
-                    dispatch.call(TodoAction::SelectPrevious);
-                }
-                KeyCode::Down if !current_state.input_mode => {
-                    dispatch.call(TodoAction::SelectNext);
-                }
-                KeyCode::Enter if !current_state.input_mode => {
-                    let filtered_todos = filter_todos(&current_state.todos, &current_state.filter);
-                    if let Some(todo) = filtered_todos.get(current_state.selected_index) {
-                        dispatch.call(TodoAction::ToggleTodo(todo.id));
-                    }
-                }
-                KeyCode::Delete if !current_state.input_mode && current_state.dialog.is_none() => {
-                    let filtered_todos = filter_todos(&current_state.todos, &current_state.filter);
-                    if let Some(todo) = filtered_todos.get(current_state.selected_index) {
-                        dispatch.call(TodoAction::ShowDialog(
-                            DialogType::DeleteConfirmation,
-                            "Delete Task".to_string(),
-                            format!("Are you sure you want to delete '{}'?", todo.text),
-                            Some(todo.id),
-                        ));
-                    }
-                }
-                KeyCode::Enter if current_state.input_mode => {
-                    dispatch.call(TodoAction::AddTodo(
-                        current_state.input_text.clone(),
-                        Priority::Medium,
-                    ));
-                }
-                KeyCode::Esc if current_state.input_mode => {
-                    dispatch.call(TodoAction::ToggleInputMode);
-                }
-                KeyCode::Char(c) if current_state.input_mode => {
-                    let mut new_text = current_state.input_text.clone();
-                    new_text.push(c);
-                    dispatch.call(TodoAction::UpdateInput(new_text));
-                }
-                KeyCode::Backspace if current_state.input_mode => {
-                    let mut new_text = current_state.input_text.clone();
-                    new_text.pop();
-                    dispatch.call(TodoAction::UpdateInput(new_text));
-                }
-                // Dialog controls
-                KeyCode::Enter if current_state.dialog.is_some() => {
-                    dispatch.call(TodoAction::ConfirmDialog);
-                }
-                KeyCode::Esc if current_state.dialog.is_some() => {
-                    dispatch.call(TodoAction::CloseDialog);
-                }
-                KeyCode::Char('y') if current_state.dialog.is_some() => {
-                    dispatch.call(TodoAction::ConfirmDialog);
-                }
-                KeyCode::Char('n') if current_state.dialog.is_some() => {
-                    dispatch.call(TodoAction::CloseDialog);
-                }
-                _ => {}
-            }
+            let confirm = |_: &KeyEvent| {
+                dispatch.call(TodoAction::ConfirmDialog);
+                mode.set(Mode::Browsing);
+            };
+            let close = |_: &KeyEvent| {
+                dispatch.call(TodoAction::CloseDialog);
+                mode.set(Mode::Browsing);
+            };
+
+            mode.handle_key(
+                &key,
+                [
+                    when(
+                        Mode::Browsing,
+                        Keymap::new()
+                            .bind('a', |_| mode.set(Mode::Adding))
+                            .bind('1', |_| {
+                                filter.clear();
+                                dispatch.call(TodoAction::ResetSelection);
+                            })
+                            .bind('2', |_| {
+                                filter.set_only("Active");
+                                dispatch.call(TodoAction::ResetSelection);
+                            })
+                            .bind('3', |_| {
+                                filter.set_only("Completed");
+                                dispatch.call(TodoAction::ResetSelection);
+                            })
+                            .bind('c', |_| {
+                                dispatch.call(TodoAction::ShowDialog(
+                                    DialogType::ClearCompleted,
+                                    "Clear Completed Tasks".to_string(),
+                                    "Are you sure you want to clear all completed tasks? This action cannot be undone.".to_string(),
+                                    None,
+                                ));
+                                mode.set(Mode::Confirming);
+                            })
+                            .bind(KeyCode::Up, |_| {
+                                dispatch.call(TodoAction::SelectPrevious(visible_todos.len()));
+                            })
+                            .bind(KeyCode::Down, |_| {
+                                dispatch.call(TodoAction::SelectNext(visible_todos.len()));
+                            })
+                            .bind(KeyCode::Enter, |_| {
+                                if let Some(todo) = visible_todos.get(current_state.selected_index) {
+                                    dispatch.call(TodoAction::ToggleTodo(todo.id));
+                                }
+                            })
+                            .bind(KeyCode::Delete, |_| {
+                                if let Some(todo) = visible_todos.get(current_state.selected_index) {
+                                    dispatch.call(TodoAction::ShowDialog(
+                                        DialogType::DeleteConfirmation,
+                                        "Delete Task".to_string(),
+                                        format!("Are you sure you want to delete '{}'?", todo.text),
+                                        Some(todo.id),
+                                    ));
+                                    mode.set(Mode::Confirming);
+                                }
+                            }),
+                    ),
+                    when(
+                        Mode::Adding,
+                        Keymap::new()
+                            .bind(KeyCode::Enter, |_| {
+                                dispatch.call(TodoAction::AddTodo(
+                                    current_state.input_text.clone(),
+                                    Priority::Medium,
+                                ));
+                                mode.set(Mode::Browsing);
+                            })
+                            .bind(KeyCode::Esc, |_| mode.set(Mode::Browsing))
+                            .bind(KeyCode::Backspace, |_| {
+                                let mut new_text = current_state.input_text.clone();
+                                new_text.pop();
+                                dispatch.call(TodoAction::UpdateInput(new_text));
+                            })
+                            .fallback(|key| {
+                                if let KeyCode::Char(c) = key.code {
+                                    let mut new_text = current_state.input_text.clone();
+                                    new_text.push(c);
+                                    dispatch.call(TodoAction::UpdateInput(new_text));
+                                }
+                            }),
+                    ),
+                    when(
+                        Mode::Confirming,
+                        Keymap::new()
+                            .bind(KeyCode::Enter, confirm)
+                            .bind('y', confirm)
+                            .bind(KeyCode::Esc, close)
+                            .bind('n', close),
+                    ),
+                ],
+            );
         }
 
         let chunks = Layout::default()
@@ -511,7 +519,14 @@ impl Component for TodoListComponent {
             .split(area);
 
         // Main todo list
-        self.render_todo_list(&current_state, chunks[0], frame);
+        self.render_todo_list(
+            &current_state,
+            &visible_todos,
+            &filter,
+            mode.is(&Mode::Adding),
+            chunks[0],
+            frame,
+        );
 
         // Sidebar with stats and controls
         self.render_sidebar(&current_state, chunks[1], frame);
@@ -524,10 +539,16 @@ impl Component for TodoListComponent {
 }
 
 impl TodoListComponent {
-    fn render_todo_list(&self, state: &TodoState, area: Rect, frame: &mut Frame) {
-        let filtered_todos = filter_todos(&state.todos, &state.filter);
-
-        let items: Vec<ListItem> = filtered_todos
+    fn render_todo_list(
+        &self,
+        state: &TodoState,
+        visible_todos: &[Todo],
+        filter: &FilterHandle,
+        adding: bool,
+        area: Rect,
+        frame: &mut Frame,
+    ) {
+        let items: Vec<ListItem> = visible_todos
             .iter()
             .enumerate()
             .map(|(i, todo)| {
@@ -564,10 +585,9 @@ impl TodoListComponent {
             })
             .collect();
 
-        let filter_text = match state.filter {
-            Filter::All => "All Tasks",
-            Filter::Active => "Active Tasks",
-            Filter::Completed => "Completed Tasks",
+        let filter_text = match filter.active().as_slice() {
+            [] => "All Tasks".to_string(),
+            active => format!("{} Tasks", active.join(" & ")),
         };
 
         let list = List::new(items).block(
@@ -575,7 +595,7 @@ impl TodoListComponent {
                 .title(format!(
                     "📋 {} ({}/{})",
                     filter_text,
-                    filtered_todos.len(),
+                    visible_todos.len(),
                     state.todos.len()
                 ))
                 .borders(Borders::ALL)
@@ -591,7 +611,7 @@ impl TodoListComponent {
         frame.render_widget(list, chunks[0]);
 
         // Input box
-        if state.input_mode {
+        if adding {
             let input = Paragraph::new(state.input_text.as_str())
                 .block(
                     Block::default()
@@ -692,18 +712,8 @@ impl TodoListComponent {
 
     fn render_dialog(&self, state: &TodoState, area: Rect, frame: &mut Frame) {
         if let Some(dialog) = &state.dialog {
-            // Center the dialog
-            let dialog_width = 60;
-            let dialog_height = 12;
-            let x = (area.width.saturating_sub(dialog_width)) / 2;
-            let y = (area.height.saturating_sub(dialog_height)) / 2;
-
-            let dialog_area = Rect {
-                x: area.x + x,
-                y: area.y + y,
-                width: dialog_width,
-                height: dialog_height,
-            };
+            // Center the dialog, shrinking it on terminals smaller than it
+            let dialog_area = centered_size(60, 12, area);
 
             // Clear the area for the dialog overlay
             frame.render_widget(Clear, dialog_area);
@@ -1,28 +1,137 @@
 pub use crossterm;
+pub use pulse_core::global_signal;
+pub use pulse_core::t;
 pub use pulse_core::{
-    Component, Element, IntoElement,
+    Children, Component, Element, IntoElement, ParentComponent,
+    color::{ColorMode, Theme, adapt_color, adapt_style, color_mode, set_color_mode},
+    conditional::{Conditional, SizeGuard, match_render, render_or_message, show_if},
+    devtools::{ComponentNode, DevOverlay, component_tree},
+    error::{ContextError, ResultExt},
     exit::request_exit,
+    format::{DateOrder, LocaleFormat, format_currency, format_date, format_number},
     hooks::{
-        callback::{Callback, CallbackFactory, use_callback, use_callback_once},
-        context::{Context, use_context, use_context_provider, use_context_with_default},
+        async_callback::{AsyncCallback, use_async_callback},
+        async_state::{AsyncStateHandle, use_async_state},
+        batch::{Priority, batch_updates, schedule_render, with_render_priority},
+        bell::{BellHandle, ring_bell, use_bell},
+        cached_render::{CachedRender, use_cached_render},
+        callback::{
+            Callback, CallbackFactory, StableHandler, use_callback, use_callback_once,
+            use_effect_event,
+        },
+        cancellation::{CancellationToken, use_cancellation},
+        color_picker::{COARSE_STEP, ColorPickerHandle, RgbChannel, use_color_picker},
+        context::{
+            Context, use_context, use_context_provider, use_context_selector,
+            use_context_with_default, with_context_scope,
+        },
+        date_picker::{DatePickerHandle, use_date_picker},
+        debug_value::use_debug_value,
+        deferred::use_deferred_value,
         effect::{
-            EffectDependencies, use_async_effect, use_async_effect_always, use_async_effect_once,
-            use_effect, use_effect_always, use_effect_once,
+            EffectDependencies, spawn_effect_task, use_async_effect, use_async_effect_always,
+            use_async_effect_once, use_effect, use_effect_always, use_effect_once, use_lifecycle,
+        },
+        env::{
+            ColorSupport, SystemInfo, use_color_support, use_env, use_env_with_refresh,
+            use_system_info,
+        },
+        error::{ErrorSetter, use_error},
+        event::{
+            ConsumeEvent,
+            app_events::{AppEvent, CustomEvent, post_event, use_app_event},
+            consume_event,
+            filtered::{use_event_filtered, use_key_event, use_mouse_event},
+            global_events::on_global_event,
+            use_event,
+        },
+        filter::{FilterHandle, FilterMode, FilterPredicate, use_filter},
+        form::{Field, FormHandle, FormValues, use_form, validators},
+        frame::{use_fps, use_frame},
+        future::{
+            FutureError, FutureHandle, FutureState, loading_count, use_any_loading, use_future,
+            use_future_with_progress,
         },
-        event::{global_events::on_global_event, use_event},
-        future::{FutureError, FutureHandle, FutureState, use_future, use_future_with_progress},
+        heatmap::{HeatmapDataHandle, use_heatmap_data},
         hover::{use_hover, use_hover_with_callbacks},
         idle::{use_idle, use_idle_timing, use_idle_with_callback},
-        interval::{use_async_interval, use_interval},
-        reducer::{DispatchFn, ReducerStateHandle, use_reducer},
+        interval::{
+            AsyncIntervalHandle, use_async_interval, use_frame_interval, use_interval,
+            use_interval_async, use_interval_jittered,
+        },
+        latest::{Latest, use_latest},
+        layout::use_layout,
+        measure::{measure_text, use_measure},
+        mode::{KeyBinding, KeyHint, Keymap, ModeHandle, use_mode, when},
+        navigation::{NavHandle, use_navigation, use_navigation_with},
+        number_input::{NumberInputHandle, use_number_input},
+        pagination::{PaginationHandle, use_pagination},
+        previous::{use_changed, use_previous_distinct},
+        reducer::{
+            ActionQueue, AsyncEffect, DispatchFn, Effect, ReducerStateHandle, Selection,
+            use_async_reducer, use_reducer, use_reducer_selector, use_reducer_with_effects,
+        },
+        scroll::{ScrollHandle, use_persistent_scroll},
+        search::{SearchHandle, SearchResult, fuzzy_match, use_search},
         signal::{GlobalSignal, Signal, use_global_signal},
-        state::{StateHandle, StateSetter, use_state},
-        storage::{LocalStorageConfig, set_storage_config, use_local_storage},
+        sort::{SortDirection, SortHandle, SortKey, use_sort},
+        spawn::{SpawnHandle, TaskStatus, use_spawn},
+        state::{StateHandle, StateRef, StateSetter, use_state},
+        storage::{
+            ImportMode, LocalStorageConfig, LocalStorageMap, export_all, import_all,
+            set_storage_config, use_local_storage, use_local_storage_map, use_persistent_reducer,
+            use_persistent_reducer_with_debounce,
+        },
+        theme::{HighContrastToggle, use_high_contrast},
+        time_series::{TimeSeriesHandle, use_time_series},
+        toggle_list::{ToggleListHandle, use_toggle_list},
+        transition::{Transition, use_transition},
+        tree::{TreeHandle, TreeNode, TreePath, VisibleNode, use_tree},
+        vim::{VimMode, VimNavState, use_vim_nav},
+    },
+    i18n::{
+        Catalog, Translations, interpolate, locale, set_locale, use_translations,
+        use_translations_context,
+    },
+    layout::{centered_rect, centered_size, safe_area},
+    memo::{Memo, memo},
+    redraw::{
+        FpsMeter, FrameMetrics, damage_regions, last_frame_duration, last_frame_metrics,
+        request_full_redraw, skip_non_essential,
     },
+    router::{RouteView, Router},
+    slots::{render_into_slot, use_slot},
+    widgets::{
+        AnnotatedSparkline, ColorPicker, DatePicker, Heatmap, KeyHints, RETRY_KEY, ThresholdGauge,
+        TooSmall, TreeView, render_error_with_retry,
+    },
+};
+pub use pulse_core_macros::saved_state;
+
+#[cfg(feature = "bidi")]
+pub use pulse_core::bidi::{
+    TextDirection, bidi_reorder, bidi_reorder_with, bidi_text, use_text_direction,
 };
 
 #[cfg(feature = "sqlite")]
-pub use pulse_core::hooks::storage::{AsyncStorageBackend, SqliteStorageBackend};
+pub use pulse_core::hooks::storage::{AsyncStorageBackend, SqliteStorageBackend, migrate_storage};
+
+#[cfg(feature = "compression")]
+pub use pulse_core::hooks::storage::CompressingStorageBackend;
+
+#[cfg(feature = "parallel")]
+pub use pulse_core::parallel::ParallelPanels;
+
+#[cfg(feature = "state-snapshot")]
+pub use pulse_core::snapshot::{
+    SnapshotError, register_reducer, register_state, restore_app_state, serialize_app_state,
+    unregister_state,
+};
+
+#[cfg(feature = "watch")]
+pub use pulse_core::hooks::file_watcher::{
+    FileChangeEvent, FileChangeKind, use_file_watcher, use_file_watcher_with_debounce,
+};
 
 pub use pulse_runtime::*;
 
@@ -0,0 +1,52 @@
+//! Tests for the `saved_state!` macro
+
+use pulse::saved_state;
+use pulse_core::hooks::storage::{MemoryStorageBackend, StorageBackend, set_storage_backend};
+use std::sync::Arc;
+
+#[test]
+fn saved_state_persists_under_module_key() {
+    let backend = Arc::new(MemoryStorageBackend::new());
+    backend.write("saved_state.volume", "7").unwrap();
+    set_storage_backend(backend.clone());
+
+    // A stored value replaces the default
+    let (volume, set_volume) = saved_state!("volume", 50u8);
+    assert_eq!(volume.get(), 7);
+
+    // Updates are written back under the derived key
+    set_volume.set(9);
+    assert_eq!(
+        backend.read("saved_state.volume").unwrap().as_deref(),
+        Some("9")
+    );
+
+    let (muted, set_muted) = saved_state!("muted", false);
+    assert!(!muted.get());
+    set_muted.set(true);
+    assert_eq!(
+        backend.read("saved_state.muted").unwrap().as_deref(),
+        Some("true")
+    );
+
+    // Grouped declarations persist each entry under its own key
+    let ((width, set_width), (label, _)) = saved_state!(
+        "width" => 80u16,
+        "label" => String::from("main"),
+    );
+    assert_eq!(width.get(), 80);
+    assert_eq!(label.get(), "main");
+
+    set_width.set(100);
+    assert_eq!(
+        backend.read("saved_state.width").unwrap().as_deref(),
+        Some("100")
+    );
+}
+
+#[test]
+fn duplicate_keys_in_a_module_fail_to_build() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/saved_state_module_keys.rs");
+    cases.compile_fail("tests/ui/saved_state_duplicate_key.rs");
+}
@@ -0,0 +1,17 @@
+use pulse::saved_state;
+
+fn volume_slider() {
+    let (_volume, _set_volume) = saved_state!("volume", 50u8);
+}
+
+fn volume_label() {
+    let ((_volume, _), (_muted, _)) = saved_state!(
+        "volume" => 50u8,
+        "muted" => false,
+    );
+}
+
+fn main() {
+    volume_slider();
+    volume_label();
+}
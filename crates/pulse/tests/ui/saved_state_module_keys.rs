@@ -0,0 +1,26 @@
+use pulse::saved_state;
+
+// Each module has its own keys, so reusing one elsewhere is fine
+mod mixer {
+    use pulse::saved_state;
+
+    pub fn volume() -> u8 {
+        saved_state!("volume", 50u8).0.get()
+    }
+}
+
+mod player {
+    use pulse::saved_state;
+
+    pub fn volume() -> u8 {
+        saved_state!("volume", 80u8).0.get()
+    }
+}
+
+fn muted() -> bool {
+    saved_state!("muted", false).0.get()
+}
+
+fn main() {
+    let _ = (mixer::volume, player::volume, muted);
+}
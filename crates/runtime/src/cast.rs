@@ -0,0 +1,282 @@
+//! Exporting terminal output as asciinema recordings
+//!
+//! [`CastWriter`] writes an [asciinema v2] `.cast` file: a JSON header line
+//! followed by one `[time, "o", data]` line per chunk of terminal output.
+//! [`CastBackend`] is a ratatui backend producing that output in memory, and
+//! [`session_to_cast`] uses both to turn a recorded session into a cast that
+//! can be shared or played back with `asciinema play`.
+//!
+//! [asciinema v2]: https://docs.asciinema.org/manual/asciicast/v2/
+
+use crate::config::RuntimeConfig;
+use crate::driver::DriverBackend;
+use crate::session::{load_session, replay_events};
+use pulse_core::IntoElement;
+use ratatui::{
+    Terminal,
+    backend::{Backend, ClearType, CrosstermBackend, WindowSize},
+    buffer::Cell,
+    layout::{Position, Size},
+};
+use serde::Serialize;
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// Header line of an asciinema v2 recording
+#[derive(Debug, Serialize)]
+struct CastHeader {
+    version: u32,
+    width: u16,
+    height: u16,
+}
+
+/// Writes terminal output as an asciinema v2 recording
+pub struct CastWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> CastWriter<W> {
+    /// Start a recording of a terminal of `size`, writing the header
+    pub fn new(mut writer: W, size: Size) -> io::Result<Self> {
+        let header = CastHeader {
+            version: 2,
+            width: size.width,
+            height: size.height,
+        };
+        serde_json::to_writer(&mut writer, &header)?;
+        writer.write_all(b"\n")?;
+        Ok(Self { writer })
+    }
+
+    /// Append output written `time` after the start of the recording
+    ///
+    /// Empty output is skipped.
+    pub fn output(&mut self, time: Duration, data: &[u8]) -> io::Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let event = (time.as_secs_f64(), "o", String::from_utf8_lossy(data));
+        serde_json::to_writer(&mut self.writer, &event)?;
+        self.writer.write_all(b"\n")
+    }
+
+    /// Flush the recording and return the underlying writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Output of a [`CastBackend`], shared with the crossterm backend writing it
+#[derive(Clone, Default)]
+struct SharedOutput(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Backend collecting the escape sequences a real terminal would receive
+///
+/// Unlike a plain [`CrosstermBackend`], its size is fixed rather than read
+/// from the controlling terminal, so it works without one.
+pub struct CastBackend {
+    inner: CrosstermBackend<SharedOutput>,
+    output: SharedOutput,
+    size: Size,
+}
+
+impl CastBackend {
+    /// Create a backend for a screen of `size`
+    pub fn new(size: Size) -> Self {
+        let output = SharedOutput::default();
+        Self {
+            inner: CrosstermBackend::new(output.clone()),
+            output,
+            size,
+        }
+    }
+
+    /// Take the output written since the last call
+    pub fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(&mut *self.output.0.borrow_mut())
+    }
+}
+
+impl Backend for CastBackend {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        self.inner.draw(content)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.inner.show_cursor()
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        Ok(Position::ORIGIN)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.inner.set_cursor_position(position)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.inner.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.inner.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        Ok(WindowSize {
+            columns_rows: self.size,
+            pixels: Size::default(),
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Backend::flush(&mut self.inner)
+    }
+}
+
+impl DriverBackend for CastBackend {
+    fn resize(&mut self, width: u16, height: u16) {
+        self.size = Size::new(width, height);
+    }
+}
+
+/// Replay the session at `session` against an app and save it as a cast
+///
+/// Output is timed with the recorded time of the event that caused it. The
+/// cast has the size the session was recorded at.
+///
+/// # Example
+/// ```no_run
+/// use pulse_runtime::cast::session_to_cast;
+/// use pulse_core::Component;
+/// use ratatui::{Frame, layout::Rect};
+///
+/// #[derive(Clone)]
+/// struct App;
+///
+/// impl Component for App {
+///     fn render(&self, _area: Rect, _frame: &mut Frame) {}
+/// }
+///
+/// session_to_cast("demo.session", "demo.cast", || App).unwrap();
+/// ```
+pub fn session_to_cast<F, T>(
+    session: impl AsRef<Path>,
+    cast: impl AsRef<Path>,
+    initializer: F,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    F: Fn() -> T,
+    T: IntoElement,
+{
+    let (header, events) = load_session(session)?;
+    let size = Size::new(header.width, header.height);
+
+    let mut writer = CastWriter::new(BufWriter::new(File::create(cast)?), size)?;
+    let mut terminal = Terminal::new(CastBackend::new(size))?;
+    replay_events(
+        &RuntimeConfig::default(),
+        &mut terminal,
+        events,
+        None,
+        initializer,
+        |terminal, time| writer.output(time, &terminal.backend_mut().take_output()),
+    )?;
+    writer.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::SessionRecorder;
+    use crossterm::event::{Event, KeyCode, KeyEvent};
+    use pulse_core::{Component, hooks::event::filtered::use_key_event, hooks::state::use_state};
+    use ratatui::{Frame, layout::Rect, widgets::Paragraph};
+    use serde_json::Value;
+
+    #[derive(Clone)]
+    struct Counter;
+
+    impl Component for Counter {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let (count, set_count) = use_state(|| 0);
+            if use_key_event().is_some_and(|key| key.code == KeyCode::Up) {
+                set_count.update(|count| count + 1);
+            }
+            frame.render_widget(Paragraph::new(format!("Count: {}", count.get())), area);
+        }
+    }
+
+    #[test]
+    fn test_session_exports_to_cast() {
+        let dir = tempfile::tempdir().unwrap();
+        let session = dir.path().join("counter.session");
+        let cast = dir.path().join("counter.cast");
+
+        let mut recorder = SessionRecorder::create(&session, Size::new(16, 2)).unwrap();
+        recorder
+            .record(2, &Event::Key(KeyEvent::from(KeyCode::Up)))
+            .unwrap();
+        drop(recorder);
+
+        session_to_cast(&session, &cast, || Counter).unwrap();
+
+        let contents = std::fs::read_to_string(&cast).unwrap();
+        let mut lines = contents.lines();
+
+        let header: Value = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(header["version"], 2);
+        assert_eq!(header["width"], 16);
+        assert_eq!(header["height"], 2);
+
+        let events: Vec<Value> = lines
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(!events.is_empty());
+        for event in &events {
+            assert!(event[0].as_f64().unwrap() >= 0.0);
+            assert_eq!(event[1], "o");
+        }
+        let output: String = events
+            .iter()
+            .map(|event| event[2].as_str().unwrap())
+            .collect();
+        // Unchanged blank cells aren't written, so words appear separately
+        assert!(output.contains("Count:"));
+        assert!(output.contains('0'));
+        assert!(output.contains('1'));
+        assert!(
+            events
+                .windows(2)
+                .all(|pair| pair[0][0].as_f64() <= pair[1][0].as_f64())
+        );
+    }
+}
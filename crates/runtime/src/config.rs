@@ -0,0 +1,166 @@
+//! Runtime configuration
+
+use crate::middleware::{EventMiddleware, EventMiddlewares};
+use crate::plugin::{Plugin, Plugins};
+use crossterm::event::KeyCode;
+use pulse_core::devtools::set_tree_tracking;
+use pulse_core::redraw::set_skip_non_essential_when_over_budget;
+use pulse_core::tasks::{DEFAULT_MAX_CONCURRENT_TASKS, set_max_concurrent_tasks};
+#[cfg(any(feature = "session", all(feature = "state-snapshot", debug_assertions)))]
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Settings for [`render_with_config`](crate::render_with_config) and
+/// [`render_async_with_config`](crate::render_async_with_config)
+///
+/// # Example
+/// ```no_run
+/// use pulse_runtime::{RuntimeConfig, render_with_config};
+/// use pulse_core::Component;
+/// use ratatui::{Frame, layout::Rect};
+/// use std::time::Duration;
+///
+/// #[derive(Clone)]
+/// struct App;
+///
+/// impl Component for App {
+///     fn render(&self, _area: Rect, _frame: &mut Frame) {}
+/// }
+///
+/// let config = RuntimeConfig::new()
+///     .max_concurrent_tasks(16)
+///     .render_budget(Duration::from_millis(16))
+///     .handle_signals(true);
+/// render_with_config(config, || App).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeConfig {
+    /// Maximum number of tasks spawned by hooks that run at the same time;
+    /// further tasks are queued
+    pub max_concurrent_tasks: usize,
+    /// Maximum time a frame may spend rendering before a warning is logged
+    pub render_budget: Option<Duration>,
+    /// Whether `skip_non_essential()` reports true after a frame over budget
+    pub skip_non_essential_over_budget: bool,
+    /// Whether SIGINT and SIGTERM shut the app down through the regular exit
+    /// path, restoring the terminal
+    pub handle_signals: bool,
+    /// Key that toggles the dev overlay with the component tree
+    pub dev_overlay_key: Option<KeyCode>,
+    /// Plugins notified of frames, events and exit, in registration order
+    pub plugins: Plugins,
+    /// Middleware transforming terminal events before dispatch, in order
+    pub middleware: EventMiddlewares,
+    /// Snapshot file restoring registered states across restarts
+    #[cfg(all(feature = "state-snapshot", debug_assertions))]
+    pub hot_reload_snapshot: Option<PathBuf>,
+    /// Session file recording every terminal event for later replay
+    #[cfg(feature = "session")]
+    pub session_recording: Option<PathBuf>,
+}
+
+impl RuntimeConfig {
+    /// Create the default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of concurrently running hook tasks
+    pub fn max_concurrent_tasks(mut self, max_concurrent_tasks: usize) -> Self {
+        self.max_concurrent_tasks = max_concurrent_tasks;
+        self
+    }
+
+    /// Log a warning for frames that take longer than `budget` to render
+    pub fn render_budget(mut self, budget: Duration) -> Self {
+        self.render_budget = Some(budget);
+        self
+    }
+
+    /// Let components skip non-essential work after a frame over budget
+    ///
+    /// See [`pulse_core::redraw::skip_non_essential`].
+    pub fn skip_non_essential_over_budget(mut self, enabled: bool) -> Self {
+        self.skip_non_essential_over_budget = enabled;
+        self
+    }
+
+    /// Restore the terminal and exit cleanly on SIGINT and SIGTERM
+    ///
+    /// Signals are only caught on Unix platforms.
+    pub fn handle_signals(mut self, enabled: bool) -> Self {
+        self.handle_signals = enabled;
+        self
+    }
+
+    /// Toggle a dev overlay showing the component tree with `key`
+    ///
+    /// Enabling the overlay records the component tree on every render; see
+    /// [`pulse_core::devtools`].
+    pub fn dev_overlay(mut self, key: KeyCode) -> Self {
+        self.dev_overlay_key = Some(key);
+        self
+    }
+
+    /// Register a plugin hooking into the runtime lifecycle
+    ///
+    /// See [`Plugin`] for the available hooks.
+    pub fn plugin(mut self, plugin: impl Plugin + 'static) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// Pass terminal events through `middleware` before dispatching them
+    ///
+    /// Middleware runs in registration order; see [`EventMiddleware`].
+    pub fn middleware(mut self, middleware: impl EventMiddleware + 'static) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Restore registered states from `path` on startup and keep it updated
+    ///
+    /// Restarting the binary after a code change then preserves the UI
+    /// state. Only available in debug builds; see
+    /// [`pulse_core::snapshot::HotReloadSnapshot`].
+    #[cfg(all(feature = "state-snapshot", debug_assertions))]
+    pub fn hot_reload_snapshot(mut self, path: impl Into<PathBuf>) -> Self {
+        self.hot_reload_snapshot = Some(path.into());
+        self
+    }
+
+    /// Record every terminal event to a session file at `path`
+    ///
+    /// Replay the file with [`replay_session`](crate::session::replay_session)
+    /// to reproduce what happened.
+    #[cfg(feature = "session")]
+    pub fn record_session(mut self, path: impl Into<PathBuf>) -> Self {
+        self.session_recording = Some(path.into());
+        self
+    }
+
+    /// Apply the global settings before the first render
+    pub(crate) fn apply(&self) {
+        set_max_concurrent_tasks(self.max_concurrent_tasks);
+        set_skip_non_essential_when_over_budget(self.skip_non_essential_over_budget);
+        set_tree_tracking(self.dev_overlay_key.is_some());
+    }
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_tasks: DEFAULT_MAX_CONCURRENT_TASKS,
+            render_budget: None,
+            skip_non_essential_over_budget: false,
+            handle_signals: false,
+            dev_overlay_key: None,
+            plugins: Plugins::default(),
+            middleware: EventMiddlewares::default(),
+            #[cfg(all(feature = "state-snapshot", debug_assertions))]
+            hot_reload_snapshot: None,
+            #[cfg(feature = "session")]
+            session_recording: None,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Toggling the dev overlay from the render loop
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use pulse_core::Component;
+use pulse_core::devtools::{DevOverlay, commit_component_tree};
+use ratatui::Frame;
+
+/// Shows the dev overlay while toggled on by its key
+pub(crate) struct DevOverlayToggle {
+    key: Option<KeyCode>,
+    visible: bool,
+}
+
+impl DevOverlayToggle {
+    /// Toggle the overlay with `key`; `None` disables the overlay
+    pub(crate) fn new(key: Option<KeyCode>) -> Self {
+        Self {
+            key,
+            visible: false,
+        }
+    }
+
+    /// Toggle the overlay if `key_event` is a press of the overlay key
+    ///
+    /// Returns true if the event was consumed.
+    pub(crate) fn handle_key(&mut self, key_event: &KeyEvent) -> bool {
+        let toggled = self.key == Some(key_event.code) && key_event.kind == KeyEventKind::Press;
+        if toggled {
+            self.visible = !self.visible;
+        }
+        toggled
+    }
+
+    /// Finish the frame's component tree and draw the overlay if visible
+    pub(crate) fn render(&self, frame: &mut Frame) {
+        if self.key.is_none() {
+            return;
+        }
+
+        commit_component_tree();
+        if self.visible {
+            DevOverlay.render(frame.area(), frame);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_overlay_key_toggles_visibility() {
+        let mut toggle = DevOverlayToggle::new(Some(KeyCode::F(12)));
+        let press = KeyEvent::new(KeyCode::F(12), KeyModifiers::NONE);
+
+        assert!(toggle.handle_key(&press));
+        assert!(toggle.visible);
+        assert!(!toggle.handle_key(&KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE)));
+        assert!(toggle.visible);
+        assert!(toggle.handle_key(&press));
+        assert!(!toggle.visible);
+    }
+
+    #[test]
+    fn test_disabled_overlay_ignores_keys() {
+        let mut toggle = DevOverlayToggle::new(None);
+        assert!(!toggle.handle_key(&KeyEvent::new(KeyCode::F(12), KeyModifiers::NONE)));
+        assert!(!toggle.visible);
+    }
+}
@@ -0,0 +1,536 @@
+//! The render loop shared by every way of running an app
+//!
+//! Local terminals, remote clients and session replays only differ in where
+//! terminal events come from and where frames are drawn. [`AppDriver`] runs
+//! everything in between, one frame per [`step`](AppDriver::step): posted
+//! events, middleware, plugins, the dev overlay, global handlers, drawing
+//! and the per-frame cleanup. Each caller loops over `step` with its own
+//! backend and event source.
+//!
+//! A frame is only drawn when it handles an event or a render was requested
+//! through the [`batch`](pulse_core::hooks::batch) scheduler. Frames with
+//! input or a high-priority request serve
+//! [`Priority::High`](pulse_core::hooks::batch::Priority::High); low-priority
+//! requests wait for a frame with neither.
+
+use crate::config::RuntimeConfig;
+use crate::dev_overlay::DevOverlayToggle;
+use crate::frame::FrameDrawer;
+use crate::hot_reload::HotReload;
+use crate::middleware::EventPipeline;
+use crate::plugin::Plugins;
+use crate::signals::{ShutdownSignals, install_if, shutdown_requested};
+use crossterm::event::Event;
+use pulse_core::{
+    Component,
+    color::apply_color_mode,
+    component::cleanup_unmounted,
+    exit::{ExitGuard, exit_guard, should_exit},
+    hooks::{
+        HookContext,
+        batch::{serve_render, take_frame_render},
+        event::{
+            app_events::{AppEvent, set_current_app_event, try_next_app_event},
+            global_events::process_global_event,
+            set_current_event,
+        },
+    },
+    slots::render_slots,
+};
+use ratatui::{
+    Terminal,
+    backend::{Backend, CrosstermBackend, TestBackend},
+};
+use std::io;
+use std::rc::Rc;
+
+/// Backend an [`AppDriver`] can draw to
+pub(crate) trait DriverBackend: Backend {
+    /// Change the size of the screen after a resize event
+    ///
+    /// Backends reading their size from a real terminal ignore this.
+    fn resize(&mut self, _width: u16, _height: u16) {}
+}
+
+impl<W: io::Write> DriverBackend for CrosstermBackend<W> {}
+
+impl DriverBackend for TestBackend {
+    fn resize(&mut self, width: u16, height: u16) {
+        TestBackend::resize(self, width, height);
+    }
+}
+
+/// What an event source produced for the current frame
+pub(crate) enum Input {
+    /// A terminal event, passed through the middleware before dispatch
+    Event(Event),
+    /// Nothing arrived; the frame is only drawn if a render was requested
+    Idle,
+    /// The source is exhausted and the app stops
+    #[cfg_attr(not(any(feature = "remote", feature = "session")), allow(dead_code))]
+    Closed,
+}
+
+/// Runs an app's frames against any backend and event source
+///
+/// Creating the driver applies the [`RuntimeConfig`] and installs a hook
+/// context for the component tree, which must be created afterwards.
+pub(crate) struct AppDriver {
+    hook_context: Rc<HookContext>,
+    frame_drawer: FrameDrawer,
+    dev_overlay: DevOverlayToggle,
+    hot_reload: HotReload,
+    pipeline: EventPipeline,
+    plugins: Plugins,
+    signals: Option<ShutdownSignals>,
+    _exit_guard: Option<ExitGuard>,
+    frames: u64,
+    #[cfg(feature = "session")]
+    recorder: Option<crate::session::SessionRecorder>,
+    #[cfg(feature = "session")]
+    replaying: bool,
+}
+
+impl AppDriver {
+    /// Set up the render loop described by `config` for `terminal`
+    #[cfg_attr(not(feature = "session"), allow(unused_variables))]
+    pub(crate) fn new<B: Backend>(
+        config: &RuntimeConfig,
+        terminal: &mut Terminal<B>,
+    ) -> io::Result<Self> {
+        config.apply();
+
+        // Leave through the regular exit path on SIGINT/SIGTERM
+        let signals = install_if(config.handle_signals)?;
+        let exit_guard = signals.is_some().then(exit_guard);
+
+        let hook_context = Rc::new(HookContext::new());
+        pulse_core::hooks::set_hook_context(hook_context.clone());
+
+        Ok(Self {
+            hook_context,
+            frame_drawer: FrameDrawer::new()
+                .with_budget(config.render_budget)
+                .with_plugins(config.plugins.clone()),
+            dev_overlay: DevOverlayToggle::new(config.dev_overlay_key),
+            hot_reload: HotReload::start(config),
+            pipeline: EventPipeline::new(config.middleware.clone()),
+            plugins: config.plugins.clone(),
+            signals,
+            _exit_guard: exit_guard,
+            frames: 0,
+            #[cfg(feature = "session")]
+            recorder: crate::session::start_recording(config, terminal.size()?)?,
+            #[cfg(feature = "session")]
+            replaying: false,
+        })
+    }
+
+    /// Number of frames stepped so far, including those with nothing to draw
+    #[cfg_attr(not(feature = "session"), allow(dead_code))]
+    pub(crate) fn frame(&self) -> u64 {
+        self.frames
+    }
+
+    /// Deliver posted terminal events from the recording being replayed
+    ///
+    /// The replayed app posts them again; dropping those copies keeps each
+    /// event from being handled twice.
+    #[cfg(feature = "session")]
+    pub(crate) fn replaying(mut self) -> Self {
+        self.replaying = true;
+        self
+    }
+
+    /// Record a terminal event and pass it through the middleware
+    ///
+    /// Raw input is recorded, so replays run the middleware again.
+    fn input(&mut self, event: Event) -> Option<AppEvent> {
+        #[cfg(feature = "session")]
+        {
+            let frame = self.frame();
+            crate::session::record_event(&mut self.recorder, frame, &event);
+        }
+        self.pipeline.push(event);
+        self.pipeline.pop().map(AppEvent::Terminal)
+    }
+
+    /// Handle the next event and draw one frame of `element` if needed
+    ///
+    /// Events posted with `post_event` go first, then events the middleware
+    /// queued earlier; `read` is only called when neither is waiting. Posted
+    /// terminal events and those from `read` go through the middleware and
+    /// are recorded alike. The
+    /// first frame, frames handling an event and frames with a pending render
+    /// request are drawn; others are skipped. Returns false without drawing
+    /// once the app should stop.
+    pub(crate) fn step<B, C>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        element: &C,
+        read: impl FnOnce() -> io::Result<Input>,
+    ) -> io::Result<bool>
+    where
+        B: DriverBackend,
+        C: Component,
+    {
+        if shutdown_requested(self.signals.as_ref()) {
+            return Ok(false);
+        }
+
+        // Reset hook index before each render
+        self.hook_context.reset_hook_index();
+
+        let posted = try_next_app_event();
+        // A replay delivers posted input from the recording instead
+        #[cfg(feature = "session")]
+        let posted =
+            posted.filter(|event| !(self.replaying && matches!(event, AppEvent::Terminal(_))));
+
+        let next = match posted {
+            Some(AppEvent::Terminal(event)) => self.input(event),
+            Some(app_event) => Some(app_event),
+            None => match self.pipeline.pop() {
+                Some(event) => Some(AppEvent::Terminal(event)),
+                None => match read()? {
+                    Input::Event(event) => self.input(event),
+                    Input::Idle => None,
+                    Input::Closed => return Ok(false),
+                },
+            },
+        };
+
+        // Frames handling input are drawn whether or not a render was requested
+        let urgent = self.frames == 0 || next.is_some();
+        match next {
+            Some(AppEvent::Terminal(event)) => {
+                set_current_app_event(None);
+                self.plugins.on_event(&event);
+
+                if let Event::Resize(width, height) = event {
+                    terminal.backend_mut().resize(width, height);
+                }
+
+                // Key events go to the dev overlay and global handlers first
+                let processed = match &event {
+                    Event::Key(key_event) if self.dev_overlay.handle_key(key_event) => true,
+                    Event::Key(key_event) => process_global_event(key_event),
+                    _ => false,
+                };
+
+                // If not processed as a global event, make it available to components
+                if !processed {
+                    set_current_event(Some(event.into()));
+                }
+            }
+            Some(AppEvent::Custom(custom_event)) => {
+                // Custom events are surfaced through use_app_event
+                set_current_event(None);
+                set_current_app_event(Some(custom_event));
+            }
+            None => {
+                // No events, clear the current events
+                set_current_event(None);
+                set_current_app_event(None);
+            }
+        }
+
+        if should_exit() {
+            return Ok(false);
+        }
+
+        // Requests made while drawing are served by the next frame
+        self.frames += 1;
+        let Some(priority) = take_frame_render(urgent) else {
+            return Ok(true);
+        };
+
+        // Render the component using render_with_mount to ensure on_mount is called
+        let dev_overlay = &self.dev_overlay;
+        let frame_drawer = &mut self.frame_drawer;
+        serve_render(priority, || {
+            frame_drawer.draw(terminal, |frame| {
+                element.render_with_mount(frame.area(), frame);
+                render_slots(frame);
+                dev_overlay.render(frame);
+                apply_color_mode(frame.buffer_mut());
+            })
+        })?;
+
+        // Clean up unmounted components after render
+        cleanup_unmounted();
+
+        // Persist state changes for the next run
+        self.hot_reload.after_frame();
+
+        Ok(true)
+    }
+}
+
+impl Drop for AppDriver {
+    fn drop(&mut self) {
+        self.plugins.on_exit();
+
+        // Clear the current events
+        set_current_event(None);
+        set_current_app_event(None);
+
+        // Clean up the hook context
+        pulse_core::hooks::clear_hook_context();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use pulse_core::{
+        IntoElement,
+        hooks::{
+            batch::{
+                Priority, RenderScope, batch_updates, render_request_count, schedule_render,
+                serving_priority,
+            },
+            deferred::use_deferred_value,
+            event::{
+                app_events::{AppEventScope, post_event},
+                filtered::use_key_event,
+            },
+            state::use_state,
+        },
+    };
+    use ratatui::{
+        Frame,
+        backend::WindowSize,
+        buffer::{Buffer, Cell},
+        layout::{Position, Rect, Size},
+        widgets::Paragraph,
+    };
+    use std::sync::{Arc, Mutex};
+
+    /// Test backend that counts the frames drawn to it
+    struct DrawCounter {
+        inner: TestBackend,
+        draws: usize,
+    }
+
+    impl Backend for DrawCounter {
+        fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+        where
+            I: Iterator<Item = (u16, u16, &'a Cell)>,
+        {
+            self.inner.draw(content)
+        }
+
+        fn hide_cursor(&mut self) -> io::Result<()> {
+            self.inner.hide_cursor()
+        }
+
+        fn show_cursor(&mut self) -> io::Result<()> {
+            self.inner.show_cursor()
+        }
+
+        fn get_cursor_position(&mut self) -> io::Result<Position> {
+            self.inner.get_cursor_position()
+        }
+
+        fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+            self.inner.set_cursor_position(position)
+        }
+
+        fn clear(&mut self) -> io::Result<()> {
+            self.inner.clear()
+        }
+
+        fn size(&self) -> io::Result<Size> {
+            self.inner.size()
+        }
+
+        fn window_size(&mut self) -> io::Result<WindowSize> {
+            self.inner.window_size()
+        }
+
+        // `Terminal::draw` flushes once per frame
+        fn flush(&mut self) -> io::Result<()> {
+            self.draws += 1;
+            self.inner.flush()
+        }
+    }
+
+    impl DriverBackend for DrawCounter {}
+
+    /// Records the priority served by each frame it is drawn in
+    #[derive(Clone, Default)]
+    struct PriorityLog(Arc<Mutex<Vec<Option<Priority>>>>);
+
+    impl Component for PriorityLog {
+        fn render(&self, _area: Rect, _frame: &mut Frame) {
+            self.0.lock().unwrap().push(serving_priority());
+        }
+    }
+
+    fn idle() -> io::Result<Input> {
+        Ok(Input::Idle)
+    }
+
+    #[test]
+    fn test_low_priority_render_waits_for_a_later_frame() {
+        let _renders = RenderScope::start();
+        let _app_events = AppEventScope::start();
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut driver = AppDriver::new(&RuntimeConfig::default(), &mut terminal).unwrap();
+        let log = PriorityLog::default();
+        let element = log.clone().into_element();
+
+        // The first frame is drawn without a request, the second has nothing to draw
+        assert!(driver.step(&mut terminal, &element, idle).unwrap());
+        assert!(driver.step(&mut terminal, &element, idle).unwrap());
+        assert_eq!(*log.0.lock().unwrap(), [Some(Priority::High)]);
+
+        schedule_render(Priority::Low);
+        schedule_render(Priority::High);
+        for _ in 0..3 {
+            assert!(driver.step(&mut terminal, &element, idle).unwrap());
+        }
+
+        assert_eq!(
+            *log.0.lock().unwrap(),
+            [
+                Some(Priority::High),
+                Some(Priority::High),
+                Some(Priority::Low)
+            ]
+        );
+        assert_eq!(driver.frame(), 5);
+        assert_eq!(driver.frame_drawer.frame(), 3);
+    }
+
+    /// Updates three states at once when `b` is pressed
+    #[derive(Clone)]
+    struct Profile;
+
+    impl Component for Profile {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let (name, set_name) = use_state(|| "-");
+            let (age, set_age) = use_state(|| 0);
+            let (saved, set_saved) = use_state(|| false);
+            if use_key_event().is_some_and(|key| key.code == KeyCode::Char('b')) {
+                batch_updates(|| {
+                    set_name.set("Ada");
+                    set_age.set(36);
+                    set_saved.set(true);
+                });
+            }
+            let text = format!("{} {} {}", name.get(), age.get(), saved.get());
+            frame.render_widget(Paragraph::new(text), area);
+        }
+    }
+
+    #[test]
+    fn test_batched_updates_draw_one_frame() {
+        let _renders = RenderScope::start();
+        let _app_events = AppEventScope::start();
+        let mut terminal = Terminal::new(DrawCounter {
+            inner: TestBackend::new(11, 1),
+            draws: 0,
+        })
+        .unwrap();
+        let mut driver = AppDriver::new(&RuntimeConfig::default(), &mut terminal).unwrap();
+        let element = Profile.into_element();
+
+        assert!(driver.step(&mut terminal, &element, idle).unwrap());
+        assert_eq!(terminal.backend().draws, 1);
+
+        // The key is drawn, and the batch inside its handler requests one frame
+        let requests = render_request_count();
+        let key = || Ok(Input::Event(Event::Key(KeyEvent::from(KeyCode::Char('b')))));
+        assert!(driver.step(&mut terminal, &element, key).unwrap());
+        assert_eq!(render_request_count() - requests, 1);
+        assert_eq!(terminal.backend().draws, 2);
+
+        for _ in 0..3 {
+            assert!(driver.step(&mut terminal, &element, idle).unwrap());
+        }
+        assert_eq!(terminal.backend().draws, 3);
+        assert_eq!(
+            terminal.backend().inner.buffer(),
+            &Buffer::with_lines(["Ada 36 true"])
+        );
+    }
+
+    /// Echoes typed characters, with a deferred copy of them
+    #[derive(Clone)]
+    struct Typeahead;
+
+    impl Component for Typeahead {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let (query, set_query) = use_state(String::new);
+            if let Some(KeyCode::Char(c)) = use_key_event().map(|key| key.code) {
+                set_query.update(move |query| format!("{query}{c}"));
+            }
+            let query = query.get();
+            let deferred = use_deferred_value(query.clone());
+            frame.render_widget(Paragraph::new(format!("{query}|{deferred}")), area);
+        }
+    }
+
+    #[test]
+    fn test_typed_input_is_drawn_before_the_deferred_catch_up() {
+        let _renders = RenderScope::start();
+        let _app_events = AppEventScope::start();
+        let mut terminal = Terminal::new(TestBackend::new(5, 1)).unwrap();
+        let mut driver = AppDriver::new(&RuntimeConfig::default(), &mut terminal).unwrap();
+        let element = Typeahead.into_element();
+        let key = |c| move || Ok(Input::Event(Event::Key(KeyEvent::from(KeyCode::Char(c)))));
+
+        driver.step(&mut terminal, &element, idle).unwrap();
+        let mut screens = Vec::new();
+        for input in [key('a'), key('b')] {
+            driver.step(&mut terminal, &element, input).unwrap();
+            screens.push(terminal.backend().buffer().clone());
+        }
+        for _ in 0..3 {
+            driver.step(&mut terminal, &element, idle).unwrap();
+            screens.push(terminal.backend().buffer().clone());
+        }
+
+        // The high-priority frame requested by the last key still shows the
+        // old deferred value; the low-priority frame after it catches up
+        let expected = ["a|   ", "ab|  ", "ab|  ", "ab|ab", "ab|ab"];
+        assert_eq!(screens, expected.map(|line| Buffer::with_lines([line])));
+        assert_eq!(driver.frame_drawer.frame(), 5);
+    }
+
+    /// Records the key codes it sees
+    #[derive(Clone, Default)]
+    struct KeyLog(Arc<Mutex<Vec<KeyCode>>>);
+
+    impl Component for KeyLog {
+        fn render(&self, _area: Rect, _frame: &mut Frame) {
+            if let Some(key) = use_key_event() {
+                self.0.lock().unwrap().push(key.code);
+            }
+        }
+    }
+
+    #[test]
+    fn test_posted_terminal_events_pass_the_middleware() {
+        let _renders = RenderScope::start();
+        let _app_events = AppEventScope::start();
+        let config = RuntimeConfig::new()
+            .middleware(crate::KeyRemap::new().map(KeyCode::Char('j'), KeyCode::Down));
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut driver = AppDriver::new(&config, &mut terminal).unwrap();
+        let log = KeyLog::default();
+        let element = log.clone().into_element();
+
+        post_event(AppEvent::Terminal(Event::Key(KeyEvent::from(
+            KeyCode::Char('j'),
+        ))));
+        let key = || Ok(Input::Event(Event::Key(KeyEvent::from(KeyCode::Char('j')))));
+        driver.step(&mut terminal, &element, idle).unwrap();
+        driver.step(&mut terminal, &element, key).unwrap();
+
+        assert_eq!(*log.0.lock().unwrap(), [KeyCode::Down, KeyCode::Down]);
+    }
+}
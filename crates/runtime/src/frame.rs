@@ -0,0 +1,323 @@
+//! Frame drawing with diff metrics and forced full redraws
+//!
+//! Wraps `Terminal::draw` to honour [`request_full_redraw`] and to record
+//! [`FrameMetrics`] describing how many cells each frame wrote and how long
+//! rendering took, along with the damage regions covering the changed cells.
+//!
+//! [`request_full_redraw`]: pulse_core::redraw::request_full_redraw
+
+use crate::plugin::Plugins;
+use pulse_core::clock;
+use pulse_core::redraw::{
+    FrameMetrics, compute_damage_regions, record_damage_regions, record_frame_metrics,
+    record_frame_tick, take_full_redraw_request,
+};
+use ratatui::{Frame, Terminal, backend::Backend, buffer::Buffer, layout::Rect};
+use std::io;
+use std::time::{Duration, Instant};
+
+/// Draws frames while tracking the previously displayed buffer
+#[derive(Debug, Default)]
+pub struct FrameDrawer {
+    previous: Option<Buffer>,
+    frame: u64,
+    budget: Option<Duration>,
+    damage: Vec<Rect>,
+    plugins: Plugins,
+}
+
+impl FrameDrawer {
+    /// Create a new frame drawer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Warn about frames whose rendering takes longer than `budget`
+    pub fn with_budget(mut self, budget: Option<Duration>) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Notify `plugins` before and after every frame
+    pub fn with_plugins(mut self, plugins: Plugins) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    /// Number of frames drawn so far
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Rectangles covering the cells changed by the last drawn frame
+    pub fn damage_regions(&self) -> &[Rect] {
+        &self.damage
+    }
+
+    /// Draw a frame, forcing a complete redraw if one was requested
+    ///
+    /// On a full redraw the terminal is cleared and every cell of the new
+    /// frame is written, regardless of ratatui's internal diff.
+    pub fn draw<B, F>(&mut self, terminal: &mut Terminal<B>, render: F) -> io::Result<FrameMetrics>
+    where
+        B: Backend,
+        F: FnOnce(&mut Frame),
+    {
+        self.plugins.before_frame(self.frame + 1);
+        let full_redraw = take_full_redraw_request();
+        if full_redraw {
+            terminal.clear()?;
+        }
+
+        let mut render_time = Duration::ZERO;
+        let buffer = terminal
+            .draw(|frame| {
+                let started = Instant::now();
+                render(frame);
+                render_time = started.elapsed();
+            })?
+            .buffer;
+        let total_cells = buffer.content.len();
+
+        let (changed_cells, damage) = match &self.previous {
+            Some(previous) if !full_redraw && previous.area == buffer.area => {
+                let diff = previous.diff(buffer);
+                let damage = compute_damage_regions(diff.iter().map(|&(x, y, _)| (x, y)));
+                (diff.len(), damage)
+            }
+            _ => (total_cells, vec![buffer.area]),
+        };
+
+        // Reuse the previous frame's allocation instead of cloning a new buffer
+        match &mut self.previous {
+            Some(previous) => {
+                previous.area = buffer.area;
+                previous.content.clone_from(&buffer.content);
+            }
+            None => self.previous = Some(buffer.clone()),
+        }
+
+        if full_redraw && let Some(displayed) = &self.previous {
+            let cells = displayed.content.iter().enumerate().map(|(index, cell)| {
+                let (x, y) = displayed.pos_of(index);
+                (x, y, cell)
+            });
+            let backend = terminal.backend_mut();
+            backend.draw(cells)?;
+            backend.flush()?;
+        }
+
+        self.frame += 1;
+        let over_budget = self.budget.is_some_and(|budget| render_time > budget);
+        if let Some(budget) = self.budget.filter(|_| over_budget) {
+            tracing::warn!(
+                target: "pulse_runtime::frame",
+                "Frame {} exceeded the render budget: {:?} > {:?}",
+                self.frame,
+                render_time,
+                budget
+            );
+        }
+
+        let metrics = FrameMetrics {
+            frame: self.frame,
+            changed_cells,
+            total_cells,
+            full_redraw,
+            render_time,
+            over_budget,
+        };
+        record_frame_metrics(metrics);
+        record_frame_tick(clock::now());
+        self.plugins.after_frame(&metrics);
+        record_damage_regions(damage.clone());
+        self.damage = damage;
+
+        Ok(metrics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulse_core::redraw::{last_frame_metrics, request_full_redraw};
+    use ratatui::{
+        backend::{TestBackend, WindowSize},
+        buffer::Cell,
+        layout::{Position, Size},
+        widgets::Paragraph,
+    };
+
+    /// Test backend that counts the cells written to it
+    struct CountingBackend {
+        inner: TestBackend,
+        written: usize,
+    }
+
+    impl Backend for CountingBackend {
+        fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+        where
+            I: Iterator<Item = (u16, u16, &'a Cell)>,
+        {
+            let cells: Vec<_> = content.collect();
+            self.written += cells.len();
+            self.inner.draw(cells.into_iter())
+        }
+
+        fn hide_cursor(&mut self) -> io::Result<()> {
+            self.inner.hide_cursor()
+        }
+
+        fn show_cursor(&mut self) -> io::Result<()> {
+            self.inner.show_cursor()
+        }
+
+        fn get_cursor_position(&mut self) -> io::Result<Position> {
+            self.inner.get_cursor_position()
+        }
+
+        fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+            self.inner.set_cursor_position(position)
+        }
+
+        fn clear(&mut self) -> io::Result<()> {
+            self.inner.clear()
+        }
+
+        fn size(&self) -> io::Result<Size> {
+            self.inner.size()
+        }
+
+        fn window_size(&mut self) -> io::Result<WindowSize> {
+            self.inner.window_size()
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    fn counting_terminal(width: u16, height: u16) -> Terminal<CountingBackend> {
+        Terminal::new(CountingBackend {
+            inner: TestBackend::new(width, height),
+            written: 0,
+        })
+        .unwrap()
+    }
+
+    fn render_text(text: &'static str) -> impl FnOnce(&mut Frame) {
+        move |frame| frame.render_widget(Paragraph::new(text), frame.area())
+    }
+
+    #[test]
+    fn test_full_redraw_writes_all_cells() {
+        let mut terminal = counting_terminal(10, 3);
+        let mut drawer = FrameDrawer::new();
+
+        let first = drawer.draw(&mut terminal, render_text("hello")).unwrap();
+        assert_eq!(first.frame, 1);
+        assert_eq!(first.total_cells, 30);
+
+        // An unchanged frame writes nothing
+        terminal.backend_mut().written = 0;
+        let unchanged = drawer.draw(&mut terminal, render_text("hello")).unwrap();
+        assert_eq!(unchanged.changed_cells, 0);
+        assert_eq!(terminal.backend().written, 0);
+
+        // A forced redraw writes every cell, even though nothing changed
+        request_full_redraw();
+        terminal.backend_mut().written = 0;
+        let redraw = drawer.draw(&mut terminal, render_text("hello")).unwrap();
+        assert!(redraw.full_redraw);
+        assert_eq!(redraw.changed_cells, 30);
+        assert!(terminal.backend().written >= 30);
+        assert_eq!(terminal.backend().inner.buffer()[(0, 0)].symbol(), "h");
+
+        // The request only applies to a single frame
+        let after = drawer.draw(&mut terminal, render_text("hello")).unwrap();
+        assert!(!after.full_redraw);
+        assert_eq!(after.changed_cells, 0);
+    }
+
+    #[test]
+    fn test_metrics_count_changed_cells() {
+        let mut terminal = counting_terminal(10, 1);
+        let mut drawer = FrameDrawer::new();
+
+        drawer.draw(&mut terminal, render_text("abc")).unwrap();
+        let metrics = drawer.draw(&mut terminal, render_text("abd")).unwrap();
+
+        assert_eq!(metrics.changed_cells, 1);
+        assert_eq!(metrics.total_cells, 10);
+        assert_eq!(metrics.frame, 2);
+        assert!(last_frame_metrics().is_some());
+    }
+
+    #[test]
+    fn test_localized_change_produces_small_damage_region() {
+        let mut terminal = counting_terminal(40, 10);
+        let mut drawer = FrameDrawer::new();
+
+        let render_counter = |count: u32| {
+            move |frame: &mut Frame| {
+                let text = format!("Title\n\nCount: {:>3}\n\nFooter", count);
+                frame.render_widget(Paragraph::new(text), frame.area());
+            }
+        };
+
+        drawer.draw(&mut terminal, render_counter(1)).unwrap();
+        assert_eq!(drawer.damage_regions(), [Rect::new(0, 0, 40, 10)]);
+
+        drawer.draw(&mut terminal, render_counter(2)).unwrap();
+        assert_eq!(drawer.damage_regions(), [Rect::new(9, 2, 1, 1)]);
+
+        drawer.draw(&mut terminal, render_counter(2)).unwrap();
+        assert!(drawer.damage_regions().is_empty());
+    }
+
+    /// Writer collecting log output for assertions
+    #[derive(Clone, Default)]
+    struct LogBuffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl io::Write for LogBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_slow_render_exceeds_budget() {
+        let logs = LogBuffer::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .finish();
+
+        let mut terminal = counting_terminal(10, 1);
+        let mut drawer = FrameDrawer::new().with_budget(Some(Duration::from_millis(5)));
+
+        let (fast, slow) = tracing::subscriber::with_default(subscriber, || {
+            let fast = drawer.draw(&mut terminal, render_text("fast")).unwrap();
+            let slow = drawer
+                .draw(&mut terminal, |frame| {
+                    std::thread::sleep(Duration::from_millis(20));
+                    render_text("slow")(frame);
+                })
+                .unwrap();
+            (fast, slow)
+        });
+
+        assert!(!fast.over_budget);
+        assert!(slow.over_budget);
+        assert!(slow.render_time >= Duration::from_millis(20));
+
+        let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("Frame 2 exceeded the render budget"));
+        assert!(!logs.contains("Frame 1 exceeded"));
+    }
+}
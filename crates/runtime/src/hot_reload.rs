@@ -0,0 +1,39 @@
+//! Keeping the hot-reload snapshot in step with the render loop
+
+use crate::config::RuntimeConfig;
+
+/// Restores and saves the snapshot configured in the [`RuntimeConfig`]
+///
+/// Does nothing unless the `state-snapshot` feature is enabled in a debug
+/// build and a snapshot path is configured.
+pub(crate) struct HotReload {
+    #[cfg(all(feature = "state-snapshot", debug_assertions))]
+    snapshot: Option<pulse_core::snapshot::HotReloadSnapshot>,
+}
+
+impl HotReload {
+    /// Restore the snapshot of the previous run, before the first render
+    #[cfg_attr(
+        not(all(feature = "state-snapshot", debug_assertions)),
+        allow(unused_variables)
+    )]
+    pub(crate) fn start(config: &RuntimeConfig) -> Self {
+        Self {
+            #[cfg(all(feature = "state-snapshot", debug_assertions))]
+            snapshot: config
+                .hot_reload_snapshot
+                .as_ref()
+                .map(pulse_core::snapshot::HotReloadSnapshot::load),
+        }
+    }
+
+    /// Save the snapshot if a frame changed the registered states
+    pub(crate) fn after_frame(&mut self) {
+        #[cfg(all(feature = "state-snapshot", debug_assertions))]
+        if let Some(snapshot) = &mut self.snapshot
+            && let Err(error) = snapshot.save_if_changed()
+        {
+            tracing::warn!("Failed to save hot-reload snapshot: {}", error);
+        }
+    }
+}
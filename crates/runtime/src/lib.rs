@@ -1,4 +1,23 @@
+#[cfg(feature = "session")]
+pub mod cast;
+mod config;
+mod dev_overlay;
+mod driver;
+mod frame;
+mod hot_reload;
+mod middleware;
+mod plugin;
+mod registry;
+#[cfg(feature = "remote")]
+pub mod remote;
 mod renderer;
+#[cfg(feature = "session")]
+pub mod session;
+mod signals;
 mod terminal;
-pub use renderer::{render, render_async};
+pub use config::RuntimeConfig;
+pub use frame::FrameDrawer;
+pub use middleware::{EventMiddleware, EventMiddlewares, KeyRemap};
+pub use plugin::{Plugin, Plugins};
+pub use renderer::{render, render_async, render_async_with_config, render_with_config};
 pub use terminal::{ManagedTerminal, restore_terminal, setup_terminal};
@@ -0,0 +1,266 @@
+//! Transforming terminal input before it is dispatched
+//!
+//! Event middleware sits between the terminal and the rest of the runtime.
+//! Each terminal event passes through the registered middleware in order;
+//! every middleware may pass it on unchanged, replace it, drop it or add more
+//! events after it. Whatever comes out of the last middleware is dispatched
+//! one event per frame, exactly as if the terminal had produced it, so global
+//! handlers, plugins and components only ever see the processed events.
+//! Session recordings keep the raw input instead, and replaying them passes
+//! it through the middleware again.
+//!
+//! Register middleware with [`RuntimeConfig::middleware`]. [`KeyRemap`]
+//! covers the common case of mapping one key to another.
+//!
+//! [`RuntimeConfig::middleware`]: crate::RuntimeConfig::middleware
+
+use crate::registry::Registry;
+use crossterm::event::{Event, KeyCode, KeyEvent};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+/// A step in the input pipeline
+///
+/// Returns the events that replace `event`: an empty list drops it, and
+/// several events are dispatched in order on consecutive frames. Closures
+/// taking an [`Event`] and returning a `Vec<Event>` are middleware too.
+///
+/// # Example
+/// ```no_run
+/// use crossterm::event::{Event, KeyCode, KeyEvent};
+/// use pulse_runtime::RuntimeConfig;
+///
+/// // Expand F2 into typing "hi"
+/// let config = RuntimeConfig::new().middleware(|event: Event| match event {
+///     Event::Key(key) if key.code == KeyCode::F(2) => vec![
+///         Event::Key(KeyEvent::from(KeyCode::Char('h'))),
+///         Event::Key(KeyEvent::from(KeyCode::Char('i'))),
+///     ],
+///     event => vec![event],
+/// });
+/// ```
+pub trait EventMiddleware: Send + Sync {
+    /// Process one event, returning the events to pass on
+    fn process(&self, event: Event) -> Vec<Event>;
+}
+
+impl<F> EventMiddleware for F
+where
+    F: Fn(Event) -> Vec<Event> + Send + Sync,
+{
+    fn process(&self, event: Event) -> Vec<Event> {
+        self(event)
+    }
+}
+
+/// Middleware replacing the code of key events
+///
+/// Modifiers and the event kind are kept, so remapping `j` to `Down` also
+/// turns a held `j` into a held `Down`.
+///
+/// # Example
+/// ```no_run
+/// use crossterm::event::KeyCode;
+/// use pulse_runtime::{KeyRemap, RuntimeConfig};
+///
+/// let vim_arrows = KeyRemap::new()
+///     .map(KeyCode::Char('h'), KeyCode::Left)
+///     .map(KeyCode::Char('j'), KeyCode::Down)
+///     .map(KeyCode::Char('k'), KeyCode::Up)
+///     .map(KeyCode::Char('l'), KeyCode::Right);
+/// let config = RuntimeConfig::new().middleware(vim_arrows);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct KeyRemap {
+    keys: HashMap<KeyCode, KeyCode>,
+}
+
+impl KeyRemap {
+    /// Create a remap that changes nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deliver presses of `from` as presses of `to`
+    pub fn map(mut self, from: KeyCode, to: KeyCode) -> Self {
+        self.keys.insert(from, to);
+        self
+    }
+}
+
+impl EventMiddleware for KeyRemap {
+    fn process(&self, event: Event) -> Vec<Event> {
+        match event {
+            Event::Key(key) => match self.keys.get(&key.code) {
+                Some(&code) => vec![Event::Key(KeyEvent { code, ..key })],
+                None => vec![event],
+            },
+            event => vec![event],
+        }
+    }
+}
+
+/// Middleware registered with a [`RuntimeConfig`](crate::RuntimeConfig)
+///
+/// The middleware chain is part of a config's identity: two chains are
+/// equal when one is a clone of the other, not when they would rewrite
+/// events the same way.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventMiddlewares(Registry<dyn EventMiddleware>);
+
+impl EventMiddlewares {
+    /// Add a middleware after the already registered ones
+    pub fn push(&mut self, middleware: impl EventMiddleware + 'static) {
+        self.0.push(Arc::new(middleware));
+    }
+
+    /// Number of registered middleware
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether no middleware is registered
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Pass `event` through every middleware in order
+    pub fn process(&self, event: Event) -> Vec<Event> {
+        self.0.iter().fold(vec![event], |events, middleware| {
+            events
+                .into_iter()
+                .flat_map(|event| middleware.process(event))
+                .collect()
+        })
+    }
+}
+
+/// Terminal events waiting to be dispatched after passing the middleware
+#[derive(Debug, Default)]
+pub(crate) struct EventPipeline {
+    middleware: EventMiddlewares,
+    pending: VecDeque<Event>,
+}
+
+impl EventPipeline {
+    pub(crate) fn new(middleware: EventMiddlewares) -> Self {
+        Self {
+            middleware,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Queue the events `event` turns into
+    pub(crate) fn push(&mut self, event: Event) {
+        self.pending.extend(self.middleware.process(event));
+    }
+
+    /// Take the next event to dispatch
+    pub(crate) fn pop(&mut self) -> Option<Event> {
+        self.pending.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FrameDrawer;
+    use crossterm::event::{KeyEventKind, KeyModifiers};
+    use pulse_core::{
+        Component, IntoElement,
+        hooks::{HookContext, event::filtered::use_key_event, event::set_current_event},
+    };
+    use ratatui::{Frame, Terminal, backend::TestBackend, layout::Rect};
+    use std::rc::Rc;
+    use std::sync::Mutex;
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::from(code))
+    }
+
+    /// Records the key codes it sees
+    #[derive(Clone)]
+    struct KeyLog(Arc<Mutex<Vec<KeyCode>>>);
+
+    impl Component for KeyLog {
+        fn render(&self, _area: Rect, _frame: &mut Frame) {
+            if let Some(key) = use_key_event() {
+                self.0.lock().unwrap().push(key.code);
+            }
+        }
+    }
+
+    #[test]
+    fn test_remapped_key_reaches_components() {
+        let mut middleware = EventMiddlewares::default();
+        middleware.push(KeyRemap::new().map(KeyCode::Char('j'), KeyCode::Down));
+        let mut pipeline = EventPipeline::new(middleware);
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let element = KeyLog(seen.clone()).into_element();
+        let hook_context = Rc::new(HookContext::new());
+        pulse_core::hooks::set_hook_context(hook_context.clone());
+
+        let mut terminal = Terminal::new(TestBackend::new(4, 1)).unwrap();
+        let mut drawer = FrameDrawer::new();
+        for event in [key(KeyCode::Char('j')), key(KeyCode::Char('k'))] {
+            pipeline.push(event);
+            while let Some(event) = pipeline.pop() {
+                hook_context.reset_hook_index();
+                set_current_event(Some(event.into()));
+                drawer
+                    .draw(&mut terminal, |frame| {
+                        element.render_with_mount(frame.area(), frame)
+                    })
+                    .unwrap();
+            }
+        }
+        set_current_event(None);
+        pulse_core::hooks::clear_hook_context();
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![KeyCode::Down, KeyCode::Char('k')]
+        );
+    }
+
+    #[test]
+    fn test_remap_keeps_modifiers_and_kind() {
+        let remap = KeyRemap::new().map(KeyCode::Char('j'), KeyCode::Down);
+        let held = KeyEvent::new_with_kind(
+            KeyCode::Char('j'),
+            KeyModifiers::SHIFT,
+            KeyEventKind::Repeat,
+        );
+        assert_eq!(
+            remap.process(Event::Key(held)),
+            vec![Event::Key(KeyEvent::new_with_kind(
+                KeyCode::Down,
+                KeyModifiers::SHIFT,
+                KeyEventKind::Repeat
+            ))]
+        );
+    }
+
+    #[test]
+    fn test_middleware_drops_and_injects_in_order() {
+        let mut middleware = EventMiddlewares::default();
+        middleware.push(|event: Event| match event {
+            Event::Key(key) if key.code == KeyCode::F(1) => Vec::new(),
+            Event::Key(key) if key.code == KeyCode::F(2) => {
+                vec![Event::Key(key), self::key(KeyCode::Char('x'))]
+            }
+            event => vec![event],
+        });
+        middleware.push(KeyRemap::new().map(KeyCode::Char('x'), KeyCode::Char('y')));
+
+        let mut pipeline = EventPipeline::new(middleware);
+        pipeline.push(key(KeyCode::F(1)));
+        assert_eq!(pipeline.pop(), None);
+
+        pipeline.push(key(KeyCode::F(2)));
+        assert_eq!(pipeline.pop(), Some(key(KeyCode::F(2))));
+        assert_eq!(pipeline.pop(), Some(key(KeyCode::Char('y'))));
+        assert_eq!(pipeline.pop(), None);
+    }
+}
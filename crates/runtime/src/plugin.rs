@@ -0,0 +1,179 @@
+//! Extending the runtime lifecycle with plugins
+//!
+//! A [`Plugin`] is notified at fixed points of the render loop: before and
+//! after every frame, for every terminal event and once when the app exits.
+//! Plugins are registered with [`RuntimeConfig::plugin`] and run in the order
+//! they were registered, which makes them a place for cross-cutting features
+//! such as metrics or logging without changing the runtime itself.
+//!
+//! [`RuntimeConfig::plugin`]: crate::RuntimeConfig::plugin
+
+use crate::registry::Registry;
+use crossterm::event::Event;
+use pulse_core::redraw::FrameMetrics;
+use std::sync::Arc;
+
+/// Hooks into the runtime lifecycle
+///
+/// Every method does nothing by default, so a plugin only implements the
+/// ones it needs. Methods take `&self`; plugins keeping state use atomics or
+/// a lock.
+///
+/// # Example
+/// ```no_run
+/// use pulse_runtime::{Plugin, RuntimeConfig, render_with_config};
+/// use pulse_core::{Component, redraw::FrameMetrics};
+/// use ratatui::{Frame, layout::Rect};
+///
+/// struct SlowFrameLogger;
+///
+/// impl Plugin for SlowFrameLogger {
+///     fn after_frame(&self, metrics: &FrameMetrics) {
+///         if metrics.render_time.as_millis() > 16 {
+///             eprintln!("frame {} took {:?}", metrics.frame, metrics.render_time);
+///         }
+///     }
+/// }
+///
+/// #[derive(Clone)]
+/// struct App;
+///
+/// impl Component for App {
+///     fn render(&self, _area: Rect, _frame: &mut Frame) {}
+/// }
+///
+/// render_with_config(RuntimeConfig::new().plugin(SlowFrameLogger), || App).unwrap();
+/// ```
+pub trait Plugin: Send + Sync {
+    /// Called before frame number `frame` is rendered, counting from 1
+    fn before_frame(&self, _frame: u64) {}
+
+    /// Called after a frame was drawn to the terminal
+    fn after_frame(&self, _metrics: &FrameMetrics) {}
+
+    /// Called for every terminal event before it is dispatched
+    fn on_event(&self, _event: &Event) {}
+
+    /// Called once when the render loop ends, before the terminal is restored
+    fn on_exit(&self) {}
+}
+
+/// A shared plugin, so its state stays readable after registering it
+impl<P: Plugin + ?Sized> Plugin for Arc<P> {
+    fn before_frame(&self, frame: u64) {
+        (**self).before_frame(frame)
+    }
+
+    fn after_frame(&self, metrics: &FrameMetrics) {
+        (**self).after_frame(metrics)
+    }
+
+    fn on_event(&self, event: &Event) {
+        (**self).on_event(event)
+    }
+
+    fn on_exit(&self) {
+        (**self).on_exit()
+    }
+}
+
+/// Plugins registered with a [`RuntimeConfig`](crate::RuntimeConfig)
+///
+/// Clones share the plugin instances, and compare equal as long as no
+/// plugin was added to one of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Plugins(Registry<dyn Plugin>);
+
+impl Plugins {
+    /// Add a plugin after the already registered ones
+    pub fn push(&mut self, plugin: impl Plugin + 'static) {
+        self.0.push(Arc::new(plugin));
+    }
+
+    /// Number of registered plugins
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether no plugin is registered
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn before_frame(&self, frame: u64) {
+        self.0.iter().for_each(|plugin| plugin.before_frame(frame));
+    }
+
+    pub(crate) fn after_frame(&self, metrics: &FrameMetrics) {
+        self.0.iter().for_each(|plugin| plugin.after_frame(metrics));
+    }
+
+    pub(crate) fn on_event(&self, event: &Event) {
+        self.0.iter().for_each(|plugin| plugin.on_event(event));
+    }
+
+    pub(crate) fn on_exit(&self) {
+        self.0.iter().for_each(|plugin| plugin.on_exit());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FrameDrawer;
+    use ratatui::{Terminal, backend::TestBackend, widgets::Paragraph};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// Counts the frames it is notified of
+    #[derive(Default)]
+    struct FrameCounter {
+        started: AtomicU64,
+        finished: AtomicU64,
+    }
+
+    impl Plugin for FrameCounter {
+        fn before_frame(&self, frame: u64) {
+            assert_eq!(frame, self.started.load(Ordering::SeqCst) + 1);
+            self.started.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn after_frame(&self, metrics: &FrameMetrics) {
+            assert_eq!(metrics.frame, self.started.load(Ordering::SeqCst));
+            self.finished.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_after_frame_called_for_each_render() {
+        let counter = Arc::new(FrameCounter::default());
+        let mut plugins = Plugins::default();
+        plugins.push(counter.clone());
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let mut drawer = FrameDrawer::new().with_plugins(plugins);
+        for text in ["one", "two", "three"] {
+            drawer
+                .draw(&mut terminal, |frame| {
+                    frame.render_widget(Paragraph::new(text), frame.area())
+                })
+                .unwrap();
+            assert_eq!(
+                counter.finished.load(Ordering::SeqCst),
+                drawer.frame(),
+                "after_frame should run once per frame"
+            );
+        }
+        assert_eq!(counter.started.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_plugins_compare_by_instance() {
+        let mut plugins = Plugins::default();
+        plugins.push(FrameCounter::default());
+        assert_eq!(plugins, plugins.clone());
+
+        let mut other = Plugins::default();
+        other.push(FrameCounter::default());
+        assert_ne!(plugins, other);
+    }
+}
@@ -0,0 +1,89 @@
+//! Ordered lists of shared extensions
+//!
+//! Plugins and event middleware are trait objects registered with a
+//! [`RuntimeConfig`](crate::RuntimeConfig) and shared by every clone of it.
+//! [`Registry`] keeps them in registration order and gives the wrappers
+//! around it their `Debug` and equality.
+
+use std::fmt;
+use std::sync::Arc;
+
+/// Shared instances of `T`, in the order they were registered
+pub(crate) struct Registry<T: ?Sized>(Vec<Arc<T>>);
+
+impl<T: ?Sized> Registry<T> {
+    /// Add an instance after the already registered ones
+    pub(crate) fn push(&mut self, instance: Arc<T>) {
+        self.0.push(instance);
+    }
+
+    /// Number of registered instances
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether nothing is registered
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The registered instances, in registration order
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &T> {
+        self.0.iter().map(|instance| &**instance)
+    }
+}
+
+impl<T: ?Sized> Default for Registry<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T: ?Sized> Clone for Registry<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Trait objects can't be printed, so only their number is shown
+impl<T: ?Sized> fmt::Debug for Registry<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registry")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Trait objects can't be compared by value, so registries compare by
+/// identity: equal registries hold the same instances in the same order,
+/// as a config and its clones do
+impl<T: ?Sized> PartialEq for Registry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.0.iter().zip(&other.0).all(|(a, b)| Arc::ptr_eq(a, b))
+    }
+}
+
+impl<T: ?Sized> Eq for Registry<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registries_compare_by_identity() {
+        let mut registry = Registry::<str>::default();
+        registry.push(Arc::from("logger"));
+        let clone = registry.clone();
+        assert_eq!(registry, clone);
+        assert_eq!(format!("{:?}", clone), "Registry { len: 1 }");
+
+        // Equal values registered separately are different instances
+        let mut other = Registry::<str>::default();
+        other.push(Arc::from("logger"));
+        assert_ne!(registry, other);
+
+        registry.push(Arc::from("metrics"));
+        assert_ne!(registry, clone);
+        assert_eq!(registry.iter().collect::<Vec<_>>(), ["logger", "metrics"]);
+    }
+}
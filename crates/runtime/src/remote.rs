@@ -0,0 +1,464 @@
+//! Experimental rendering to a remote client over TCP
+//!
+//! [`serve`] runs a component tree with a network connection in place of the
+//! local terminal. Ratatui's diff decides which cells changed in a frame and
+//! only those are sent to the client; the client sends its terminal events
+//! back over the same connection. [`RemoteClient`] is the other end of the
+//! connection, and [`run_client`] mirrors a served app in the local terminal.
+//!
+//! Messages are newline-delimited JSON. The connection is neither encrypted
+//! nor authenticated, so only serve on trusted networks.
+
+use crate::config::RuntimeConfig;
+use crate::driver::{AppDriver, DriverBackend, Input};
+use crate::terminal::{restore_terminal, setup_terminal};
+use crossterm::event::{self, Event};
+use pulse_core::IntoElement;
+use ratatui::{
+    Terminal,
+    backend::{Backend, WindowSize},
+    buffer::{Buffer, Cell},
+    layout::{Position, Rect, Size},
+};
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// Message sent from the server to the client
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServerMessage {
+    /// Cells that changed since the previous frame, for a screen of the given size
+    Frame {
+        width: u16,
+        height: u16,
+        cells: Vec<(u16, u16, Cell)>,
+    },
+    /// The screen was cleared; the next frame contains every cell
+    Clear,
+}
+
+/// Message sent from the client to the server
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClientMessage {
+    /// First message of a connection, with the size of the client's screen
+    Hello { width: u16, height: u16 },
+    /// A terminal event on the client
+    Event(Event),
+}
+
+/// Write `message` as a single line of JSON
+fn send<M: Serialize>(writer: &mut impl Write, message: &M) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, message)?;
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
+
+/// Read the next line of JSON, or `None` once the peer closed the connection
+fn receive<M: for<'de> Deserialize<'de>>(reader: &mut impl BufRead) -> io::Result<Option<M>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(serde_json::from_str(&line)?))
+}
+
+/// Whether `error` means the peer went away
+fn is_disconnect(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::BrokenPipe
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+    )
+}
+
+/// Backend that sends the cells ratatui draws to a remote client
+struct RemoteBackend {
+    writer: BufWriter<TcpStream>,
+    size: Size,
+    cursor: Position,
+    pending: Vec<(u16, u16, Cell)>,
+}
+
+impl Backend for RemoteBackend {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        self.pending
+            .extend(content.map(|(x, y, cell)| (x, y, cell.clone())));
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        Ok(self.cursor)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.cursor = position.into();
+        Ok(())
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.pending.clear();
+        send(&mut self.writer, &ServerMessage::Clear)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        Ok(WindowSize {
+            columns_rows: self.size,
+            pixels: Size::default(),
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let message = ServerMessage::Frame {
+            width: self.size.width,
+            height: self.size.height,
+            cells: std::mem::take(&mut self.pending),
+        };
+        send(&mut self.writer, &message)
+    }
+}
+
+impl DriverBackend for RemoteBackend {
+    fn resize(&mut self, width: u16, height: u16) {
+        self.size = Size::new(width, height);
+    }
+}
+
+/// A listening socket that serves a Pulse app to one client at a time
+pub struct RemoteServer {
+    listener: TcpListener,
+    config: RuntimeConfig,
+}
+
+impl RemoteServer {
+    /// Listen for a client on `addr`
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            config: RuntimeConfig::default(),
+        })
+    }
+
+    /// Run served apps with `config` instead of the default configuration
+    pub fn with_config(mut self, config: RuntimeConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Address the server is listening on
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Wait for a client and run the app for it until it disconnects or exits
+    pub fn serve<F, T>(self, initializer: F) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: Fn() -> T,
+        T: IntoElement,
+    {
+        let (stream, peer) = self.listener.accept()?;
+        tracing::info!(target: "pulse_runtime::remote", "Serving remote client {}", peer);
+        stream.set_nodelay(true)?;
+
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let size = match receive(&mut reader)? {
+            Some(ClientMessage::Hello { width, height }) => Size::new(width, height),
+            Some(other) => {
+                return Err(format!("expected a hello message, got {:?}", other).into());
+            }
+            None => return Ok(()),
+        };
+
+        let events = spawn_event_reader(reader);
+        let backend = RemoteBackend {
+            writer: BufWriter::new(stream),
+            size,
+            cursor: Position::ORIGIN,
+            pending: Vec::new(),
+        };
+        let mut terminal = Terminal::new(backend)?;
+
+        match run_remote_app(&self.config, &mut terminal, &events, initializer) {
+            Err(error) if is_disconnect(&error) => Ok(()),
+            result => Ok(result?),
+        }
+    }
+}
+
+/// Forward events read from the client to a channel, closing it on disconnect
+fn spawn_event_reader(mut reader: BufReader<TcpStream>) -> Receiver<Event> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        while let Ok(Some(ClientMessage::Event(event))) = receive(&mut reader) {
+            if sender.send(event).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// Render loop of a served app, reading events from the client
+fn run_remote_app<F, T>(
+    config: &RuntimeConfig,
+    terminal: &mut Terminal<RemoteBackend>,
+    events: &Receiver<Event>,
+    initializer: F,
+) -> io::Result<()>
+where
+    F: Fn() -> T,
+    T: IntoElement,
+{
+    let mut driver = AppDriver::new(config, terminal)?;
+    let element = initializer().into_element();
+
+    while driver.step(terminal, &element, || {
+        Ok(match events.recv_timeout(Duration::from_millis(16)) {
+            Ok(event) => Input::Event(event),
+            Err(RecvTimeoutError::Timeout) => Input::Idle,
+            Err(RecvTimeoutError::Disconnected) => Input::Closed,
+        })
+    })? {}
+    Ok(())
+}
+
+/// Serve a component-based TUI application to a remote client on `addr`
+///
+/// Blocks until a client connects, then renders the app for that client
+/// until it disconnects or the app exits. Use [`run_client`] on the other
+/// machine to display it.
+///
+/// # Example
+/// ```no_run
+/// use pulse_runtime::remote::serve;
+/// use pulse_core::Component;
+/// use ratatui::{Frame, layout::Rect};
+///
+/// #[derive(Clone)]
+/// struct MyComponent;
+///
+/// impl Component for MyComponent {
+///     fn render(&self, _area: Rect, _frame: &mut Frame) {}
+/// }
+///
+/// serve(|| MyComponent, "0.0.0.0:7000").unwrap();
+/// ```
+pub fn serve<F, T>(
+    initializer: F,
+    addr: impl ToSocketAddrs,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    F: Fn() -> T,
+    T: IntoElement,
+{
+    RemoteServer::bind(addr)?.serve(initializer)
+}
+
+/// Client side of a remote rendering connection
+///
+/// Keeps a copy of the server's screen in a [`Buffer`], updated by each
+/// message received.
+pub struct RemoteClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    buffer: Buffer,
+}
+
+impl RemoteClient {
+    /// Connect to a server, announcing a screen of `size`
+    pub fn connect(addr: impl ToSocketAddrs, size: Size) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+
+        let mut client = Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            buffer: Buffer::empty(Rect::new(0, 0, size.width, size.height)),
+        };
+        send(
+            &mut client.writer,
+            &ClientMessage::Hello {
+                width: size.width,
+                height: size.height,
+            },
+        )?;
+        Ok(client)
+    }
+
+    /// Send a terminal event to the server
+    pub fn send_event(&mut self, event: Event) -> io::Result<()> {
+        send(&mut self.writer, &ClientMessage::Event(event))
+    }
+
+    /// Wait for the next message and apply it to the buffer
+    ///
+    /// Returns `None` once the server closed the connection.
+    pub fn receive(&mut self) -> io::Result<Option<ServerMessage>> {
+        let Some(message) = receive(&mut self.reader)? else {
+            return Ok(None);
+        };
+
+        match &message {
+            ServerMessage::Frame {
+                width,
+                height,
+                cells,
+            } => {
+                let area = Rect::new(0, 0, *width, *height);
+                if self.buffer.area != area {
+                    self.buffer.resize(area);
+                }
+                for (x, y, cell) in cells {
+                    if let Some(target) = self.buffer.cell_mut((*x, *y)) {
+                        *target = cell.clone();
+                    }
+                }
+            }
+            ServerMessage::Clear => self.buffer.reset(),
+        }
+        Ok(Some(message))
+    }
+
+    /// The client's copy of the server's screen
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// A second handle for sending events from another thread
+    fn event_sender(&self) -> io::Result<TcpStream> {
+        self.writer.try_clone()
+    }
+}
+
+/// Display an app served on `addr` in the local terminal
+///
+/// Local terminal events are forwarded to the server. Returns when the
+/// server closes the connection.
+pub fn run_client(addr: impl ToSocketAddrs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut terminal = setup_terminal()?;
+    let size = terminal.terminal_mut().size()?;
+    let mut client = RemoteClient::connect(addr, size)?;
+
+    // Forward local events from a separate thread, since reading frames blocks
+    let mut sender = client.event_sender()?;
+    std::thread::spawn(move || {
+        while let Ok(event) = event::read() {
+            if send(&mut sender, &ClientMessage::Event(event)).is_err() {
+                break;
+            }
+        }
+    });
+
+    let result = (|| -> io::Result<()> {
+        while let Some(message) = client.receive()? {
+            let backend = terminal.terminal_mut().backend_mut();
+            match message {
+                ServerMessage::Frame { cells, .. } => {
+                    let buffer = client.buffer();
+                    backend.draw(cells.iter().filter_map(|(x, y, _)| {
+                        buffer.cell((*x, *y)).map(|cell| (*x, *y, cell))
+                    }))?;
+                    Backend::flush(backend)?;
+                }
+                ServerMessage::Clear => backend.clear()?,
+            }
+        }
+        Ok(())
+    })();
+
+    restore_terminal()?;
+    Ok(result?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use pulse_core::{Component, hooks::event::filtered::use_key_event, hooks::state::use_state};
+    use ratatui::{Frame, widgets::Paragraph};
+
+    #[derive(Clone)]
+    struct Counter;
+
+    impl Component for Counter {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let (count, set_count) = use_state(|| 0);
+            if let Some(key) = use_key_event()
+                && key.code == KeyCode::Up
+            {
+                set_count.update(|count| count + 1);
+            }
+            frame.render_widget(Paragraph::new(format!("Count: {}", count.get())), area);
+        }
+    }
+
+    fn first_row(buffer: &Buffer) -> String {
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, 0)].symbol())
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    /// Receive messages until the first row of the screen reads `expected`
+    ///
+    /// Panics if that takes longer than a few seconds.
+    fn wait_for(client: &mut RemoteClient, expected: &str) {
+        let timeout = Duration::from_secs(5);
+        let deadline = std::time::Instant::now() + timeout;
+        while first_row(client.buffer()) != expected {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            assert!(!remaining.is_zero(), "timed out waiting for {expected:?}");
+            client
+                .reader
+                .get_ref()
+                .set_read_timeout(Some(remaining))
+                .unwrap();
+            client
+                .receive()
+                .unwrap_or_else(|error| panic!("waiting for {expected:?} failed: {error}"))
+                .expect("server closed the connection early");
+        }
+    }
+
+    #[test]
+    fn test_frame_and_key_event_over_loopback() {
+        let server = RemoteServer::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let handle =
+            std::thread::spawn(move || server.serve(|| Counter).map_err(|e| e.to_string()));
+
+        let mut client = RemoteClient::connect(addr, Size::new(20, 2)).unwrap();
+        wait_for(&mut client, "Count: 0");
+        assert_eq!(client.buffer().area, Rect::new(0, 0, 20, 2));
+
+        client
+            .send_event(Event::Key(KeyEvent::from(KeyCode::Up)))
+            .unwrap();
+        wait_for(&mut client, "Count: 1");
+
+        // Disconnecting ends the served app
+        drop(client);
+        handle.join().unwrap().unwrap();
+    }
+}
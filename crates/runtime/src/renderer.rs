@@ -1,15 +1,19 @@
+use crate::config::RuntimeConfig;
+use crate::driver::{AppDriver, Input};
 use crate::terminal::{restore_terminal, setup_terminal};
 use crossterm::event;
-use pulse_core::{
-    Component, IntoElement,
-    component::cleanup_unmounted,
-    exit::should_exit,
-    hooks::{
-        HookContext,
-        event::{global_events::process_global_event, set_current_event},
-    },
-};
-use std::{rc::Rc, time::Duration};
+use pulse_core::IntoElement;
+use std::time::Duration;
+
+/// Waits up to `timeout` for the next event from the terminal
+fn read_terminal(timeout: Duration) -> std::io::Result<Input> {
+    if event::poll(timeout)?
+        && let Ok(event) = event::read()
+    {
+        return Ok(Input::Event(event));
+    }
+    Ok(Input::Idle)
+}
 
 /// Renders a component-based TUI application with hooks support
 ///
@@ -37,7 +41,10 @@ use std::{rc::Rc, time::Duration};
 ///
 /// render(|| Counter).unwrap();
 /// ```
-pub(crate) fn render_with_hooks<F, T>(initializer: F) -> Result<(), Box<dyn std::error::Error>>
+pub(crate) fn render_with_hooks<F, T>(
+    config: RuntimeConfig,
+    initializer: F,
+) -> Result<(), Box<dyn std::error::Error>>
 where
     F: Fn() -> T,
     T: IntoElement,
@@ -47,62 +54,18 @@ where
 
     // Initialize terminal backend
     let mut terminal = setup_terminal()?;
-
-    // Create a new hook context for this component tree
-    let hook_context = Rc::new(HookContext::new());
-
-    // Set the hook context for this thread
-    pulse_core::hooks::set_hook_context(hook_context.clone());
+    let mut driver = AppDriver::new(&config, terminal.terminal_mut())?;
 
     // Create the element instance and convert it
     let element = initializer().into_element();
 
-    // Main render loop
-    let mut running = true;
-    while running {
-        // Reset hook index before each render
-        hook_context.reset_hook_index();
-
-        // Handle events with a small timeout to prevent blocking
-        if event::poll(Duration::from_millis(16))? {
-            if let Ok(event) = event::read() {
-                // Process key events
-                if let event::Event::Key(key_event) = &event {
-                    // First try to process as a global event
-                    let processed = process_global_event(key_event);
-
-                    // If not processed as a global event, make it available to components
-                    if !processed {
-                        set_current_event(Some(event.into()));
-
-                        // Check for exit after component event handling
-                        if should_exit() {
-                            running = false;
-                        }
-                    }
-                }
-            }
-        } else {
-            // No events, clear the current event
-            set_current_event(None);
-        }
-
-        // Render the component using render_with_mount to ensure on_mount is called
-        terminal.draw(|frame| {
-            element.render_with_mount(frame.area(), frame);
-        })?;
+    // Main render loop, handling events with a small timeout to prevent blocking
+    while driver.step(terminal.terminal_mut(), &element, || {
+        read_terminal(Duration::from_millis(16))
+    })? {}
 
-        // Clean up unmounted components after render
-        cleanup_unmounted();
-    }
-
-    // Clear the current event
-    set_current_event(None);
-
-    // Clean up the hook context
-    pulse_core::hooks::clear_hook_context();
-
-    // Restore terminal state
+    // Plugins see the exit before the terminal state is restored
+    drop(driver);
     restore_terminal()?;
 
     Ok(())
@@ -135,7 +98,35 @@ where
     F: Fn() -> T,
     T: IntoElement,
 {
-    render_with_hooks(initializer)
+    render_with_hooks(RuntimeConfig::default(), initializer)
+}
+
+/// Renders a component-based TUI application with a custom [`RuntimeConfig`]
+///
+/// # Example
+/// ```no_run
+/// use pulse_runtime::{RuntimeConfig, render_with_config};
+/// use pulse_core::Component;
+/// use ratatui::{Frame, layout::Rect};
+///
+/// #[derive(Clone)]
+/// struct MyComponent;
+///
+/// impl Component for MyComponent {
+///     fn render(&self, _area: Rect, _frame: &mut Frame) {}
+/// }
+///
+/// render_with_config(RuntimeConfig::new().max_concurrent_tasks(8), || MyComponent).unwrap();
+/// ```
+pub fn render_with_config<F, T>(
+    config: RuntimeConfig,
+    initializer: F,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    F: Fn() -> T,
+    T: IntoElement,
+{
+    render_with_hooks(config, initializer)
 }
 
 /// Renders a component-based TUI application asynchronously with hooks support
@@ -167,6 +158,7 @@ where
 /// # }
 /// ```
 pub(crate) async fn render_async_with_hooks<F, Fut, T>(
+    config: RuntimeConfig,
     app_fn: F,
 ) -> Result<(), Box<dyn std::error::Error>>
 where
@@ -179,72 +171,21 @@ where
 
     // Initialize terminal backend
     let mut terminal = setup_terminal()?;
-
-    // Create a new hook context for this component tree
-    let hook_context = Rc::new(HookContext::new());
-
-    // Set the hook context for this thread
-    pulse_core::hooks::set_hook_context(hook_context.clone());
+    let mut driver = AppDriver::new(&config, terminal.terminal_mut())?;
 
     // Create the element instance and convert it
     let element = app_fn().await.into_element();
 
-    // Main render loop
-    loop {
-        // Reset hook index before each render
-        hook_context.reset_hook_index();
-
-        // Get terminal size for rendering
-        let size = terminal.size()?;
-
-        // Handle events with a small timeout to prevent blocking
-        if event::poll(Duration::from_millis(16))? {
-            if let Ok(event) = event::read() {
-                // Process key events
-                if let event::Event::Key(key_event) = &event {
-                    // First try to process as a global event
-                    let processed = process_global_event(key_event);
-
-                    // If not processed as a global event, make it available to components
-                    if !processed {
-                        set_current_event(Some(event.into()));
-
-                        // Check for exit after component event handling
-                        if should_exit() {
-                            break;
-                        }
-                    }
-                }
-            }
-        } else {
-            // No events, clear the current event
-            set_current_event(None);
-        }
-
-        // If no events and exit is requested, quit
-        if should_exit() {
-            break;
-        }
-
-        // Render the component using render_with_mount to ensure on_mount is called
-        terminal.draw(|frame| {
-            element.render_with_mount(size, frame);
-        })?;
-
-        // Clean up unmounted components after render
-        cleanup_unmounted();
-
+    // Main render loop, handling events with a small timeout to prevent blocking
+    while driver.step(terminal.terminal_mut(), &element, || {
+        read_terminal(Duration::from_millis(16))
+    })? {
         // Small delay to prevent high CPU usage
         tokio::time::sleep(Duration::from_millis(16)).await; // ~60 FPS
     }
 
-    // Clear the current event
-    set_current_event(None);
-
-    // Clean up the hook context
-    pulse_core::hooks::clear_hook_context();
-
-    // Restore terminal state
+    // Plugins see the exit before the terminal state is restored
+    drop(driver);
     restore_terminal()?;
 
     Ok(())
@@ -280,5 +221,37 @@ where
     Fut: std::future::Future<Output = T> + Send + 'static,
     T: IntoElement + 'static,
 {
-    render_async_with_hooks(app_fn).await
+    render_async_with_hooks(RuntimeConfig::default(), app_fn).await
+}
+
+/// Renders a component-based TUI application asynchronously with a custom [`RuntimeConfig`]
+///
+/// # Example
+/// ```no_run
+/// use pulse_runtime::{RuntimeConfig, render_async_with_config};
+/// use pulse_core::Component;
+/// use ratatui::{Frame, layout::Rect};
+///
+/// #[derive(Clone)]
+/// struct MyComponent;
+///
+/// impl Component for MyComponent {
+///     fn render(&self, _area: Rect, _frame: &mut Frame) {}
+/// }
+///
+/// # async fn example() {
+/// let config = RuntimeConfig::new().max_concurrent_tasks(8);
+/// render_async_with_config(config, || async { MyComponent }).await.unwrap();
+/// # }
+/// ```
+pub async fn render_async_with_config<F, Fut, T>(
+    config: RuntimeConfig,
+    app_fn: F,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = T> + Send + 'static,
+    T: IntoElement + 'static,
+{
+    render_async_with_hooks(config, app_fn).await
 }
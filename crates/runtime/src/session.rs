@@ -0,0 +1,486 @@
+//! Recording input sessions and replaying them against an app
+//!
+//! A session file holds every terminal event an app received, with the frame
+//! and time at which it arrived. Enable recording with
+//! [`RuntimeConfig::record_session`](crate::RuntimeConfig::record_session);
+//! [`replay_session`] then feeds the same events to the app in a headless
+//! terminal of the recorded size, which makes user-reported issues easy to
+//! reproduce.
+//!
+//! Replays are deterministic in frames rather than wall time: each event is
+//! delivered in the same frame it was recorded in, however long rendering
+//! takes. The replay runs on a [`ManualClock`] that moves to the recorded
+//! time of each event before it is delivered, so hooks reading the
+//! [`clock`](pulse_core::clock) see the recorded timing too. Terminal events
+//! posted with `post_event` are recorded like input, since they often come
+//! from threads or sockets that don't run during a replay; the replay
+//! delivers them from the recording and drops the copies the app posts
+//! again. Custom events are not recorded, since the app produces them again
+//! while replaying; the replay reads them from an [`AppEventScope`] of its
+//! own, leaving the process-wide channel to other apps.
+//!
+//! A replayed session can also be exported as an asciinema recording with
+//! [`session_to_cast`](crate::cast::session_to_cast).
+//!
+//! The file format is newline-delimited JSON: a [`SessionHeader`] line
+//! followed by one [`SessionEvent`] line per event.
+
+use crate::config::RuntimeConfig;
+use crate::driver::{AppDriver, DriverBackend, Input};
+use crossterm::event::Event;
+use pulse_core::{
+    IntoElement, clock::ManualClock, exit::exit_guard, hooks::event::app_events::AppEventScope,
+};
+use ratatui::{Terminal, backend::TestBackend, buffer::Buffer, layout::Size};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Version of the session file format
+pub const SESSION_FORMAT_VERSION: u32 = 1;
+
+/// First line of a session file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionHeader {
+    /// Version of the file format
+    pub version: u32,
+    /// Width of the terminal when recording started
+    pub width: u16,
+    /// Height of the terminal when recording started
+    pub height: u16,
+}
+
+/// A terminal event received while recording
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionEvent {
+    /// Milliseconds since recording started
+    pub at_ms: u64,
+    /// Number of frames stepped before the event was handled, counting
+    /// frames that had nothing to draw
+    pub frame: u64,
+    /// The event itself
+    pub event: Event,
+}
+
+impl SessionEvent {
+    /// An event handled after `frame` frames, at no particular time
+    ///
+    /// Convenient for building input to replay in tests.
+    pub fn at_frame(frame: u64, event: Event) -> Self {
+        Self {
+            at_ms: 0,
+            frame,
+            event,
+        }
+    }
+}
+
+/// Writes the events of a running app to a session file
+pub struct SessionRecorder {
+    writer: BufWriter<File>,
+    started: Instant,
+}
+
+impl SessionRecorder {
+    /// Create the session file at `path` for a terminal of `size`
+    pub fn create(path: impl AsRef<Path>, size: Size) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        let header = SessionHeader {
+            version: SESSION_FORMAT_VERSION,
+            width: size.width,
+            height: size.height,
+        };
+        write_line(&mut writer, &header)?;
+        Ok(Self {
+            writer,
+            started: Instant::now(),
+        })
+    }
+
+    /// Append `event`, handled after `frame` frames were stepped
+    ///
+    /// Each event is flushed immediately so the file is complete even if the
+    /// app crashes.
+    pub fn record(&mut self, frame: u64, event: &Event) -> io::Result<()> {
+        let entry = SessionEvent {
+            at_ms: self.started.elapsed().as_millis() as u64,
+            frame,
+            event: event.clone(),
+        };
+        write_line(&mut self.writer, &entry)
+    }
+}
+
+/// Start recording if the configuration names a session file
+pub(crate) fn start_recording(
+    config: &RuntimeConfig,
+    size: Size,
+) -> io::Result<Option<SessionRecorder>> {
+    config
+        .session_recording
+        .as_ref()
+        .map(|path| SessionRecorder::create(path, size))
+        .transpose()
+}
+
+/// Record `event` if a recording is running, logging failures
+pub(crate) fn record_event(recorder: &mut Option<SessionRecorder>, frame: u64, event: &Event) {
+    if let Some(recorder) = recorder
+        && let Err(error) = recorder.record(frame, event)
+    {
+        tracing::warn!("Failed to record session event: {}", error);
+    }
+}
+
+fn write_line<T: Serialize>(writer: &mut impl Write, value: &T) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, value)?;
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
+
+/// Read a session file into its header and events
+pub fn load_session(path: impl AsRef<Path>) -> io::Result<(SessionHeader, Vec<SessionEvent>)> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let header: SessionHeader = match lines.next() {
+        Some(line) => serde_json::from_str(&line?)?,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "session file is empty",
+            ));
+        }
+    };
+    if header.version != SESSION_FORMAT_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported session format version {}", header.version),
+        ));
+    }
+
+    let mut events = Vec::new();
+    for line in lines {
+        let line = line?;
+        if !line.trim().is_empty() {
+            events.push(serde_json::from_str(&line)?);
+        }
+    }
+    Ok((header, events))
+}
+
+/// Replay the session recorded at `path` against an app, headlessly
+///
+/// The app renders into an in-memory terminal of the recorded size. Each
+/// recorded event is delivered in the frame it was recorded in, and one more
+/// frame is drawn after the last event so its state updates show up. Returns
+/// the final screen.
+///
+/// # Example
+/// ```no_run
+/// use pulse_runtime::session::replay_session;
+/// use pulse_core::Component;
+/// use ratatui::{Frame, layout::Rect};
+///
+/// #[derive(Clone)]
+/// struct App;
+///
+/// impl Component for App {
+///     fn render(&self, _area: Rect, _frame: &mut Frame) {}
+/// }
+///
+/// let screen = replay_session("bug-report.session", || App).unwrap();
+/// println!("{:?}", screen);
+/// ```
+pub fn replay_session<F, T>(
+    path: impl AsRef<Path>,
+    initializer: F,
+) -> Result<Buffer, Box<dyn std::error::Error>>
+where
+    F: Fn() -> T,
+    T: IntoElement,
+{
+    replay_session_with_config(RuntimeConfig::default(), path, initializer)
+}
+
+/// Replay a session like [`replay_session`], with a custom [`RuntimeConfig`]
+///
+/// The replay runs the middleware and plugins of `config`, as the recorded
+/// app did. Its signal handling, hot-reload snapshot and session recording
+/// settings are ignored.
+pub fn replay_session_with_config<F, T>(
+    config: RuntimeConfig,
+    path: impl AsRef<Path>,
+    initializer: F,
+) -> Result<Buffer, Box<dyn std::error::Error>>
+where
+    F: Fn() -> T,
+    T: IntoElement,
+{
+    let (header, events) = load_session(path)?;
+    let mut terminal = Terminal::new(TestBackend::new(header.width, header.height))?;
+    replay_events(&config, &mut terminal, events, None, initializer, |_, _| {
+        Ok(())
+    })?;
+    Ok(terminal.backend().buffer().clone())
+}
+
+/// Replay `events` against an app for exactly `frames` frames, headlessly
+///
+/// Unlike [`replay_session`], rendering goes on after the last event until
+/// `frames` frames were stepped, so the final screen doesn't depend on when
+/// the input ends. This suits golden-frame tests of whole apps. Stops early
+/// if the app exits.
+///
+/// # Example
+/// ```rust
+/// use crossterm::event::{Event, KeyCode, KeyEvent};
+/// use pulse_core::Component;
+/// use pulse_runtime::session::{SessionEvent, replay_frames};
+/// use ratatui::{Frame, buffer::Buffer, layout::{Rect, Size}, widgets::Paragraph};
+///
+/// #[derive(Clone)]
+/// struct Hello;
+///
+/// impl Component for Hello {
+///     fn render(&self, area: Rect, frame: &mut Frame) {
+///         frame.render_widget(Paragraph::new("hi"), area);
+///     }
+/// }
+///
+/// let events = vec![SessionEvent::at_frame(1, Event::Key(KeyEvent::from(KeyCode::Enter)))];
+/// let screen = replay_frames(Size::new(5, 1), events, 3, || Hello).unwrap();
+/// assert_eq!(screen, Buffer::with_lines(["hi   "]));
+/// ```
+pub fn replay_frames<F, T>(
+    size: Size,
+    events: Vec<SessionEvent>,
+    frames: u64,
+    initializer: F,
+) -> io::Result<Buffer>
+where
+    F: Fn() -> T,
+    T: IntoElement,
+{
+    let mut terminal = Terminal::new(TestBackend::new(size.width, size.height))?;
+    replay_events(
+        &RuntimeConfig::default(),
+        &mut terminal,
+        events,
+        Some(frames),
+        initializer,
+        |_, _| Ok(()),
+    )?;
+    Ok(terminal.backend().buffer().clone())
+}
+
+/// Drive an app with recorded events, as the live render loop would
+///
+/// Without a `frame_limit`, replaying ends one frame after the last event.
+/// The thread's clock is moved to the recorded time of each event before it
+/// is delivered. `after_frame` runs after every frame with the recorded time
+/// of the last delivered event.
+pub(crate) fn replay_events<B, F, T>(
+    config: &RuntimeConfig,
+    terminal: &mut Terminal<B>,
+    events: Vec<SessionEvent>,
+    frame_limit: Option<u64>,
+    initializer: F,
+    mut after_frame: impl FnMut(&mut Terminal<B>, Duration) -> io::Result<()>,
+) -> io::Result<()>
+where
+    B: DriverBackend,
+    F: Fn() -> T,
+    T: IntoElement,
+{
+    // A replay neither records itself, restores a snapshot nor catches signals
+    let config = RuntimeConfig {
+        handle_signals: false,
+        session_recording: None,
+        #[cfg(all(feature = "state-snapshot", debug_assertions))]
+        hot_reload_snapshot: None,
+        ..config.clone()
+    };
+
+    let _exit_guard = exit_guard();
+    let _app_events = AppEventScope::start();
+    let clock = ManualClock::start();
+    let mut driver = AppDriver::new(&config, terminal)?.replaying();
+
+    let element = initializer().into_element();
+    let mut events = events.into_iter().peekable();
+    let mut settled = false;
+    let mut time = Duration::ZERO;
+
+    loop {
+        let frame = driver.frame();
+        if frame_limit.is_some_and(|limit| frame >= limit) {
+            return Ok(());
+        }
+
+        let running = driver.step(terminal, &element, || {
+            Ok(match events.peek() {
+                Some(entry) if entry.frame <= frame => {
+                    let entry = events.next().expect("peeked event");
+                    let at = Duration::from_millis(entry.at_ms);
+                    clock.advance(at.saturating_sub(time));
+                    time = time.max(at);
+                    Input::Event(entry.event)
+                }
+                Some(_) => Input::Idle,
+                None if settled && frame_limit.is_none() => Input::Closed,
+                None => {
+                    settled = true;
+                    Input::Idle
+                }
+            })
+        })?;
+        if !running {
+            return Ok(());
+        }
+        after_frame(terminal, time)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use pulse_core::{
+        Component,
+        hooks::{
+            batch::RenderScope,
+            event::{
+                app_events::{AppEvent, post_event},
+                filtered::use_key_event,
+            },
+            interval::use_frame_interval,
+            state::use_state,
+        },
+    };
+    use ratatui::{Frame, layout::Rect, widgets::Paragraph};
+
+    #[derive(Clone)]
+    struct Counter;
+
+    impl Component for Counter {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let (count, set_count) = use_state(|| 0);
+            if let Some(key) = use_key_event() {
+                match key.code {
+                    KeyCode::Up => set_count.update(|count| count + 1),
+                    KeyCode::Down => set_count.update(|count| count - 1),
+                    _ => {}
+                }
+            }
+            frame.render_widget(Paragraph::new(format!("Count: {}", count.get())), area);
+        }
+    }
+
+    #[test]
+    fn test_recorded_session_replays_to_the_same_state() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+
+        let mut recorder = SessionRecorder::create(file.path(), Size::new(12, 1)).unwrap();
+        for (frame, code) in [
+            (1, KeyCode::Up),
+            (3, KeyCode::Up),
+            (4, KeyCode::Down),
+            (8, KeyCode::Up),
+        ] {
+            recorder
+                .record(frame, &Event::Key(KeyEvent::from(code)))
+                .unwrap();
+        }
+        drop(recorder);
+
+        let (header, events) = load_session(file.path()).unwrap();
+        assert_eq!((header.width, header.height), (12, 1));
+        assert_eq!(events.len(), 4);
+
+        let first = replay_session(file.path(), || Counter).unwrap();
+        let second = replay_session(file.path(), || Counter).unwrap();
+
+        assert_eq!(first, Buffer::with_lines(["Count: 2    "]));
+        assert_eq!(first, second);
+    }
+
+    /// A counter that presses Up itself on its first render
+    #[derive(Clone)]
+    struct SelfStarting;
+
+    impl Component for SelfStarting {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let (started, set_started) = use_state(|| false);
+            if !started.get() {
+                post_event(AppEvent::Terminal(Event::Key(KeyEvent::from(KeyCode::Up))));
+                set_started.set(true);
+            }
+            Counter.render(area, frame);
+        }
+    }
+
+    #[test]
+    fn test_posted_input_is_recorded_and_replayed_once() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        {
+            let _renders = RenderScope::start();
+            let _app_events = AppEventScope::start();
+            let config = RuntimeConfig::new().record_session(file.path());
+            let mut terminal = Terminal::new(TestBackend::new(12, 1)).unwrap();
+            let mut driver = AppDriver::new(&config, &mut terminal).unwrap();
+            let element = SelfStarting.into_element();
+            for _ in 0..3 {
+                driver
+                    .step(&mut terminal, &element, || Ok(Input::Idle))
+                    .unwrap();
+            }
+            assert_eq!(
+                terminal.backend().buffer(),
+                &Buffer::with_lines(["Count: 1    "])
+            );
+        }
+
+        let (_, events) = load_session(file.path()).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            replay_session(file.path(), || SelfStarting).unwrap(),
+            Buffer::with_lines(["Count: 1    "])
+        );
+    }
+
+    /// Counts the seconds that passed on the clock
+    #[derive(Clone)]
+    struct Stopwatch;
+
+    impl Component for Stopwatch {
+        fn render(&self, area: Rect, frame: &mut Frame) {
+            let (seconds, set_seconds) = use_state(|| 0);
+            use_frame_interval(
+                move || set_seconds.update(|seconds| seconds + 1),
+                Duration::from_secs(1),
+            );
+            frame.render_widget(Paragraph::new(format!("{}s", seconds.get())), area);
+        }
+    }
+
+    #[test]
+    fn test_replay_moves_the_clock_to_recorded_times() {
+        let events = [(1, 1_200), (2, 2_400), (3, 3_600)]
+            .into_iter()
+            .map(|(frame, at_ms)| SessionEvent {
+                at_ms,
+                frame,
+                event: Event::FocusGained,
+            })
+            .collect::<Vec<_>>();
+
+        // Replaying takes milliseconds, but the stopwatch sees 3.6 recorded seconds
+        let screen = replay_frames(Size::new(4, 1), events.clone(), 6, || Stopwatch).unwrap();
+        assert_eq!(screen, Buffer::with_lines(["3s  "]));
+        assert_eq!(
+            replay_frames(Size::new(4, 1), events, 6, || Stopwatch).unwrap(),
+            screen
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Graceful shutdown on termination signals
+//!
+//! Without a handler, SIGINT and SIGTERM kill the process on the spot and
+//! leave the shell in raw mode on the alternate screen. When enabled in the
+//! [`RuntimeConfig`](crate::RuntimeConfig), the runtime catches these signals
+//! and leaves the render loop through the regular exit path instead, which
+//! unmounts the component tree and restores the terminal.
+//!
+//! Note that Ctrl+C in raw mode arrives as a key event, not as SIGINT.
+
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Number of installed [`ShutdownSignals`] and the flag making SIGINT and
+/// SIGTERM take their default action again once it drops to zero
+#[cfg(unix)]
+static DEFAULT_ACTION: std::sync::Mutex<Option<(usize, Arc<AtomicBool>)>> =
+    std::sync::Mutex::new(None);
+
+/// Count an installed [`ShutdownSignals`] until the matching [`release_default`]
+///
+/// Unregistering a handler doesn't give the signal its default action back,
+/// so the first call registers handlers that run it while nothing is
+/// installed.
+#[cfg(unix)]
+fn suspend_default() -> io::Result<()> {
+    use signal_hook::consts::{SIGINT, SIGTERM};
+
+    let mut default_action = DEFAULT_ACTION.lock().unwrap_or_else(|e| e.into_inner());
+    let (installed, restore) = match default_action.as_mut() {
+        Some(state) => state,
+        None => {
+            let restore = Arc::new(AtomicBool::new(true));
+            for signal in [SIGINT, SIGTERM] {
+                signal_hook::flag::register_conditional_default(signal, restore.clone())?;
+            }
+            default_action.insert((0, restore))
+        }
+    };
+    *installed += 1;
+    restore.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Undo one [`suspend_default`], restoring the default action after the last
+#[cfg(unix)]
+fn release_default() {
+    let mut default_action = DEFAULT_ACTION.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((installed, restore)) = default_action.as_mut() {
+        *installed -= 1;
+        if *installed == 0 {
+            restore.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Flags the arrival of SIGINT or SIGTERM while installed
+pub(crate) struct ShutdownSignals {
+    received: Arc<AtomicBool>,
+    #[cfg(unix)]
+    registrations: Vec<signal_hook::SigId>,
+}
+
+impl ShutdownSignals {
+    /// Catch SIGINT and SIGTERM until the returned value is dropped
+    ///
+    /// Once the last installed value is dropped, the signals terminate the
+    /// process again.
+    #[cfg(unix)]
+    pub(crate) fn install() -> io::Result<Self> {
+        use signal_hook::consts::{SIGINT, SIGTERM};
+
+        let received = Arc::new(AtomicBool::new(false));
+        let registrations: Vec<_> = [SIGINT, SIGTERM]
+            .into_iter()
+            .map(|signal| signal_hook::flag::register(signal, received.clone()))
+            .collect::<io::Result<_>>()?;
+        if let Err(error) = suspend_default() {
+            for registration in registrations {
+                signal_hook::low_level::unregister(registration);
+            }
+            return Err(error);
+        }
+
+        Ok(Self {
+            received,
+            registrations,
+        })
+    }
+
+    /// Signals are not caught on this platform
+    #[cfg(not(unix))]
+    pub(crate) fn install() -> io::Result<Self> {
+        Ok(Self {
+            received: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    /// Returns true once a termination signal arrived
+    pub(crate) fn received(&self) -> bool {
+        self.received.load(Ordering::Acquire)
+    }
+}
+
+#[cfg(unix)]
+impl Drop for ShutdownSignals {
+    fn drop(&mut self) {
+        // Stop flagging signals once the runtime has shut down, and let
+        // them terminate the process again
+        for registration in self.registrations.drain(..) {
+            signal_hook::low_level::unregister(registration);
+        }
+        release_default();
+    }
+}
+
+/// Install the signal handlers if `enabled`
+pub(crate) fn install_if(enabled: bool) -> io::Result<Option<ShutdownSignals>> {
+    enabled.then(ShutdownSignals::install).transpose()
+}
+
+/// Returns true if the render loop should stop because of a signal
+///
+/// A received signal is turned into an exit request, so the loop leaves
+/// through the same path as an exit requested by a component.
+pub(crate) fn shutdown_requested(signals: Option<&ShutdownSignals>) -> bool {
+    let received = signals.is_some_and(ShutdownSignals::received);
+    if received {
+        pulse_core::request_exit();
+    }
+    received
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use signal_hook::consts::SIGTERM;
+
+    #[test]
+    fn test_simulated_sigterm_requests_shutdown() {
+        let _guard = pulse_core::exit_guard();
+        let signals = ShutdownSignals::install().unwrap();
+        assert!(!shutdown_requested(Some(&signals)));
+
+        // The handler keeps the test process alive and flags the signal
+        signal_hook::low_level::raise(SIGTERM).unwrap();
+        assert!(shutdown_requested(Some(&signals)));
+        assert!(pulse_core::should_exit());
+    }
+
+    /// Set in the child process of the test below
+    const DEFAULT_CHILD_ENV: &str = "PULSE_SIGNALS_DEFAULT_CHILD";
+
+    #[test]
+    fn test_dropping_restores_default_termination() {
+        use std::os::unix::process::ExitStatusExt;
+
+        // SIGTERM has to kill a process, so it is raised in a child running this test
+        if std::env::var_os(DEFAULT_CHILD_ENV).is_some() {
+            drop(ShutdownSignals::install().unwrap());
+            signal_hook::low_level::raise(SIGTERM).unwrap();
+            panic!("SIGTERM was ignored after dropping the handlers");
+        }
+
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "signals::tests::test_dropping_restores_default_termination",
+            ])
+            .env(DEFAULT_CHILD_ENV, "1")
+            .output()
+            .unwrap();
+        assert_eq!(
+            output.status.signal(),
+            Some(SIGTERM),
+            "child exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+
+    #[test]
+    fn test_disabled_handling_ignores_signals() {
+        let signals = install_if(false).unwrap();
+        assert!(signals.is_none());
+        assert!(!shutdown_requested(signals.as_ref()));
+    }
+}